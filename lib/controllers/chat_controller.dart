@@ -149,6 +149,9 @@ class ChatController {
     _ref.read(activeSessionIdProvider.notifier).state = id;
     _ref.read(messagesProvider.notifier).switchToSession(id);
 
+    // Membership lives in the project store, so register the session there
+    _ref.read(projectsProvider.notifier).addSession(projectId, id);
+
     // Bind session to the project's default role so all sessions
     // within the project use the same workspace / identity.
     if (defaultRoleId.isNotEmpty) {
@@ -804,7 +807,6 @@ class ChatController {
         sessionId: sessionId,
         title: session.title,
         messages: sessionMessages,
-        projectId: session.projectId ?? '',
         ephemeral: session.ephemeral,
         agentBinding: binding ?? '',
       );
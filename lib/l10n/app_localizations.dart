@@ -788,6 +788,12 @@ abstract class AppLocalizations {
   /// **'Deny'**
   String get deny;
 
+  /// Hint for the optional denial reason in the tool approval dialog
+  ///
+  /// In en, this message translates to:
+  /// **'Reason for denying (optional)'**
+  String get denyReasonHint;
+
   /// Workspace only setting
   ///
   /// In en, this message translates to:
@@ -2000,6 +2006,12 @@ abstract class AppLocalizations {
   /// **'All Categories'**
   String get knowledgeCategoryAll;
 
+  /// Knowledge scope filter option for the global memory
+  ///
+  /// In en, this message translates to:
+  /// **'Global Memory'**
+  String get knowledgeScopeGlobal;
+
   /// Core category
   ///
   /// In en, this message translates to:
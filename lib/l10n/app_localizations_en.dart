@@ -364,6 +364,9 @@ class AppLocalizationsEn extends AppLocalizations {
   @override
   String get deny => 'Deny';
 
+  @override
+  String get denyReasonHint => 'Reason for denying (optional)';
+
   @override
   String get workspaceOnly => 'Workspace Only';
 
@@ -1046,6 +1049,9 @@ class AppLocalizationsEn extends AppLocalizations {
   @override
   String get knowledgeCategoryAll => 'All Categories';
 
+  @override
+  String get knowledgeScopeGlobal => 'Global Memory';
+
   @override
   String get knowledgeCategoryCore => 'Core';
 
@@ -359,6 +359,9 @@ class AppLocalizationsZh extends AppLocalizations {
   @override
   String get deny => '拒绝';
 
+  @override
+  String get denyReasonHint => '拒绝原因（可选）';
+
   @override
   String get workspaceOnly => '仅限工作区';
 
@@ -1021,6 +1024,9 @@ class AppLocalizationsZh extends AppLocalizations {
   @override
   String get knowledgeCategoryAll => '全部分类';
 
+  @override
+  String get knowledgeScopeGlobal => '全局记忆';
+
   @override
   String get knowledgeCategoryCore => '核心';
 
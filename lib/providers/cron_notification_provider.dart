@@ -2,7 +2,6 @@ import 'dart:async';
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:coraldesk/models/models.dart';
-import 'package:coraldesk/providers/agent_workspace_provider.dart';
 import 'package:coraldesk/providers/chat_provider.dart';
 import 'package:coraldesk/src/rust/api/cron_notification_api.dart'
     as cron_notif_api;
@@ -127,117 +126,55 @@ class CronNotificationNotifier extends StateNotifier<CronNotificationState> {
     );
 
     // If session_target == "main" and job_type == "agent" and we have a target session,
-    // inject into that specific session (not the currently active one)
+    // the Rust side has already appended the exchange to that session's history —
+    // mirror it into the cached messages so an open chat view shows it.
     if (item.isMainSession && item.isAgent && item.hasTargetSession) {
-      _injectIntoSession(item);
+      _showAppendedMessages(item);
     }
   }
 
-  /// Inject the cron execution result as messages into the target session
-  /// that was recorded when the cron job was created.
-  Future<void> _injectIntoSession(CronNotificationItem item) async {
+  /// Pick up the exchange the cron job appended to its target session so an
+  /// open chat view shows it without a reload.
+  Future<void> _showAppendedMessages(CronNotificationItem item) async {
     final targetSessionId = item.targetSessionId;
-
-    final now = DateTime.now();
     final msgsNotifier = _ref.read(messagesProvider.notifier);
     final sessionsNotifier = _ref.read(sessionsProvider.notifier);
 
-    // Ensure message history is loaded before injecting — if the session
-    // hasn't been opened in this run, the cache is empty and a subsequent
-    // saveSession would overwrite all existing messages with only the
-    // injected ones.
-    final cached = msgsNotifier.getSessionMessages(targetSessionId);
-    if (cached.isEmpty) {
-      try {
-        final detail = await sessions_api.getSessionDetail(
-          sessionId: targetSessionId,
-        );
-        if (detail != null && detail.messages.isNotEmpty) {
-          final loaded = detail.messages
-              .map(
-                (m) => ChatMessage(
-                  id: m.id,
-                  role: m.role,
-                  content: m.content,
-                  timestamp: DateTime.fromMillisecondsSinceEpoch(
-                    (m.timestamp * 1000).toInt(),
-                  ),
-                ),
-              )
-              .toList();
-          msgsNotifier.setSessionMessages(targetSessionId, loaded);
-        }
-      } catch (e) {
-        debugPrint('Failed to pre-load session messages for cron inject: $e');
-      }
-    }
-
-    // 1. Add a system-like "user" message showing the cron prompt
-    final userMsg = ChatMessage(
-      id: 'cron_${item.jobId}_${now.millisecondsSinceEpoch}_user',
-      role: 'user',
-      content: '⏰ [${item.displayName}] ${item.prompt}',
-      timestamp: now,
-    );
-    msgsNotifier.addMessageToSession(targetSessionId, userMsg);
-    sessionsNotifier.incrementMessageCount(targetSessionId);
-
-    // 2. Add the assistant response
-    final statusIcon = item.isSuccess ? '✅' : '❌';
-    final assistantMsg = ChatMessage(
-      id: 'cron_${item.jobId}_${now.millisecondsSinceEpoch}_assistant',
-      role: 'assistant',
-      content: '$statusIcon [${item.displayName}]\n\n${item.output}',
-      timestamp: now,
-    );
-    msgsNotifier.addMessageToSession(targetSessionId, assistantMsg);
-    sessionsNotifier.incrementMessageCount(targetSessionId);
-
-    // 3. Persist to session store
-    _persistSession(targetSessionId);
-  }
-
-  Future<void> _persistSession(String sessionId) async {
     try {
-      final msgsNotifier = _ref.read(messagesProvider.notifier);
-      final messages = msgsNotifier.getSessionMessages(sessionId);
-      final sessions = _ref.read(sessionsProvider);
-      final session = sessions.firstWhere(
-        (s) => s.id == sessionId,
-        orElse: () => ChatSession(
-          id: sessionId,
-          title: 'Chat',
-          createdAt: DateTime.now(),
-          updatedAt: DateTime.now(),
-        ),
+      final detail = await sessions_api.getSessionDetail(
+        sessionId: targetSessionId,
       );
-
-      final sessionMessages = messages
-          .map(
-            (m) => sessions_api.SessionMessage(
+      if (detail == null) return;
+
+      final cached = msgsNotifier.getSessionMessages(targetSessionId);
+      final knownIds = cached.map((m) => m.id).toSet();
+      final appended = detail.messages
+          .skip(detail.messages.length < 2 ? 0 : detail.messages.length - 2)
+          .where(
+            (m) =>
+                m.id.startsWith('cron_${item.jobId}_') &&
+                !knownIds.contains(m.id),
+          );
+
+      for (final m in appended) {
+        // An empty cache is loaded from the store when the session is opened.
+        if (cached.isNotEmpty) {
+          msgsNotifier.addMessageToSession(
+            targetSessionId,
+            ChatMessage(
               id: m.id,
               role: m.role,
               content: m.content,
-              timestamp: m.timestamp.millisecondsSinceEpoch ~/ 1000,
-              toolCallsJson: '',
-              partsJson: '',
-              agentRole: '',
-              agentColor: '',
-              agentIcon: '',
+              timestamp: DateTime.fromMillisecondsSinceEpoch(
+                (m.timestamp * 1000).toInt(),
+              ),
             ),
-          )
-          .toList();
-
-      await sessions_api.saveSession(
-        sessionId: sessionId,
-        title: session.title,
-        messages: sessionMessages,
-        projectId: session.projectId ?? '',
-        ephemeral: session.ephemeral,
-        agentBinding: _ref.read(sessionAgentBindingProvider)[sessionId] ?? '',
-      );
+          );
+        }
+        sessionsNotifier.incrementMessageCount(targetSessionId);
+      }
     } catch (e) {
-      debugPrint('Failed to persist cron-injected session: $e');
+      debugPrint('Failed to load cron messages for session: $e');
     }
   }
 
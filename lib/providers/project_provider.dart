@@ -112,6 +112,17 @@ class ProjectsNotifier extends StateNotifier<List<Project>> {
           defaultRoleId: defaultRoleId,
           sessionIds: [],
           tags: tags,
+          globalMemoryFallback: true,
+          autonomy: const project_api.ProjectAutonomyDto(
+            level: '',
+            allowedCommands: [],
+            forbiddenPaths: [],
+            autoApprove: [],
+            alwaysAsk: [],
+            enabledTools: [],
+          ),
+          modelProfileId: '',
+          model: '',
           createdAt: 0,
           updatedAt: 0,
         ),
@@ -132,6 +143,10 @@ class ProjectsNotifier extends StateNotifier<List<Project>> {
   /// Update an existing project
   Future<bool> updateProject(Project project) async {
     try {
+      // The dialog doesn't edit memory, autonomy or model settings; carry the
+      // stored ones over so saving it doesn't reset them.
+      final current = await project_api.getProject(projectId: project.id);
+      if (current == null) return false;
       final result = await project_api.upsertProject(
         project: project_api.ProjectDto(
           id: project.id,
@@ -147,6 +162,11 @@ class ProjectsNotifier extends StateNotifier<List<Project>> {
           defaultRoleId: project.defaultRoleId,
           sessionIds: project.sessionIds,
           tags: project.tags,
+          globalMemoryFallback: current.globalMemoryFallback,
+          autonomy: current.autonomy,
+          modelProfileId: current.modelProfileId,
+          model: current.model,
+          temperature: current.temperature,
           createdAt: project.createdAt.millisecondsSinceEpoch ~/ 1000,
           updatedAt: 0,
         ),
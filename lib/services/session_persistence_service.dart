@@ -20,14 +20,12 @@ class SessionPersistenceService {
     required String sessionId,
     required String title,
     required List<sessions_api.SessionMessage> messages,
-    String projectId = '',
     bool ephemeral = false,
     String agentBinding = '',
   }) => sessions_api.saveSession(
     sessionId: sessionId,
     title: title,
     messages: messages,
-    projectId: projectId,
     ephemeral: ephemeral,
     agentBinding: agentBinding,
   );
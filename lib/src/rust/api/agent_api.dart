@@ -9,8 +9,8 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'agent_api.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `active_stream_tokens`, `config_state`, `ensure_session_agent`, `evict_oldest_agent_if_needed`, `global_config`, `invalidate_all_agents`, `invalidate_session_agent`, `legacy_pending_approval`, `load_default_profile_id`, `load_embedding_api_key`, `mark_turn_activity`, `pending_approvals`, `resolve_delegate_providers`, `session_agents`, `truncate_str`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChatMessageDto`, `ConfigState`, `GlobalConfig`, `PendingApproval`, `SessionAgent`, `ToolCallDto`
+// These functions are ignored because they are not marked as `pub`: `active_stream_tokens`, `build_agent`, `build_session_config`, `config_state`, `denial_feedback`, `ensure_session_agent`, `evict_oldest_agent_if_needed`, `global_config`, `global_memory_note`, `invalidate_all_agents`, `invalidate_session_agent`, `legacy_pending_approval`, `load_default_profile_id`, `load_embedding_api_key`, `mark_turn_activity`, `mirror_role_workspace`, `pending_approvals`, `project_context_note`, `record_turn_file_changes`, `repeated_denial_reason`, `request_tool_approval`, `resolve_delegate_providers`, `session_agents`, `truncate_str`, `turn_approvers`, `write_project_prompt`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChatMessageDto`, `ConfigState`, `DenialFeedback`, `GlobalConfig`, `PendingApproval`, `SessionAgent`, `ToolCallDto`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// Cancel an active generation for the given session.
@@ -36,12 +36,18 @@ Future<String> respondToToolApproval({required String decision}) => RustLib
 ///
 /// `request_id`: the unique ID sent with ToolApprovalRequest
 /// `decision` values: "yes", "no", "always"
+/// `reason`: optional explanation for a denial (e.g. "don't touch prod, use
+/// the staging DB"). The model gets it as the denied call's result, and
+/// identical calls later in the same turn are denied with it without
+/// prompting again. Ignored for approvals.
 Future<String> respondToToolApprovalById({
   required String requestId,
   required String decision,
+  String? reason,
 }) => RustLib.instance.api.crateApiAgentApiRespondToToolApprovalById(
   requestId: requestId,
   decision: decision,
+  reason: reason,
 );
 
 /// Initialize the agent runtime: load zeroclaw config from ~/.coraldesk/config.toml.
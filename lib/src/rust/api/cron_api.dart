@@ -6,8 +6,10 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `build_schedule_json`, `compute_next_run`, `db_path`, `decode_schedule_info`, `format_interval`, `open_db`, `parse_rfc3339_to_ts`, `row_to_dto`, `run_agent_job`, `run_shell_job`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `advance_schedule`, `allows_day`, `append_to_target_session`, `apply_chain_context`, `apply_rlimits`, `auto_disable_after`, `auto_disable_if_failing`, `build_schedule_json`, `capture_stream`, `carry_trigger_settings`, `chain_has_cycle`, `chain_output`, `check_active_window`, `clear_chain_references`, `compute_next_run`, `count_missed_runs`, `count_runs`, `covers`, `cron_scheduler`, `db_path`, `decode_schedule_info`, `decode_schedule_timezone`, `delete_project_cron_jobs`, `deliver_results`, `delivery_channel`, `delivery_json`, `dispatch_trigger`, `encrypt_plaintext_secrets`, `execute_job`, `execute_shell`, `executing_count`, `executing_jobs`, `expand_home`, `export_name`, `export_project_cron_jobs`, `failed`, `fire_trigger`, `floor_char_boundary`, `format_interval`, `get_setting`, `global_active_window`, `handle_webhook`, `import_project_cron_jobs`, `in_quiet_hours`, `is_default_sandbox`, `is_event_trigger`, `is_unrestricted`, `is_valid_env_name`, `job_to_spec`, `kill_process_group`, `load_chain_edges`, `load_ics_holidays`, `load_job`, `load_schedule_json`, `load_secret`, `load_watch_jobs`, `load`, `main_target_session`, `max_concurrency`, `max_run_history`, `migrate`, `new_webhook_secret`, `next_cron_fire`, `next_open`, `normalize_cron_expression`, `normalize_day_of_week`, `normalize_timezone`, `open_db`, `output_page`, `parse_at_time`, `parse_cron_schedule`, `parse_delivery`, `parse_ics_holidays`, `parse_rfc3339_to_ts`, `parse_timezone`, `parse_trigger_request`, `parse_window_time`, `percentile`, `planned_runs`, `prepare_shell_launch`, `project_cron_runs`, `prune_run_history`, `record_attempt`, `record_skipped_run`, `render_delivery_template`, `retry_delay_secs`, `row_to_dto`, `run_agent_job`, `run_chain`, `run_row_to_dto`, `run_shell_job`, `save_spec`, `scheduler_enabled`, `scheduler_tick`, `secret_store`, `secrets_match`, `send_delivery_webhook`, `send_delivery`, `set_project_cron_jobs_paused`, `set_setting`, `shell_quote`, `spec_diff`, `truncate_output`, `try_begin_execution`, `update_trigger_settings`, `validate_active_window`, `validate_delivery`, `validate_spec`, `watch_loop`, `webhook_loop`, `webhook_port`, `window_deferral`, `write_chain_output`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ActiveWindow`, `ChainContext`, `CronDeliverySpec`, `CronJobFile`, `CronJobSpec`, `CronScheduler`, `ExecutingEntry`, `ExecutionGuard`, `IcsHoliday`, `JobOutcome`, `RunAttempt`, `ShellLaunch`, `TriggerRequest`, `WatchTarget`, `WindowCheck`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `drop`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`, `default`
 
 /// Get cron system configuration and stats
 Future<CronConfigDto> getCronConfig() =>
@@ -17,20 +19,37 @@ Future<CronConfigDto> getCronConfig() =>
 Future<List<CronJobDto>> listCronJobs() =>
     RustLib.instance.api.crateApiCronApiListCronJobs();
 
-/// Add a new shell cron job
+/// List the cron jobs that belong to a project
+Future<List<CronJobDto>> listCronJobsForProject({required String projectId}) =>
+    RustLib.instance.api.crateApiCronApiListCronJobsForProject(
+      projectId: projectId,
+    );
+
+/// Add a new shell cron job.
+/// `schedule_type` "watch" takes the path to watch as `expression`; "webhook"
+/// ignores it and generates a secret (see `get_cron_webhook_info`).
+/// `timezone` is an IANA name (e.g. "Asia/Shanghai"); None = system local time.
+/// `project_id` scopes the job to a project; None = global.
 Future<String> addShellCronJob({
   String? name,
   required String scheduleType,
   required String expression,
   required String command,
+  String? timezone,
+  String? projectId,
 }) => RustLib.instance.api.crateApiCronApiAddShellCronJob(
   name: name,
   scheduleType: scheduleType,
   expression: expression,
   command: command,
+  timezone: timezone,
+  projectId: projectId,
 );
 
-/// Add a new agent cron job
+/// Add a new agent cron job.
+/// `timezone` is an IANA name (e.g. "Asia/Shanghai"); None = system local time.
+/// `project_id` scopes the job to a project; its runs use the project's
+/// directory and context. None = global.
 Future<String> addAgentCronJob({
   String? name,
   required String scheduleType,
@@ -40,6 +59,8 @@ Future<String> addAgentCronJob({
   String? model,
   required bool deleteAfterRun,
   String? targetSessionId,
+  String? timezone,
+  String? projectId,
 }) => RustLib.instance.api.crateApiCronApiAddAgentCronJob(
   name: name,
   scheduleType: scheduleType,
@@ -49,6 +70,8 @@ Future<String> addAgentCronJob({
   model: model,
   deleteAfterRun: deleteAfterRun,
   targetSessionId: targetSessionId,
+  timezone: timezone,
+  projectId: projectId,
 );
 
 /// Remove a cron job
@@ -72,6 +95,38 @@ Future<List<CronRunDto>> listCronRuns({
   limit: limit,
 );
 
+/// List every run that belongs to one chain execution, in execution order
+Future<List<CronRunDto>> listCronChainRuns({required String chainId}) =>
+    RustLib.instance.api.crateApiCronApiListCronChainRuns(chainId: chainId);
+
+/// Set the jobs that run after this one succeeds or fails (empty = none).
+/// Follow-ups run even while paused, so a paused job can serve as a
+/// chain-only step. Their prompt or command may reference the previous run
+/// with `{{prev_output}}` (stdout, or stderr when it failed),
+/// `{{prev_status}}` and `{{prev_job}}`. Shell follow-ups also get the
+/// previous output as a file named by `CRON_PREV_OUTPUT_FILE`.
+Future<String> setCronJobChain({
+  required String jobId,
+  required String onSuccess,
+  required String onFailure,
+}) => RustLib.instance.api.crateApiCronApiSetCronJobChain(
+  jobId: jobId,
+  onSuccess: onSuccess,
+  onFailure: onFailure,
+);
+
+/// Read a page of a run's full stdout and stderr. `offset` and `len` are
+/// byte positions applied to each stream and snapped to UTF-8 boundaries.
+Future<CronRunOutput?> getCronRunOutput({
+  required PlatformInt64 runId,
+  required BigInt offset,
+  required BigInt len,
+}) => RustLib.instance.api.crateApiCronApiGetCronRunOutput(
+  runId: runId,
+  offset: offset,
+  len: len,
+);
+
 /// Update a cron job
 Future<String> updateCronJob({
   required String jobId,
@@ -91,18 +146,255 @@ Future<String> updateCronJob({
   enabled: enabled,
 );
 
-/// Execute a cron job immediately (manual trigger)
+/// Change the timezone a job's schedule is evaluated in and recompute its
+/// next run. `timezone` is an IANA name; empty = system local time.
+Future<String> setCronJobTimezone({
+  required String jobId,
+  required String timezone,
+}) => RustLib.instance.api.crateApiCronApiSetCronJobTimezone(
+  jobId: jobId,
+  timezone: timezone,
+);
+
+/// Set how a job handles missed runs and whether runs may overlap.
+/// `misfire_policy`: "skip", "run_once" or "run_all".
+Future<String> setCronJobRunPolicy({
+  required String jobId,
+  required String misfirePolicy,
+  required bool noOverlap,
+}) => RustLib.instance.api.crateApiCronApiSetCronJobRunPolicy(
+  jobId: jobId,
+  misfirePolicy: misfirePolicy,
+  noOverlap: noOverlap,
+);
+
+/// Set a job's timeout (0 = no limit) and how often a failed run is retried.
+/// Retries wait `retry_backoff_secs`, doubling after each further failure.
+Future<String> setCronJobRetryPolicy({
+  required String jobId,
+  required int timeoutSecs,
+  required int maxRetries,
+  required int retryBackoffSecs,
+}) => RustLib.instance.api.crateApiCronApiSetCronJobRetryPolicy(
+  jobId: jobId,
+  timeoutSecs: timeoutSecs,
+  maxRetries: maxRetries,
+  retryBackoffSecs: retryBackoffSecs,
+);
+
+/// Set how many runs are kept per job, pruning older history right away
+Future<String> setCronMaxRunHistory({required int maxRunHistory}) => RustLib
+    .instance
+    .api
+    .crateApiCronApiSetCronMaxRunHistory(maxRunHistory: maxRunHistory);
+
+/// Disable jobs after this many failed runs in a row (0 = never)
+Future<String> setCronAutoDisableAfter({required int failures}) => RustLib
+    .instance
+    .api
+    .crateApiCronApiSetCronAutoDisableAfter(failures: failures);
+
+/// Success rate, duration percentiles and failure streak of a job over the
+/// last `window_hours` hours (0 = all kept history)
+Future<CronJobStatsDto?> getCronJobStats({
+  required String jobId,
+  required int windowHours,
+}) => RustLib.instance.api.crateApiCronApiGetCronJobStats(
+  jobId: jobId,
+  windowHours: windowHours,
+);
+
+/// Set how many cron jobs the scheduler may run at the same time
+Future<String> setCronMaxConcurrency({required int maxConcurrency}) => RustLib
+    .instance
+    .api
+    .crateApiCronApiSetCronMaxConcurrency(maxConcurrency: maxConcurrency);
+
+/// Export all cron jobs, or a project's, as a "toml" or "yaml" document.
+/// Chain targets are written by job name; webhook secrets are left out.
+Future<String> exportCronJobs({String? projectId, required String format}) =>
+    RustLib.instance.api.crateApiCronApiExportCronJobs(
+      projectId: projectId,
+      format: format,
+    );
+
+/// Import a "toml" or "yaml" cron file, creating or updating jobs matched by
+/// name. Jobs missing from the file are left alone, so importing the same
+/// file twice changes nothing. With `dry_run`, only reports the changes.
+Future<CronImportResult> importCronJobs({
+  required String content,
+  required String format,
+  required bool dryRun,
+}) => RustLib.instance.api.crateApiCronApiImportCronJobs(
+  content: content,
+  format: format,
+  dryRun: dryRun,
+);
+
+/// Replace a job's delivery targets (empty = desktop notification only)
+Future<String> setCronJobDelivery({
+  required String jobId,
+  required List<CronDeliveryDto> targets,
+}) => RustLib.instance.api.crateApiCronApiSetCronJobDelivery(
+  jobId: jobId,
+  targets: targets,
+);
+
+/// Send a sample message to a delivery target to check its settings
+Future<String> testCronDelivery({required CronDeliveryDto target}) =>
+    RustLib.instance.api.crateApiCronApiTestCronDelivery(target: target);
+
+/// Set how long a "watch" job waits for changes to settle before running
+Future<String> setCronJobWatchDebounce({
+  required String jobId,
+  required BigInt debounceMs,
+}) => RustLib.instance.api.crateApiCronApiSetCronJobWatchDebounce(
+  jobId: jobId,
+  debounceMs: debounceMs,
+);
+
+/// Replace a "webhook" job's secret; callers using the old one are rejected
+Future<String> regenerateCronWebhookSecret({required String jobId}) => RustLib
+    .instance
+    .api
+    .crateApiCronApiRegenerateCronWebhookSecret(jobId: jobId);
+
+/// Get the URL and secret for triggering a "webhook" job
+Future<CronWebhookInfo?> getCronWebhookInfo({required String jobId}) =>
+    RustLib.instance.api.crateApiCronApiGetCronWebhookInfo(jobId: jobId);
+
+/// Set the local port the webhook listener binds to.
+/// Takes effect the next time the scheduler starts.
+Future<String> setCronWebhookPort({required int port}) =>
+    RustLib.instance.api.crateApiCronApiSetCronWebhookPort(port: port);
+
+/// Set a shell job's working directory, environment and resource limits
+Future<String> setCronJobSandbox({
+  required String jobId,
+  required CronShellSandboxDto sandbox,
+}) => RustLib.instance.api.crateApiCronApiSetCronJobSandbox(
+  jobId: jobId,
+  sandbox: sandbox,
+);
+
+/// Store a secret that shell jobs can reference from their environment
+/// without the value appearing in the job itself
+Future<String> setCronSecret({required String name, required String value}) =>
+    RustLib.instance.api.crateApiCronApiSetCronSecret(name: name, value: value);
+
+Future<String> removeCronSecret({required String name}) =>
+    RustLib.instance.api.crateApiCronApiRemoveCronSecret(name: name);
+
+/// Names of the stored cron secrets (values are never returned)
+Future<List<String>> listCronSecrets() =>
+    RustLib.instance.api.crateApiCronApiListCronSecrets();
+
+/// Get the active window that applies to jobs without their own
+Future<CronActiveWindowDto> getCronActiveWindow() =>
+    RustLib.instance.api.crateApiCronApiGetCronActiveWindow();
+
+/// Set the active window that applies to jobs without their own
+Future<String> setCronActiveWindow({required CronActiveWindowDto window}) =>
+    RustLib.instance.api.crateApiCronApiSetCronActiveWindow(window: window);
+
+/// Give a job its own active window, replacing the global one for it.
+/// None = follow the global window again.
+Future<String> setCronJobActiveWindow({
+  required String jobId,
+  CronActiveWindowDto? window,
+}) => RustLib.instance.api.crateApiCronApiSetCronJobActiveWindow(
+  jobId: jobId,
+  window: window,
+);
+
+/// Validate a cron expression and list its next `count` fire times.
+/// `timezone` is an IANA name; None or empty = system local time.
+Future<CronSchedulePreview> previewCronSchedule({
+  required String expression,
+  String? timezone,
+  required int count,
+}) => RustLib.instance.api.crateApiCronApiPreviewCronSchedule(
+  expression: expression,
+  timezone: timezone,
+  count: count,
+);
+
+/// Execute a cron job immediately (manual trigger).
+/// The job's schedule is left untouched.
 Future<String> runCronJobNow({required String jobId}) =>
     RustLib.instance.api.crateApiCronApiRunCronJobNow(jobId: jobId);
 
-/// Start a background cron scheduler that polls for due jobs
+/// Start the background cron scheduler that polls for due jobs.
+/// Does nothing if the scheduler has been disabled in the cron settings.
 Future<String> startCronScheduler() =>
     RustLib.instance.api.crateApiCronApiStartCronScheduler();
 
+/// Stop the polling loop. Runs already in flight are left to finish.
+Future<String> stopCronScheduler() =>
+    RustLib.instance.api.crateApiCronApiStopCronScheduler();
+
+/// Persist the scheduler on/off switch and start or stop it accordingly
+Future<String> setCronSchedulerEnabled({required bool enabled}) => RustLib
+    .instance
+    .api
+    .crateApiCronApiSetCronSchedulerEnabled(enabled: enabled);
+
+/// Get the scheduler's live state and the runs currently in flight
+Future<CronSchedulerStatus> getCronSchedulerStatus() =>
+    RustLib.instance.api.crateApiCronApiGetCronSchedulerStatus();
+
+/// Limits on when scheduled runs may start. Runs that fall due outside the
+/// window are deferred until it opens. All fields empty = no limits.
+class CronActiveWindowDto {
+  /// Start and end of quiet hours as "HH:MM" in the job's timezone;
+  /// may wrap midnight (e.g. "22:00"–"07:00"). Empty = no quiet hours.
+  final String quietStart;
+  final String quietEnd;
+
+  /// Days runs may start on, 1 = Monday … 7 = Sunday. Empty = every day.
+  final Uint8List activeDays;
+
+  /// Local .ics file whose events are treated as holidays. Empty = none.
+  final String holidaysIcs;
+
+  const CronActiveWindowDto({
+    required this.quietStart,
+    required this.quietEnd,
+    required this.activeDays,
+    required this.holidaysIcs,
+  });
+
+  static Future<CronActiveWindowDto> default_() =>
+      RustLib.instance.api.crateApiCronApiCronActiveWindowDtoDefault();
+
+  @override
+  int get hashCode =>
+      quietStart.hashCode ^
+      quietEnd.hashCode ^
+      activeDays.hashCode ^
+      holidaysIcs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronActiveWindowDto &&
+          runtimeType == other.runtimeType &&
+          quietStart == other.quietStart &&
+          quietEnd == other.quietEnd &&
+          activeDays == other.activeDays &&
+          holidaysIcs == other.holidaysIcs;
+}
+
 /// Cron system configuration
 class CronConfigDto {
   final bool enabled;
   final int maxRunHistory;
+
+  /// Maximum number of jobs the scheduler runs at the same time
+  final int maxConcurrency;
+
+  /// Disable a job after this many failed runs in a row (0 = never)
+  final int autoDisableAfter;
   final int totalJobs;
   final int activeJobs;
   final int pausedJobs;
@@ -110,6 +402,8 @@ class CronConfigDto {
   const CronConfigDto({
     required this.enabled,
     required this.maxRunHistory,
+    required this.maxConcurrency,
+    required this.autoDisableAfter,
     required this.totalJobs,
     required this.activeJobs,
     required this.pausedJobs,
@@ -119,6 +413,8 @@ class CronConfigDto {
   int get hashCode =>
       enabled.hashCode ^
       maxRunHistory.hashCode ^
+      maxConcurrency.hashCode ^
+      autoDisableAfter.hashCode ^
       totalJobs.hashCode ^
       activeJobs.hashCode ^
       pausedJobs.hashCode;
@@ -130,11 +426,176 @@ class CronConfigDto {
           runtimeType == other.runtimeType &&
           enabled == other.enabled &&
           maxRunHistory == other.maxRunHistory &&
+          maxConcurrency == other.maxConcurrency &&
+          autoDisableAfter == other.autoDisableAfter &&
           totalJobs == other.totalJobs &&
           activeJobs == other.activeJobs &&
           pausedJobs == other.pausedJobs;
 }
 
+/// A destination for a job's run results
+class CronDeliveryDto {
+  /// "telegram", "slack", "discord", "email" or "webhook"
+  final String channel;
+
+  /// Chat id, channel id, email address or URL, depending on `channel`.
+  /// May be empty for Slack to use the configured default channel.
+  final String to;
+  final bool onSuccess;
+  final bool onFailure;
+
+  /// Message body; supports {{job}}, {{status}}, {{icon}}, {{output}},
+  /// {{prompt}}, {{duration}} and {{finished_at}}. Empty = default template.
+  final String template;
+
+  const CronDeliveryDto({
+    required this.channel,
+    required this.to,
+    required this.onSuccess,
+    required this.onFailure,
+    required this.template,
+  });
+
+  @override
+  int get hashCode =>
+      channel.hashCode ^
+      to.hashCode ^
+      onSuccess.hashCode ^
+      onFailure.hashCode ^
+      template.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronDeliveryDto &&
+          runtimeType == other.runtimeType &&
+          channel == other.channel &&
+          to == other.to &&
+          onSuccess == other.onSuccess &&
+          onFailure == other.onFailure &&
+          template == other.template;
+}
+
+/// An environment variable for a shell job
+class CronEnvVarDto {
+  final String name;
+
+  /// The value, or the name of a stored cron secret when `secret` is set
+  final String value;
+  final bool secret;
+
+  const CronEnvVarDto({
+    required this.name,
+    required this.value,
+    required this.secret,
+  });
+
+  static Future<CronEnvVarDto> default_() =>
+      RustLib.instance.api.crateApiCronApiCronEnvVarDtoDefault();
+
+  @override
+  int get hashCode => name.hashCode ^ value.hashCode ^ secret.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronEnvVarDto &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value &&
+          secret == other.secret;
+}
+
+/// A job run currently in flight
+class CronExecutingJob {
+  final String jobId;
+  final String jobName;
+
+  /// Start of the earliest in-flight run (epoch seconds)
+  final PlatformInt64 startedAt;
+
+  /// Number of in-flight runs (> 1 only when overlap is allowed)
+  final int runs;
+
+  const CronExecutingJob({
+    required this.jobId,
+    required this.jobName,
+    required this.startedAt,
+    required this.runs,
+  });
+
+  @override
+  int get hashCode =>
+      jobId.hashCode ^ jobName.hashCode ^ startedAt.hashCode ^ runs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronExecutingJob &&
+          runtimeType == other.runtimeType &&
+          jobId == other.jobId &&
+          jobName == other.jobName &&
+          startedAt == other.startedAt &&
+          runs == other.runs;
+}
+
+/// Planned (dry run) or applied change to one job in an import
+class CronImportChange {
+  final String name;
+
+  /// "create", "update" or "unchanged"
+  final String action;
+
+  /// Fields that differ from the existing job (updates only)
+  final List<String> changedFields;
+
+  const CronImportChange({
+    required this.name,
+    required this.action,
+    required this.changedFields,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ action.hashCode ^ changedFields.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronImportChange &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          action == other.action &&
+          changedFields == other.changedFields;
+}
+
+/// Outcome of importing a cron file
+class CronImportResult {
+  /// Validation errors; nothing is written when non-empty
+  final List<String> errors;
+
+  /// False for dry runs and failed imports
+  final bool applied;
+  final List<CronImportChange> changes;
+
+  const CronImportResult({
+    required this.errors,
+    required this.applied,
+    required this.changes,
+  });
+
+  @override
+  int get hashCode => errors.hashCode ^ applied.hashCode ^ changes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronImportResult &&
+          runtimeType == other.runtimeType &&
+          errors == other.errors &&
+          applied == other.applied &&
+          changes == other.changes;
+}
+
 /// Cron job summary for UI display
 class CronJobDto {
   final String id;
@@ -142,6 +603,9 @@ class CronJobDto {
   final String expression;
   final String scheduleType;
   final String scheduleDisplay;
+
+  /// IANA timezone the schedule is evaluated in (empty = system local time)
+  final String timezone;
   final String command;
   final String prompt;
   final String jobType;
@@ -159,12 +623,44 @@ class CronJobDto {
   final String lastStatus;
   final String lastOutput;
 
+  /// What to do with runs missed while the app was closed:
+  /// "skip", "run_once" or "run_all"
+  final String misfirePolicy;
+
+  /// Don't start a new run while the previous one is still executing
+  final bool noOverlap;
+
+  /// Kill the run after this many seconds (0 = no limit)
+  final int timeoutSecs;
+
+  /// Extra attempts after a failed run
+  final int maxRetries;
+
+  /// Delay before the first retry; doubles on each further retry
+  final int retryBackoffSecs;
+
+  /// Job to run after this one succeeds (empty = none)
+  final String onSuccess;
+
+  /// Job to run after this one fails (empty = none)
+  final String onFailure;
+
+  /// Where run results are sent besides the desktop notification
+  final List<CronDeliveryDto> delivery;
+
+  /// When scheduled runs may start; None = the global window applies
+  final CronActiveWindowDto? activeWindow;
+
+  /// Working directory, environment and resource limits (shell jobs)
+  final CronShellSandboxDto sandbox;
+
   const CronJobDto({
     required this.id,
     required this.name,
     required this.expression,
     required this.scheduleType,
     required this.scheduleDisplay,
+    required this.timezone,
     required this.command,
     required this.prompt,
     required this.jobType,
@@ -179,6 +675,16 @@ class CronJobDto {
     this.lastRun,
     required this.lastStatus,
     required this.lastOutput,
+    required this.misfirePolicy,
+    required this.noOverlap,
+    required this.timeoutSecs,
+    required this.maxRetries,
+    required this.retryBackoffSecs,
+    required this.onSuccess,
+    required this.onFailure,
+    required this.delivery,
+    this.activeWindow,
+    required this.sandbox,
   });
 
   @override
@@ -188,6 +694,7 @@ class CronJobDto {
       expression.hashCode ^
       scheduleType.hashCode ^
       scheduleDisplay.hashCode ^
+      timezone.hashCode ^
       command.hashCode ^
       prompt.hashCode ^
       jobType.hashCode ^
@@ -201,7 +708,17 @@ class CronJobDto {
       nextRun.hashCode ^
       lastRun.hashCode ^
       lastStatus.hashCode ^
-      lastOutput.hashCode;
+      lastOutput.hashCode ^
+      misfirePolicy.hashCode ^
+      noOverlap.hashCode ^
+      timeoutSecs.hashCode ^
+      maxRetries.hashCode ^
+      retryBackoffSecs.hashCode ^
+      onSuccess.hashCode ^
+      onFailure.hashCode ^
+      delivery.hashCode ^
+      activeWindow.hashCode ^
+      sandbox.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -213,6 +730,7 @@ class CronJobDto {
           expression == other.expression &&
           scheduleType == other.scheduleType &&
           scheduleDisplay == other.scheduleDisplay &&
+          timezone == other.timezone &&
           command == other.command &&
           prompt == other.prompt &&
           jobType == other.jobType &&
@@ -226,7 +744,81 @@ class CronJobDto {
           nextRun == other.nextRun &&
           lastRun == other.lastRun &&
           lastStatus == other.lastStatus &&
-          lastOutput == other.lastOutput;
+          lastOutput == other.lastOutput &&
+          misfirePolicy == other.misfirePolicy &&
+          noOverlap == other.noOverlap &&
+          timeoutSecs == other.timeoutSecs &&
+          maxRetries == other.maxRetries &&
+          retryBackoffSecs == other.retryBackoffSecs &&
+          onSuccess == other.onSuccess &&
+          onFailure == other.onFailure &&
+          delivery == other.delivery &&
+          activeWindow == other.activeWindow &&
+          sandbox == other.sandbox;
+}
+
+/// Health summary of a job's recent runs
+class CronJobStatsDto {
+  final String jobId;
+
+  /// Runs in the window; a run that succeeded after retries counts once
+  final int totalRuns;
+  final int succeeded;
+  final int failed;
+
+  /// succeeded / total_runs, 0.0 when there were no runs
+  final double successRate;
+
+  /// Median attempt duration in milliseconds
+  final PlatformInt64 p50DurationMs;
+
+  /// 95th percentile attempt duration in milliseconds
+  final PlatformInt64 p95DurationMs;
+
+  /// Failed runs in a row since the last success
+  final int consecutiveFailures;
+
+  /// UTC epoch seconds of the last successful run in the kept history
+  final PlatformInt64? lastSuccess;
+
+  const CronJobStatsDto({
+    required this.jobId,
+    required this.totalRuns,
+    required this.succeeded,
+    required this.failed,
+    required this.successRate,
+    required this.p50DurationMs,
+    required this.p95DurationMs,
+    required this.consecutiveFailures,
+    this.lastSuccess,
+  });
+
+  @override
+  int get hashCode =>
+      jobId.hashCode ^
+      totalRuns.hashCode ^
+      succeeded.hashCode ^
+      failed.hashCode ^
+      successRate.hashCode ^
+      p50DurationMs.hashCode ^
+      p95DurationMs.hashCode ^
+      consecutiveFailures.hashCode ^
+      lastSuccess.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronJobStatsDto &&
+          runtimeType == other.runtimeType &&
+          jobId == other.jobId &&
+          totalRuns == other.totalRuns &&
+          succeeded == other.succeeded &&
+          failed == other.failed &&
+          successRate == other.successRate &&
+          p50DurationMs == other.p50DurationMs &&
+          p95DurationMs == other.p95DurationMs &&
+          consecutiveFailures == other.consecutiveFailures &&
+          lastSuccess == other.lastSuccess;
 }
 
 /// Cron run history entry
@@ -239,6 +831,23 @@ class CronRunDto {
   final String output;
   final PlatformInt64 durationMs;
 
+  /// 1 for the first try, incremented on each retry
+  final int attempt;
+
+  /// Process exit code (shell jobs only; None if killed or not started)
+  final int? exitCode;
+
+  /// Size of the full captured stdout / stderr in bytes
+  final BigInt stdoutLen;
+  final BigInt stderrLen;
+
+  /// Set when a stream outgrew the capture limit and its end was dropped
+  final bool stdoutTruncated;
+  final bool stderrTruncated;
+
+  /// Shared by every run triggered from the same root run via on_success/on_failure
+  final String chainId;
+
   const CronRunDto({
     required this.id,
     required this.jobId,
@@ -247,6 +856,13 @@ class CronRunDto {
     required this.status,
     required this.output,
     required this.durationMs,
+    required this.attempt,
+    this.exitCode,
+    required this.stdoutLen,
+    required this.stderrLen,
+    required this.stdoutTruncated,
+    required this.stderrTruncated,
+    required this.chainId,
   });
 
   @override
@@ -257,7 +873,14 @@ class CronRunDto {
       finishedAt.hashCode ^
       status.hashCode ^
       output.hashCode ^
-      durationMs.hashCode;
+      durationMs.hashCode ^
+      attempt.hashCode ^
+      exitCode.hashCode ^
+      stdoutLen.hashCode ^
+      stderrLen.hashCode ^
+      stdoutTruncated.hashCode ^
+      stderrTruncated.hashCode ^
+      chainId.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -270,5 +893,192 @@ class CronRunDto {
           finishedAt == other.finishedAt &&
           status == other.status &&
           output == other.output &&
-          durationMs == other.durationMs;
+          durationMs == other.durationMs &&
+          attempt == other.attempt &&
+          exitCode == other.exitCode &&
+          stdoutLen == other.stdoutLen &&
+          stderrLen == other.stderrLen &&
+          stdoutTruncated == other.stdoutTruncated &&
+          stderrTruncated == other.stderrTruncated &&
+          chainId == other.chainId;
+}
+
+/// A page of a run's captured output
+class CronRunOutput {
+  final PlatformInt64 runId;
+  final String stdout;
+  final String stderr;
+
+  /// Total sizes in bytes, for paging
+  final BigInt stdoutLen;
+  final BigInt stderrLen;
+
+  /// Set when a stream outgrew the capture limit and its end was dropped
+  final bool stdoutTruncated;
+  final bool stderrTruncated;
+  final int? exitCode;
+
+  const CronRunOutput({
+    required this.runId,
+    required this.stdout,
+    required this.stderr,
+    required this.stdoutLen,
+    required this.stderrLen,
+    required this.stdoutTruncated,
+    required this.stderrTruncated,
+    this.exitCode,
+  });
+
+  @override
+  int get hashCode =>
+      runId.hashCode ^
+      stdout.hashCode ^
+      stderr.hashCode ^
+      stdoutLen.hashCode ^
+      stderrLen.hashCode ^
+      stdoutTruncated.hashCode ^
+      stderrTruncated.hashCode ^
+      exitCode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronRunOutput &&
+          runtimeType == other.runtimeType &&
+          runId == other.runId &&
+          stdout == other.stdout &&
+          stderr == other.stderr &&
+          stdoutLen == other.stdoutLen &&
+          stderrLen == other.stderrLen &&
+          stdoutTruncated == other.stdoutTruncated &&
+          stderrTruncated == other.stderrTruncated &&
+          exitCode == other.exitCode;
+}
+
+/// Result of previewing a cron expression
+class CronSchedulePreview {
+  /// Empty when the expression and timezone are valid
+  final String error;
+
+  /// Upcoming fire times (UTC epoch seconds)
+  final Int64List fireTimes;
+
+  const CronSchedulePreview({required this.error, required this.fireTimes});
+
+  @override
+  int get hashCode => error.hashCode ^ fireTimes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronSchedulePreview &&
+          runtimeType == other.runtimeType &&
+          error == other.error &&
+          fireTimes == other.fireTimes;
+}
+
+/// Live state of the background scheduler
+class CronSchedulerStatus {
+  /// Persisted on/off switch
+  final bool enabled;
+
+  /// Whether the polling loop is alive
+  final bool running;
+
+  /// Time of the last scheduler pass (epoch seconds)
+  final PlatformInt64? lastTick;
+  final List<CronExecutingJob> executing;
+
+  const CronSchedulerStatus({
+    required this.enabled,
+    required this.running,
+    this.lastTick,
+    required this.executing,
+  });
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      running.hashCode ^
+      lastTick.hashCode ^
+      executing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronSchedulerStatus &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          running == other.running &&
+          lastTick == other.lastTick &&
+          executing == other.executing;
+}
+
+/// How a shell job's process is started
+class CronShellSandboxDto {
+  /// Empty = the project's directory, or the workspace for global jobs
+  final String workingDir;
+
+  /// Pass the app's environment through; otherwise only PATH, HOME,
+  /// USER, LANG and a few other basics are kept
+  final bool inheritEnv;
+  final List<CronEnvVarDto> env;
+
+  /// CPU time limit in seconds (0 = none; Unix only)
+  final BigInt cpuLimitSecs;
+
+  /// Address space limit in MB (0 = none; Unix only)
+  final BigInt memoryLimitMb;
+
+  const CronShellSandboxDto({
+    required this.workingDir,
+    required this.inheritEnv,
+    required this.env,
+    required this.cpuLimitSecs,
+    required this.memoryLimitMb,
+  });
+
+  static Future<CronShellSandboxDto> default_() =>
+      RustLib.instance.api.crateApiCronApiCronShellSandboxDtoDefault();
+
+  @override
+  int get hashCode =>
+      workingDir.hashCode ^
+      inheritEnv.hashCode ^
+      env.hashCode ^
+      cpuLimitSecs.hashCode ^
+      memoryLimitMb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronShellSandboxDto &&
+          runtimeType == other.runtimeType &&
+          workingDir == other.workingDir &&
+          inheritEnv == other.inheritEnv &&
+          env == other.env &&
+          cpuLimitSecs == other.cpuLimitSecs &&
+          memoryLimitMb == other.memoryLimitMb;
+}
+
+/// Endpoint for triggering a webhook job
+class CronWebhookInfo {
+  /// Local URL to POST to
+  final String url;
+
+  /// Sent as the `X-Cron-Secret` header (or `?secret=` query parameter)
+  final String secret;
+
+  const CronWebhookInfo({required this.url, required this.secret});
+
+  @override
+  int get hashCode => url.hashCode ^ secret.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CronWebhookInfo &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          secret == other.secret;
 }
@@ -48,6 +48,9 @@ class CronNotification {
   /// UTC epoch seconds when the job finished
   final PlatformInt64 finishedAt;
 
+  /// The job was disabled because it failed too many times in a row
+  final bool autoDisabled;
+
   const CronNotification({
     required this.jobId,
     required this.jobName,
@@ -59,6 +62,7 @@ class CronNotification {
     required this.prompt,
     required this.durationMs,
     required this.finishedAt,
+    required this.autoDisabled,
   });
 
   @override
@@ -72,7 +76,8 @@ class CronNotification {
       output.hashCode ^
       prompt.hashCode ^
       durationMs.hashCode ^
-      finishedAt.hashCode;
+      finishedAt.hashCode ^
+      autoDisabled.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -88,5 +93,6 @@ class CronNotification {
           output == other.output &&
           prompt == other.prompt &&
           durationMs == other.durationMs &&
-          finishedAt == other.finishedAt;
+          finishedAt == other.finishedAt &&
+          autoDisabled == other.autoDisabled;
 }
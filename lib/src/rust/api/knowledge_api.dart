@@ -6,49 +6,65 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `category_to_string`, `ensure_memory_backend`, `entry_to_dto`, `memory_backend`, `parse_category`
+// These functions are ignored because they are not marked as `pub`: `category_to_string`, `delete_project_knowledge`, `ensure_memory_backend`, `entry_to_dto`, `memory_backends`, `parse_category`, `project_memory_workspace`, `recall_entries`, `recall_for_project`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
 
 /// Get knowledge base statistics
 Future<KnowledgeStats> getKnowledgeStats() =>
     RustLib.instance.api.crateApiKnowledgeApiGetKnowledgeStats();
 
-/// List knowledge entries, optionally filtered by category
+/// List knowledge entries, optionally filtered by category.
+/// With `project_id`, lists that project's memory instead of the global one.
 Future<List<KnowledgeEntry>> listKnowledgeEntries({
   String? category,
   required int limit,
+  String? projectId,
 }) => RustLib.instance.api.crateApiKnowledgeApiListKnowledgeEntries(
   category: category,
   limit: limit,
+  projectId: projectId,
 );
 
-/// Search knowledge entries by query (semantic search if embeddings available, otherwise keyword)
+/// Search knowledge entries by query (semantic search if embeddings available, otherwise keyword).
+/// With `project_id`, searches that project's memory instead of the global one.
 Future<List<KnowledgeEntry>> searchKnowledge({
   required String query,
   required int limit,
+  String? projectId,
 }) => RustLib.instance.api.crateApiKnowledgeApiSearchKnowledge(
   query: query,
   limit: limit,
+  projectId: projectId,
 );
 
-/// Add a new knowledge entry
+/// Add a new knowledge entry, to a project's memory when `project_id` is set
 Future<String> addKnowledgeEntry({
   required String key,
   required String content,
   required String category,
+  String? projectId,
 }) => RustLib.instance.api.crateApiKnowledgeApiAddKnowledgeEntry(
   key: key,
   content: content,
   category: category,
+  projectId: projectId,
 );
 
 /// Delete a knowledge entry by key
-Future<String> deleteKnowledgeEntry({required String key}) =>
-    RustLib.instance.api.crateApiKnowledgeApiDeleteKnowledgeEntry(key: key);
+Future<String> deleteKnowledgeEntry({required String key, String? projectId}) =>
+    RustLib.instance.api.crateApiKnowledgeApiDeleteKnowledgeEntry(
+      key: key,
+      projectId: projectId,
+    );
 
 /// Get a single knowledge entry by key
-Future<KnowledgeEntry?> getKnowledgeEntry({required String key}) =>
-    RustLib.instance.api.crateApiKnowledgeApiGetKnowledgeEntry(key: key);
+Future<KnowledgeEntry?> getKnowledgeEntry({
+  required String key,
+  String? projectId,
+}) => RustLib.instance.api.crateApiKnowledgeApiGetKnowledgeEntry(
+  key: key,
+  projectId: projectId,
+);
 
 /// A memory/knowledge entry for display in the GUI
 class KnowledgeEntry {
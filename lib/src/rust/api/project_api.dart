@@ -6,15 +6,21 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `default_project_status`, `default_project_type`, `persist_store`, `persisted_to_dto`, `persisted_to_summary`, `project_store`, `project_type_to_string`, `status_to_string`, `store_file_path`, `string_to_project_type`, `string_to_status`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PersistedProject`, `ProjectStore`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `apply_autonomy_overrides`, `apply_model_overrides`, `char_cost`, `default_project_status`, `default_project_type`, `default_true`, `detach_session`, `estimate_tokens`, `is_valid_project_id`, `narrow_enabled_tools`, `persist_store`, `persisted_to_dto`, `persisted_to_summary`, `profile_exists`, `project_context_tool`, `project_info`, `project_prompt_context`, `project_store`, `project_type_to_string`, `remove_sessions_from_projects`, `session_project_map`, `set_session_project`, `status_to_string`, `store_file_path`, `string_to_project_type`, `string_to_status`, `sync_archived_cron_jobs`, `truncate_to_tokens`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PersistedProject`, `ProjectContextTool`, `ProjectStore`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `description`, `eq`, `execute`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `name`, `parameters_schema`
 // These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
 
 /// Initialize project store — load from disk
 Future<String> initProjectStore() =>
     RustLib.instance.api.crateApiProjectApiInitProjectStore();
 
+/// Check that every project session exists and belongs to only one project,
+/// and adopt memberships still recorded on the session side by older versions.
+/// With `repair`, the problems found are fixed and saved.
+Future<ProjectSessionReport> checkProjectSessions({required bool repair}) =>
+    RustLib.instance.api.crateApiProjectApiCheckProjectSessions(repair: repair);
+
 /// List all projects (summary only)
 Future<List<ProjectSummary>> listProjects() =>
     RustLib.instance.api.crateApiProjectApiListProjects();
@@ -23,15 +29,18 @@ Future<List<ProjectSummary>> listProjects() =>
 Future<ProjectDto?> getProject({required String projectId}) =>
     RustLib.instance.api.crateApiProjectApiGetProject(projectId: projectId);
 
-/// Create or update a project
+/// Create or update a project. Session membership of an existing project is
+/// left as is; a new project takes over the listed sessions from other projects.
 Future<String> upsertProject({required ProjectDto project}) =>
     RustLib.instance.api.crateApiProjectApiUpsertProject(project: project);
 
-/// Delete a project (does NOT delete the sessions; only the project container)
+/// Delete a project (does NOT delete the sessions; only the project container).
+/// Its sessions become free sessions; the project's cron jobs and memory are
+/// deleted with it.
 Future<String> deleteProject({required String projectId}) =>
     RustLib.instance.api.crateApiProjectApiDeleteProject(projectId: projectId);
 
-/// Add a session to a project
+/// Add a session to a project, moving it out of any other project
 Future<String> addSessionToProject({
   required String projectId,
   required String sessionId,
@@ -58,7 +67,8 @@ Future<String> updateProjectContext({
   pinnedContext: pinnedContext,
 );
 
-/// Update project status
+/// Update project status. Archiving pauses the project's cron jobs;
+/// restoring it resumes them.
 Future<String> updateProjectStatus({
   required String projectId,
   required ProjectStatus status,
@@ -107,6 +117,69 @@ Future<String> getProjectPinnedContext({required String projectId}) => RustLib
     .api
     .crateApiProjectApiGetProjectPinnedContext(projectId: projectId);
 
+/// A project's overrides of the global autonomy settings, applied to the
+/// agents of its sessions and its cron jobs. Empty fields inherit the
+/// global value.
+class ProjectAutonomyDto {
+  /// "read_only", "supervised" or "full"; empty = global level
+  final String level;
+
+  /// Run without approval prompts; None = global setting
+  final bool? trustMe;
+
+  /// Replaces the global allowed commands when non-empty
+  final List<String> allowedCommands;
+
+  /// Forbidden in addition to the global forbidden paths
+  final List<String> forbiddenPaths;
+
+  /// Tools approved without asking, on top of the global list
+  final List<String> autoApprove;
+
+  /// Tools always asked about, on top of the global list. Wins over
+  /// `auto_approve`, here and globally.
+  final List<String> alwaysAsk;
+
+  /// The only tools the agents get; empty = no restriction
+  final List<String> enabledTools;
+
+  const ProjectAutonomyDto({
+    required this.level,
+    this.trustMe,
+    required this.allowedCommands,
+    required this.forbiddenPaths,
+    required this.autoApprove,
+    required this.alwaysAsk,
+    required this.enabledTools,
+  });
+
+  static Future<ProjectAutonomyDto> default_() =>
+      RustLib.instance.api.crateApiProjectApiProjectAutonomyDtoDefault();
+
+  @override
+  int get hashCode =>
+      level.hashCode ^
+      trustMe.hashCode ^
+      allowedCommands.hashCode ^
+      forbiddenPaths.hashCode ^
+      autoApprove.hashCode ^
+      alwaysAsk.hashCode ^
+      enabledTools.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProjectAutonomyDto &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          trustMe == other.trustMe &&
+          allowedCommands == other.allowedCommands &&
+          forbiddenPaths == other.forbiddenPaths &&
+          autoApprove == other.autoApprove &&
+          alwaysAsk == other.alwaysAsk &&
+          enabledTools == other.enabledTools;
+}
+
 /// Full project DTO for Flutter UI
 class ProjectDto {
   final String id;
@@ -129,9 +202,28 @@ class ProjectDto {
   /// Default role ID for new sessions (empty = no default)
   final String defaultRoleId;
 
-  /// Associated session IDs
+  /// Associated session IDs. Ignored by `upsert_project` for existing
+  /// projects; use `add_session_to_project` / `remove_session_from_project`.
   final List<String> sessionIds;
   final List<String> tags;
+
+  /// Agents recall global memory when the project's own memory has too
+  /// few matches
+  final bool globalMemoryFallback;
+
+  /// Autonomy and tool settings for this project's sessions
+  final ProjectAutonomyDto autonomy;
+
+  /// Model provider profile for this project's sessions (empty = the
+  /// global default provider)
+  final String modelProfileId;
+
+  /// Model for this project's sessions (empty = the profile's default
+  /// model, or the global one)
+  final String model;
+
+  /// None = the global default temperature
+  final double? temperature;
   final PlatformInt64 createdAt;
   final PlatformInt64 updatedAt;
 
@@ -149,6 +241,11 @@ class ProjectDto {
     required this.defaultRoleId,
     required this.sessionIds,
     required this.tags,
+    required this.globalMemoryFallback,
+    required this.autonomy,
+    required this.modelProfileId,
+    required this.model,
+    this.temperature,
     required this.createdAt,
     required this.updatedAt,
   });
@@ -168,6 +265,11 @@ class ProjectDto {
       defaultRoleId.hashCode ^
       sessionIds.hashCode ^
       tags.hashCode ^
+      globalMemoryFallback.hashCode ^
+      autonomy.hashCode ^
+      modelProfileId.hashCode ^
+      model.hashCode ^
+      temperature.hashCode ^
       createdAt.hashCode ^
       updatedAt.hashCode;
 
@@ -189,10 +291,57 @@ class ProjectDto {
           defaultRoleId == other.defaultRoleId &&
           sessionIds == other.sessionIds &&
           tags == other.tags &&
+          globalMemoryFallback == other.globalMemoryFallback &&
+          autonomy == other.autonomy &&
+          modelProfileId == other.modelProfileId &&
+          model == other.model &&
+          temperature == other.temperature &&
           createdAt == other.createdAt &&
           updatedAt == other.updatedAt;
 }
 
+/// Result of checking project ↔ session membership
+class ProjectSessionReport {
+  /// Sessions whose legacy session-side project_id was adopted by that project
+  final List<String> migratedSessions;
+
+  /// Memberships of sessions that no longer exist
+  final List<String> missingSessions;
+
+  /// Sessions listed by more than one project (the first project keeps them)
+  final List<String> duplicateSessions;
+
+  /// Whether the problems were fixed
+  final bool repaired;
+
+  const ProjectSessionReport({
+    required this.migratedSessions,
+    required this.missingSessions,
+    required this.duplicateSessions,
+    required this.repaired,
+  });
+
+  static Future<ProjectSessionReport> default_() =>
+      RustLib.instance.api.crateApiProjectApiProjectSessionReportDefault();
+
+  @override
+  int get hashCode =>
+      migratedSessions.hashCode ^
+      missingSessions.hashCode ^
+      duplicateSessions.hashCode ^
+      repaired.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProjectSessionReport &&
+          runtimeType == other.runtimeType &&
+          migratedSessions == other.migratedSessions &&
+          missingSessions == other.missingSessions &&
+          duplicateSessions == other.duplicateSessions &&
+          repaired == other.repaired;
+}
+
 /// Project status enum exposed to Flutter
 enum ProjectStatus { active, paused, archived, completed }
 
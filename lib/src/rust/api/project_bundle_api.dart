@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `default_true`, `parse_bundle`, `roll_back_import`, `unique_name`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BundleKnowledge`, `BundleProject`, `ProjectBundle`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Write a project with its sessions, roles, cron jobs and knowledge to a
+/// bundle file at `path`. Returns "ok" or "error: ...".
+Future<String> exportProject({
+  required String projectId,
+  required String path,
+}) => RustLib.instance.api.crateApiProjectBundleApiExportProject(
+  projectId: projectId,
+  path: path,
+);
+
+/// Restore a project from a bundle file written by `export_project`, as a
+/// new project next to the existing ones
+Future<ProjectImportResult> importProject({required String path}) =>
+    RustLib.instance.api.crateApiProjectBundleApiImportProject(path: path);
+
+/// Outcome of `import_project`
+class ProjectImportResult {
+  /// ID of the imported project (empty when the import failed)
+  final String projectId;
+  final List<String> errors;
+
+  /// What was renamed, reused or left out along the way
+  final List<String> notes;
+
+  const ProjectImportResult({
+    required this.projectId,
+    required this.errors,
+    required this.notes,
+  });
+
+  @override
+  int get hashCode => projectId.hashCode ^ errors.hashCode ^ notes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProjectImportResult &&
+          runtimeType == other.runtimeType &&
+          projectId == other.projectId &&
+          errors == other.errors &&
+          notes == other.notes;
+}
@@ -0,0 +1,126 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `blob_before_turn`, `command`, `git_command`, `git`, `git`, `hash_file`, `keep_turn_snapshot`, `open_snapshot_repo`, `parse_commit_line`, `read_status`, `restore_files`, `run_with_input`, `run`, `save_dirty_files`, `snapshot_before_turn`, `snapshot_git_dir`, `snapshot_ref`, `turn_tree`, `write_tree`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SnapshotFile`, `SnapshotRepo`, `TurnSnapshot`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Branch, changes and recent commits of the project's directory, or None
+/// when it isn't in a git work tree (or git isn't installed)
+Future<ProjectGitStatus?> getProjectGitStatus({required String projectId}) =>
+    RustLib.instance.api.crateApiProjectGitApiGetProjectGitStatus(
+      projectId: projectId,
+    );
+
+/// Revert the files written by the session's last undoable agent turn.
+/// Files changed again since then are left alone. Calling it again undoes
+/// the turn before that.
+Future<AgentUndoResult> undoLastAgentChanges({required String sessionId}) =>
+    RustLib.instance.api.crateApiProjectGitApiUndoLastAgentChanges(
+      sessionId: sessionId,
+    );
+
+/// Outcome of `undo_last_agent_changes`. File paths are relative to the
+/// project directory.
+class AgentUndoResult {
+  /// Files put back the way they were before the turn
+  final List<String> restored;
+
+  /// Files the turn created, now deleted
+  final List<String> removed;
+
+  /// Files left alone, and why; or why nothing could be undone
+  final List<String> errors;
+
+  const AgentUndoResult({
+    required this.restored,
+    required this.removed,
+    required this.errors,
+  });
+
+  @override
+  int get hashCode => restored.hashCode ^ removed.hashCode ^ errors.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AgentUndoResult &&
+          runtimeType == other.runtimeType &&
+          restored == other.restored &&
+          removed == other.removed &&
+          errors == other.errors;
+}
+
+/// A commit in a project's history
+class GitCommitDto {
+  /// Abbreviated hash
+  final String hash;
+  final String summary;
+  final String author;
+
+  /// Commit time, epoch seconds
+  final PlatformInt64 timestamp;
+
+  const GitCommitDto({
+    required this.hash,
+    required this.summary,
+    required this.author,
+    required this.timestamp,
+  });
+
+  @override
+  int get hashCode =>
+      hash.hashCode ^ summary.hashCode ^ author.hashCode ^ timestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GitCommitDto &&
+          runtimeType == other.runtimeType &&
+          hash == other.hash &&
+          summary == other.summary &&
+          author == other.author &&
+          timestamp == other.timestamp;
+}
+
+/// Git state of a project directory
+class ProjectGitStatus {
+  /// Current branch ("HEAD" when detached)
+  final String branch;
+
+  /// Uncommitted changes, untracked files included
+  final bool dirty;
+  final int changedFiles;
+
+  /// Newest first
+  final List<GitCommitDto> recentCommits;
+
+  const ProjectGitStatus({
+    required this.branch,
+    required this.dirty,
+    required this.changedFiles,
+    required this.recentCommits,
+  });
+
+  @override
+  int get hashCode =>
+      branch.hashCode ^
+      dirty.hashCode ^
+      changedFiles.hashCode ^
+      recentCommits.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProjectGitStatus &&
+          runtimeType == other.runtimeType &&
+          branch == other.branch &&
+          dirty == other.dirty &&
+          changedFiles == other.changedFiles &&
+          recentCommits == other.recentCommits;
+}
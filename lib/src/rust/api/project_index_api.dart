@@ -0,0 +1,121 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `blob_to_embedding`, `chunk_lines`, `cosine_similarity`, `embed_chunks`, `embedding_provider`, `embedding_to_blob`, `get_meta`, `index_db_path`, `index_project`, `indexable_project_dir`, `indexing`, `keyword_score`, `list_source_files`, `merge_hits`, `open_index_db`, `project_search_tool`, `query_terms`, `search_index`, `set_meta`, `spawn_project_indexer`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Chunk`, `IndexingGuard`, `ProjectSearchTool`, `SourceFile`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `description`, `drop`, `execute`, `fmt`, `fmt`, `name`, `parameters_schema`
+
+/// Start (re)indexing a code project in the background
+Future<String> indexProjectCode({required String projectId}) => RustLib
+    .instance
+    .api
+    .crateApiProjectIndexApiIndexProjectCode(projectId: projectId);
+
+/// Get the state of a project's code index
+Future<ProjectCodeIndexStatus> getProjectCodeIndexStatus({
+  required String projectId,
+}) => RustLib.instance.api.crateApiProjectIndexApiGetProjectCodeIndexStatus(
+  projectId: projectId,
+);
+
+/// Search a code project's files for `query`
+Future<List<CodeSearchHit>> searchProjectCode({
+  required String projectId,
+  required String query,
+}) => RustLib.instance.api.crateApiProjectIndexApiSearchProjectCode(
+  projectId: projectId,
+  query: query,
+);
+
+/// A matching chunk of a project file
+class CodeSearchHit {
+  /// Path relative to the project directory
+  final String path;
+
+  /// 1-based, inclusive
+  final int startLine;
+  final int endLine;
+  final String snippet;
+
+  /// Cosine similarity for semantic matches, term match ratio for chunks
+  /// without an embedding; semantic matches are listed first
+  final double score;
+
+  const CodeSearchHit({
+    required this.path,
+    required this.startLine,
+    required this.endLine,
+    required this.snippet,
+    required this.score,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      startLine.hashCode ^
+      endLine.hashCode ^
+      snippet.hashCode ^
+      score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CodeSearchHit &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          startLine == other.startLine &&
+          endLine == other.endLine &&
+          snippet == other.snippet &&
+          score == other.score;
+}
+
+/// State of a project's code index
+class ProjectCodeIndexStatus {
+  final bool indexing;
+  final int files;
+  final int chunks;
+
+  /// Chunks with an embedding; semantic search is used when > 0
+  final int embeddedChunks;
+
+  /// UTC epoch seconds of the last completed indexing run
+  final PlatformInt64? lastIndexedAt;
+
+  /// Error of the last run (empty = none)
+  final String lastError;
+
+  const ProjectCodeIndexStatus({
+    required this.indexing,
+    required this.files,
+    required this.chunks,
+    required this.embeddedChunks,
+    this.lastIndexedAt,
+    required this.lastError,
+  });
+
+  @override
+  int get hashCode =>
+      indexing.hashCode ^
+      files.hashCode ^
+      chunks.hashCode ^
+      embeddedChunks.hashCode ^
+      lastIndexedAt.hashCode ^
+      lastError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProjectCodeIndexStatus &&
+          runtimeType == other.runtimeType &&
+          indexing == other.indexing &&
+          files == other.files &&
+          chunks == other.chunks &&
+          embeddedChunks == other.embeddedChunks &&
+          lastIndexedAt == other.lastIndexedAt &&
+          lastError == other.lastError;
+}
@@ -0,0 +1,100 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `load_templates`, `parse_template`, `relative_file_path`, `render_template_text`, `scaffold_files`, `user_templates_dir`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ProjectTemplate`, `TemplateCronJob`, `TemplateFile`, `TemplateKnowledge`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`, `default`
+
+/// List built-in and user-defined project templates
+Future<List<ProjectTemplateDto>> listProjectTemplates() =>
+    RustLib.instance.api.crateApiProjectTemplateApiListProjectTemplates();
+
+/// Directory where user-defined templates are read from (created if missing)
+Future<String> getProjectTemplatesDir() =>
+    RustLib.instance.api.crateApiProjectTemplateApiGetProjectTemplatesDir();
+
+/// Create a project from a template: sets its pinned context and roles,
+/// creates the starter cron jobs and knowledge entries, and with `scaffold`
+/// writes the template's files into `project_dir` (existing files are kept).
+/// Returns the new project ID, or "error: ...".
+Future<String> createProjectFromTemplate({
+  required String templateId,
+  required String name,
+  required String projectDir,
+  required bool scaffold,
+}) => RustLib.instance.api.crateApiProjectTemplateApiCreateProjectFromTemplate(
+  templateId: templateId,
+  name: name,
+  projectDir: projectDir,
+  scaffold: scaffold,
+);
+
+/// A project template for the "new project" picker
+class ProjectTemplateDto {
+  final String id;
+  final String name;
+  final String description;
+  final String icon;
+  final String colorTag;
+
+  /// "general", "code_project", "data_processing", "writing" or "automation"
+  final String projectType;
+
+  /// Shipped with the app (false = loaded from the user's templates dir)
+  final bool isBuiltin;
+  final List<String> roleIds;
+  final int cronJobCount;
+  final int knowledgeCount;
+  final int fileCount;
+
+  const ProjectTemplateDto({
+    required this.id,
+    required this.name,
+    required this.description,
+    required this.icon,
+    required this.colorTag,
+    required this.projectType,
+    required this.isBuiltin,
+    required this.roleIds,
+    required this.cronJobCount,
+    required this.knowledgeCount,
+    required this.fileCount,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      description.hashCode ^
+      icon.hashCode ^
+      colorTag.hashCode ^
+      projectType.hashCode ^
+      isBuiltin.hashCode ^
+      roleIds.hashCode ^
+      cronJobCount.hashCode ^
+      knowledgeCount.hashCode ^
+      fileCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProjectTemplateDto &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          description == other.description &&
+          icon == other.icon &&
+          colorTag == other.colorTag &&
+          projectType == other.projectType &&
+          isBuiltin == other.isBuiltin &&
+          roleIds == other.roleIds &&
+          cronJobCount == other.cronJobCount &&
+          knowledgeCount == other.knowledgeCount &&
+          fileCount == other.fileCount;
+}
@@ -0,0 +1,94 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `activity_db_path`, `can_write_files`, `cron_events`, `file_change_events`, `file_changes`, `knowledge_events`, `normalize`, `open_activity_db`, `paginate`, `query_file_changes`, `record_agent_file_changes`, `record_file_changes`, `session_events`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Activity of a project older than `cursor` (epoch seconds; None = from
+/// now), newest first. A page holds about `limit` events; events sharing a
+/// timestamp are never split across pages.
+Future<ProjectTimelinePage> getProjectTimeline({
+  required String projectId,
+  PlatformInt64? cursor,
+  required int limit,
+}) => RustLib.instance.api.crateApiProjectTimelineApiGetProjectTimeline(
+  projectId: projectId,
+  cursor: cursor,
+  limit: limit,
+);
+
+/// One entry of a project's activity feed
+class ProjectTimelineEvent {
+  /// Epoch seconds
+  final PlatformInt64 timestamp;
+
+  /// "session_created", "session_updated", "cron_run", "knowledge_added"
+  /// or "file_changed"
+  final String kind;
+
+  /// Session title, job name, knowledge key or file path
+  final String title;
+  final String detail;
+
+  /// The session (sessions and file changes) or cron job the event is about
+  final String refId;
+
+  /// Run status for cron runs ("ok", "error", ...); empty otherwise
+  final String status;
+
+  const ProjectTimelineEvent({
+    required this.timestamp,
+    required this.kind,
+    required this.title,
+    required this.detail,
+    required this.refId,
+    required this.status,
+  });
+
+  @override
+  int get hashCode =>
+      timestamp.hashCode ^
+      kind.hashCode ^
+      title.hashCode ^
+      detail.hashCode ^
+      refId.hashCode ^
+      status.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProjectTimelineEvent &&
+          runtimeType == other.runtimeType &&
+          timestamp == other.timestamp &&
+          kind == other.kind &&
+          title == other.title &&
+          detail == other.detail &&
+          refId == other.refId &&
+          status == other.status;
+}
+
+/// A page of the timeline
+class ProjectTimelinePage {
+  final List<ProjectTimelineEvent> events;
+
+  /// Pass as `cursor` to get the next (older) page; None at the end
+  final PlatformInt64? nextCursor;
+
+  const ProjectTimelinePage({required this.events, this.nextCursor});
+
+  @override
+  int get hashCode => events.hashCode ^ nextCursor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProjectTimelinePage &&
+          runtimeType == other.runtimeType &&
+          events == other.events &&
+          nextCursor == other.nextCursor;
+}
@@ -6,7 +6,7 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `persist_to_disk`, `session_store`, `sessions_file_path`
+// These functions are ignored because they are not marked as `pub`: `append_session_messages`, `clear_legacy_session_projects`, `export_sessions`, `import_sessions`, `legacy_session_projects`, `persist_to_disk`, `session_store`, `sessions_file_path`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PersistedMessage`, `PersistedSession`, `SessionStore`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 // These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
@@ -25,24 +25,24 @@ Future<SessionDetail?> getSessionDetail({required String sessionId}) => RustLib
     .api
     .crateApiSessionsApiGetSessionDetail(sessionId: sessionId);
 
-/// Save/update a session with its messages
+/// Save/update a session with its messages.
+/// Project membership is left alone; it only changes through
+/// `add_session_to_project` / `remove_session_from_project`.
 Future<String> saveSession({
   required String sessionId,
   required String title,
   required List<SessionMessage> messages,
-  required String projectId,
   required bool ephemeral,
   required String agentBinding,
 }) => RustLib.instance.api.crateApiSessionsApiSaveSession(
   sessionId: sessionId,
   title: title,
   messages: messages,
-  projectId: projectId,
   ephemeral: ephemeral,
   agentBinding: agentBinding,
 );
 
-/// Delete a session
+/// Delete a session and remove it from its project
 Future<String> deleteSession({required String sessionId}) =>
     RustLib.instance.api.crateApiSessionsApiDeleteSession(sessionId: sessionId);
 
@@ -77,7 +77,7 @@ Future<String> updateSessionMetadata({
 Future<SessionStats> getSessionStats() =>
     RustLib.instance.api.crateApiSessionsApiGetSessionStats();
 
-/// Clear all sessions
+/// Clear all sessions, emptying their projects
 Future<String> clearAllSessions() =>
     RustLib.instance.api.crateApiSessionsApiClearAllSessions();
 
@@ -16,6 +16,11 @@ import 'api/knowledge_api.dart';
 import 'api/llm_debug_api.dart';
 import 'api/mcp_api.dart';
 import 'api/project_api.dart';
+import 'api/project_bundle_api.dart';
+import 'api/project_git_api.dart';
+import 'api/project_index_api.dart';
+import 'api/project_template_api.dart';
+import 'api/project_timeline_api.dart';
 import 'api/providers_api.dart';
 import 'api/proxy_api.dart';
 import 'api/routes_api.dart';
@@ -85,7 +90,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -845868303;
+  int get rustContentHash => -7856974;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -105,6 +110,8 @@ abstract class RustLibApi extends BaseApi {
     String? model,
     required bool deleteAfterRun,
     String? targetSessionId,
+    String? timezone,
+    String? projectId,
   });
 
   Future<String> crateApiWorkspaceApiAddAllowedCommand({
@@ -115,6 +122,7 @@ abstract class RustLibApi extends BaseApi {
     required String key,
     required String content,
     required String category,
+    String? projectId,
   });
 
   Future<String> crateApiMcpApiAddMcpServer({required McpServerDto server});
@@ -139,6 +147,8 @@ abstract class RustLibApi extends BaseApi {
     required String scheduleType,
     required String expression,
     required String command,
+    String? timezone,
+    String? projectId,
   });
 
   Future<AppConfig> crateApiConfigApiAppConfigDefault();
@@ -155,6 +165,10 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiAgentApiCancelGeneration({required String sessionId});
 
+  Future<ProjectSessionReport> crateApiProjectApiCheckProjectSessions({
+    required bool repair,
+  });
+
   Future<String> crateApiSessionsApiClearAllSessions();
 
   Future<String> crateApiLlmDebugApiClearLlmDebugEntries();
@@ -184,8 +198,21 @@ abstract class RustLibApi extends BaseApi {
     required String dst,
   });
 
+  Future<String> crateApiProjectTemplateApiCreateProjectFromTemplate({
+    required String templateId,
+    required String name,
+    required String projectDir,
+    required bool scaffold,
+  });
+
   ChatSessionInfo crateApiAgentApiCreateSession();
 
+  Future<CronActiveWindowDto> crateApiCronApiCronActiveWindowDtoDefault();
+
+  Future<CronEnvVarDto> crateApiCronApiCronEnvVarDtoDefault();
+
+  Future<CronShellSandboxDto> crateApiCronApiCronShellSandboxDtoDefault();
+
   int crateApiAgentsApiDelegateAgentCount();
 
   Future<String> crateApiAgentWorkspaceApiDeleteAgentWorkspace({
@@ -194,12 +221,23 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiKnowledgeApiDeleteKnowledgeEntry({
     required String key,
+    String? projectId,
   });
 
   Future<String> crateApiProjectApiDeleteProject({required String projectId});
 
   Future<String> crateApiSessionsApiDeleteSession({required String sessionId});
 
+  Future<String> crateApiCronApiExportCronJobs({
+    String? projectId,
+    required String format,
+  });
+
+  Future<String> crateApiProjectBundleApiExportProject({
+    required String projectId,
+    required String path,
+  });
+
   Future<FeatureToggles> crateApiWorkspaceApiFeatureTogglesDefault();
 
   Future<String> crateApiBrowserBootstrapFindAgentBrowser();
@@ -224,8 +262,27 @@ abstract class RustLibApi extends BaseApi {
 
   Future<CostConfigDto> crateApiWorkspaceApiGetCostConfig();
 
+  Future<CronActiveWindowDto> crateApiCronApiGetCronActiveWindow();
+
   Future<CronConfigDto> crateApiCronApiGetCronConfig();
 
+  Future<CronJobStatsDto?> crateApiCronApiGetCronJobStats({
+    required String jobId,
+    required int windowHours,
+  });
+
+  Future<CronRunOutput?> crateApiCronApiGetCronRunOutput({
+    required PlatformInt64 runId,
+    required BigInt offset,
+    required BigInt len,
+  });
+
+  Future<CronSchedulerStatus> crateApiCronApiGetCronSchedulerStatus();
+
+  Future<CronWebhookInfo?> crateApiCronApiGetCronWebhookInfo({
+    required String jobId,
+  });
+
   Future<AppConfig> crateApiAgentApiGetCurrentConfig();
 
   Future<String> crateApiProvidersApiGetDefaultProfileId();
@@ -240,6 +297,7 @@ abstract class RustLibApi extends BaseApi {
 
   Future<KnowledgeEntry?> crateApiKnowledgeApiGetKnowledgeEntry({
     required String key,
+    String? projectId,
   });
 
   Future<KnowledgeStats> crateApiKnowledgeApiGetKnowledgeStats();
@@ -261,10 +319,25 @@ abstract class RustLibApi extends BaseApi {
 
   Future<ProjectDto?> crateApiProjectApiGetProject({required String projectId});
 
+  Future<ProjectCodeIndexStatus>
+  crateApiProjectIndexApiGetProjectCodeIndexStatus({required String projectId});
+
+  Future<ProjectGitStatus?> crateApiProjectGitApiGetProjectGitStatus({
+    required String projectId,
+  });
+
   Future<String> crateApiProjectApiGetProjectPinnedContext({
     required String projectId,
   });
 
+  Future<String> crateApiProjectTemplateApiGetProjectTemplatesDir();
+
+  Future<ProjectTimelinePage> crateApiProjectTimelineApiGetProjectTimeline({
+    required String projectId,
+    PlatformInt64? cursor,
+    required int limit,
+  });
+
   Future<ProxyConfigDto> crateApiProxyApiGetProxyConfig();
 
   ProxyConfigDto crateApiProxyApiGetRuntimeProxyStatus();
@@ -305,6 +378,20 @@ abstract class RustLibApi extends BaseApi {
 
   String crateApiSimpleGreet({required String name});
 
+  Future<CronImportResult> crateApiCronApiImportCronJobs({
+    required String content,
+    required String format,
+    required bool dryRun,
+  });
+
+  Future<ProjectImportResult> crateApiProjectBundleApiImportProject({
+    required String path,
+  });
+
+  Future<String> crateApiProjectIndexApiIndexProjectCode({
+    required String projectId,
+  });
+
   Future<String> crateApiAgentWorkspaceApiInitAgentWorkspaceStore();
 
   Future<void> crateApiSimpleInitApp();
@@ -330,13 +417,23 @@ abstract class RustLibApi extends BaseApi {
 
   Future<List<ChannelSummary>> crateApiWorkspaceApiListChannels();
 
+  Future<List<CronRunDto>> crateApiCronApiListCronChainRuns({
+    required String chainId,
+  });
+
   Future<List<CronJobDto>> crateApiCronApiListCronJobs();
 
+  Future<List<CronJobDto>> crateApiCronApiListCronJobsForProject({
+    required String projectId,
+  });
+
   Future<List<CronRunDto>> crateApiCronApiListCronRuns({
     required String jobId,
     required int limit,
   });
 
+  Future<List<String>> crateApiCronApiListCronSecrets();
+
   Future<List<DelegateAgentDto>> crateApiAgentsApiListDelegateAgents();
 
   Future<List<EmbeddingRouteDto>> crateApiRoutesApiListEmbeddingRoutes();
@@ -344,6 +441,7 @@ abstract class RustLibApi extends BaseApi {
   Future<List<KnowledgeEntry>> crateApiKnowledgeApiListKnowledgeEntries({
     String? category,
     required int limit,
+    String? projectId,
   });
 
   Future<List<McpServerDto>> crateApiMcpApiListMcpServers();
@@ -353,6 +451,9 @@ abstract class RustLibApi extends BaseApi {
 
   Future<List<ModelRouteDto>> crateApiRoutesApiListModelRoutes();
 
+  Future<List<ProjectTemplateDto>>
+  crateApiProjectTemplateApiListProjectTemplates();
+
   Future<List<ProjectSummary>> crateApiProjectApiListProjects();
 
   List<ProviderInfo> crateApiConfigApiListProviders();
@@ -379,8 +480,22 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiCronApiPauseCronJob({required String jobId});
 
+  Future<CronSchedulePreview> crateApiCronApiPreviewCronSchedule({
+    required String expression,
+    String? timezone,
+    required int count,
+  });
+
+  Future<ProjectAutonomyDto> crateApiProjectApiProjectAutonomyDtoDefault();
+
+  Future<ProjectSessionReport> crateApiProjectApiProjectSessionReportDefault();
+
   Future<ProxyConfigDto> crateApiProxyApiProxyConfigDtoDefault();
 
+  Future<String> crateApiCronApiRegenerateCronWebhookSecret({
+    required String jobId,
+  });
+
   Future<String> crateApiAgentApiReloadConfigFromDisk();
 
   Future<String> crateApiWorkspaceApiRemoveAllowedCommand({
@@ -389,6 +504,8 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiCronApiRemoveCronJob({required String jobId});
 
+  Future<String> crateApiCronApiRemoveCronSecret({required String name});
+
   Future<String> crateApiAgentsApiRemoveDelegateAgent({required String name});
 
   Future<String> crateApiRoutesApiRemoveEmbeddingRoute({required String hint});
@@ -434,6 +551,7 @@ abstract class RustLibApi extends BaseApi {
   Future<String> crateApiAgentApiRespondToToolApprovalById({
     required String requestId,
     required String decision,
+    String? reason,
   });
 
   Future<String> crateApiChannelRuntimeApiRestartChannelListeners();
@@ -457,7 +575,6 @@ abstract class RustLibApi extends BaseApi {
     required String sessionId,
     required String title,
     required List<SessionMessage> messages,
-    required String projectId,
     required bool ephemeral,
     required String agentBinding,
   });
@@ -470,6 +587,12 @@ abstract class RustLibApi extends BaseApi {
   Future<List<KnowledgeEntry>> crateApiKnowledgeApiSearchKnowledge({
     required String query,
     required int limit,
+    String? projectId,
+  });
+
+  Future<List<CodeSearchHit>> crateApiProjectIndexApiSearchProjectCode({
+    required String projectId,
+    required String query,
   });
 
   Future<int> crateApiAgentsApiSeedPresetRoles();
@@ -486,6 +609,77 @@ abstract class RustLibApi extends BaseApi {
     required String message,
   });
 
+  Future<String> crateApiCronApiSetCronActiveWindow({
+    required CronActiveWindowDto window,
+  });
+
+  Future<String> crateApiCronApiSetCronAutoDisableAfter({
+    required int failures,
+  });
+
+  Future<String> crateApiCronApiSetCronJobActiveWindow({
+    required String jobId,
+    CronActiveWindowDto? window,
+  });
+
+  Future<String> crateApiCronApiSetCronJobChain({
+    required String jobId,
+    required String onSuccess,
+    required String onFailure,
+  });
+
+  Future<String> crateApiCronApiSetCronJobDelivery({
+    required String jobId,
+    required List<CronDeliveryDto> targets,
+  });
+
+  Future<String> crateApiCronApiSetCronJobRetryPolicy({
+    required String jobId,
+    required int timeoutSecs,
+    required int maxRetries,
+    required int retryBackoffSecs,
+  });
+
+  Future<String> crateApiCronApiSetCronJobRunPolicy({
+    required String jobId,
+    required String misfirePolicy,
+    required bool noOverlap,
+  });
+
+  Future<String> crateApiCronApiSetCronJobSandbox({
+    required String jobId,
+    required CronShellSandboxDto sandbox,
+  });
+
+  Future<String> crateApiCronApiSetCronJobTimezone({
+    required String jobId,
+    required String timezone,
+  });
+
+  Future<String> crateApiCronApiSetCronJobWatchDebounce({
+    required String jobId,
+    required BigInt debounceMs,
+  });
+
+  Future<String> crateApiCronApiSetCronMaxConcurrency({
+    required int maxConcurrency,
+  });
+
+  Future<String> crateApiCronApiSetCronMaxRunHistory({
+    required int maxRunHistory,
+  });
+
+  Future<String> crateApiCronApiSetCronSchedulerEnabled({
+    required bool enabled,
+  });
+
+  Future<String> crateApiCronApiSetCronSecret({
+    required String name,
+    required String value,
+  });
+
+  Future<String> crateApiCronApiSetCronWebhookPort({required int port});
+
   Future<String> crateApiProvidersApiSetDefaultProfile({required String id});
 
   void crateApiLlmDebugApiSetLlmDebugEnabled({required bool enabled});
@@ -514,6 +708,8 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiChannelRuntimeApiStopChannelListeners();
 
+  Future<String> crateApiCronApiStopCronScheduler();
+
   Stream<CronNotification>
   crateApiCronNotificationApiSubscribeCronNotifications();
 
@@ -524,6 +720,10 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiAgentApiSwitchSession({required String sessionId});
 
+  Future<String> crateApiCronApiTestCronDelivery({
+    required CronDeliveryDto target,
+  });
+
   Future<String> crateApiWorkspaceApiToggleChannel({
     required String channelType,
     required bool enabled,
@@ -545,6 +745,10 @@ abstract class RustLibApi extends BaseApi {
     required String sessionId,
   });
 
+  Future<AgentUndoResult> crateApiProjectGitApiUndoLastAgentChanges({
+    required String sessionId,
+  });
+
   Future<String> crateApiWorkspaceApiUpdateAgentConfig({
     int? maxToolIterations,
     int? maxHistoryMessages,
@@ -659,6 +863,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     String? model,
     required bool deleteAfterRun,
     String? targetSessionId,
+    String? timezone,
+    String? projectId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -672,6 +878,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_opt_String(model, serializer);
           sse_encode_bool(deleteAfterRun, serializer);
           sse_encode_opt_String(targetSessionId, serializer);
+          sse_encode_opt_String(timezone, serializer);
+          sse_encode_opt_String(projectId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -693,6 +901,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           model,
           deleteAfterRun,
           targetSessionId,
+          timezone,
+          projectId,
         ],
         apiImpl: this,
       ),
@@ -711,6 +921,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           "model",
           "deleteAfterRun",
           "targetSessionId",
+          "timezone",
+          "projectId",
         ],
       );
 
@@ -752,6 +964,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required String key,
     required String content,
     required String category,
+    String? projectId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -760,6 +973,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(key, serializer);
           sse_encode_String(content, serializer);
           sse_encode_String(category, serializer);
+          sse_encode_opt_String(projectId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -772,7 +986,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiKnowledgeApiAddKnowledgeEntryConstMeta,
-        argValues: [key, content, category],
+        argValues: [key, content, category, projectId],
         apiImpl: this,
       ),
     );
@@ -781,7 +995,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiKnowledgeApiAddKnowledgeEntryConstMeta =>
       const TaskConstMeta(
         debugName: "add_knowledge_entry",
-        argNames: ["key", "content", "category"],
+        argNames: ["key", "content", "category", "projectId"],
       );
 
   @override
@@ -923,6 +1137,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required String scheduleType,
     required String expression,
     required String command,
+    String? timezone,
+    String? projectId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -932,6 +1148,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(scheduleType, serializer);
           sse_encode_String(expression, serializer);
           sse_encode_String(command, serializer);
+          sse_encode_opt_String(timezone, serializer);
+          sse_encode_opt_String(projectId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -944,7 +1162,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiCronApiAddShellCronJobConstMeta,
-        argValues: [name, scheduleType, expression, command],
+        argValues: [
+          name,
+          scheduleType,
+          expression,
+          command,
+          timezone,
+          projectId,
+        ],
         apiImpl: this,
       ),
     );
@@ -953,7 +1178,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiCronApiAddShellCronJobConstMeta =>
       const TaskConstMeta(
         debugName: "add_shell_cron_job",
-        argNames: ["name", "scheduleType", "expression", "command"],
+        argNames: [
+          "name",
+          "scheduleType",
+          "expression",
+          "command",
+          "timezone",
+          "projectId",
+        ],
       );
 
   @override
@@ -1085,11 +1317,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<String> crateApiSessionsApiClearAllSessions() {
+  Future<ProjectSessionReport> crateApiProjectApiCheckProjectSessions({
+    required bool repair,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(repair, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1097,6 +1332,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             port: port_,
           );
         },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_project_session_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProjectApiCheckProjectSessionsConstMeta,
+        argValues: [repair],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProjectApiCheckProjectSessionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_project_sessions",
+        argNames: ["repair"],
+      );
+
+  @override
+  Future<String> crateApiSessionsApiClearAllSessions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
@@ -1120,7 +1385,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 15,
             port: port_,
           );
         },
@@ -1147,7 +1412,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 16,
             port: port_,
           );
         },
@@ -1175,7 +1440,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 17,
             port: port_,
           );
         },
@@ -1208,7 +1473,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 18,
             port: port_,
           );
         },
@@ -1235,7 +1500,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_String,
@@ -1260,7 +1525,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 20,
             port: port_,
           );
         },
@@ -1287,7 +1552,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 21,
             port: port_,
           );
         },
@@ -1317,7 +1582,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 22,
             port: port_,
           );
         },
@@ -1347,7 +1612,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 23,
             port: port_,
           );
         },
@@ -1379,7 +1644,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 24,
             port: port_,
           );
         },
@@ -1398,164 +1663,294 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "copy_file_to", argNames: ["src", "dst"]);
 
   @override
-  ChatSessionInfo crateApiAgentApiCreateSession() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<String> crateApiProjectTemplateApiCreateProjectFromTemplate({
+    required String templateId,
+    required String name,
+    required String projectDir,
+    required bool scaffold,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+          sse_encode_String(templateId, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_String(projectDir, serializer);
+          sse_encode_bool(scaffold, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_chat_session_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentApiCreateSessionConstMeta,
-        argValues: [],
+        constMeta:
+            kCrateApiProjectTemplateApiCreateProjectFromTemplateConstMeta,
+        argValues: [templateId, name, projectDir, scaffold],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentApiCreateSessionConstMeta =>
-      const TaskConstMeta(debugName: "create_session", argNames: []);
+  TaskConstMeta
+  get kCrateApiProjectTemplateApiCreateProjectFromTemplateConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_project_from_template",
+        argNames: ["templateId", "name", "projectDir", "scaffold"],
+      );
 
   @override
-  int crateApiAgentsApiDelegateAgentCount() {
+  ChatSessionInfo crateApiAgentApiCreateSession() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_32,
+          decodeSuccessData: sse_decode_chat_session_info,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentsApiDelegateAgentCountConstMeta,
+        constMeta: kCrateApiAgentApiCreateSessionConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentsApiDelegateAgentCountConstMeta =>
-      const TaskConstMeta(debugName: "delegate_agent_count", argNames: []);
+  TaskConstMeta get kCrateApiAgentApiCreateSessionConstMeta =>
+      const TaskConstMeta(debugName: "create_session", argNames: []);
 
   @override
-  Future<String> crateApiAgentWorkspaceApiDeleteAgentWorkspace({
-    required String workspaceId,
-  }) {
+  Future<CronActiveWindowDto> crateApiCronApiCronActiveWindowDtoDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(workspaceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 27,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_cron_active_window_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentWorkspaceApiDeleteAgentWorkspaceConstMeta,
-        argValues: [workspaceId],
+        constMeta: kCrateApiCronApiCronActiveWindowDtoDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentWorkspaceApiDeleteAgentWorkspaceConstMeta =>
+  TaskConstMeta get kCrateApiCronApiCronActiveWindowDtoDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "delete_agent_workspace",
-        argNames: ["workspaceId"],
+        debugName: "cron_active_window_dto_default",
+        argNames: [],
       );
 
   @override
-  Future<String> crateApiKnowledgeApiDeleteKnowledgeEntry({
-    required String key,
-  }) {
+  Future<CronEnvVarDto> crateApiCronApiCronEnvVarDtoDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(key, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 28,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_cron_env_var_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiKnowledgeApiDeleteKnowledgeEntryConstMeta,
-        argValues: [key],
+        constMeta: kCrateApiCronApiCronEnvVarDtoDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiKnowledgeApiDeleteKnowledgeEntryConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_knowledge_entry",
-        argNames: ["key"],
-      );
+  TaskConstMeta get kCrateApiCronApiCronEnvVarDtoDefaultConstMeta =>
+      const TaskConstMeta(debugName: "cron_env_var_dto_default", argNames: []);
 
   @override
-  Future<String> crateApiProjectApiDeleteProject({required String projectId}) {
+  Future<CronShellSandboxDto> crateApiCronApiCronShellSandboxDtoDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(projectId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 29,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_cron_shell_sandbox_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiProjectApiDeleteProjectConstMeta,
-        argValues: [projectId],
+        constMeta: kCrateApiCronApiCronShellSandboxDtoDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProjectApiDeleteProjectConstMeta =>
-      const TaskConstMeta(debugName: "delete_project", argNames: ["projectId"]);
+  TaskConstMeta get kCrateApiCronApiCronShellSandboxDtoDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "cron_shell_sandbox_dto_default",
+        argNames: [],
+      );
 
   @override
-  Future<String> crateApiSessionsApiDeleteSession({required String sessionId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  int crateApiAgentsApiDelegateAgentCount() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sessionId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 29,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_u_32,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSessionsApiDeleteSessionConstMeta,
-        argValues: [sessionId],
-        apiImpl: this,
+        constMeta: kCrateApiAgentsApiDelegateAgentCountConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAgentsApiDelegateAgentCountConstMeta =>
+      const TaskConstMeta(debugName: "delegate_agent_count", argNames: []);
+
+  @override
+  Future<String> crateApiAgentWorkspaceApiDeleteAgentWorkspace({
+    required String workspaceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(workspaceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAgentWorkspaceApiDeleteAgentWorkspaceConstMeta,
+        argValues: [workspaceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAgentWorkspaceApiDeleteAgentWorkspaceConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_agent_workspace",
+        argNames: ["workspaceId"],
+      );
+
+  @override
+  Future<String> crateApiKnowledgeApiDeleteKnowledgeEntry({
+    required String key,
+    String? projectId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          sse_encode_opt_String(projectId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiKnowledgeApiDeleteKnowledgeEntryConstMeta,
+        argValues: [key, projectId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiKnowledgeApiDeleteKnowledgeEntryConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_knowledge_entry",
+        argNames: ["key", "projectId"],
+      );
+
+  @override
+  Future<String> crateApiProjectApiDeleteProject({required String projectId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(projectId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProjectApiDeleteProjectConstMeta,
+        argValues: [projectId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProjectApiDeleteProjectConstMeta =>
+      const TaskConstMeta(debugName: "delete_project", argNames: ["projectId"]);
+
+  @override
+  Future<String> crateApiSessionsApiDeleteSession({required String sessionId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionsApiDeleteSessionConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
       ),
     );
   }
@@ -1563,6 +1958,76 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSessionsApiDeleteSessionConstMeta =>
       const TaskConstMeta(debugName: "delete_session", argNames: ["sessionId"]);
 
+  @override
+  Future<String> crateApiCronApiExportCronJobs({
+    String? projectId,
+    required String format,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(projectId, serializer);
+          sse_encode_String(format, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiExportCronJobsConstMeta,
+        argValues: [projectId, format],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiExportCronJobsConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_cron_jobs",
+        argNames: ["projectId", "format"],
+      );
+
+  @override
+  Future<String> crateApiProjectBundleApiExportProject({
+    required String projectId,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(projectId, serializer);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProjectBundleApiExportProjectConstMeta,
+        argValues: [projectId, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProjectBundleApiExportProjectConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_project",
+        argNames: ["projectId", "path"],
+      );
+
   @override
   Future<FeatureToggles> crateApiWorkspaceApiFeatureTogglesDefault() {
     return handler.executeNormal(
@@ -1572,7 +2037,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 37,
             port: port_,
           );
         },
@@ -1599,7 +2064,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 38,
             port: port_,
           );
         },
@@ -1626,7 +2091,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 39,
             port: port_,
           );
         },
@@ -1653,7 +2118,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 40,
             port: port_,
           );
         },
@@ -1683,7 +2148,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 41,
             port: port_,
           );
         },
@@ -1716,7 +2181,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 42,
             port: port_,
           );
         },
@@ -1746,7 +2211,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 43,
             port: port_,
           );
         },
@@ -1776,7 +2241,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 44,
             port: port_,
           );
         },
@@ -1806,7 +2271,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 45,
             port: port_,
           );
         },
@@ -1825,7 +2290,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "get_cost_config", argNames: []);
 
   @override
-  Future<CronConfigDto> crateApiCronApiGetCronConfig() {
+  Future<CronActiveWindowDto> crateApiCronApiGetCronActiveWindow() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1833,26 +2298,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 46,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_cron_config_dto,
+          decodeSuccessData: sse_decode_cron_active_window_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCronApiGetCronConfigConstMeta,
+        constMeta: kCrateApiCronApiGetCronActiveWindowConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCronApiGetCronConfigConstMeta =>
-      const TaskConstMeta(debugName: "get_cron_config", argNames: []);
+  TaskConstMeta get kCrateApiCronApiGetCronActiveWindowConstMeta =>
+      const TaskConstMeta(debugName: "get_cron_active_window", argNames: []);
 
   @override
-  Future<AppConfig> crateApiAgentApiGetCurrentConfig() {
+  Future<CronConfigDto> crateApiCronApiGetCronConfig() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1860,83 +2325,98 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 47,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_app_config,
+          decodeSuccessData: sse_decode_cron_config_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentApiGetCurrentConfigConstMeta,
+        constMeta: kCrateApiCronApiGetCronConfigConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentApiGetCurrentConfigConstMeta =>
-      const TaskConstMeta(debugName: "get_current_config", argNames: []);
+  TaskConstMeta get kCrateApiCronApiGetCronConfigConstMeta =>
+      const TaskConstMeta(debugName: "get_cron_config", argNames: []);
 
   @override
-  Future<String> crateApiProvidersApiGetDefaultProfileId() {
+  Future<CronJobStatsDto?> crateApiCronApiGetCronJobStats({
+    required String jobId,
+    required int windowHours,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_u_32(windowHours, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 48,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_opt_box_autoadd_cron_job_stats_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiProvidersApiGetDefaultProfileIdConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCronApiGetCronJobStatsConstMeta,
+        argValues: [jobId, windowHours],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProvidersApiGetDefaultProfileIdConstMeta =>
-      const TaskConstMeta(debugName: "get_default_profile_id", argNames: []);
+  TaskConstMeta get kCrateApiCronApiGetCronJobStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cron_job_stats",
+        argNames: ["jobId", "windowHours"],
+      );
 
   @override
-  Future<DelegateAgentDto?> crateApiAgentsApiGetDelegateAgent({
-    required String name,
+  Future<CronRunOutput?> crateApiCronApiGetCronRunOutput({
+    required PlatformInt64 runId,
+    required BigInt offset,
+    required BigInt len,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
+          sse_encode_i_64(runId, serializer);
+          sse_encode_u_64(offset, serializer);
+          sse_encode_u_64(len, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 42,
+            funcId: 49,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_delegate_agent_dto,
+          decodeSuccessData: sse_decode_opt_box_autoadd_cron_run_output,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentsApiGetDelegateAgentConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiCronApiGetCronRunOutputConstMeta,
+        argValues: [runId, offset, len],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentsApiGetDelegateAgentConstMeta =>
-      const TaskConstMeta(debugName: "get_delegate_agent", argNames: ["name"]);
+  TaskConstMeta get kCrateApiCronApiGetCronRunOutputConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cron_run_output",
+        argNames: ["runId", "offset", "len"],
+      );
 
   @override
-  Future<EmbeddingConfigDto> crateApiRoutesApiGetEmbeddingConfig() {
+  Future<CronSchedulerStatus> crateApiCronApiGetCronSchedulerStatus() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1944,64 +2424,210 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 43,
+            funcId: 50,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_embedding_config_dto,
+          decodeSuccessData: sse_decode_cron_scheduler_status,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiRoutesApiGetEmbeddingConfigConstMeta,
+        constMeta: kCrateApiCronApiGetCronSchedulerStatusConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRoutesApiGetEmbeddingConfigConstMeta =>
-      const TaskConstMeta(debugName: "get_embedding_config", argNames: []);
+  TaskConstMeta get kCrateApiCronApiGetCronSchedulerStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_cron_scheduler_status", argNames: []);
 
   @override
-  Future<FeatureToggles> crateApiWorkspaceApiGetFeatureToggles() {
+  Future<CronWebhookInfo?> crateApiCronApiGetCronWebhookInfo({
+    required String jobId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 51,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_feature_toggles,
+          decodeSuccessData: sse_decode_opt_box_autoadd_cron_webhook_info,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceApiGetFeatureTogglesConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCronApiGetCronWebhookInfoConstMeta,
+        argValues: [jobId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceApiGetFeatureTogglesConstMeta =>
-      const TaskConstMeta(debugName: "get_feature_toggles", argNames: []);
-
+  TaskConstMeta get kCrateApiCronApiGetCronWebhookInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cron_webhook_info",
+        argNames: ["jobId"],
+      );
+
+  @override
+  Future<AppConfig> crateApiAgentApiGetCurrentConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAgentApiGetCurrentConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAgentApiGetCurrentConfigConstMeta =>
+      const TaskConstMeta(debugName: "get_current_config", argNames: []);
+
+  @override
+  Future<String> crateApiProvidersApiGetDefaultProfileId() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProvidersApiGetDefaultProfileIdConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProvidersApiGetDefaultProfileIdConstMeta =>
+      const TaskConstMeta(debugName: "get_default_profile_id", argNames: []);
+
+  @override
+  Future<DelegateAgentDto?> crateApiAgentsApiGetDelegateAgent({
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_delegate_agent_dto,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAgentsApiGetDelegateAgentConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAgentsApiGetDelegateAgentConstMeta =>
+      const TaskConstMeta(debugName: "get_delegate_agent", argNames: ["name"]);
+
+  @override
+  Future<EmbeddingConfigDto> crateApiRoutesApiGetEmbeddingConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_embedding_config_dto,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRoutesApiGetEmbeddingConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRoutesApiGetEmbeddingConfigConstMeta =>
+      const TaskConstMeta(debugName: "get_embedding_config", argNames: []);
+
+  @override
+  Future<FeatureToggles> crateApiWorkspaceApiGetFeatureToggles() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_feature_toggles,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceApiGetFeatureTogglesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceApiGetFeatureTogglesConstMeta =>
+      const TaskConstMeta(debugName: "get_feature_toggles", argNames: []);
+
   @override
   Future<KnowledgeEntry?> crateApiKnowledgeApiGetKnowledgeEntry({
     required String key,
+    String? projectId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(key, serializer);
+          sse_encode_opt_String(projectId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 57,
             port: port_,
           );
         },
@@ -2010,14 +2636,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiKnowledgeApiGetKnowledgeEntryConstMeta,
-        argValues: [key],
+        argValues: [key, projectId],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta get kCrateApiKnowledgeApiGetKnowledgeEntryConstMeta =>
-      const TaskConstMeta(debugName: "get_knowledge_entry", argNames: ["key"]);
+      const TaskConstMeta(
+        debugName: "get_knowledge_entry",
+        argNames: ["key", "projectId"],
+      );
 
   @override
   Future<KnowledgeStats> crateApiKnowledgeApiGetKnowledgeStats() {
@@ -2028,7 +2657,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 58,
             port: port_,
           );
         },
@@ -2060,7 +2689,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 59,
             port: port_,
           );
         },
@@ -2093,7 +2722,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 48,
+            funcId: 60,
             port: port_,
           );
         },
@@ -2120,7 +2749,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -2145,7 +2774,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 50,
+            funcId: 62,
             port: port_,
           );
         },
@@ -2172,7 +2801,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 51,
+            funcId: 63,
             port: port_,
           );
         },
@@ -2202,7 +2831,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 52,
+            funcId: 64,
             port: port_,
           );
         },
@@ -2221,7 +2850,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "get_project", argNames: ["projectId"]);
 
   @override
-  Future<String> crateApiProjectApiGetProjectPinnedContext({
+  Future<ProjectCodeIndexStatus>
+  crateApiProjectIndexApiGetProjectCodeIndexStatus({
     required String projectId,
   }) {
     return handler.executeNormal(
@@ -2232,78 +2862,96 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 53,
+            funcId: 65,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_project_code_index_status,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiProjectApiGetProjectPinnedContextConstMeta,
+        constMeta: kCrateApiProjectIndexApiGetProjectCodeIndexStatusConstMeta,
         argValues: [projectId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProjectApiGetProjectPinnedContextConstMeta =>
+  TaskConstMeta
+  get kCrateApiProjectIndexApiGetProjectCodeIndexStatusConstMeta =>
       const TaskConstMeta(
-        debugName: "get_project_pinned_context",
+        debugName: "get_project_code_index_status",
         argNames: ["projectId"],
       );
 
   @override
-  Future<ProxyConfigDto> crateApiProxyApiGetProxyConfig() {
+  Future<ProjectGitStatus?> crateApiProjectGitApiGetProjectGitStatus({
+    required String projectId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(projectId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 54,
+            funcId: 66,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_proxy_config_dto,
+          decodeSuccessData: sse_decode_opt_box_autoadd_project_git_status,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiProxyApiGetProxyConfigConstMeta,
-        argValues: [],
+        constMeta: kCrateApiProjectGitApiGetProjectGitStatusConstMeta,
+        argValues: [projectId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProxyApiGetProxyConfigConstMeta =>
-      const TaskConstMeta(debugName: "get_proxy_config", argNames: []);
+  TaskConstMeta get kCrateApiProjectGitApiGetProjectGitStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_project_git_status",
+        argNames: ["projectId"],
+      );
 
   @override
-  ProxyConfigDto crateApiProxyApiGetRuntimeProxyStatus() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<String> crateApiProjectApiGetProjectPinnedContext({
+    required String projectId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+          sse_encode_String(projectId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_proxy_config_dto,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiProxyApiGetRuntimeProxyStatusConstMeta,
-        argValues: [],
+        constMeta: kCrateApiProjectApiGetProjectPinnedContextConstMeta,
+        argValues: [projectId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProxyApiGetRuntimeProxyStatusConstMeta =>
-      const TaskConstMeta(debugName: "get_runtime_proxy_status", argNames: []);
+  TaskConstMeta get kCrateApiProjectApiGetProjectPinnedContextConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_project_pinned_context",
+        argNames: ["projectId"],
+      );
 
   @override
-  Future<RuntimeStatus> crateApiAgentApiGetRuntimeStatus() {
+  Future<String> crateApiProjectTemplateApiGetProjectTemplatesDir() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -2311,95 +2959,143 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 56,
+            funcId: 68,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_runtime_status,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentApiGetRuntimeStatusConstMeta,
+        constMeta: kCrateApiProjectTemplateApiGetProjectTemplatesDirConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentApiGetRuntimeStatusConstMeta =>
-      const TaskConstMeta(debugName: "get_runtime_status", argNames: []);
+  TaskConstMeta
+  get kCrateApiProjectTemplateApiGetProjectTemplatesDirConstMeta =>
+      const TaskConstMeta(debugName: "get_project_templates_dir", argNames: []);
 
   @override
-  Future<List<String>> crateApiAgentsApiGetSessionActiveRoles({
-    required String sessionId,
+  Future<ProjectTimelinePage> crateApiProjectTimelineApiGetProjectTimeline({
+    required String projectId,
+    PlatformInt64? cursor,
+    required int limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sessionId, serializer);
+          sse_encode_String(projectId, serializer);
+          sse_encode_opt_box_autoadd_i_64(cursor, serializer);
+          sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 57,
+            funcId: 69,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData: sse_decode_project_timeline_page,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentsApiGetSessionActiveRolesConstMeta,
-        argValues: [sessionId],
+        constMeta: kCrateApiProjectTimelineApiGetProjectTimelineConstMeta,
+        argValues: [projectId, cursor, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentsApiGetSessionActiveRolesConstMeta =>
+  TaskConstMeta get kCrateApiProjectTimelineApiGetProjectTimelineConstMeta =>
       const TaskConstMeta(
-        debugName: "get_session_active_roles",
-        argNames: ["sessionId"],
+        debugName: "get_project_timeline",
+        argNames: ["projectId", "cursor", "limit"],
       );
 
   @override
-  Future<String?> crateApiAgentWorkspaceApiGetSessionAgentBinding({
-    required String sessionId,
-  }) {
+  Future<ProxyConfigDto> crateApiProxyApiGetProxyConfig() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sessionId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 58,
+            funcId: 70,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_proxy_config_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentWorkspaceApiGetSessionAgentBindingConstMeta,
-        argValues: [sessionId],
+        constMeta: kCrateApiProxyApiGetProxyConfigConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentWorkspaceApiGetSessionAgentBindingConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_session_agent_binding",
-        argNames: ["sessionId"],
-      );
+  TaskConstMeta get kCrateApiProxyApiGetProxyConfigConstMeta =>
+      const TaskConstMeta(debugName: "get_proxy_config", argNames: []);
 
   @override
-  Future<SessionDetail?> crateApiSessionsApiGetSessionDetail({
-    required String sessionId,
-  }) {
-    return handler.executeNormal(
+  ProxyConfigDto crateApiProxyApiGetRuntimeProxyStatus() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_proxy_config_dto,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProxyApiGetRuntimeProxyStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyApiGetRuntimeProxyStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_runtime_proxy_status", argNames: []);
+
+  @override
+  Future<RuntimeStatus> crateApiAgentApiGetRuntimeStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_runtime_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAgentApiGetRuntimeStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAgentApiGetRuntimeStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_runtime_status", argNames: []);
+
+  @override
+  Future<List<String>> crateApiAgentsApiGetSessionActiveRoles({
+    required String sessionId,
+  }) {
+    return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2407,7 +3103,73 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 59,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAgentsApiGetSessionActiveRolesConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAgentsApiGetSessionActiveRolesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_session_active_roles",
+        argNames: ["sessionId"],
+      );
+
+  @override
+  Future<String?> crateApiAgentWorkspaceApiGetSessionAgentBinding({
+    required String sessionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAgentWorkspaceApiGetSessionAgentBindingConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAgentWorkspaceApiGetSessionAgentBindingConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_session_agent_binding",
+        argNames: ["sessionId"],
+      );
+
+  @override
+  Future<SessionDetail?> crateApiSessionsApiGetSessionDetail({
+    required String sessionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
             port: port_,
           );
         },
@@ -2440,7 +3202,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
+            funcId: 76,
             port: port_,
           );
         },
@@ -2473,7 +3235,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 61,
+            funcId: 77,
             port: port_,
           );
         },
@@ -2503,7 +3265,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 62,
+            funcId: 78,
             port: port_,
           );
         },
@@ -2533,7 +3295,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 63,
+            funcId: 79,
             port: port_,
           );
         },
@@ -2563,7 +3325,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 64,
+            funcId: 80,
             port: port_,
           );
         },
@@ -2591,7 +3353,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 65,
+            funcId: 81,
             port: port_,
           );
         },
@@ -2618,7 +3380,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 66,
+            funcId: 82,
             port: port_,
           );
         },
@@ -2643,7 +3405,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -2659,6 +3421,106 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
       const TaskConstMeta(debugName: "greet", argNames: ["name"]);
 
+  @override
+  Future<CronImportResult> crateApiCronApiImportCronJobs({
+    required String content,
+    required String format,
+    required bool dryRun,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(content, serializer);
+          sse_encode_String(format, serializer);
+          sse_encode_bool(dryRun, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cron_import_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiImportCronJobsConstMeta,
+        argValues: [content, format, dryRun],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiImportCronJobsConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_cron_jobs",
+        argNames: ["content", "format", "dryRun"],
+      );
+
+  @override
+  Future<ProjectImportResult> crateApiProjectBundleApiImportProject({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_project_import_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProjectBundleApiImportProjectConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProjectBundleApiImportProjectConstMeta =>
+      const TaskConstMeta(debugName: "import_project", argNames: ["path"]);
+
+  @override
+  Future<String> crateApiProjectIndexApiIndexProjectCode({
+    required String projectId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(projectId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProjectIndexApiIndexProjectCodeConstMeta,
+        argValues: [projectId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProjectIndexApiIndexProjectCodeConstMeta =>
+      const TaskConstMeta(
+        debugName: "index_project_code",
+        argNames: ["projectId"],
+      );
+
   @override
   Future<String> crateApiAgentWorkspaceApiInitAgentWorkspaceStore() {
     return handler.executeNormal(
@@ -2668,7 +3530,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 68,
+            funcId: 87,
             port: port_,
           );
         },
@@ -2699,7 +3561,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 69,
+            funcId: 88,
             port: port_,
           );
         },
@@ -2726,7 +3588,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 70,
+            funcId: 89,
             port: port_,
           );
         },
@@ -2753,7 +3615,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 71,
+            funcId: 90,
             port: port_,
           );
         },
@@ -2780,7 +3642,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 72,
+            funcId: 91,
             port: port_,
           );
         },
@@ -2808,7 +3670,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 73,
+            funcId: 92,
             port: port_,
           );
         },
@@ -2835,7 +3697,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 74,
+            funcId: 93,
             port: port_,
           );
         },
@@ -2863,7 +3725,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -2891,7 +3753,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 76,
+            funcId: 95,
             port: port_,
           );
         },
@@ -2922,7 +3784,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 77,
+            funcId: 96,
             port: port_,
           );
         },
@@ -2949,7 +3811,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 78,
+            funcId: 97,
             port: port_,
           );
         },
@@ -2968,67 +3830,160 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "list_channels", argNames: []);
 
   @override
-  Future<List<CronJobDto>> crateApiCronApiListCronJobs() {
+  Future<List<CronRunDto>> crateApiCronApiListCronChainRuns({
+    required String chainId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(chainId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 79,
+            funcId: 98,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_cron_job_dto,
+          decodeSuccessData: sse_decode_list_cron_run_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCronApiListCronJobsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCronApiListCronChainRunsConstMeta,
+        argValues: [chainId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCronApiListCronJobsConstMeta =>
-      const TaskConstMeta(debugName: "list_cron_jobs", argNames: []);
+  TaskConstMeta get kCrateApiCronApiListCronChainRunsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_cron_chain_runs",
+        argNames: ["chainId"],
+      );
 
   @override
-  Future<List<CronRunDto>> crateApiCronApiListCronRuns({
-    required String jobId,
-    required int limit,
-  }) {
+  Future<List<CronJobDto>> crateApiCronApiListCronJobs() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(jobId, serializer);
-          sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 80,
+            funcId: 99,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_cron_run_dto,
+          decodeSuccessData: sse_decode_list_cron_job_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCronApiListCronRunsConstMeta,
-        argValues: [jobId, limit],
+        constMeta: kCrateApiCronApiListCronJobsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCronApiListCronRunsConstMeta =>
-      const TaskConstMeta(
-        debugName: "list_cron_runs",
+  TaskConstMeta get kCrateApiCronApiListCronJobsConstMeta =>
+      const TaskConstMeta(debugName: "list_cron_jobs", argNames: []);
+
+  @override
+  Future<List<CronJobDto>> crateApiCronApiListCronJobsForProject({
+    required String projectId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(projectId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_cron_job_dto,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiListCronJobsForProjectConstMeta,
+        argValues: [projectId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiListCronJobsForProjectConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_cron_jobs_for_project",
+        argNames: ["projectId"],
+      );
+
+  @override
+  Future<List<CronRunDto>> crateApiCronApiListCronRuns({
+    required String jobId,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_cron_run_dto,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiListCronRunsConstMeta,
+        argValues: [jobId, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiListCronRunsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_cron_runs",
         argNames: ["jobId", "limit"],
       );
 
+  @override
+  Future<List<String>> crateApiCronApiListCronSecrets() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiListCronSecretsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiListCronSecretsConstMeta =>
+      const TaskConstMeta(debugName: "list_cron_secrets", argNames: []);
+
   @override
   Future<List<DelegateAgentDto>> crateApiAgentsApiListDelegateAgents() {
     return handler.executeNormal(
@@ -3038,7 +3993,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 81,
+            funcId: 103,
             port: port_,
           );
         },
@@ -3065,7 +4020,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 82,
+            funcId: 104,
             port: port_,
           );
         },
@@ -3087,6 +4042,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Future<List<KnowledgeEntry>> crateApiKnowledgeApiListKnowledgeEntries({
     String? category,
     required int limit,
+    String? projectId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -3094,10 +4050,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_opt_String(category, serializer);
           sse_encode_u_32(limit, serializer);
+          sse_encode_opt_String(projectId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 83,
+            funcId: 105,
             port: port_,
           );
         },
@@ -3106,7 +4063,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiKnowledgeApiListKnowledgeEntriesConstMeta,
-        argValues: [category, limit],
+        argValues: [category, limit, projectId],
         apiImpl: this,
       ),
     );
@@ -3115,7 +4072,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiKnowledgeApiListKnowledgeEntriesConstMeta =>
       const TaskConstMeta(
         debugName: "list_knowledge_entries",
-        argNames: ["category", "limit"],
+        argNames: ["category", "limit", "projectId"],
       );
 
   @override
@@ -3127,7 +4084,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 84,
+            funcId: 106,
             port: port_,
           );
         },
@@ -3155,7 +4112,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 85,
+            funcId: 107,
             port: port_,
           );
         },
@@ -3185,7 +4142,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 86,
+            funcId: 108,
             port: port_,
           );
         },
@@ -3203,6 +4160,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiRoutesApiListModelRoutesConstMeta =>
       const TaskConstMeta(debugName: "list_model_routes", argNames: []);
 
+  @override
+  Future<List<ProjectTemplateDto>>
+  crateApiProjectTemplateApiListProjectTemplates() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_project_template_dto,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProjectTemplateApiListProjectTemplatesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProjectTemplateApiListProjectTemplatesConstMeta =>
+      const TaskConstMeta(debugName: "list_project_templates", argNames: []);
+
   @override
   Future<List<ProjectSummary>> crateApiProjectApiListProjects() {
     return handler.executeNormal(
@@ -3212,7 +4197,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 87,
+            funcId: 110,
             port: port_,
           );
         },
@@ -3236,7 +4221,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_provider_info,
@@ -3258,7 +4247,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_proxy_service_info,
@@ -3286,7 +4279,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 90,
+            funcId: 113,
             port: port_,
           );
         },
@@ -3316,7 +4309,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 91,
+            funcId: 114,
             port: port_,
           );
         },
@@ -3343,7 +4336,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 92,
+            funcId: 115,
             port: port_,
           );
         },
@@ -3367,7 +4360,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_tool_spec_dto,
@@ -3392,7 +4389,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 94,
+            funcId: 117,
             port: port_,
           );
         },
@@ -3419,7 +4416,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 95,
+            funcId: 118,
             port: port_,
           );
         },
@@ -3443,7 +4440,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_u_32,
@@ -3472,7 +4473,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 97,
+            funcId: 120,
             port: port_,
           );
         },
@@ -3500,7 +4501,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 98,
+            funcId: 121,
             port: port_,
           );
         },
@@ -3519,34 +4520,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "pause_cron_job", argNames: ["jobId"]);
 
   @override
-  Future<ProxyConfigDto> crateApiProxyApiProxyConfigDtoDefault() {
+  Future<CronSchedulePreview> crateApiCronApiPreviewCronSchedule({
+    required String expression,
+    String? timezone,
+    required int count,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(expression, serializer);
+          sse_encode_opt_String(timezone, serializer);
+          sse_encode_u_32(count, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 99,
+            funcId: 122,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_proxy_config_dto,
+          decodeSuccessData: sse_decode_cron_schedule_preview,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiProxyApiProxyConfigDtoDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCronApiPreviewCronScheduleConstMeta,
+        argValues: [expression, timezone, count],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProxyApiProxyConfigDtoDefaultConstMeta =>
-      const TaskConstMeta(debugName: "proxy_config_dto_default", argNames: []);
+  TaskConstMeta get kCrateApiCronApiPreviewCronScheduleConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_cron_schedule",
+        argNames: ["expression", "timezone", "count"],
+      );
 
   @override
-  Future<String> crateApiAgentApiReloadConfigFromDisk() {
+  Future<ProjectAutonomyDto> crateApiProjectApiProjectAutonomyDtoDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -3554,96 +4565,97 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 100,
+            funcId: 123,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_project_autonomy_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentApiReloadConfigFromDiskConstMeta,
+        constMeta: kCrateApiProjectApiProjectAutonomyDtoDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentApiReloadConfigFromDiskConstMeta =>
-      const TaskConstMeta(debugName: "reload_config_from_disk", argNames: []);
+  TaskConstMeta get kCrateApiProjectApiProjectAutonomyDtoDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "project_autonomy_dto_default",
+        argNames: [],
+      );
 
   @override
-  Future<String> crateApiWorkspaceApiRemoveAllowedCommand({
-    required String command,
-  }) {
+  Future<ProjectSessionReport> crateApiProjectApiProjectSessionReportDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(command, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 101,
+            funcId: 124,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_project_session_report,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceApiRemoveAllowedCommandConstMeta,
-        argValues: [command],
+        constMeta: kCrateApiProjectApiProjectSessionReportDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceApiRemoveAllowedCommandConstMeta =>
+  TaskConstMeta get kCrateApiProjectApiProjectSessionReportDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "remove_allowed_command",
-        argNames: ["command"],
+        debugName: "project_session_report_default",
+        argNames: [],
       );
 
   @override
-  Future<String> crateApiCronApiRemoveCronJob({required String jobId}) {
+  Future<ProxyConfigDto> crateApiProxyApiProxyConfigDtoDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(jobId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 102,
+            funcId: 125,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_proxy_config_dto,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCronApiRemoveCronJobConstMeta,
-        argValues: [jobId],
+        constMeta: kCrateApiProxyApiProxyConfigDtoDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCronApiRemoveCronJobConstMeta =>
-      const TaskConstMeta(debugName: "remove_cron_job", argNames: ["jobId"]);
+  TaskConstMeta get kCrateApiProxyApiProxyConfigDtoDefaultConstMeta =>
+      const TaskConstMeta(debugName: "proxy_config_dto_default", argNames: []);
 
   @override
-  Future<String> crateApiAgentsApiRemoveDelegateAgent({required String name}) {
+  Future<String> crateApiCronApiRegenerateCronWebhookSecret({
+    required String jobId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
+          sse_encode_String(jobId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 103,
+            funcId: 126,
             port: port_,
           );
         },
@@ -3651,30 +4663,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAgentsApiRemoveDelegateAgentConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiCronApiRegenerateCronWebhookSecretConstMeta,
+        argValues: [jobId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAgentsApiRemoveDelegateAgentConstMeta =>
+  TaskConstMeta get kCrateApiCronApiRegenerateCronWebhookSecretConstMeta =>
       const TaskConstMeta(
-        debugName: "remove_delegate_agent",
-        argNames: ["name"],
+        debugName: "regenerate_cron_webhook_secret",
+        argNames: ["jobId"],
       );
 
   @override
-  Future<String> crateApiRoutesApiRemoveEmbeddingRoute({required String hint}) {
+  Future<String> crateApiAgentApiReloadConfigFromDisk() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(hint, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 104,
+            funcId: 127,
             port: port_,
           );
         },
@@ -3682,30 +4693,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiRoutesApiRemoveEmbeddingRouteConstMeta,
-        argValues: [hint],
+        constMeta: kCrateApiAgentApiReloadConfigFromDiskConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRoutesApiRemoveEmbeddingRouteConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_embedding_route",
-        argNames: ["hint"],
-      );
+  TaskConstMeta get kCrateApiAgentApiReloadConfigFromDiskConstMeta =>
+      const TaskConstMeta(debugName: "reload_config_from_disk", argNames: []);
 
   @override
-  Future<String> crateApiMcpApiRemoveMcpServer({required String name}) {
+  Future<String> crateApiWorkspaceApiRemoveAllowedCommand({
+    required String command,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
+          sse_encode_String(command, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 105,
+            funcId: 128,
             port: port_,
           );
         },
@@ -3713,18 +4723,167 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpApiRemoveMcpServerConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiWorkspaceApiRemoveAllowedCommandConstMeta,
+        argValues: [command],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpApiRemoveMcpServerConstMeta =>
-      const TaskConstMeta(debugName: "remove_mcp_server", argNames: ["name"]);
+  TaskConstMeta get kCrateApiWorkspaceApiRemoveAllowedCommandConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_allowed_command",
+        argNames: ["command"],
+      );
 
   @override
-  Future<String> crateApiProvidersApiRemoveModelProviderProfile({
+  Future<String> crateApiCronApiRemoveCronJob({required String jobId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 129,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiRemoveCronJobConstMeta,
+        argValues: [jobId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiRemoveCronJobConstMeta =>
+      const TaskConstMeta(debugName: "remove_cron_job", argNames: ["jobId"]);
+
+  @override
+  Future<String> crateApiCronApiRemoveCronSecret({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiRemoveCronSecretConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiRemoveCronSecretConstMeta =>
+      const TaskConstMeta(debugName: "remove_cron_secret", argNames: ["name"]);
+
+  @override
+  Future<String> crateApiAgentsApiRemoveDelegateAgent({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAgentsApiRemoveDelegateAgentConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAgentsApiRemoveDelegateAgentConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_delegate_agent",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<String> crateApiRoutesApiRemoveEmbeddingRoute({required String hint}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(hint, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRoutesApiRemoveEmbeddingRouteConstMeta,
+        argValues: [hint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRoutesApiRemoveEmbeddingRouteConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_embedding_route",
+        argNames: ["hint"],
+      );
+
+  @override
+  Future<String> crateApiMcpApiRemoveMcpServer({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpApiRemoveMcpServerConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpApiRemoveMcpServerConstMeta =>
+      const TaskConstMeta(debugName: "remove_mcp_server", argNames: ["name"]);
+
+  @override
+  Future<String> crateApiProvidersApiRemoveModelProviderProfile({
     required String id,
   }) {
     return handler.executeNormal(
@@ -3735,7 +4894,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 106,
+            funcId: 134,
             port: port_,
           );
         },
@@ -3766,7 +4925,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 107,
+            funcId: 135,
             port: port_,
           );
         },
@@ -3798,7 +4957,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 108,
+            funcId: 136,
             port: port_,
           );
         },
@@ -3829,7 +4988,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 109,
+            funcId: 137,
             port: port_,
           );
         },
@@ -3864,7 +5023,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 110,
+            funcId: 138,
             port: port_,
           );
         },
@@ -3899,7 +5058,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 111,
+            funcId: 139,
             port: port_,
           );
         },
@@ -3930,7 +5089,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 112,
+            funcId: 140,
             port: port_,
           );
         },
@@ -3962,7 +5121,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 113,
+            funcId: 141,
             port: port_,
           );
         },
@@ -3992,7 +5151,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 114,
+            funcId: 142,
             port: port_,
           );
         },
@@ -4022,7 +5181,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 115,
+            funcId: 143,
             port: port_,
           );
         },
@@ -4047,6 +5206,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Future<String> crateApiAgentApiRespondToToolApprovalById({
     required String requestId,
     required String decision,
+    String? reason,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -4054,10 +5214,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(requestId, serializer);
           sse_encode_String(decision, serializer);
+          sse_encode_opt_String(reason, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 116,
+            funcId: 144,
             port: port_,
           );
         },
@@ -4066,7 +5227,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiAgentApiRespondToToolApprovalByIdConstMeta,
-        argValues: [requestId, decision],
+        argValues: [requestId, decision, reason],
         apiImpl: this,
       ),
     );
@@ -4075,7 +5236,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiAgentApiRespondToToolApprovalByIdConstMeta =>
       const TaskConstMeta(
         debugName: "respond_to_tool_approval_by_id",
-        argNames: ["requestId", "decision"],
+        argNames: ["requestId", "decision", "reason"],
       );
 
   @override
@@ -4087,7 +5248,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 117,
+            funcId: 145,
             port: port_,
           );
         },
@@ -4116,7 +5277,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 118,
+            funcId: 146,
             port: port_,
           );
         },
@@ -4144,7 +5305,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 119,
+            funcId: 147,
             port: port_,
           );
         },
@@ -4176,7 +5337,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 120,
+            funcId: 148,
             port: port_,
           );
         },
@@ -4207,7 +5368,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 121,
+            funcId: 149,
             port: port_,
           );
         },
@@ -4234,7 +5395,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 122,
+            funcId: 150,
             port: port_,
           );
         },
@@ -4261,7 +5422,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 123,
+            funcId: 151,
             port: port_,
           );
         },
@@ -4284,7 +5445,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required String sessionId,
     required String title,
     required List<SessionMessage> messages,
-    required String projectId,
     required bool ephemeral,
     required String agentBinding,
   }) {
@@ -4295,13 +5455,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(sessionId, serializer);
           sse_encode_String(title, serializer);
           sse_encode_list_session_message(messages, serializer);
-          sse_encode_String(projectId, serializer);
           sse_encode_bool(ephemeral, serializer);
           sse_encode_String(agentBinding, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 124,
+            funcId: 152,
             port: port_,
           );
         },
@@ -4310,14 +5469,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiSessionsApiSaveSessionConstMeta,
-        argValues: [
-          sessionId,
-          title,
-          messages,
-          projectId,
-          ephemeral,
-          agentBinding,
-        ],
+        argValues: [sessionId, title, messages, ephemeral, agentBinding],
         apiImpl: this,
       ),
     );
@@ -4330,7 +5482,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           "sessionId",
           "title",
           "messages",
-          "projectId",
           "ephemeral",
           "agentBinding",
         ],
@@ -4350,7 +5501,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 125,
+            funcId: 153,
             port: port_,
           );
         },
@@ -4375,6 +5526,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Future<List<KnowledgeEntry>> crateApiKnowledgeApiSearchKnowledge({
     required String query,
     required int limit,
+    String? projectId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -4382,10 +5534,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(query, serializer);
           sse_encode_u_32(limit, serializer);
+          sse_encode_opt_String(projectId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 126,
+            funcId: 154,
             port: port_,
           );
         },
@@ -4394,7 +5547,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiKnowledgeApiSearchKnowledgeConstMeta,
-        argValues: [query, limit],
+        argValues: [query, limit, projectId],
         apiImpl: this,
       ),
     );
@@ -4403,7 +5556,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiKnowledgeApiSearchKnowledgeConstMeta =>
       const TaskConstMeta(
         debugName: "search_knowledge",
-        argNames: ["query", "limit"],
+        argNames: ["query", "limit", "projectId"],
+      );
+
+  @override
+  Future<List<CodeSearchHit>> crateApiProjectIndexApiSearchProjectCode({
+    required String projectId,
+    required String query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(projectId, serializer);
+          sse_encode_String(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 155,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_code_search_hit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProjectIndexApiSearchProjectCodeConstMeta,
+        argValues: [projectId, query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProjectIndexApiSearchProjectCodeConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_project_code",
+        argNames: ["projectId", "query"],
       );
 
   @override
@@ -4415,7 +5603,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 127,
+            funcId: 156,
             port: port_,
           );
         },
@@ -4442,7 +5630,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 128,
+            funcId: 157,
             port: port_,
           );
         },
@@ -4474,7 +5662,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 129,
+            funcId: 158,
             port: port_,
           );
         },
@@ -4512,7 +5700,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 130,
+              funcId: 159,
               port: port_,
             );
           },
@@ -4526,13 +5714,532 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         ),
       ),
     );
-    return sink.stream;
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAgentApiSendMessageStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_message_stream",
+        argNames: ["sessionId", "message", "sink"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronActiveWindow({
+    required CronActiveWindowDto window,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cron_active_window_dto(window, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 160,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronActiveWindowConstMeta,
+        argValues: [window],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronActiveWindowConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_active_window",
+        argNames: ["window"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronAutoDisableAfter({
+    required int failures,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(failures, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 161,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronAutoDisableAfterConstMeta,
+        argValues: [failures],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronAutoDisableAfterConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_auto_disable_after",
+        argNames: ["failures"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronJobActiveWindow({
+    required String jobId,
+    CronActiveWindowDto? window,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_opt_box_autoadd_cron_active_window_dto(window, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 162,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronJobActiveWindowConstMeta,
+        argValues: [jobId, window],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronJobActiveWindowConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_job_active_window",
+        argNames: ["jobId", "window"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronJobChain({
+    required String jobId,
+    required String onSuccess,
+    required String onFailure,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_String(onSuccess, serializer);
+          sse_encode_String(onFailure, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 163,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronJobChainConstMeta,
+        argValues: [jobId, onSuccess, onFailure],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronJobChainConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_job_chain",
+        argNames: ["jobId", "onSuccess", "onFailure"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronJobDelivery({
+    required String jobId,
+    required List<CronDeliveryDto> targets,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_list_cron_delivery_dto(targets, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 164,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronJobDeliveryConstMeta,
+        argValues: [jobId, targets],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronJobDeliveryConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_job_delivery",
+        argNames: ["jobId", "targets"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronJobRetryPolicy({
+    required String jobId,
+    required int timeoutSecs,
+    required int maxRetries,
+    required int retryBackoffSecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_u_32(timeoutSecs, serializer);
+          sse_encode_u_32(maxRetries, serializer);
+          sse_encode_u_32(retryBackoffSecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 165,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronJobRetryPolicyConstMeta,
+        argValues: [jobId, timeoutSecs, maxRetries, retryBackoffSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronJobRetryPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_job_retry_policy",
+        argNames: ["jobId", "timeoutSecs", "maxRetries", "retryBackoffSecs"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronJobRunPolicy({
+    required String jobId,
+    required String misfirePolicy,
+    required bool noOverlap,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_String(misfirePolicy, serializer);
+          sse_encode_bool(noOverlap, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 166,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronJobRunPolicyConstMeta,
+        argValues: [jobId, misfirePolicy, noOverlap],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronJobRunPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_job_run_policy",
+        argNames: ["jobId", "misfirePolicy", "noOverlap"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronJobSandbox({
+    required String jobId,
+    required CronShellSandboxDto sandbox,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_box_autoadd_cron_shell_sandbox_dto(sandbox, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 167,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronJobSandboxConstMeta,
+        argValues: [jobId, sandbox],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronJobSandboxConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_job_sandbox",
+        argNames: ["jobId", "sandbox"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronJobTimezone({
+    required String jobId,
+    required String timezone,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_String(timezone, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 168,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronJobTimezoneConstMeta,
+        argValues: [jobId, timezone],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronJobTimezoneConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_job_timezone",
+        argNames: ["jobId", "timezone"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronJobWatchDebounce({
+    required String jobId,
+    required BigInt debounceMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jobId, serializer);
+          sse_encode_u_64(debounceMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 169,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronJobWatchDebounceConstMeta,
+        argValues: [jobId, debounceMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronJobWatchDebounceConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_job_watch_debounce",
+        argNames: ["jobId", "debounceMs"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronMaxConcurrency({
+    required int maxConcurrency,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(maxConcurrency, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 170,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronMaxConcurrencyConstMeta,
+        argValues: [maxConcurrency],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronMaxConcurrencyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_max_concurrency",
+        argNames: ["maxConcurrency"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronMaxRunHistory({
+    required int maxRunHistory,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(maxRunHistory, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 171,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronMaxRunHistoryConstMeta,
+        argValues: [maxRunHistory],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronMaxRunHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_max_run_history",
+        argNames: ["maxRunHistory"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronSchedulerEnabled({
+    required bool enabled,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 172,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronSchedulerEnabledConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronSchedulerEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_scheduler_enabled",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronSecret({
+    required String name,
+    required String value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 173,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronSecretConstMeta,
+        argValues: [name, value],
+        apiImpl: this,
+      ),
+    );
   }
 
-  TaskConstMeta get kCrateApiAgentApiSendMessageStreamConstMeta =>
+  TaskConstMeta get kCrateApiCronApiSetCronSecretConstMeta =>
       const TaskConstMeta(
-        debugName: "send_message_stream",
-        argNames: ["sessionId", "message", "sink"],
+        debugName: "set_cron_secret",
+        argNames: ["name", "value"],
+      );
+
+  @override
+  Future<String> crateApiCronApiSetCronWebhookPort({required int port}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_16(port, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 174,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiSetCronWebhookPortConstMeta,
+        argValues: [port],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiSetCronWebhookPortConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cron_webhook_port",
+        argNames: ["port"],
       );
 
   @override
@@ -4545,7 +6252,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 131,
+            funcId: 175,
             port: port_,
           );
         },
@@ -4573,7 +6280,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 132,
+            funcId: 176,
           )!;
         },
         codec: SseCodec(
@@ -4603,7 +6310,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 133,
+            funcId: 177,
             port: port_,
           );
         },
@@ -4635,7 +6342,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 134,
+            funcId: 178,
             port: port_,
           );
         },
@@ -4672,7 +6379,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 135,
+            funcId: 179,
             port: port_,
           );
         },
@@ -4707,7 +6414,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 136,
+            funcId: 180,
             port: port_,
           );
         },
@@ -4737,7 +6444,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 137,
+            funcId: 181,
             port: port_,
           );
         },
@@ -4764,7 +6471,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 138,
+            funcId: 182,
             port: port_,
           );
         },
@@ -4791,7 +6498,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 139,
+            funcId: 183,
             port: port_,
           );
         },
@@ -4809,6 +6516,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiChannelRuntimeApiStopChannelListenersConstMeta =>
       const TaskConstMeta(debugName: "stop_channel_listeners", argNames: []);
 
+  @override
+  Future<String> crateApiCronApiStopCronScheduler() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 184,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiStopCronSchedulerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiStopCronSchedulerConstMeta =>
+      const TaskConstMeta(debugName: "stop_cron_scheduler", argNames: []);
+
   @override
   Stream<CronNotification>
   crateApiCronNotificationApiSubscribeCronNotifications() {
@@ -4822,7 +6556,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 140,
+              funcId: 185,
               port: port_,
             );
           },
@@ -4861,7 +6595,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 141,
+            funcId: 186,
             port: port_,
           );
         },
@@ -4892,7 +6626,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 142,
+            funcId: 187,
             port: port_,
           );
         },
@@ -4910,6 +6644,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiAgentApiSwitchSessionConstMeta =>
       const TaskConstMeta(debugName: "switch_session", argNames: ["sessionId"]);
 
+  @override
+  Future<String> crateApiCronApiTestCronDelivery({
+    required CronDeliveryDto target,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cron_delivery_dto(target, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 188,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCronApiTestCronDeliveryConstMeta,
+        argValues: [target],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCronApiTestCronDeliveryConstMeta =>
+      const TaskConstMeta(
+        debugName: "test_cron_delivery",
+        argNames: ["target"],
+      );
+
   @override
   Future<String> crateApiWorkspaceApiToggleChannel({
     required String channelType,
@@ -4924,7 +6691,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 143,
+            funcId: 189,
             port: port_,
           );
         },
@@ -4959,7 +6726,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 144,
+            funcId: 190,
             port: port_,
           );
         },
@@ -4990,7 +6757,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 145,
+            funcId: 191,
             port: port_,
           );
         },
@@ -5025,7 +6792,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 146,
+            funcId: 192,
             port: port_,
           );
         },
@@ -5058,7 +6825,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 147,
+            funcId: 193,
             port: port_,
           );
         },
@@ -5079,6 +6846,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sessionId"],
       );
 
+  @override
+  Future<AgentUndoResult> crateApiProjectGitApiUndoLastAgentChanges({
+    required String sessionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 194,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_agent_undo_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProjectGitApiUndoLastAgentChangesConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProjectGitApiUndoLastAgentChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "undo_last_agent_changes",
+        argNames: ["sessionId"],
+      );
+
   @override
   Future<String> crateApiWorkspaceApiUpdateAgentConfig({
     int? maxToolIterations,
@@ -5097,7 +6897,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 148,
+            funcId: 195,
             port: port_,
           );
         },
@@ -5140,7 +6940,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 149,
+            funcId: 196,
             port: port_,
           );
         },
@@ -5173,7 +6973,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 150,
+            funcId: 197,
             port: port_,
           );
         },
@@ -5214,7 +7014,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 151,
+            funcId: 198,
             port: port_,
           );
         },
@@ -5259,7 +7059,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 152,
+            funcId: 199,
             port: port_,
           );
         },
@@ -5308,7 +7108,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 153,
+            funcId: 200,
             port: port_,
           );
         },
@@ -5343,7 +7143,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 154,
+            funcId: 201,
             port: port_,
           );
         },
@@ -5374,7 +7174,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 155,
+            funcId: 202,
             port: port_,
           );
         },
@@ -5406,7 +7206,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 156,
+            funcId: 203,
             port: port_,
           );
         },
@@ -5441,7 +7241,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 157,
+            funcId: 204,
             port: port_,
           );
         },
@@ -5474,7 +7274,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 158,
+            funcId: 205,
             port: port_,
           );
         },
@@ -5507,7 +7307,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 159,
+            funcId: 206,
             port: port_,
           );
         },
@@ -5546,7 +7346,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 160,
+            funcId: 207,
             port: port_,
           );
         },
@@ -5577,7 +7377,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 161,
+            funcId: 208,
             port: port_,
           );
         },
@@ -5607,7 +7407,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 162,
+            funcId: 209,
             port: port_,
           );
         },
@@ -5640,7 +7440,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 163,
+            funcId: 210,
             port: port_,
           );
         },
@@ -5673,7 +7473,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 164,
+            funcId: 211,
             port: port_,
           );
         },
@@ -5709,7 +7509,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 165,
+            funcId: 212,
             port: port_,
           );
         },
@@ -5742,7 +7542,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 166,
+            funcId: 213,
             port: port_,
           );
         },
@@ -5772,7 +7572,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 167,
+            funcId: 214,
             port: port_,
           );
         },
@@ -5800,7 +7600,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 168,
+            funcId: 215,
           )!;
         },
         codec: SseCodec(
@@ -5915,6 +7715,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AgentUndoResult dco_decode_agent_undo_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AgentUndoResult(
+      restored: dco_decode_list_String(arr[0]),
+      removed: dco_decode_list_String(arr[1]),
+      errors: dco_decode_list_String(arr[2]),
+    );
+  }
+
   @protected
   AgentWorkspaceDto dco_decode_agent_workspace_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6025,6 +7838,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  CronActiveWindowDto dco_decode_box_autoadd_cron_active_window_dto(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cron_active_window_dto(raw);
+  }
+
+  @protected
+  CronDeliveryDto dco_decode_box_autoadd_cron_delivery_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cron_delivery_dto(raw);
+  }
+
+  @protected
+  CronJobStatsDto dco_decode_box_autoadd_cron_job_stats_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cron_job_stats_dto(raw);
+  }
+
+  @protected
+  CronRunOutput dco_decode_box_autoadd_cron_run_output(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cron_run_output(raw);
+  }
+
+  @protected
+  CronShellSandboxDto dco_decode_box_autoadd_cron_shell_sandbox_dto(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cron_shell_sandbox_dto(raw);
+  }
+
+  @protected
+  CronWebhookInfo dco_decode_box_autoadd_cron_webhook_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cron_webhook_info(raw);
+  }
+
   @protected
   DelegateAgentDto dco_decode_box_autoadd_delegate_agent_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6049,6 +7902,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6093,6 +7952,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_project_dto(raw);
   }
 
+  @protected
+  ProjectGitStatus dco_decode_box_autoadd_project_git_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_project_git_status(raw);
+  }
+
   @protected
   ProxyConfigDto dco_decode_box_autoadd_proxy_config_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6147,6 +8012,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  CodeSearchHit dco_decode_code_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CodeSearchHit(
+      path: dco_decode_String(arr[0]),
+      startLine: dco_decode_u_32(arr[1]),
+      endLine: dco_decode_u_32(arr[2]),
+      snippet: dco_decode_String(arr[3]),
+      score: dco_decode_f_64(arr[4]),
+    );
+  }
+
   @protected
   CopilotAuthStatus dco_decode_copilot_auth_status(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6201,18 +8081,102 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  CronActiveWindowDto dco_decode_cron_active_window_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CronActiveWindowDto(
+      quietStart: dco_decode_String(arr[0]),
+      quietEnd: dco_decode_String(arr[1]),
+      activeDays: dco_decode_list_prim_u_8_strict(arr[2]),
+      holidaysIcs: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   CronConfigDto dco_decode_cron_config_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
     return CronConfigDto(
       enabled: dco_decode_bool(arr[0]),
       maxRunHistory: dco_decode_u_32(arr[1]),
-      totalJobs: dco_decode_u_32(arr[2]),
-      activeJobs: dco_decode_u_32(arr[3]),
-      pausedJobs: dco_decode_u_32(arr[4]),
+      maxConcurrency: dco_decode_u_32(arr[2]),
+      autoDisableAfter: dco_decode_u_32(arr[3]),
+      totalJobs: dco_decode_u_32(arr[4]),
+      activeJobs: dco_decode_u_32(arr[5]),
+      pausedJobs: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  CronDeliveryDto dco_decode_cron_delivery_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CronDeliveryDto(
+      channel: dco_decode_String(arr[0]),
+      to: dco_decode_String(arr[1]),
+      onSuccess: dco_decode_bool(arr[2]),
+      onFailure: dco_decode_bool(arr[3]),
+      template: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  CronEnvVarDto dco_decode_cron_env_var_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CronEnvVarDto(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+      secret: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  CronExecutingJob dco_decode_cron_executing_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CronExecutingJob(
+      jobId: dco_decode_String(arr[0]),
+      jobName: dco_decode_String(arr[1]),
+      startedAt: dco_decode_i_64(arr[2]),
+      runs: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  CronImportChange dco_decode_cron_import_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CronImportChange(
+      name: dco_decode_String(arr[0]),
+      action: dco_decode_String(arr[1]),
+      changedFields: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  CronImportResult dco_decode_cron_import_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CronImportResult(
+      errors: dco_decode_list_String(arr[0]),
+      applied: dco_decode_bool(arr[1]),
+      changes: dco_decode_list_cron_import_change(arr[2]),
     );
   }
 
@@ -6220,28 +8184,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   CronJobDto dco_decode_cron_job_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 19)
-      throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
+    if (arr.length != 30)
+      throw Exception('unexpected arr length: expect 30 but see ${arr.length}');
     return CronJobDto(
       id: dco_decode_String(arr[0]),
       name: dco_decode_String(arr[1]),
       expression: dco_decode_String(arr[2]),
       scheduleType: dco_decode_String(arr[3]),
       scheduleDisplay: dco_decode_String(arr[4]),
-      command: dco_decode_String(arr[5]),
-      prompt: dco_decode_String(arr[6]),
-      jobType: dco_decode_String(arr[7]),
-      sessionTarget: dco_decode_String(arr[8]),
-      targetSessionId: dco_decode_String(arr[9]),
-      model: dco_decode_String(arr[10]),
-      enabled: dco_decode_bool(arr[11]),
-      deleteAfterRun: dco_decode_bool(arr[12]),
-      projectId: dco_decode_String(arr[13]),
-      createdAt: dco_decode_i_64(arr[14]),
-      nextRun: dco_decode_i_64(arr[15]),
-      lastRun: dco_decode_opt_box_autoadd_i_64(arr[16]),
-      lastStatus: dco_decode_String(arr[17]),
-      lastOutput: dco_decode_String(arr[18]),
+      timezone: dco_decode_String(arr[5]),
+      command: dco_decode_String(arr[6]),
+      prompt: dco_decode_String(arr[7]),
+      jobType: dco_decode_String(arr[8]),
+      sessionTarget: dco_decode_String(arr[9]),
+      targetSessionId: dco_decode_String(arr[10]),
+      model: dco_decode_String(arr[11]),
+      enabled: dco_decode_bool(arr[12]),
+      deleteAfterRun: dco_decode_bool(arr[13]),
+      projectId: dco_decode_String(arr[14]),
+      createdAt: dco_decode_i_64(arr[15]),
+      nextRun: dco_decode_i_64(arr[16]),
+      lastRun: dco_decode_opt_box_autoadd_i_64(arr[17]),
+      lastStatus: dco_decode_String(arr[18]),
+      lastOutput: dco_decode_String(arr[19]),
+      misfirePolicy: dco_decode_String(arr[20]),
+      noOverlap: dco_decode_bool(arr[21]),
+      timeoutSecs: dco_decode_u_32(arr[22]),
+      maxRetries: dco_decode_u_32(arr[23]),
+      retryBackoffSecs: dco_decode_u_32(arr[24]),
+      onSuccess: dco_decode_String(arr[25]),
+      onFailure: dco_decode_String(arr[26]),
+      delivery: dco_decode_list_cron_delivery_dto(arr[27]),
+      activeWindow: dco_decode_opt_box_autoadd_cron_active_window_dto(arr[28]),
+      sandbox: dco_decode_cron_shell_sandbox_dto(arr[29]),
+    );
+  }
+
+  @protected
+  CronJobStatsDto dco_decode_cron_job_stats_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return CronJobStatsDto(
+      jobId: dco_decode_String(arr[0]),
+      totalRuns: dco_decode_u_32(arr[1]),
+      succeeded: dco_decode_u_32(arr[2]),
+      failed: dco_decode_u_32(arr[3]),
+      successRate: dco_decode_f_64(arr[4]),
+      p50DurationMs: dco_decode_i_64(arr[5]),
+      p95DurationMs: dco_decode_i_64(arr[6]),
+      consecutiveFailures: dco_decode_u_32(arr[7]),
+      lastSuccess: dco_decode_opt_box_autoadd_i_64(arr[8]),
     );
   }
 
@@ -6249,8 +8243,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   CronNotification dco_decode_cron_notification(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
     return CronNotification(
       jobId: dco_decode_String(arr[0]),
       jobName: dco_decode_String(arr[1]),
@@ -6262,6 +8256,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       prompt: dco_decode_String(arr[7]),
       durationMs: dco_decode_i_64(arr[8]),
       finishedAt: dco_decode_i_64(arr[9]),
+      autoDisabled: dco_decode_bool(arr[10]),
     );
   }
 
@@ -6269,8 +8264,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   CronRunDto dco_decode_cron_run_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
     return CronRunDto(
       id: dco_decode_i_64(arr[0]),
       jobId: dco_decode_String(arr[1]),
@@ -6279,6 +8274,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       status: dco_decode_String(arr[4]),
       output: dco_decode_String(arr[5]),
       durationMs: dco_decode_i_64(arr[6]),
+      attempt: dco_decode_u_32(arr[7]),
+      exitCode: dco_decode_opt_box_autoadd_i_32(arr[8]),
+      stdoutLen: dco_decode_u_64(arr[9]),
+      stderrLen: dco_decode_u_64(arr[10]),
+      stdoutTruncated: dco_decode_bool(arr[11]),
+      stderrTruncated: dco_decode_bool(arr[12]),
+      chainId: dco_decode_String(arr[13]),
+    );
+  }
+
+  @protected
+  CronRunOutput dco_decode_cron_run_output(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return CronRunOutput(
+      runId: dco_decode_i_64(arr[0]),
+      stdout: dco_decode_String(arr[1]),
+      stderr: dco_decode_String(arr[2]),
+      stdoutLen: dco_decode_u_64(arr[3]),
+      stderrLen: dco_decode_u_64(arr[4]),
+      stdoutTruncated: dco_decode_bool(arr[5]),
+      stderrTruncated: dco_decode_bool(arr[6]),
+      exitCode: dco_decode_opt_box_autoadd_i_32(arr[7]),
+    );
+  }
+
+  @protected
+  CronSchedulePreview dco_decode_cron_schedule_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CronSchedulePreview(
+      error: dco_decode_String(arr[0]),
+      fireTimes: dco_decode_list_prim_i_64_strict(arr[1]),
+    );
+  }
+
+  @protected
+  CronSchedulerStatus dco_decode_cron_scheduler_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CronSchedulerStatus(
+      enabled: dco_decode_bool(arr[0]),
+      running: dco_decode_bool(arr[1]),
+      lastTick: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      executing: dco_decode_list_cron_executing_job(arr[3]),
+    );
+  }
+
+  @protected
+  CronShellSandboxDto dco_decode_cron_shell_sandbox_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CronShellSandboxDto(
+      workingDir: dco_decode_String(arr[0]),
+      inheritEnv: dco_decode_bool(arr[1]),
+      env: dco_decode_list_cron_env_var_dto(arr[2]),
+      cpuLimitSecs: dco_decode_u_64(arr[3]),
+      memoryLimitMb: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  CronWebhookInfo dco_decode_cron_webhook_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CronWebhookInfo(
+      url: dco_decode_String(arr[0]),
+      secret: dco_decode_String(arr[1]),
     );
   }
 
@@ -6366,6 +8439,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  GitCommitDto dco_decode_git_commit_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return GitCommitDto(
+      hash: dco_decode_String(arr[0]),
+      summary: dco_decode_String(arr[1]),
+      author: dco_decode_String(arr[2]),
+      timestamp: dco_decode_i_64(arr[3]),
+    );
+  }
+
   @protected
   int dco_decode_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6458,6 +8545,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_channel_summary).toList();
   }
 
+  @protected
+  List<CodeSearchHit> dco_decode_list_code_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_code_search_hit).toList();
+  }
+
+  @protected
+  List<CronDeliveryDto> dco_decode_list_cron_delivery_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cron_delivery_dto).toList();
+  }
+
+  @protected
+  List<CronEnvVarDto> dco_decode_list_cron_env_var_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cron_env_var_dto).toList();
+  }
+
+  @protected
+  List<CronExecutingJob> dco_decode_list_cron_executing_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cron_executing_job).toList();
+  }
+
+  @protected
+  List<CronImportChange> dco_decode_list_cron_import_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cron_import_change).toList();
+  }
+
   @protected
   List<CronJobDto> dco_decode_list_cron_job_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6482,6 +8599,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_embedding_route_dto).toList();
   }
 
+  @protected
+  List<GitCommitDto> dco_decode_list_git_commit_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_git_commit_dto).toList();
+  }
+
   @protected
   List<KeyValueDto> dco_decode_list_key_value_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6540,6 +8663,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_model_route_dto).toList();
   }
 
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6552,6 +8681,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_project_summary).toList();
   }
 
+  @protected
+  List<ProjectTemplateDto> dco_decode_list_project_template_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_project_template_dto).toList();
+  }
+
+  @protected
+  List<ProjectTimelineEvent> dco_decode_list_project_timeline_event(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_project_timeline_event)
+        .toList();
+  }
+
   @protected
   List<ProviderInfo> dco_decode_list_provider_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6757,6 +8902,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_bool(raw);
   }
 
+  @protected
+  CronActiveWindowDto? dco_decode_opt_box_autoadd_cron_active_window_dto(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_cron_active_window_dto(raw);
+  }
+
+  @protected
+  CronJobStatsDto? dco_decode_opt_box_autoadd_cron_job_stats_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cron_job_stats_dto(raw);
+  }
+
+  @protected
+  CronRunOutput? dco_decode_opt_box_autoadd_cron_run_output(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cron_run_output(raw);
+  }
+
+  @protected
+  CronWebhookInfo? dco_decode_opt_box_autoadd_cron_webhook_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cron_webhook_info(raw);
+  }
+
   @protected
   DelegateAgentDto? dco_decode_opt_box_autoadd_delegate_agent_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6769,6 +8942,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
   }
 
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6795,6 +8974,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_project_dto(raw);
   }
 
+  @protected
+  ProjectGitStatus? dco_decode_opt_box_autoadd_project_git_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_project_git_status(raw);
+  }
+
   @protected
   SessionDetail? dco_decode_opt_box_autoadd_session_detail(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6813,12 +8998,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  ProjectAutonomyDto dco_decode_project_autonomy_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ProjectAutonomyDto(
+      level: dco_decode_String(arr[0]),
+      trustMe: dco_decode_opt_box_autoadd_bool(arr[1]),
+      allowedCommands: dco_decode_list_String(arr[2]),
+      forbiddenPaths: dco_decode_list_String(arr[3]),
+      autoApprove: dco_decode_list_String(arr[4]),
+      alwaysAsk: dco_decode_list_String(arr[5]),
+      enabledTools: dco_decode_list_String(arr[6]),
+    );
+  }
+
+  @protected
+  ProjectCodeIndexStatus dco_decode_project_code_index_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ProjectCodeIndexStatus(
+      indexing: dco_decode_bool(arr[0]),
+      files: dco_decode_u_32(arr[1]),
+      chunks: dco_decode_u_32(arr[2]),
+      embeddedChunks: dco_decode_u_32(arr[3]),
+      lastIndexedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      lastError: dco_decode_String(arr[5]),
+    );
+  }
+
   @protected
   ProjectDto dco_decode_project_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 15)
-      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    if (arr.length != 20)
+      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
     return ProjectDto(
       id: dco_decode_String(arr[0]),
       name: dco_decode_String(arr[1]),
@@ -6833,8 +9051,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       defaultRoleId: dco_decode_String(arr[10]),
       sessionIds: dco_decode_list_String(arr[11]),
       tags: dco_decode_list_String(arr[12]),
-      createdAt: dco_decode_i_64(arr[13]),
-      updatedAt: dco_decode_i_64(arr[14]),
+      globalMemoryFallback: dco_decode_bool(arr[13]),
+      autonomy: dco_decode_project_autonomy_dto(arr[14]),
+      modelProfileId: dco_decode_String(arr[15]),
+      model: dco_decode_String(arr[16]),
+      temperature: dco_decode_opt_box_autoadd_f_64(arr[17]),
+      createdAt: dco_decode_i_64(arr[18]),
+      updatedAt: dco_decode_i_64(arr[19]),
+    );
+  }
+
+  @protected
+  ProjectGitStatus dco_decode_project_git_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ProjectGitStatus(
+      branch: dco_decode_String(arr[0]),
+      dirty: dco_decode_bool(arr[1]),
+      changedFiles: dco_decode_u_32(arr[2]),
+      recentCommits: dco_decode_list_git_commit_dto(arr[3]),
+    );
+  }
+
+  @protected
+  ProjectImportResult dco_decode_project_import_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ProjectImportResult(
+      projectId: dco_decode_String(arr[0]),
+      errors: dco_decode_list_String(arr[1]),
+      notes: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  ProjectSessionReport dco_decode_project_session_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ProjectSessionReport(
+      migratedSessions: dco_decode_list_String(arr[0]),
+      missingSessions: dco_decode_list_String(arr[1]),
+      duplicateSessions: dco_decode_list_String(arr[2]),
+      repaired: dco_decode_bool(arr[3]),
     );
   }
 
@@ -6868,6 +9132,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ProjectTemplateDto dco_decode_project_template_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return ProjectTemplateDto(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      description: dco_decode_String(arr[2]),
+      icon: dco_decode_String(arr[3]),
+      colorTag: dco_decode_String(arr[4]),
+      projectType: dco_decode_String(arr[5]),
+      isBuiltin: dco_decode_bool(arr[6]),
+      roleIds: dco_decode_list_String(arr[7]),
+      cronJobCount: dco_decode_u_32(arr[8]),
+      knowledgeCount: dco_decode_u_32(arr[9]),
+      fileCount: dco_decode_u_32(arr[10]),
+    );
+  }
+
+  @protected
+  ProjectTimelineEvent dco_decode_project_timeline_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ProjectTimelineEvent(
+      timestamp: dco_decode_i_64(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      detail: dco_decode_String(arr[3]),
+      refId: dco_decode_String(arr[4]),
+      status: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  ProjectTimelinePage dco_decode_project_timeline_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ProjectTimelinePage(
+      events: dco_decode_list_project_timeline_event(arr[0]),
+      nextCursor: dco_decode_opt_box_autoadd_i_64(arr[1]),
+    );
+  }
+
   @protected
   ProjectType dco_decode_project_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -7102,6 +9415,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -7259,6 +9578,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AgentUndoResult sse_decode_agent_undo_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_restored = sse_decode_list_String(deserializer);
+    var var_removed = sse_decode_list_String(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    return AgentUndoResult(
+      restored: var_restored,
+      removed: var_removed,
+      errors: var_errors,
+    );
+  }
+
   @protected
   AgentWorkspaceDto sse_decode_agent_workspace_dto(
     SseDeserializer deserializer,
@@ -7386,27 +9718,75 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AgentWorkspaceDto sse_decode_box_autoadd_agent_workspace_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_agent_workspace_dto(deserializer));
+  }
+
+  @protected
+  AppConfig sse_decode_box_autoadd_app_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_app_config(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CronActiveWindowDto sse_decode_box_autoadd_cron_active_window_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cron_active_window_dto(deserializer));
+  }
+
+  @protected
+  CronDeliveryDto sse_decode_box_autoadd_cron_delivery_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cron_delivery_dto(deserializer));
+  }
+
+  @protected
+  CronJobStatsDto sse_decode_box_autoadd_cron_job_stats_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cron_job_stats_dto(deserializer));
   }
 
   @protected
-  AgentWorkspaceDto sse_decode_box_autoadd_agent_workspace_dto(
+  CronRunOutput sse_decode_box_autoadd_cron_run_output(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_agent_workspace_dto(deserializer));
+    return (sse_decode_cron_run_output(deserializer));
   }
 
   @protected
-  AppConfig sse_decode_box_autoadd_app_config(SseDeserializer deserializer) {
+  CronShellSandboxDto sse_decode_box_autoadd_cron_shell_sandbox_dto(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_app_config(deserializer));
+    return (sse_decode_cron_shell_sandbox_dto(deserializer));
   }
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+  CronWebhookInfo sse_decode_box_autoadd_cron_webhook_info(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_bool(deserializer));
+    return (sse_decode_cron_webhook_info(deserializer));
   }
 
   @protected
@@ -7439,6 +9819,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_f_64(deserializer));
   }
 
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7491,6 +9877,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_project_dto(deserializer));
   }
 
+  @protected
+  ProjectGitStatus sse_decode_box_autoadd_project_git_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_project_git_status(deserializer));
+  }
+
   @protected
   ProxyConfigDto sse_decode_box_autoadd_proxy_config_dto(
     SseDeserializer deserializer,
@@ -7553,6 +9947,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  CodeSearchHit sse_decode_code_search_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_startLine = sse_decode_u_32(deserializer);
+    var var_endLine = sse_decode_u_32(deserializer);
+    var var_snippet = sse_decode_String(deserializer);
+    var var_score = sse_decode_f_64(deserializer);
+    return CodeSearchHit(
+      path: var_path,
+      startLine: var_startLine,
+      endLine: var_endLine,
+      snippet: var_snippet,
+      score: var_score,
+    );
+  }
+
   @protected
   CopilotAuthStatus sse_decode_copilot_auth_status(
     SseDeserializer deserializer,
@@ -7612,23 +10023,111 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  CronActiveWindowDto sse_decode_cron_active_window_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_quietStart = sse_decode_String(deserializer);
+    var var_quietEnd = sse_decode_String(deserializer);
+    var var_activeDays = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_holidaysIcs = sse_decode_String(deserializer);
+    return CronActiveWindowDto(
+      quietStart: var_quietStart,
+      quietEnd: var_quietEnd,
+      activeDays: var_activeDays,
+      holidaysIcs: var_holidaysIcs,
+    );
+  }
+
   @protected
   CronConfigDto sse_decode_cron_config_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_enabled = sse_decode_bool(deserializer);
     var var_maxRunHistory = sse_decode_u_32(deserializer);
+    var var_maxConcurrency = sse_decode_u_32(deserializer);
+    var var_autoDisableAfter = sse_decode_u_32(deserializer);
     var var_totalJobs = sse_decode_u_32(deserializer);
     var var_activeJobs = sse_decode_u_32(deserializer);
     var var_pausedJobs = sse_decode_u_32(deserializer);
     return CronConfigDto(
       enabled: var_enabled,
       maxRunHistory: var_maxRunHistory,
+      maxConcurrency: var_maxConcurrency,
+      autoDisableAfter: var_autoDisableAfter,
       totalJobs: var_totalJobs,
       activeJobs: var_activeJobs,
       pausedJobs: var_pausedJobs,
     );
   }
 
+  @protected
+  CronDeliveryDto sse_decode_cron_delivery_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_channel = sse_decode_String(deserializer);
+    var var_to = sse_decode_String(deserializer);
+    var var_onSuccess = sse_decode_bool(deserializer);
+    var var_onFailure = sse_decode_bool(deserializer);
+    var var_template = sse_decode_String(deserializer);
+    return CronDeliveryDto(
+      channel: var_channel,
+      to: var_to,
+      onSuccess: var_onSuccess,
+      onFailure: var_onFailure,
+      template: var_template,
+    );
+  }
+
+  @protected
+  CronEnvVarDto sse_decode_cron_env_var_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    var var_secret = sse_decode_bool(deserializer);
+    return CronEnvVarDto(name: var_name, value: var_value, secret: var_secret);
+  }
+
+  @protected
+  CronExecutingJob sse_decode_cron_executing_job(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_jobId = sse_decode_String(deserializer);
+    var var_jobName = sse_decode_String(deserializer);
+    var var_startedAt = sse_decode_i_64(deserializer);
+    var var_runs = sse_decode_u_32(deserializer);
+    return CronExecutingJob(
+      jobId: var_jobId,
+      jobName: var_jobName,
+      startedAt: var_startedAt,
+      runs: var_runs,
+    );
+  }
+
+  @protected
+  CronImportChange sse_decode_cron_import_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_action = sse_decode_String(deserializer);
+    var var_changedFields = sse_decode_list_String(deserializer);
+    return CronImportChange(
+      name: var_name,
+      action: var_action,
+      changedFields: var_changedFields,
+    );
+  }
+
+  @protected
+  CronImportResult sse_decode_cron_import_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_errors = sse_decode_list_String(deserializer);
+    var var_applied = sse_decode_bool(deserializer);
+    var var_changes = sse_decode_list_cron_import_change(deserializer);
+    return CronImportResult(
+      errors: var_errors,
+      applied: var_applied,
+      changes: var_changes,
+    );
+  }
+
   @protected
   CronJobDto sse_decode_cron_job_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7637,6 +10136,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_expression = sse_decode_String(deserializer);
     var var_scheduleType = sse_decode_String(deserializer);
     var var_scheduleDisplay = sse_decode_String(deserializer);
+    var var_timezone = sse_decode_String(deserializer);
     var var_command = sse_decode_String(deserializer);
     var var_prompt = sse_decode_String(deserializer);
     var var_jobType = sse_decode_String(deserializer);
@@ -7651,12 +10151,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_lastRun = sse_decode_opt_box_autoadd_i_64(deserializer);
     var var_lastStatus = sse_decode_String(deserializer);
     var var_lastOutput = sse_decode_String(deserializer);
+    var var_misfirePolicy = sse_decode_String(deserializer);
+    var var_noOverlap = sse_decode_bool(deserializer);
+    var var_timeoutSecs = sse_decode_u_32(deserializer);
+    var var_maxRetries = sse_decode_u_32(deserializer);
+    var var_retryBackoffSecs = sse_decode_u_32(deserializer);
+    var var_onSuccess = sse_decode_String(deserializer);
+    var var_onFailure = sse_decode_String(deserializer);
+    var var_delivery = sse_decode_list_cron_delivery_dto(deserializer);
+    var var_activeWindow = sse_decode_opt_box_autoadd_cron_active_window_dto(
+      deserializer,
+    );
+    var var_sandbox = sse_decode_cron_shell_sandbox_dto(deserializer);
     return CronJobDto(
       id: var_id,
       name: var_name,
       expression: var_expression,
       scheduleType: var_scheduleType,
       scheduleDisplay: var_scheduleDisplay,
+      timezone: var_timezone,
       command: var_command,
       prompt: var_prompt,
       jobType: var_jobType,
@@ -7671,6 +10184,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       lastRun: var_lastRun,
       lastStatus: var_lastStatus,
       lastOutput: var_lastOutput,
+      misfirePolicy: var_misfirePolicy,
+      noOverlap: var_noOverlap,
+      timeoutSecs: var_timeoutSecs,
+      maxRetries: var_maxRetries,
+      retryBackoffSecs: var_retryBackoffSecs,
+      onSuccess: var_onSuccess,
+      onFailure: var_onFailure,
+      delivery: var_delivery,
+      activeWindow: var_activeWindow,
+      sandbox: var_sandbox,
+    );
+  }
+
+  @protected
+  CronJobStatsDto sse_decode_cron_job_stats_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_jobId = sse_decode_String(deserializer);
+    var var_totalRuns = sse_decode_u_32(deserializer);
+    var var_succeeded = sse_decode_u_32(deserializer);
+    var var_failed = sse_decode_u_32(deserializer);
+    var var_successRate = sse_decode_f_64(deserializer);
+    var var_p50DurationMs = sse_decode_i_64(deserializer);
+    var var_p95DurationMs = sse_decode_i_64(deserializer);
+    var var_consecutiveFailures = sse_decode_u_32(deserializer);
+    var var_lastSuccess = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return CronJobStatsDto(
+      jobId: var_jobId,
+      totalRuns: var_totalRuns,
+      succeeded: var_succeeded,
+      failed: var_failed,
+      successRate: var_successRate,
+      p50DurationMs: var_p50DurationMs,
+      p95DurationMs: var_p95DurationMs,
+      consecutiveFailures: var_consecutiveFailures,
+      lastSuccess: var_lastSuccess,
     );
   }
 
@@ -7687,6 +10235,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_prompt = sse_decode_String(deserializer);
     var var_durationMs = sse_decode_i_64(deserializer);
     var var_finishedAt = sse_decode_i_64(deserializer);
+    var var_autoDisabled = sse_decode_bool(deserializer);
     return CronNotification(
       jobId: var_jobId,
       jobName: var_jobName,
@@ -7698,6 +10247,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       prompt: var_prompt,
       durationMs: var_durationMs,
       finishedAt: var_finishedAt,
+      autoDisabled: var_autoDisabled,
     );
   }
 
@@ -7711,6 +10261,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_status = sse_decode_String(deserializer);
     var var_output = sse_decode_String(deserializer);
     var var_durationMs = sse_decode_i_64(deserializer);
+    var var_attempt = sse_decode_u_32(deserializer);
+    var var_exitCode = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_stdoutLen = sse_decode_u_64(deserializer);
+    var var_stderrLen = sse_decode_u_64(deserializer);
+    var var_stdoutTruncated = sse_decode_bool(deserializer);
+    var var_stderrTruncated = sse_decode_bool(deserializer);
+    var var_chainId = sse_decode_String(deserializer);
     return CronRunDto(
       id: var_id,
       jobId: var_jobId,
@@ -7719,9 +10276,93 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       status: var_status,
       output: var_output,
       durationMs: var_durationMs,
+      attempt: var_attempt,
+      exitCode: var_exitCode,
+      stdoutLen: var_stdoutLen,
+      stderrLen: var_stderrLen,
+      stdoutTruncated: var_stdoutTruncated,
+      stderrTruncated: var_stderrTruncated,
+      chainId: var_chainId,
+    );
+  }
+
+  @protected
+  CronRunOutput sse_decode_cron_run_output(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_runId = sse_decode_i_64(deserializer);
+    var var_stdout = sse_decode_String(deserializer);
+    var var_stderr = sse_decode_String(deserializer);
+    var var_stdoutLen = sse_decode_u_64(deserializer);
+    var var_stderrLen = sse_decode_u_64(deserializer);
+    var var_stdoutTruncated = sse_decode_bool(deserializer);
+    var var_stderrTruncated = sse_decode_bool(deserializer);
+    var var_exitCode = sse_decode_opt_box_autoadd_i_32(deserializer);
+    return CronRunOutput(
+      runId: var_runId,
+      stdout: var_stdout,
+      stderr: var_stderr,
+      stdoutLen: var_stdoutLen,
+      stderrLen: var_stderrLen,
+      stdoutTruncated: var_stdoutTruncated,
+      stderrTruncated: var_stderrTruncated,
+      exitCode: var_exitCode,
+    );
+  }
+
+  @protected
+  CronSchedulePreview sse_decode_cron_schedule_preview(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_error = sse_decode_String(deserializer);
+    var var_fireTimes = sse_decode_list_prim_i_64_strict(deserializer);
+    return CronSchedulePreview(error: var_error, fireTimes: var_fireTimes);
+  }
+
+  @protected
+  CronSchedulerStatus sse_decode_cron_scheduler_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_running = sse_decode_bool(deserializer);
+    var var_lastTick = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_executing = sse_decode_list_cron_executing_job(deserializer);
+    return CronSchedulerStatus(
+      enabled: var_enabled,
+      running: var_running,
+      lastTick: var_lastTick,
+      executing: var_executing,
+    );
+  }
+
+  @protected
+  CronShellSandboxDto sse_decode_cron_shell_sandbox_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_workingDir = sse_decode_String(deserializer);
+    var var_inheritEnv = sse_decode_bool(deserializer);
+    var var_env = sse_decode_list_cron_env_var_dto(deserializer);
+    var var_cpuLimitSecs = sse_decode_u_64(deserializer);
+    var var_memoryLimitMb = sse_decode_u_64(deserializer);
+    return CronShellSandboxDto(
+      workingDir: var_workingDir,
+      inheritEnv: var_inheritEnv,
+      env: var_env,
+      cpuLimitSecs: var_cpuLimitSecs,
+      memoryLimitMb: var_memoryLimitMb,
     );
   }
 
+  @protected
+  CronWebhookInfo sse_decode_cron_webhook_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_secret = sse_decode_String(deserializer);
+    return CronWebhookInfo(url: var_url, secret: var_secret);
+  }
+
   @protected
   DelegateAgentDto sse_decode_delegate_agent_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7836,6 +10477,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  GitCommitDto sse_decode_git_commit_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hash = sse_decode_String(deserializer);
+    var var_summary = sse_decode_String(deserializer);
+    var var_author = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    return GitCommitDto(
+      hash: var_hash,
+      summary: var_summary,
+      author: var_author,
+      timestamp: var_timestamp,
+    );
+  }
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7956,6 +10612,76 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<CodeSearchHit> sse_decode_list_code_search_hit(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CodeSearchHit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_code_search_hit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CronDeliveryDto> sse_decode_list_cron_delivery_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CronDeliveryDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cron_delivery_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CronEnvVarDto> sse_decode_list_cron_env_var_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CronEnvVarDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cron_env_var_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CronExecutingJob> sse_decode_list_cron_executing_job(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CronExecutingJob>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cron_executing_job(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CronImportChange> sse_decode_list_cron_import_change(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CronImportChange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cron_import_change(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<CronJobDto> sse_decode_list_cron_job_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8008,6 +10734,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<GitCommitDto> sse_decode_list_git_commit_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GitCommitDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_git_commit_dto(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<KeyValueDto> sse_decode_list_key_value_dto(
     SseDeserializer deserializer,
@@ -8120,6 +10860,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8141,6 +10888,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ProjectTemplateDto> sse_decode_list_project_template_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProjectTemplateDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_project_template_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ProjectTimelineEvent> sse_decode_list_project_timeline_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProjectTimelineEvent>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_project_timeline_event(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<ProviderInfo> sse_decode_list_provider_info(
     SseDeserializer deserializer,
@@ -8413,50 +11188,102 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  ModelRouteDto sse_decode_model_route_dto(SseDeserializer deserializer) {
+  ModelRouteDto sse_decode_model_route_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hint = sse_decode_String(deserializer);
+    var var_provider = sse_decode_String(deserializer);
+    var var_model = sse_decode_String(deserializer);
+    var var_apiKey = sse_decode_opt_String(deserializer);
+    return ModelRouteDto(
+      hint: var_hint,
+      provider: var_provider,
+      model: var_model,
+      apiKey: var_apiKey,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AgentWorkspaceDto? sse_decode_opt_box_autoadd_agent_workspace_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_agent_workspace_dto(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CronActiveWindowDto? sse_decode_opt_box_autoadd_cron_active_window_dto(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_hint = sse_decode_String(deserializer);
-    var var_provider = sse_decode_String(deserializer);
-    var var_model = sse_decode_String(deserializer);
-    var var_apiKey = sse_decode_opt_String(deserializer);
-    return ModelRouteDto(
-      hint: var_hint,
-      provider: var_provider,
-      model: var_model,
-      apiKey: var_apiKey,
-    );
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cron_active_window_dto(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  CronJobStatsDto? sse_decode_opt_box_autoadd_cron_job_stats_dto(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
+      return (sse_decode_box_autoadd_cron_job_stats_dto(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  AgentWorkspaceDto? sse_decode_opt_box_autoadd_agent_workspace_dto(
+  CronRunOutput? sse_decode_opt_box_autoadd_cron_run_output(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_agent_workspace_dto(deserializer));
+      return (sse_decode_box_autoadd_cron_run_output(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+  CronWebhookInfo? sse_decode_opt_box_autoadd_cron_webhook_info(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
+      return (sse_decode_box_autoadd_cron_webhook_info(deserializer));
     } else {
       return null;
     }
@@ -8486,6 +11313,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8536,6 +11374,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  ProjectGitStatus? sse_decode_opt_box_autoadd_project_git_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_project_git_status(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   SessionDetail? sse_decode_opt_box_autoadd_session_detail(
     SseDeserializer deserializer,
@@ -8571,6 +11422,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  ProjectAutonomyDto sse_decode_project_autonomy_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_String(deserializer);
+    var var_trustMe = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_allowedCommands = sse_decode_list_String(deserializer);
+    var var_forbiddenPaths = sse_decode_list_String(deserializer);
+    var var_autoApprove = sse_decode_list_String(deserializer);
+    var var_alwaysAsk = sse_decode_list_String(deserializer);
+    var var_enabledTools = sse_decode_list_String(deserializer);
+    return ProjectAutonomyDto(
+      level: var_level,
+      trustMe: var_trustMe,
+      allowedCommands: var_allowedCommands,
+      forbiddenPaths: var_forbiddenPaths,
+      autoApprove: var_autoApprove,
+      alwaysAsk: var_alwaysAsk,
+      enabledTools: var_enabledTools,
+    );
+  }
+
+  @protected
+  ProjectCodeIndexStatus sse_decode_project_code_index_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_indexing = sse_decode_bool(deserializer);
+    var var_files = sse_decode_u_32(deserializer);
+    var var_chunks = sse_decode_u_32(deserializer);
+    var var_embeddedChunks = sse_decode_u_32(deserializer);
+    var var_lastIndexedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_lastError = sse_decode_String(deserializer);
+    return ProjectCodeIndexStatus(
+      indexing: var_indexing,
+      files: var_files,
+      chunks: var_chunks,
+      embeddedChunks: var_embeddedChunks,
+      lastIndexedAt: var_lastIndexedAt,
+      lastError: var_lastError,
+    );
+  }
+
   @protected
   ProjectDto sse_decode_project_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8587,6 +11482,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_defaultRoleId = sse_decode_String(deserializer);
     var var_sessionIds = sse_decode_list_String(deserializer);
     var var_tags = sse_decode_list_String(deserializer);
+    var var_globalMemoryFallback = sse_decode_bool(deserializer);
+    var var_autonomy = sse_decode_project_autonomy_dto(deserializer);
+    var var_modelProfileId = sse_decode_String(deserializer);
+    var var_model = sse_decode_String(deserializer);
+    var var_temperature = sse_decode_opt_box_autoadd_f_64(deserializer);
     var var_createdAt = sse_decode_i_64(deserializer);
     var var_updatedAt = sse_decode_i_64(deserializer);
     return ProjectDto(
@@ -8603,11 +11503,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       defaultRoleId: var_defaultRoleId,
       sessionIds: var_sessionIds,
       tags: var_tags,
+      globalMemoryFallback: var_globalMemoryFallback,
+      autonomy: var_autonomy,
+      modelProfileId: var_modelProfileId,
+      model: var_model,
+      temperature: var_temperature,
       createdAt: var_createdAt,
       updatedAt: var_updatedAt,
     );
   }
 
+  @protected
+  ProjectGitStatus sse_decode_project_git_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_branch = sse_decode_String(deserializer);
+    var var_dirty = sse_decode_bool(deserializer);
+    var var_changedFiles = sse_decode_u_32(deserializer);
+    var var_recentCommits = sse_decode_list_git_commit_dto(deserializer);
+    return ProjectGitStatus(
+      branch: var_branch,
+      dirty: var_dirty,
+      changedFiles: var_changedFiles,
+      recentCommits: var_recentCommits,
+    );
+  }
+
+  @protected
+  ProjectImportResult sse_decode_project_import_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_projectId = sse_decode_String(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    var var_notes = sse_decode_list_String(deserializer);
+    return ProjectImportResult(
+      projectId: var_projectId,
+      errors: var_errors,
+      notes: var_notes,
+    );
+  }
+
+  @protected
+  ProjectSessionReport sse_decode_project_session_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_migratedSessions = sse_decode_list_String(deserializer);
+    var var_missingSessions = sse_decode_list_String(deserializer);
+    var var_duplicateSessions = sse_decode_list_String(deserializer);
+    var var_repaired = sse_decode_bool(deserializer);
+    return ProjectSessionReport(
+      migratedSessions: var_migratedSessions,
+      missingSessions: var_missingSessions,
+      duplicateSessions: var_duplicateSessions,
+      repaired: var_repaired,
+    );
+  }
+
   @protected
   ProjectStatus sse_decode_project_status(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8650,6 +11602,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ProjectTemplateDto sse_decode_project_template_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_icon = sse_decode_String(deserializer);
+    var var_colorTag = sse_decode_String(deserializer);
+    var var_projectType = sse_decode_String(deserializer);
+    var var_isBuiltin = sse_decode_bool(deserializer);
+    var var_roleIds = sse_decode_list_String(deserializer);
+    var var_cronJobCount = sse_decode_u_32(deserializer);
+    var var_knowledgeCount = sse_decode_u_32(deserializer);
+    var var_fileCount = sse_decode_u_32(deserializer);
+    return ProjectTemplateDto(
+      id: var_id,
+      name: var_name,
+      description: var_description,
+      icon: var_icon,
+      colorTag: var_colorTag,
+      projectType: var_projectType,
+      isBuiltin: var_isBuiltin,
+      roleIds: var_roleIds,
+      cronJobCount: var_cronJobCount,
+      knowledgeCount: var_knowledgeCount,
+      fileCount: var_fileCount,
+    );
+  }
+
+  @protected
+  ProjectTimelineEvent sse_decode_project_timeline_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_detail = sse_decode_String(deserializer);
+    var var_refId = sse_decode_String(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    return ProjectTimelineEvent(
+      timestamp: var_timestamp,
+      kind: var_kind,
+      title: var_title,
+      detail: var_detail,
+      refId: var_refId,
+      status: var_status,
+    );
+  }
+
+  @protected
+  ProjectTimelinePage sse_decode_project_timeline_page(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_events = sse_decode_list_project_timeline_event(deserializer);
+    var var_nextCursor = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return ProjectTimelinePage(events: var_events, nextCursor: var_nextCursor);
+  }
+
   @protected
   ProjectType sse_decode_project_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8915,6 +11929,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ToolSpecDto(name: var_name, description: var_description);
   }
 
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9081,6 +12101,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_agent_undo_result(
+    AgentUndoResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.restored, serializer);
+    sse_encode_list_String(self.removed, serializer);
+    sse_encode_list_String(self.errors, serializer);
+  }
+
   @protected
   void sse_encode_agent_workspace_dto(
     AgentWorkspaceDto self,
@@ -9186,6 +12217,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_cron_active_window_dto(
+    CronActiveWindowDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cron_active_window_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cron_delivery_dto(
+    CronDeliveryDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cron_delivery_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cron_job_stats_dto(
+    CronJobStatsDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cron_job_stats_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cron_run_output(
+    CronRunOutput self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cron_run_output(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cron_shell_sandbox_dto(
+    CronShellSandboxDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cron_shell_sandbox_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cron_webhook_info(
+    CronWebhookInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cron_webhook_info(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_delegate_agent_dto(
     DelegateAgentDto self,
@@ -9219,6 +12304,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_f_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_i_64(
     PlatformInt64 self,
@@ -9282,6 +12373,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_project_dto(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_project_git_status(
+    ProjectGitStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_project_git_status(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_proxy_config_dto(
     ProxyConfigDto self,
@@ -9338,6 +12438,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_32(self.messageCount, serializer);
   }
 
+  @protected
+  void sse_encode_code_search_hit(
+    CodeSearchHit self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.startLine, serializer);
+    sse_encode_u_32(self.endLine, serializer);
+    sse_encode_String(self.snippet, serializer);
+    sse_encode_f_64(self.score, serializer);
+  }
+
   @protected
   void sse_encode_copilot_auth_status(
     CopilotAuthStatus self,
@@ -9384,6 +12497,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_8(self.warnAtPercent, serializer);
   }
 
+  @protected
+  void sse_encode_cron_active_window_dto(
+    CronActiveWindowDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.quietStart, serializer);
+    sse_encode_String(self.quietEnd, serializer);
+    sse_encode_list_prim_u_8_strict(self.activeDays, serializer);
+    sse_encode_String(self.holidaysIcs, serializer);
+  }
+
   @protected
   void sse_encode_cron_config_dto(
     CronConfigDto self,
@@ -9392,11 +12517,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self.enabled, serializer);
     sse_encode_u_32(self.maxRunHistory, serializer);
+    sse_encode_u_32(self.maxConcurrency, serializer);
+    sse_encode_u_32(self.autoDisableAfter, serializer);
     sse_encode_u_32(self.totalJobs, serializer);
     sse_encode_u_32(self.activeJobs, serializer);
     sse_encode_u_32(self.pausedJobs, serializer);
   }
 
+  @protected
+  void sse_encode_cron_delivery_dto(
+    CronDeliveryDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.channel, serializer);
+    sse_encode_String(self.to, serializer);
+    sse_encode_bool(self.onSuccess, serializer);
+    sse_encode_bool(self.onFailure, serializer);
+    sse_encode_String(self.template, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_env_var_dto(
+    CronEnvVarDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+    sse_encode_bool(self.secret, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_executing_job(
+    CronExecutingJob self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.jobId, serializer);
+    sse_encode_String(self.jobName, serializer);
+    sse_encode_i_64(self.startedAt, serializer);
+    sse_encode_u_32(self.runs, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_import_change(
+    CronImportChange self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.action, serializer);
+    sse_encode_list_String(self.changedFields, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_import_result(
+    CronImportResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.errors, serializer);
+    sse_encode_bool(self.applied, serializer);
+    sse_encode_list_cron_import_change(self.changes, serializer);
+  }
+
   @protected
   void sse_encode_cron_job_dto(CronJobDto self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9405,6 +12590,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.expression, serializer);
     sse_encode_String(self.scheduleType, serializer);
     sse_encode_String(self.scheduleDisplay, serializer);
+    sse_encode_String(self.timezone, serializer);
     sse_encode_String(self.command, serializer);
     sse_encode_String(self.prompt, serializer);
     sse_encode_String(self.jobType, serializer);
@@ -9419,6 +12605,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_i_64(self.lastRun, serializer);
     sse_encode_String(self.lastStatus, serializer);
     sse_encode_String(self.lastOutput, serializer);
+    sse_encode_String(self.misfirePolicy, serializer);
+    sse_encode_bool(self.noOverlap, serializer);
+    sse_encode_u_32(self.timeoutSecs, serializer);
+    sse_encode_u_32(self.maxRetries, serializer);
+    sse_encode_u_32(self.retryBackoffSecs, serializer);
+    sse_encode_String(self.onSuccess, serializer);
+    sse_encode_String(self.onFailure, serializer);
+    sse_encode_list_cron_delivery_dto(self.delivery, serializer);
+    sse_encode_opt_box_autoadd_cron_active_window_dto(
+      self.activeWindow,
+      serializer,
+    );
+    sse_encode_cron_shell_sandbox_dto(self.sandbox, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_job_stats_dto(
+    CronJobStatsDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.jobId, serializer);
+    sse_encode_u_32(self.totalRuns, serializer);
+    sse_encode_u_32(self.succeeded, serializer);
+    sse_encode_u_32(self.failed, serializer);
+    sse_encode_f_64(self.successRate, serializer);
+    sse_encode_i_64(self.p50DurationMs, serializer);
+    sse_encode_i_64(self.p95DurationMs, serializer);
+    sse_encode_u_32(self.consecutiveFailures, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastSuccess, serializer);
   }
 
   @protected
@@ -9437,6 +12653,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.prompt, serializer);
     sse_encode_i_64(self.durationMs, serializer);
     sse_encode_i_64(self.finishedAt, serializer);
+    sse_encode_bool(self.autoDisabled, serializer);
   }
 
   @protected
@@ -9449,6 +12666,74 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.status, serializer);
     sse_encode_String(self.output, serializer);
     sse_encode_i_64(self.durationMs, serializer);
+    sse_encode_u_32(self.attempt, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.exitCode, serializer);
+    sse_encode_u_64(self.stdoutLen, serializer);
+    sse_encode_u_64(self.stderrLen, serializer);
+    sse_encode_bool(self.stdoutTruncated, serializer);
+    sse_encode_bool(self.stderrTruncated, serializer);
+    sse_encode_String(self.chainId, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_run_output(
+    CronRunOutput self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.runId, serializer);
+    sse_encode_String(self.stdout, serializer);
+    sse_encode_String(self.stderr, serializer);
+    sse_encode_u_64(self.stdoutLen, serializer);
+    sse_encode_u_64(self.stderrLen, serializer);
+    sse_encode_bool(self.stdoutTruncated, serializer);
+    sse_encode_bool(self.stderrTruncated, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.exitCode, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_schedule_preview(
+    CronSchedulePreview self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.error, serializer);
+    sse_encode_list_prim_i_64_strict(self.fireTimes, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_scheduler_status(
+    CronSchedulerStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_bool(self.running, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastTick, serializer);
+    sse_encode_list_cron_executing_job(self.executing, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_shell_sandbox_dto(
+    CronShellSandboxDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.workingDir, serializer);
+    sse_encode_bool(self.inheritEnv, serializer);
+    sse_encode_list_cron_env_var_dto(self.env, serializer);
+    sse_encode_u_64(self.cpuLimitSecs, serializer);
+    sse_encode_u_64(self.memoryLimitMb, serializer);
+  }
+
+  @protected
+  void sse_encode_cron_webhook_info(
+    CronWebhookInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.secret, serializer);
   }
 
   @protected
@@ -9527,6 +12812,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.skillsOpenEnabled, serializer);
   }
 
+  @protected
+  void sse_encode_git_commit_dto(GitCommitDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.hash, serializer);
+    sse_encode_String(self.summary, serializer);
+    sse_encode_String(self.author, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9627,6 +12921,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_code_search_hit(
+    List<CodeSearchHit> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_code_search_hit(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_cron_delivery_dto(
+    List<CronDeliveryDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cron_delivery_dto(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_cron_env_var_dto(
+    List<CronEnvVarDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cron_env_var_dto(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_cron_executing_job(
+    List<CronExecutingJob> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cron_executing_job(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_cron_import_change(
+    List<CronImportChange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cron_import_change(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_cron_job_dto(
     List<CronJobDto> self,
@@ -9675,6 +13029,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_git_commit_dto(
+    List<GitCommitDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_git_commit_dto(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_key_value_dto(
     List<KeyValueDto> self,
@@ -9771,6 +13137,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -9793,6 +13169,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_project_template_dto(
+    List<ProjectTemplateDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_project_template_dto(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_project_timeline_event(
+    List<ProjectTimelineEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_project_timeline_event(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_provider_info(
     List<ProviderInfo> self,
@@ -10043,6 +13443,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_cron_active_window_dto(
+    CronActiveWindowDto? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cron_active_window_dto(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_cron_job_stats_dto(
+    CronJobStatsDto? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cron_job_stats_dto(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_cron_run_output(
+    CronRunOutput? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cron_run_output(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_cron_webhook_info(
+    CronWebhookInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cron_webhook_info(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_delegate_agent_dto(
     DelegateAgentDto? self,
@@ -10066,6 +13518,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_i_64(
     PlatformInt64? self,
@@ -10118,6 +13580,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_project_git_status(
+    ProjectGitStatus? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_project_git_status(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_session_detail(
     SessionDetail? self,
@@ -10151,6 +13626,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_project_autonomy_dto(
+    ProjectAutonomyDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.level, serializer);
+    sse_encode_opt_box_autoadd_bool(self.trustMe, serializer);
+    sse_encode_list_String(self.allowedCommands, serializer);
+    sse_encode_list_String(self.forbiddenPaths, serializer);
+    sse_encode_list_String(self.autoApprove, serializer);
+    sse_encode_list_String(self.alwaysAsk, serializer);
+    sse_encode_list_String(self.enabledTools, serializer);
+  }
+
+  @protected
+  void sse_encode_project_code_index_status(
+    ProjectCodeIndexStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.indexing, serializer);
+    sse_encode_u_32(self.files, serializer);
+    sse_encode_u_32(self.chunks, serializer);
+    sse_encode_u_32(self.embeddedChunks, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastIndexedAt, serializer);
+    sse_encode_String(self.lastError, serializer);
+  }
+
   @protected
   void sse_encode_project_dto(ProjectDto self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -10167,10 +13671,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.defaultRoleId, serializer);
     sse_encode_list_String(self.sessionIds, serializer);
     sse_encode_list_String(self.tags, serializer);
+    sse_encode_bool(self.globalMemoryFallback, serializer);
+    sse_encode_project_autonomy_dto(self.autonomy, serializer);
+    sse_encode_String(self.modelProfileId, serializer);
+    sse_encode_String(self.model, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.temperature, serializer);
     sse_encode_i_64(self.createdAt, serializer);
     sse_encode_i_64(self.updatedAt, serializer);
   }
 
+  @protected
+  void sse_encode_project_git_status(
+    ProjectGitStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.branch, serializer);
+    sse_encode_bool(self.dirty, serializer);
+    sse_encode_u_32(self.changedFiles, serializer);
+    sse_encode_list_git_commit_dto(self.recentCommits, serializer);
+  }
+
+  @protected
+  void sse_encode_project_import_result(
+    ProjectImportResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.projectId, serializer);
+    sse_encode_list_String(self.errors, serializer);
+    sse_encode_list_String(self.notes, serializer);
+  }
+
+  @protected
+  void sse_encode_project_session_report(
+    ProjectSessionReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.migratedSessions, serializer);
+    sse_encode_list_String(self.missingSessions, serializer);
+    sse_encode_list_String(self.duplicateSessions, serializer);
+    sse_encode_bool(self.repaired, serializer);
+  }
+
   @protected
   void sse_encode_project_status(ProjectStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -10199,6 +13743,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self.updatedAt, serializer);
   }
 
+  @protected
+  void sse_encode_project_template_dto(
+    ProjectTemplateDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_String(self.icon, serializer);
+    sse_encode_String(self.colorTag, serializer);
+    sse_encode_String(self.projectType, serializer);
+    sse_encode_bool(self.isBuiltin, serializer);
+    sse_encode_list_String(self.roleIds, serializer);
+    sse_encode_u_32(self.cronJobCount, serializer);
+    sse_encode_u_32(self.knowledgeCount, serializer);
+    sse_encode_u_32(self.fileCount, serializer);
+  }
+
+  @protected
+  void sse_encode_project_timeline_event(
+    ProjectTimelineEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.detail, serializer);
+    sse_encode_String(self.refId, serializer);
+    sse_encode_String(self.status, serializer);
+  }
+
+  @protected
+  void sse_encode_project_timeline_page(
+    ProjectTimelinePage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_project_timeline_event(self.events, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextCursor, serializer);
+  }
+
   @protected
   void sse_encode_project_type(ProjectType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -10384,6 +13971,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.description, serializer);
   }
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
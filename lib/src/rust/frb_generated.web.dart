@@ -19,6 +19,11 @@ import 'api/knowledge_api.dart';
 import 'api/llm_debug_api.dart';
 import 'api/mcp_api.dart';
 import 'api/project_api.dart';
+import 'api/project_bundle_api.dart';
+import 'api/project_git_api.dart';
+import 'api/project_index_api.dart';
+import 'api/project_template_api.dart';
+import 'api/project_timeline_api.dart';
 import 'api/providers_api.dart';
 import 'api/proxy_api.dart';
 import 'api/routes_api.dart';
@@ -59,6 +64,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AgentEvent dco_decode_agent_event(dynamic raw);
 
+  @protected
+  AgentUndoResult dco_decode_agent_undo_result(dynamic raw);
+
   @protected
   AgentWorkspaceDto dco_decode_agent_workspace_dto(dynamic raw);
 
@@ -83,6 +91,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw);
 
+  @protected
+  CronActiveWindowDto dco_decode_box_autoadd_cron_active_window_dto(
+    dynamic raw,
+  );
+
+  @protected
+  CronDeliveryDto dco_decode_box_autoadd_cron_delivery_dto(dynamic raw);
+
+  @protected
+  CronJobStatsDto dco_decode_box_autoadd_cron_job_stats_dto(dynamic raw);
+
+  @protected
+  CronRunOutput dco_decode_box_autoadd_cron_run_output(dynamic raw);
+
+  @protected
+  CronShellSandboxDto dco_decode_box_autoadd_cron_shell_sandbox_dto(
+    dynamic raw,
+  );
+
+  @protected
+  CronWebhookInfo dco_decode_box_autoadd_cron_webhook_info(dynamic raw);
+
   @protected
   DelegateAgentDto dco_decode_box_autoadd_delegate_agent_dto(dynamic raw);
 
@@ -95,6 +125,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
@@ -118,6 +151,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ProjectDto dco_decode_box_autoadd_project_dto(dynamic raw);
 
+  @protected
+  ProjectGitStatus dco_decode_box_autoadd_project_git_status(dynamic raw);
+
   @protected
   ProxyConfigDto dco_decode_box_autoadd_proxy_config_dto(dynamic raw);
 
@@ -136,6 +172,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ChatSessionInfo dco_decode_chat_session_info(dynamic raw);
 
+  @protected
+  CodeSearchHit dco_decode_code_search_hit(dynamic raw);
+
   @protected
   CopilotAuthStatus dco_decode_copilot_auth_status(dynamic raw);
 
@@ -148,18 +187,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CostConfigDto dco_decode_cost_config_dto(dynamic raw);
 
+  @protected
+  CronActiveWindowDto dco_decode_cron_active_window_dto(dynamic raw);
+
   @protected
   CronConfigDto dco_decode_cron_config_dto(dynamic raw);
 
+  @protected
+  CronDeliveryDto dco_decode_cron_delivery_dto(dynamic raw);
+
+  @protected
+  CronEnvVarDto dco_decode_cron_env_var_dto(dynamic raw);
+
+  @protected
+  CronExecutingJob dco_decode_cron_executing_job(dynamic raw);
+
+  @protected
+  CronImportChange dco_decode_cron_import_change(dynamic raw);
+
+  @protected
+  CronImportResult dco_decode_cron_import_result(dynamic raw);
+
   @protected
   CronJobDto dco_decode_cron_job_dto(dynamic raw);
 
+  @protected
+  CronJobStatsDto dco_decode_cron_job_stats_dto(dynamic raw);
+
   @protected
   CronNotification dco_decode_cron_notification(dynamic raw);
 
   @protected
   CronRunDto dco_decode_cron_run_dto(dynamic raw);
 
+  @protected
+  CronRunOutput dco_decode_cron_run_output(dynamic raw);
+
+  @protected
+  CronSchedulePreview dco_decode_cron_schedule_preview(dynamic raw);
+
+  @protected
+  CronSchedulerStatus dco_decode_cron_scheduler_status(dynamic raw);
+
+  @protected
+  CronShellSandboxDto dco_decode_cron_shell_sandbox_dto(dynamic raw);
+
+  @protected
+  CronWebhookInfo dco_decode_cron_webhook_info(dynamic raw);
+
   @protected
   DelegateAgentDto dco_decode_delegate_agent_dto(dynamic raw);
 
@@ -175,6 +250,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FeatureToggles dco_decode_feature_toggles(dynamic raw);
 
+  @protected
+  GitCommitDto dco_decode_git_commit_dto(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
@@ -207,6 +285,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<ChannelSummary> dco_decode_list_channel_summary(dynamic raw);
 
+  @protected
+  List<CodeSearchHit> dco_decode_list_code_search_hit(dynamic raw);
+
+  @protected
+  List<CronDeliveryDto> dco_decode_list_cron_delivery_dto(dynamic raw);
+
+  @protected
+  List<CronEnvVarDto> dco_decode_list_cron_env_var_dto(dynamic raw);
+
+  @protected
+  List<CronExecutingJob> dco_decode_list_cron_executing_job(dynamic raw);
+
+  @protected
+  List<CronImportChange> dco_decode_list_cron_import_change(dynamic raw);
+
   @protected
   List<CronJobDto> dco_decode_list_cron_job_dto(dynamic raw);
 
@@ -219,6 +312,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<EmbeddingRouteDto> dco_decode_list_embedding_route_dto(dynamic raw);
 
+  @protected
+  List<GitCommitDto> dco_decode_list_git_commit_dto(dynamic raw);
+
   @protected
   List<KeyValueDto> dco_decode_list_key_value_dto(dynamic raw);
 
@@ -247,12 +343,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<ModelRouteDto> dco_decode_list_model_route_dto(dynamic raw);
 
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
   List<ProjectSummary> dco_decode_list_project_summary(dynamic raw);
 
+  @protected
+  List<ProjectTemplateDto> dco_decode_list_project_template_dto(dynamic raw);
+
+  @protected
+  List<ProjectTimelineEvent> dco_decode_list_project_timeline_event(
+    dynamic raw,
+  );
+
   @protected
   List<ProviderInfo> dco_decode_list_provider_info(dynamic raw);
 
@@ -315,12 +422,29 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
+  @protected
+  CronActiveWindowDto? dco_decode_opt_box_autoadd_cron_active_window_dto(
+    dynamic raw,
+  );
+
+  @protected
+  CronJobStatsDto? dco_decode_opt_box_autoadd_cron_job_stats_dto(dynamic raw);
+
+  @protected
+  CronRunOutput? dco_decode_opt_box_autoadd_cron_run_output(dynamic raw);
+
+  @protected
+  CronWebhookInfo? dco_decode_opt_box_autoadd_cron_webhook_info(dynamic raw);
+
   @protected
   DelegateAgentDto? dco_decode_opt_box_autoadd_delegate_agent_dto(dynamic raw);
 
   @protected
   double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
@@ -333,6 +457,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ProjectDto? dco_decode_opt_box_autoadd_project_dto(dynamic raw);
 
+  @protected
+  ProjectGitStatus? dco_decode_opt_box_autoadd_project_git_status(dynamic raw);
+
   @protected
   SessionDetail? dco_decode_opt_box_autoadd_session_detail(dynamic raw);
 
@@ -342,15 +469,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  ProjectAutonomyDto dco_decode_project_autonomy_dto(dynamic raw);
+
+  @protected
+  ProjectCodeIndexStatus dco_decode_project_code_index_status(dynamic raw);
+
   @protected
   ProjectDto dco_decode_project_dto(dynamic raw);
 
+  @protected
+  ProjectGitStatus dco_decode_project_git_status(dynamic raw);
+
+  @protected
+  ProjectImportResult dco_decode_project_import_result(dynamic raw);
+
+  @protected
+  ProjectSessionReport dco_decode_project_session_report(dynamic raw);
+
   @protected
   ProjectStatus dco_decode_project_status(dynamic raw);
 
   @protected
   ProjectSummary dco_decode_project_summary(dynamic raw);
 
+  @protected
+  ProjectTemplateDto dco_decode_project_template_dto(dynamic raw);
+
+  @protected
+  ProjectTimelineEvent dco_decode_project_timeline_event(dynamic raw);
+
+  @protected
+  ProjectTimelinePage dco_decode_project_timeline_page(dynamic raw);
+
   @protected
   ProjectType dco_decode_project_type(dynamic raw);
 
@@ -402,6 +553,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ToolSpecDto dco_decode_tool_spec_dto(dynamic raw);
 
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -439,6 +593,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AgentEvent sse_decode_agent_event(SseDeserializer deserializer);
 
+  @protected
+  AgentUndoResult sse_decode_agent_undo_result(SseDeserializer deserializer);
+
   @protected
   AgentWorkspaceDto sse_decode_agent_workspace_dto(
     SseDeserializer deserializer,
@@ -469,6 +626,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  CronActiveWindowDto sse_decode_box_autoadd_cron_active_window_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronDeliveryDto sse_decode_box_autoadd_cron_delivery_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronJobStatsDto sse_decode_box_autoadd_cron_job_stats_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronRunOutput sse_decode_box_autoadd_cron_run_output(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronShellSandboxDto sse_decode_box_autoadd_cron_shell_sandbox_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronWebhookInfo sse_decode_box_autoadd_cron_webhook_info(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DelegateAgentDto sse_decode_box_autoadd_delegate_agent_dto(
     SseDeserializer deserializer,
@@ -487,6 +674,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
@@ -518,6 +708,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ProjectDto sse_decode_box_autoadd_project_dto(SseDeserializer deserializer);
 
+  @protected
+  ProjectGitStatus sse_decode_box_autoadd_project_git_status(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ProxyConfigDto sse_decode_box_autoadd_proxy_config_dto(
     SseDeserializer deserializer,
@@ -540,6 +735,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ChatSessionInfo sse_decode_chat_session_info(SseDeserializer deserializer);
 
+  @protected
+  CodeSearchHit sse_decode_code_search_hit(SseDeserializer deserializer);
+
   @protected
   CopilotAuthStatus sse_decode_copilot_auth_status(
     SseDeserializer deserializer,
@@ -558,18 +756,62 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CostConfigDto sse_decode_cost_config_dto(SseDeserializer deserializer);
 
+  @protected
+  CronActiveWindowDto sse_decode_cron_active_window_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   CronConfigDto sse_decode_cron_config_dto(SseDeserializer deserializer);
 
+  @protected
+  CronDeliveryDto sse_decode_cron_delivery_dto(SseDeserializer deserializer);
+
+  @protected
+  CronEnvVarDto sse_decode_cron_env_var_dto(SseDeserializer deserializer);
+
+  @protected
+  CronExecutingJob sse_decode_cron_executing_job(SseDeserializer deserializer);
+
+  @protected
+  CronImportChange sse_decode_cron_import_change(SseDeserializer deserializer);
+
+  @protected
+  CronImportResult sse_decode_cron_import_result(SseDeserializer deserializer);
+
   @protected
   CronJobDto sse_decode_cron_job_dto(SseDeserializer deserializer);
 
+  @protected
+  CronJobStatsDto sse_decode_cron_job_stats_dto(SseDeserializer deserializer);
+
   @protected
   CronNotification sse_decode_cron_notification(SseDeserializer deserializer);
 
   @protected
   CronRunDto sse_decode_cron_run_dto(SseDeserializer deserializer);
 
+  @protected
+  CronRunOutput sse_decode_cron_run_output(SseDeserializer deserializer);
+
+  @protected
+  CronSchedulePreview sse_decode_cron_schedule_preview(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronSchedulerStatus sse_decode_cron_scheduler_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronShellSandboxDto sse_decode_cron_shell_sandbox_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronWebhookInfo sse_decode_cron_webhook_info(SseDeserializer deserializer);
+
   @protected
   DelegateAgentDto sse_decode_delegate_agent_dto(SseDeserializer deserializer);
 
@@ -589,6 +831,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FeatureToggles sse_decode_feature_toggles(SseDeserializer deserializer);
 
+  @protected
+  GitCommitDto sse_decode_git_commit_dto(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
@@ -623,6 +868,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<CodeSearchHit> sse_decode_list_code_search_hit(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CronDeliveryDto> sse_decode_list_cron_delivery_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CronEnvVarDto> sse_decode_list_cron_env_var_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CronExecutingJob> sse_decode_list_cron_executing_job(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CronImportChange> sse_decode_list_cron_import_change(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<CronJobDto> sse_decode_list_cron_job_dto(SseDeserializer deserializer);
 
@@ -639,6 +909,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<GitCommitDto> sse_decode_list_git_commit_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<KeyValueDto> sse_decode_list_key_value_dto(SseDeserializer deserializer);
 
@@ -677,6 +952,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -685,6 +963,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<ProjectTemplateDto> sse_decode_list_project_template_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ProjectTimelineEvent> sse_decode_list_project_timeline_event(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ProviderInfo> sse_decode_list_provider_info(
     SseDeserializer deserializer,
@@ -765,6 +1053,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  CronActiveWindowDto? sse_decode_opt_box_autoadd_cron_active_window_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronJobStatsDto? sse_decode_opt_box_autoadd_cron_job_stats_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronRunOutput? sse_decode_opt_box_autoadd_cron_run_output(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CronWebhookInfo? sse_decode_opt_box_autoadd_cron_webhook_info(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DelegateAgentDto? sse_decode_opt_box_autoadd_delegate_agent_dto(
     SseDeserializer deserializer,
@@ -773,6 +1081,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
@@ -791,6 +1102,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ProjectGitStatus? sse_decode_opt_box_autoadd_project_git_status(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SessionDetail? sse_decode_opt_box_autoadd_session_detail(
     SseDeserializer deserializer,
@@ -802,15 +1118,53 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  ProjectAutonomyDto sse_decode_project_autonomy_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProjectCodeIndexStatus sse_decode_project_code_index_status(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ProjectDto sse_decode_project_dto(SseDeserializer deserializer);
 
+  @protected
+  ProjectGitStatus sse_decode_project_git_status(SseDeserializer deserializer);
+
+  @protected
+  ProjectImportResult sse_decode_project_import_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProjectSessionReport sse_decode_project_session_report(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ProjectStatus sse_decode_project_status(SseDeserializer deserializer);
 
   @protected
   ProjectSummary sse_decode_project_summary(SseDeserializer deserializer);
 
+  @protected
+  ProjectTemplateDto sse_decode_project_template_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProjectTimelineEvent sse_decode_project_timeline_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProjectTimelinePage sse_decode_project_timeline_page(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ProjectType sse_decode_project_type(SseDeserializer deserializer);
 
@@ -862,6 +1216,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ToolSpecDto sse_decode_tool_spec_dto(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -907,6 +1264,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_agent_event(AgentEvent self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_agent_undo_result(
+    AgentUndoResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_agent_workspace_dto(
     AgentWorkspaceDto self,
@@ -946,6 +1309,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_cron_active_window_dto(
+    CronActiveWindowDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cron_delivery_dto(
+    CronDeliveryDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cron_job_stats_dto(
+    CronJobStatsDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cron_run_output(
+    CronRunOutput self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cron_shell_sandbox_dto(
+    CronShellSandboxDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cron_webhook_info(
+    CronWebhookInfo self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_delegate_agent_dto(
     DelegateAgentDto self,
@@ -967,6 +1366,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_i_64(
     PlatformInt64 self,
@@ -1009,6 +1411,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_project_git_status(
+    ProjectGitStatus self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_proxy_config_dto(
     ProxyConfigDto self,
@@ -1039,6 +1447,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_code_search_hit(CodeSearchHit self, SseSerializer serializer);
+
   @protected
   void sse_encode_copilot_auth_status(
     CopilotAuthStatus self,
@@ -1060,12 +1471,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_cost_config_dto(CostConfigDto self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_cron_active_window_dto(
+    CronActiveWindowDto self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_cron_config_dto(CronConfigDto self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_cron_delivery_dto(
+    CronDeliveryDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cron_env_var_dto(
+    CronEnvVarDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cron_executing_job(
+    CronExecutingJob self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cron_import_change(
+    CronImportChange self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cron_import_result(
+    CronImportResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_cron_job_dto(CronJobDto self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_cron_job_stats_dto(
+    CronJobStatsDto self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_cron_notification(
     CronNotification self,
@@ -1075,6 +1528,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_cron_run_dto(CronRunDto self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_cron_run_output(CronRunOutput self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cron_schedule_preview(
+    CronSchedulePreview self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cron_scheduler_status(
+    CronSchedulerStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cron_shell_sandbox_dto(
+    CronShellSandboxDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cron_webhook_info(
+    CronWebhookInfo self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_delegate_agent_dto(
     DelegateAgentDto self,
@@ -1102,6 +1582,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_git_commit_dto(GitCommitDto self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
@@ -1147,6 +1630,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_code_search_hit(
+    List<CodeSearchHit> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_cron_delivery_dto(
+    List<CronDeliveryDto> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_cron_env_var_dto(
+    List<CronEnvVarDto> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_cron_executing_job(
+    List<CronExecutingJob> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_cron_import_change(
+    List<CronImportChange> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_cron_job_dto(
     List<CronJobDto> self,
@@ -1171,6 +1684,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_git_commit_dto(
+    List<GitCommitDto> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_key_value_dto(
     List<KeyValueDto> self,
@@ -1219,6 +1738,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -1231,6 +1756,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_project_template_dto(
+    List<ProjectTemplateDto> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_project_timeline_event(
+    List<ProjectTimelineEvent> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_provider_info(
     List<ProviderInfo> self,
@@ -1330,6 +1867,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_cron_active_window_dto(
+    CronActiveWindowDto? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_cron_job_stats_dto(
+    CronJobStatsDto? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_cron_run_output(
+    CronRunOutput? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_cron_webhook_info(
+    CronWebhookInfo? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_delegate_agent_dto(
     DelegateAgentDto? self,
@@ -1339,6 +1900,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_i_64(
     PlatformInt64? self,
@@ -1363,6 +1927,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_project_git_status(
+    ProjectGitStatus? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_session_detail(
     SessionDetail? self,
@@ -1375,9 +1945,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_project_autonomy_dto(
+    ProjectAutonomyDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_project_code_index_status(
+    ProjectCodeIndexStatus self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_project_dto(ProjectDto self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_project_git_status(
+    ProjectGitStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_project_import_result(
+    ProjectImportResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_project_session_report(
+    ProjectSessionReport self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_project_status(ProjectStatus self, SseSerializer serializer);
 
@@ -1387,6 +1987,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_project_template_dto(
+    ProjectTemplateDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_project_timeline_event(
+    ProjectTimelineEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_project_timeline_page(
+    ProjectTimelinePage self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_project_type(ProjectType self, SseSerializer serializer);
 
@@ -1456,6 +2074,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_tool_spec_dto(ToolSpecDto self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -136,6 +136,7 @@ class _ChatViewState extends ConsumerState<ChatView> {
 
   void _showToolApprovalDialog(ToolApprovalRequest request) {
     final l10n = AppLocalizations.of(context)!;
+    final reasonController = TextEditingController();
     showDialog(
       context: context,
       barrierDismissible: false,
@@ -180,6 +181,18 @@ class _ChatViewState extends ConsumerState<ChatView> {
                     ),
                   ),
                 ),
+                const SizedBox(height: 12),
+                TextField(
+                  controller: reasonController,
+                  maxLines: 2,
+                  minLines: 1,
+                  style: const TextStyle(fontSize: 13),
+                  decoration: InputDecoration(
+                    hintText: l10n.denyReasonHint,
+                    isDense: true,
+                    border: const OutlineInputBorder(),
+                  ),
+                ),
               ],
             ),
           ),
@@ -187,15 +200,23 @@ class _ChatViewState extends ConsumerState<ChatView> {
         actions: [
           TextButton(
             onPressed: () {
+              final reason = reasonController.text.trim();
               Navigator.of(ctx).pop();
-              agent_api.respondToToolApproval(decision: 'no');
+              agent_api.respondToToolApprovalById(
+                requestId: request.requestId,
+                decision: 'no',
+                reason: reason.isEmpty ? null : reason,
+              );
             },
             child: Text(l10n.deny, style: const TextStyle(color: Colors.red)),
           ),
           FilledButton(
             onPressed: () {
               Navigator.of(ctx).pop();
-              agent_api.respondToToolApproval(decision: 'yes');
+              agent_api.respondToToolApprovalById(
+                requestId: request.requestId,
+                decision: 'yes',
+              );
             },
             child: Text(l10n.approve),
           ),
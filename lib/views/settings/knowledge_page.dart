@@ -1,6 +1,7 @@
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:coraldesk/l10n/app_localizations.dart';
+import 'package:coraldesk/providers/providers.dart';
 import 'package:coraldesk/theme/app_theme.dart';
 import 'package:coraldesk/views/settings/widgets/settings_scaffold.dart';
 import 'package:coraldesk/views/settings/widgets/desktop_dialog.dart';
@@ -21,9 +22,14 @@ class _KnowledgePageState extends ConsumerState<KnowledgePage> {
   String? _message;
   String? _searchQuery;
   String _filterCategory = 'all';
+
+  /// Project whose memory is shown; empty = global memory
+  String _projectId = '';
   final TextEditingController _searchController = TextEditingController();
   CoralDeskColors get c => CoralDeskColors.of(context);
 
+  String? get _scopeProjectId => _projectId.isEmpty ? null : _projectId;
+
   @override
   void initState() {
     super.initState();
@@ -45,11 +51,13 @@ class _KnowledgePageState extends ConsumerState<KnowledgePage> {
         entries = await kb_api.searchKnowledge(
           query: _searchQuery!,
           limit: 100,
+          projectId: _scopeProjectId,
         );
       } else {
         entries = await kb_api.listKnowledgeEntries(
           category: _filterCategory == 'all' ? null : _filterCategory,
           limit: 200,
+          projectId: _scopeProjectId,
         );
       }
       if (mounted) {
@@ -101,6 +109,7 @@ class _KnowledgePageState extends ConsumerState<KnowledgePage> {
       key: key,
       content: content,
       category: category,
+      projectId: _scopeProjectId,
     );
     if (!mounted) return;
     if (res == 'ok') {
@@ -135,7 +144,10 @@ class _KnowledgePageState extends ConsumerState<KnowledgePage> {
     );
     if (confirmed != true) return;
 
-    final res = await kb_api.deleteKnowledgeEntry(key: key);
+    final res = await kb_api.deleteKnowledgeEntry(
+      key: key,
+      projectId: _scopeProjectId,
+    );
     if (!mounted) return;
     if (res == 'ok') {
       _showMessage(AppLocalizations.of(context)!.knowledgeEntryDeleted);
@@ -356,6 +368,8 @@ class _KnowledgePageState extends ConsumerState<KnowledgePage> {
 
   Widget _buildSearchAndActions() {
     final l10n = AppLocalizations.of(context)!;
+    final projects = ref.watch(projectsProvider);
+    final scope = projects.any((p) => p.id == _projectId) ? _projectId : '';
     return Row(
       children: [
         // Search bar
@@ -386,6 +400,46 @@ class _KnowledgePageState extends ConsumerState<KnowledgePage> {
           ),
         ),
         const SizedBox(width: 12),
+        // Memory scope: global or a project's own memory
+        Container(
+          height: 40,
+          constraints: const BoxConstraints(maxWidth: 200),
+          padding: const EdgeInsets.symmetric(horizontal: 12),
+          decoration: BoxDecoration(
+            color: c.inputBg,
+            borderRadius: BorderRadius.circular(8),
+            border: Border.all(color: c.inputBorder),
+          ),
+          child: DropdownButtonHideUnderline(
+            child: DropdownButton<String>(
+              value: scope,
+              isExpanded: true,
+              style: TextStyle(fontSize: 13, color: c.textPrimary),
+              dropdownColor: c.cardBg,
+              items: [
+                DropdownMenuItem(
+                  value: '',
+                  child: Text(l10n.knowledgeScopeGlobal),
+                ),
+                for (final p in projects)
+                  DropdownMenuItem(
+                    value: p.id,
+                    child: Text(
+                      '${p.icon} ${p.name}',
+                      overflow: TextOverflow.ellipsis,
+                    ),
+                  ),
+              ],
+              onChanged: (v) {
+                setState(() => _projectId = v ?? '');
+                _searchQuery = null;
+                _searchController.clear();
+                _loadAll();
+              },
+            ),
+          ),
+        ),
+        const SizedBox(width: 12),
         // Category filter
         Container(
           height: 40,
@@ -145,6 +145,9 @@ pub(crate) struct SessionAgent {
     pub(crate) last_used: Instant,
     /// Tracks which files were injected into allowed_roots for this session.
     pub(crate) injected_allowed_roots: Vec<String>,
+    /// Project block the agent has seen, in its system prompt or in a later
+    /// update note. `None` outside projects.
+    pub(crate) project_prompt: Option<String>,
 }
 
 /// Global configuration (shared across all sessions)
@@ -244,6 +247,51 @@ pub async fn cancel_generation(session_id: String) -> String {
 /// Pending approval request waiting for Flutter's response.
 struct PendingApproval {
     response_tx: tokio::sync::oneshot::Sender<zeroclaw::approval::ApprovalResponse>,
+    /// Session the tool call belongs to (used to route denial feedback)
+    session_id: String,
+    tool_name: String,
+    /// JSON-serialized tool arguments
+    tool_args: String,
+}
+
+/// A tool call the user denied together with an explanation.
+/// Kept until the turn ends so the model can't retry the same call blindly.
+struct DenialFeedback {
+    tool_name: String,
+    tool_args: String,
+    reason: String,
+}
+
+/// Map of session_id -> denial feedback of the session's running turn.
+type DenialFeedbackMap = HashMap<String, Vec<DenialFeedback>>;
+
+fn denial_feedback() -> &'static TokioMutex<DenialFeedbackMap> {
+    static FEEDBACK: OnceLock<TokioMutex<DenialFeedbackMap>> = OnceLock::new();
+    FEEDBACK.get_or_init(|| TokioMutex::new(HashMap::new()))
+}
+
+/// Map of session_id -> approval callback of the session's running streamed
+/// turn. Registered in trust-me mode too, for tools that always ask.
+type TurnApproversMap = HashMap<String, Arc<zeroclaw::agent::loop_::OnApprovalFn>>;
+
+fn turn_approvers() -> &'static TokioMutex<TurnApproversMap> {
+    static APPROVERS: OnceLock<TokioMutex<TurnApproversMap>> = OnceLock::new();
+    APPROVERS.get_or_init(|| TokioMutex::new(HashMap::new()))
+}
+
+/// Ask the user to approve a tool call from inside the tool, through the
+/// same dialog as the agent loop's approvals. Denied when the session has
+/// no streamed turn running to show it in.
+pub(crate) async fn request_tool_approval(
+    session_id: &str,
+    tool_name: &str,
+    tool_args: serde_json::Value,
+) -> zeroclaw::approval::ApprovalResponse {
+    let approver = turn_approvers().lock().await.get(session_id).cloned();
+    match approver {
+        Some(approver) => approver.as_ref()(tool_name.to_string(), tool_args).await,
+        None => zeroclaw::approval::ApprovalResponse::No,
+    }
 }
 
 /// Map of pending approval requests: request_id -> PendingApproval
@@ -301,35 +349,215 @@ pub async fn respond_to_tool_approval(decision: String) -> String {
 ///
 /// `request_id`: the unique ID sent with ToolApprovalRequest
 /// `decision` values: "yes", "no", "always"
-pub async fn respond_to_tool_approval_by_id(request_id: String, decision: String) -> String {
+/// `reason`: optional explanation for a denial (e.g. "don't touch prod, use
+/// the staging DB"). The model gets it as the denied call's result, and
+/// identical calls later in the same turn are denied with it without
+/// prompting again. Ignored for approvals.
+pub async fn respond_to_tool_approval_by_id(
+    request_id: String,
+    decision: String,
+    reason: Option<String>,
+) -> String {
+    let reason = reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
     let response = match decision.to_lowercase().as_str() {
         "yes" | "y" => zeroclaw::approval::ApprovalResponse::Yes,
         "always" | "a" => zeroclaw::approval::ApprovalResponse::Always,
-        _ => zeroclaw::approval::ApprovalResponse::No,
+        _ => match &reason {
+            Some(reason) => zeroclaw::approval::ApprovalResponse::DenyWithReason(reason.clone()),
+            None => zeroclaw::approval::ApprovalResponse::No,
+        },
     };
 
-    // Check legacy slot first
-    let mut legacy = legacy_pending_approval().lock().await;
-    if let Some((ref stored_id, _)) = *legacy {
-        if stored_id == &request_id {
-            if let Some((_, pending)) = legacy.take() {
-                let _ = pending.response_tx.send(response);
-                return "ok".into();
+    // Check legacy slot first, then the HashMap
+    let pending = {
+        let mut legacy = legacy_pending_approval().lock().await;
+        match legacy.take() {
+            Some((stored_id, pending)) if stored_id == request_id => Some(pending),
+            other => {
+                *legacy = other;
+                None
             }
         }
+    };
+    let pending = match pending {
+        Some(p) => p,
+        None => match pending_approvals().lock().await.remove(&request_id) {
+            Some(p) => p,
+            None => return format!("error: no pending approval request with id {request_id}"),
+        },
+    };
+
+    // Record the denial reason before releasing the agent loop
+    if let zeroclaw::approval::ApprovalResponse::DenyWithReason(reason) = &response {
+        let mut feedback = denial_feedback().lock().await;
+        feedback
+            .entry(pending.session_id.clone())
+            .or_default()
+            .push(DenialFeedback {
+                tool_name: pending.tool_name.clone(),
+                tool_args: pending.tool_args.clone(),
+                reason: reason.clone(),
+            });
     }
-    drop(legacy);
 
-    // Then check HashMap
-    let mut approvals = pending_approvals().lock().await;
-    if let Some(pending) = approvals.remove(&request_id) {
-        let _ = pending.response_tx.send(response);
-        "ok".into()
-    } else {
-        format!("error: no pending approval request with id {request_id}")
+    let _ = pending.response_tx.send(response);
+    "ok".into()
+}
+
+/// Find the reason the user gave when denying an identical tool call earlier
+/// in this turn. Used to auto-deny blind retries without prompting again.
+async fn repeated_denial_reason(
+    session_id: &str,
+    tool_name: &str,
+    tool_args: &str,
+) -> Option<String> {
+    let feedback = denial_feedback().lock().await;
+    feedback.get(session_id).and_then(|entries| {
+        entries
+            .iter()
+            .find(|f| f.tool_name == tool_name && f.tool_args == tool_args)
+            .map(|f| f.reason.clone())
+    })
+}
+
+/// Entries recalled for a message from a project session
+const PROJECT_RECALL_LIMIT: usize = 5;
+
+/// Agents in project sessions recall from the project's own memory. When the
+/// project allows it, global entries that fill up a recall the project memory
+/// couldn't are passed along as a note.
+async fn global_memory_note(session_id: &str, message: &str) -> Option<String> {
+    let project_id = super::project_api::get_session_project(session_id.to_string()).await?;
+    let project = super::project_api::project_info(&project_id).await?;
+    if !project.global_memory_fallback {
+        return None;
+    }
+    let entries =
+        super::knowledge_api::recall_for_project(&project_id, message, PROJECT_RECALL_LIMIT, true)
+            .await;
+    let lines = entries
+        .iter()
+        .filter(|e| e.project_id.is_empty())
+        .map(|e| format!("- {}: {}", e.key, truncate_str(&e.content, 500)))
+        .collect::<Vec<_>>();
+    if lines.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "[Possibly relevant entries from the global memory, outside this project:]\n{}",
+        lines.join("\n")
+    ))
+}
+
+/// When the session's project context changed since the agent last saw it
+/// (pinned context edited, session moved between projects), a note with the
+/// current block. Keeps the conversation instead of rebuilding the agent.
+async fn project_context_note(
+    session_id: &str,
+    agent_arc: &Arc<TokioMutex<SessionAgent>>,
+) -> Option<String> {
+    let project_id = super::project_api::get_session_project(session_id.to_string()).await;
+    let prompt = match project_id {
+        Some(id) => super::project_api::project_prompt_context(&id).await,
+        None => None,
+    };
+    let mut session_agent = agent_arc.lock().await;
+    if session_agent.project_prompt == prompt {
+        return None;
+    }
+    session_agent.project_prompt = prompt.clone();
+    Some(match prompt {
+        Some(block) => format!(
+            "[The project context has changed. It replaces the project context in your \
+             instructions:]\n{block}"
+        ),
+        None => "[This session no longer belongs to a project; disregard the project \
+                 context in your instructions.]"
+            .into(),
+    })
+}
+
+const PROJECT_PROMPT_BEGIN: &str = "<!-- coraldesk:project -->";
+const PROJECT_PROMPT_END: &str = "<!-- /coraldesk:project -->";
+
+/// Put the project block at the end of the workspace's SOUL.md, replacing
+/// the one from a previous build, or remove it when `block` is `None`
+fn write_project_prompt(workspace: &std::path::Path, block: Option<&str>) {
+    let soul_path = workspace.join("SOUL.md");
+    let existing = std::fs::read_to_string(&soul_path).unwrap_or_default();
+    let mut soul = match (
+        existing.find(PROJECT_PROMPT_BEGIN),
+        existing.find(PROJECT_PROMPT_END),
+    ) {
+        (Some(start), Some(end)) if end > start => format!(
+            "{}{}",
+            &existing[..start],
+            &existing[end + PROJECT_PROMPT_END.len()..]
+        ),
+        _ => existing.clone(),
+    };
+    if let Some(block) = block {
+        let base = soul.trim_end();
+        soul = if base.is_empty() {
+            format!("{PROJECT_PROMPT_BEGIN}\n{block}{PROJECT_PROMPT_END}\n")
+        } else {
+            format!("{base}\n\n{PROJECT_PROMPT_BEGIN}\n{block}{PROJECT_PROMPT_END}\n")
+        };
+    }
+    if soul != existing {
+        let _ = std::fs::write(&soul_path, soul);
     }
 }
 
+/// Identity files `resolve_workspace_config` writes into a role workspace
+const ROLE_WORKSPACE_COPIES: [&str; 5] = [
+    "SOUL.md",
+    "AGENTS.md",
+    "USER.md",
+    "IDENTITY.md",
+    "allowed_skills.json",
+];
+
+/// Mirror a role workspace into `dir` for a single session: the identity
+/// files are copied so they can be changed per session, everything else
+/// except the memory link is linked back to the role's workspace
+fn mirror_role_workspace(role_dir: &std::path::Path, dir: &std::path::Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for name in ROLE_WORKSPACE_COPIES {
+        let source = role_dir.join(name);
+        if source.is_file() {
+            std::fs::copy(&source, dir.join(name))?;
+        } else {
+            let _ = std::fs::remove_file(dir.join(name));
+        }
+    }
+    for entry in std::fs::read_dir(role_dir)?.flatten() {
+        let name = entry.file_name();
+        let link = dir.join(&name);
+        if name == "memory"
+            || ROLE_WORKSPACE_COPIES.iter().any(|c| name == *c)
+            || link.symlink_metadata().is_ok()
+        {
+            continue;
+        }
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(entry.path(), &link)?;
+        }
+        #[cfg(windows)]
+        {
+            if entry.path().is_dir() {
+                std::os::windows::fs::symlink_dir(entry.path(), &link)?;
+            } else {
+                std::os::windows::fs::symlink_file(entry.path(), &link)?;
+            }
+        }
+    }
+    Ok(())
+}
+
 // ──────────────────── Initialization API ──────────────────────
 
 /// Load default_profile_id from config file (custom field not in zeroclaw::Config)
@@ -1350,6 +1578,8 @@ pub async fn clear_session_agent(session_id: String) {
         let mut agent_guard = agent_arc.lock().await;
         agent_guard.agent.clear_history();
     }
+    drop(agents);
+    denial_feedback().lock().await.remove(&session_id);
 }
 
 /// Truncate a session agent's history to keep only the first N user turns.
@@ -1491,26 +1721,10 @@ fn resolve_delegate_providers(config: &mut zeroclaw::Config) {
 /// Helper: ensure agent exists for a session, creating if needed.
 /// Returns an Arc to the session's agent mutex for concurrent access.
 async fn ensure_session_agent(session_id: &str) -> Result<Arc<TokioMutex<SessionAgent>>, String> {
-    // 1. Read global config
-    let mut config = {
-        let gc = global_config().read().await;
-        match &gc.config {
-            Some(c) => c.clone(),
-            None => return Err("Runtime not initialized. Call init_runtime() first.".into()),
-        }
-    };
-
-    // 2. Check API key
-    let provider_name = config.default_provider.as_deref().unwrap_or("openrouter");
-    let needs_key = !matches!(provider_name, "ollama");
-    if needs_key && config.api_key.is_none() {
-        return Err("No API key configured. Please set your API key in Settings → Models.".into());
-    }
-
-    // 3. Get session attached files
+    // 1. Get session attached files
     let session_files = super::sessions_api::get_session_files(session_id.to_string()).await;
 
-    // 4. Check if agent exists and is up-to-date
+    // 2. Check if agent exists and is up-to-date
     {
         let agents = session_agents().read().await;
         if let Some(agent_arc) = agents.get(session_id) {
@@ -1526,7 +1740,7 @@ async fn ensure_session_agent(session_id: &str) -> Result<Arc<TokioMutex<Session
         }
     }
 
-    // 5. Need to create new agent — evict oldest if at capacity
+    // 3. Need to create new agent — evict oldest if at capacity
     evict_oldest_agent_if_needed().await;
 
     tracing::info!(
@@ -1534,77 +1748,199 @@ async fn ensure_session_agent(session_id: &str) -> Result<Arc<TokioMutex<Session
         session_files.len()
     );
 
-    // 6. Configure session-specific workspace
+    // 4. Build the session config and the agent
+    let config = build_session_config(session_id, &session_files, None).await?;
+    let project_id = super::project_api::get_session_project(session_id.to_string()).await;
+    let agent = build_agent(&config, project_id.as_deref(), Some(session_id)).await?;
+    let project_prompt = match &project_id {
+        Some(id) => super::project_api::project_prompt_context(id).await,
+        None => None,
+    };
+
+    let session_agent = SessionAgent {
+        agent,
+        last_used: Instant::now(),
+        injected_allowed_roots: session_files,
+        project_prompt,
+    };
+
+    let agent_arc = Arc::new(TokioMutex::new(session_agent));
+
+    // 5. Store in map
+    {
+        let mut agents = session_agents().write().await;
+        agents.insert(session_id.to_string(), agent_arc.clone());
+    }
+
+    Ok(agent_arc)
+}
+
+/// Build the agent config for a session: project directory and prompt,
+/// agent-workspace binding, shared memory, attached files, delegate
+/// providers and the multi-agent orchestrator SOUL.md.
+///
+/// `project_id` overrides the session's own project membership (used by
+/// cron jobs, which run in a workspace of their own).
+pub(crate) async fn build_session_config(
+    session_id: &str,
+    session_files: &[String],
+    project_id: Option<&str>,
+) -> Result<zeroclaw::Config, String> {
+    // 1. Read global config
+    let mut config = {
+        let gc = global_config().read().await;
+        match &gc.config {
+            Some(c) => c.clone(),
+            None => return Err("Runtime not initialized. Call init_runtime() first.".into()),
+        }
+    };
+
+    // 1a. Project sessions may use their own provider profile and model
+    let session_project_id = match project_id {
+        Some(id) => Some(id.to_string()),
+        None => super::project_api::get_session_project(session_id.to_string()).await,
+    };
+    let session_project = match &session_project_id {
+        Some(proj_id) => super::project_api::project_info(proj_id).await,
+        None => None,
+    };
+    if let Some(ref project) = session_project {
+        super::project_api::apply_model_overrides(&mut config, project);
+    }
+
+    // 2. Check API key
+    let provider_name = config.default_provider.as_deref().unwrap_or("openrouter");
+    let needs_key = !matches!(provider_name, "ollama");
+    if needs_key && config.api_key.is_none() {
+        return Err("No API key configured. Please set your API key in Settings → Models.".into());
+    }
+
+    // 3. Configure session-specific workspace
     // Check if this session is bound to an agent workspace
     let agent_binding = super::agent_workspace_api::get_binding_for_session(session_id).await;
 
-    // 6a. Check if this session belongs to a project and inject project context
-    let session_project_id = super::project_api::get_session_project(session_id.to_string()).await;
-    if let Some(ref proj_id) = session_project_id {
-        if let Some(project) = super::project_api::get_project(proj_id.clone()).await {
-            // Inject project directory into allowed_roots
-            if !project.project_dir.is_empty() {
-                let proj_dir = project.project_dir.clone();
-                if !config.autonomy.allowed_roots.contains(&proj_dir) {
-                    config.autonomy.allowed_roots.push(proj_dir);
-                }
+    // 3a. Inject the project directory into allowed_roots
+    if let Some(ref project) = session_project {
+        if !project.project_dir.is_empty() {
+            let proj_dir = project.project_dir.clone();
+            if !config.autonomy.allowed_roots.contains(&proj_dir) {
+                config.autonomy.allowed_roots.push(proj_dir);
             }
         }
     }
 
-    let session_workspace = if let Some(ref ws_id) = agent_binding {
+    let session_dir = dirs::home_dir()
+        .unwrap_or_default()
+        .join(".coraldesk")
+        .join("workspace")
+        .join("session")
+        .join(session_id);
+    // Whether the workspace belongs to this session alone; a role's own
+    // workspace is shared by all of its sessions
+    let (session_workspace, private_workspace) = if let Some(ref ws_id) = agent_binding {
         // Use agent workspace directory — independent identity/personality
         if let Err(e) =
             super::agent_workspace_api::resolve_workspace_config(&mut config, ws_id).await
         {
             tracing::warn!("Failed to resolve agent workspace {ws_id}: {e}");
             // Fall back to default session workspace
-            let fallback = dirs::home_dir()
+            let _ = std::fs::create_dir_all(&session_dir);
+            config.workspace_dir = session_dir.clone();
+            (session_dir, true)
+        } else if session_project.is_some() {
+            // A project session gets its own mirror of the role workspace,
+            // so the project block and memory link don't reach the role's
+            // other sessions
+            let role_dir = config.workspace_dir.clone();
+            let mirror = dirs::home_dir()
                 .unwrap_or_default()
                 .join(".coraldesk")
                 .join("workspace")
-                .join("session")
+                .join("role_sessions")
+                .join(ws_id)
                 .join(session_id);
-            let _ = std::fs::create_dir_all(&fallback);
-            config.workspace_dir = fallback.clone();
-            fallback
+            if let Err(e) = mirror_role_workspace(&role_dir, &mirror) {
+                tracing::warn!("Failed to mirror agent workspace {ws_id}: {e}");
+            }
+            let role_root = role_dir.to_string_lossy().to_string();
+            if !config.autonomy.allowed_roots.contains(&role_root) {
+                config.autonomy.allowed_roots.push(role_root);
+            }
+            config.workspace_dir = mirror.clone();
+            (mirror, true)
         } else {
-            config.workspace_dir.clone()
+            (config.workspace_dir.clone(), false)
         }
     } else {
         // Default: session-specific workspace
-        let ws = dirs::home_dir()
+        let _ = std::fs::create_dir_all(&session_dir);
+        config.workspace_dir = session_dir.clone();
+        (session_dir, true)
+    };
+
+    // 3c. Project autonomy overrides, after the role has filtered the tools
+    if let Some(ref project) = session_project {
+        super::project_api::apply_autonomy_overrides(&mut config, &project.autonomy);
+        super::project_api::narrow_enabled_tools(&mut config, &project.autonomy)
+            .map_err(|e| format!("Project \"{}\": {e}", project.name))?;
+        // The pinned context tool asks the user itself on every call, so the
+        // agent loop must not prompt a second time
+        let tool = super::project_api::CONTEXT_UPDATE_TOOL.to_string();
+        config.autonomy.always_ask.retain(|t| t != &tool);
+        if !config.autonomy.auto_approve.contains(&tool) {
+            config.autonomy.auto_approve.push(tool);
+        }
+    }
+
+    // Symlink the memory directory: the project's own memory for project
+    // sessions, the shared global memory otherwise. Project sessions always
+    // have a private workspace, so a shared one only ever links to the
+    // global memory.
+    let memory_dir = match &session_project_id {
+        Some(proj_id) => super::knowledge_api::project_memory_workspace(proj_id).join("memory"),
+        None => dirs::home_dir()
             .unwrap_or_default()
             .join(".coraldesk")
             .join("workspace")
-            .join("session")
-            .join(session_id);
-        let _ = std::fs::create_dir_all(&ws);
-        config.workspace_dir = ws.clone();
-        ws
+            .join("memory"),
     };
-
-    // Symlink shared memory directory
-    let global_memory_dir = dirs::home_dir()
-        .unwrap_or_default()
-        .join(".coraldesk")
-        .join("workspace")
-        .join("memory");
     let session_memory_link = session_workspace.join("memory");
+    // Re-point a link made before the session joined or left a project
+    if std::fs::read_link(&session_memory_link).is_ok_and(|target| target != memory_dir) {
+        #[cfg(unix)]
+        {
+            let _ = std::fs::remove_file(&session_memory_link);
+        }
+        #[cfg(windows)]
+        {
+            let _ = std::fs::remove_dir(&session_memory_link);
+        }
+    }
     if !session_memory_link.exists() {
-        let _ = std::fs::create_dir_all(&global_memory_dir);
+        let _ = std::fs::create_dir_all(&memory_dir);
         #[cfg(unix)]
         {
-            let _ = std::os::unix::fs::symlink(&global_memory_dir, &session_memory_link);
+            let _ = std::os::unix::fs::symlink(&memory_dir, &session_memory_link);
         }
         #[cfg(windows)]
         {
-            let _ = std::os::windows::fs::symlink_dir(&global_memory_dir, &session_memory_link);
+            let _ = std::os::windows::fs::symlink_dir(&memory_dir, &session_memory_link);
         }
     }
 
+    // 3b. Project block (description, tags, pinned context) in SOUL.md, so
+    //     the SystemPromptBuilder puts it in the system prompt. A role's
+    //     shared SOUL.md is rewritten from the role on every build.
+    if private_workspace {
+        let project_prompt = match &session_project_id {
+            Some(proj_id) => super::project_api::project_prompt_context(proj_id).await,
+            None => None,
+        };
+        write_project_prompt(&session_workspace, project_prompt.as_deref());
+    }
+
     // Inject session files into allowed_roots
-    for file_path in &session_files {
+    for file_path in session_files {
         let path_buf = std::path::PathBuf::from(file_path);
         if !config.autonomy.allowed_roots.contains(file_path) {
             config.autonomy.allowed_roots.push(file_path.clone());
@@ -1617,14 +1953,14 @@ async fn ensure_session_agent(session_id: &str) -> Result<Arc<TokioMutex<Session
         }
     }
 
-    // 7. Resolve delegate agent providers through model_providers profiles.
+    // 4. Resolve delegate agent providers through model_providers profiles.
     //    When a delegate agent's provider + api_key matches a model_providers
     //    profile that has a custom base_url, transform the provider to
     //    "custom:{base_url}" so the delegate hits the correct API endpoint
     //    instead of the provider's default URL (e.g. api.openai.com).
     resolve_delegate_providers(&mut config);
 
-    // 7a. In trust-me mode, allow all public domains for network-facing tools
+    // 4a. In trust-me mode, allow all public domains for network-facing tools
     // without mutating the persisted config. Private/local network guards still
     // apply inside zeroclaw's URL validation layer.
     if config.autonomy.trust_me {
@@ -1633,7 +1969,7 @@ async fn ensure_session_agent(session_id: &str) -> Result<Arc<TokioMutex<Session
         config.web_fetch.allowed_domains = vec!["*".to_string()];
     }
 
-    // 7b. Multi-agent mode: inject orchestrator identity when active.
+    // 4b. Multi-agent mode: inject orchestrator identity when active.
     //     This writes orchestrator instructions to the session workspace's
     //     SOUL.md file so the agent's SystemPromptBuilder picks them up,
     //     and augments delegate agent configs with the active roles.
@@ -1755,11 +2091,23 @@ async fn ensure_session_agent(session_id: &str) -> Result<Arc<TokioMutex<Session
         }
     }
 
-    // 8. Create the agent
-    let mut agent = zeroclaw::agent::Agent::from_config(&config)
+    Ok(config)
+}
+
+/// Create an agent from a prepared session config and attach MCP tools, plus
+/// the project's own tools when it runs for a project. The pinned context
+/// tool needs a chat session to ask for approval in, so runs without one
+/// (cron jobs) don't get it.
+pub(crate) async fn build_agent(
+    config: &zeroclaw::Config,
+    project_id: Option<&str>,
+    session_id: Option<&str>,
+) -> Result<zeroclaw::agent::Agent, String> {
+    // 1. Create the agent
+    let mut agent = zeroclaw::agent::Agent::from_config(config)
         .map_err(|e| format!("Failed to create agent: {e}"))?;
 
-    // 8a. Asynchronously connect MCP servers and inject their tools
+    // 2. Asynchronously connect MCP servers and inject their tools
     if config.mcp.enabled && !config.mcp.servers.is_empty() {
         tracing::info!(
             "DeskClaw: initializing MCP — {} server(s)",
@@ -1794,21 +2142,21 @@ async fn ensure_session_agent(session_id: &str) -> Result<Arc<TokioMutex<Session
         }
     }
 
-    let session_agent = SessionAgent {
-        agent,
-        last_used: Instant::now(),
-        injected_allowed_roots: session_files,
-    };
-
-    let agent_arc = Arc::new(TokioMutex::new(session_agent));
-
-    // 9. Store in map
-    {
-        let mut agents = session_agents().write().await;
-        agents.insert(session_id.to_string(), agent_arc.clone());
+    // 3. Project tools
+    if let Some(project_id) = project_id {
+        let mut tools = Vec::new();
+        if let Some(session_id) = session_id {
+            tools.push(super::project_api::project_context_tool(
+                project_id, session_id,
+            ));
+        }
+        if let Some(tool) = super::project_index_api::project_search_tool(project_id).await {
+            tools.push(tool);
+        }
+        agent.add_tools(tools);
     }
 
-    Ok(agent_arc)
+    Ok(agent)
 }
 
 /// Send a message to the zeroclaw agent and get response events.
@@ -1824,6 +2172,14 @@ pub async fn send_message(session_id: String, message: String) -> Vec<AgentEvent
     // Enrich message with session attached files context
     let enriched_message = {
         let files = super::sessions_api::get_session_files(session_id.clone()).await;
+        let message = match global_memory_note(&session_id, &message).await {
+            Some(note) => format!("{note}\n\n{message}"),
+            None => message,
+        };
+        let message = match project_context_note(&session_id, &agent_arc).await {
+            Some(note) => format!("{note}\n\n{message}"),
+            None => message,
+        };
         if files.is_empty() {
             message
         } else {
@@ -1845,6 +2201,11 @@ pub async fn send_message(session_id: String, message: String) -> Vec<AgentEvent
     let mut session_agent = agent_arc.lock().await;
     session_agent.last_used = Instant::now();
     let agent = &mut session_agent.agent;
+    let snapshot = if super::project_timeline_api::can_write_files(agent) {
+        super::project_git_api::snapshot_before_turn(&session_id).await
+    } else {
+        None
+    };
 
     let history_before = agent.history().len();
     let mut events = Vec::new();
@@ -1969,9 +2330,41 @@ pub async fn send_message(session_id: String, message: String) -> Vec<AgentEvent
         }
     }
 
+    let turn_messages = agent
+        .history()
+        .get(history_before..)
+        .unwrap_or_default()
+        .to_vec();
+    drop(session_agent);
+    record_turn_file_changes(&session_id, &turn_messages, snapshot).await;
+
     events
 }
 
+/// Add the project files an agent turn wrote to the project's timeline, and
+/// keep the pre-turn git snapshot when there is something to undo
+async fn record_turn_file_changes(
+    session_id: &str,
+    messages: &[zeroclaw::providers::ConversationMessage],
+    snapshot: Option<super::project_git_api::TurnSnapshot>,
+) {
+    if messages.is_empty() {
+        return;
+    }
+    if let Some(project_id) = super::project_api::get_session_project(session_id.to_string()).await
+    {
+        let paths = super::project_timeline_api::record_agent_file_changes(
+            &project_id,
+            session_id,
+            messages,
+        )
+        .await;
+        if let Some(snapshot) = snapshot {
+            super::project_git_api::keep_turn_snapshot(session_id, snapshot, &paths).await;
+        }
+    }
+}
+
 /// Streaming version: sends agent events in real-time through a StreamSink.
 ///
 /// Uses zeroclaw's `Agent::turn_streaming()` which delegates to the internal
@@ -2002,6 +2395,14 @@ pub async fn send_message_stream(
     // Enrich message with session attached files context
     let enriched_message = {
         let files = super::sessions_api::get_session_files(session_id.clone()).await;
+        let message = match global_memory_note(&session_id, &message).await {
+            Some(note) => format!("{note}\n\n{message}"),
+            None => message,
+        };
+        let message = match project_context_note(&session_id, &agent_arc).await {
+            Some(note) => format!("{note}\n\n{message}"),
+            None => message,
+        };
         if files.is_empty() {
             message
         } else {
@@ -2376,93 +2777,126 @@ pub async fn send_message_stream(
             .unwrap_or(false)
     };
 
-    // Build the approval callback. When trust_me is OFF, this callback sends
-    // a ToolApprovalRequest event to Flutter and waits for the user's decision
-    // via `respond_to_tool_approval()`.
+    // Build the approval callback. It sends a ToolApprovalRequest event to
+    // Flutter and waits for the user's decision via
+    // `respond_to_tool_approval()`. The agent loop only uses it when trust_me
+    // is OFF; tools that always ask reach it through `turn_approvers()`.
     let sink_for_approval = sink.clone();
     let session_id_for_approval = session_id.clone();
     let approval_activity_epoch = activity_epoch.clone();
     let approval_last_activity_ms = last_activity_ms.clone();
     let approval_awaiting_flag = awaiting_approval.clone();
-    let on_approval_fn: Option<zeroclaw::agent::loop_::OnApprovalFn> = if !trust_me {
-        Some(Box::new(
-            move |tool_name: String, tool_args: serde_json::Value| {
-                let sink_inner = sink_for_approval.clone();
-                let _session_id = session_id_for_approval.clone();
-                let activity_epoch = approval_activity_epoch.clone();
-                let last_activity_ms = approval_last_activity_ms.clone();
-                let awaiting_approval = approval_awaiting_flag.clone();
-                Box::pin(async move {
-                    let request_id = uuid::Uuid::new_v4().to_string();
-                    let args_str = serde_json::to_string(&tool_args).unwrap_or_default();
-
-                    // Send approval request to Flutter UI
-                    awaiting_approval.store(true, Ordering::Relaxed);
-                    mark_turn_activity(activity_epoch.as_ref(), last_activity_ms.as_ref());
-                    let _ = sink_inner.add(AgentEvent::ToolApprovalRequest {
-                        request_id: request_id.clone(),
-                        name: tool_name,
-                        args: args_str,
-                    });
+    let approver: Arc<zeroclaw::agent::loop_::OnApprovalFn> = Arc::new(Box::new(
+        move |tool_name: String, tool_args: serde_json::Value| {
+            let sink_inner = sink_for_approval.clone();
+            let session_id = session_id_for_approval.clone();
+            let activity_epoch = approval_activity_epoch.clone();
+            let last_activity_ms = approval_last_activity_ms.clone();
+            let awaiting_approval = approval_awaiting_flag.clone();
+            Box::pin(async move {
+                let request_id = uuid::Uuid::new_v4().to_string();
+                let args_str = serde_json::to_string(&tool_args).unwrap_or_default();
+
+                // The user already denied this exact call with a reason —
+                // don't prompt again for a blind retry.
+                if let Some(reason) =
+                    repeated_denial_reason(&session_id, &tool_name, &args_str).await
+                {
+                    tracing::info!(
+                        tool = %tool_name,
+                        "Auto-denying repeated tool call (previously denied: {reason})"
+                    );
+                    return zeroclaw::approval::ApprovalResponse::DenyWithReason(reason);
+                }
 
-                    // Create a oneshot channel
-                    let (resp_tx, resp_rx) = tokio::sync::oneshot::channel();
-
-                    // Store in legacy slot for FRB single-argument respond_to_tool_approval
-                    {
-                        let mut legacy = legacy_pending_approval().lock().await;
-                        *legacy = Some((
-                            request_id.clone(),
-                            PendingApproval {
-                                response_tx: resp_tx,
-                            },
-                        ));
-                    }
+                // Send approval request to Flutter UI
+                awaiting_approval.store(true, Ordering::Relaxed);
+                mark_turn_activity(activity_epoch.as_ref(), last_activity_ms.as_ref());
+                let _ = sink_inner.add(AgentEvent::ToolApprovalRequest {
+                    request_id: request_id.clone(),
+                    name: tool_name.clone(),
+                    args: args_str.clone(),
+                });
 
-                    // Wait for Flutter to respond (with a generous timeout)
-                    match tokio::time::timeout(Duration::from_secs(300), resp_rx).await {
-                        Ok(Ok(decision)) => {
-                            awaiting_approval.store(false, Ordering::Relaxed);
-                            mark_turn_activity(activity_epoch.as_ref(), last_activity_ms.as_ref());
-                            decision
-                        }
-                        Ok(Err(_)) => {
-                            awaiting_approval.store(false, Ordering::Relaxed);
-                            // Channel was dropped — treat as denied
-                            zeroclaw::approval::ApprovalResponse::No
-                        }
-                        Err(_) => {
-                            awaiting_approval.store(false, Ordering::Relaxed);
-                            // Timeout — clean up and treat as denied
-                            {
-                                let mut legacy = legacy_pending_approval().lock().await;
-                                *legacy = None;
-                            }
-                            tracing::warn!("Tool approval timed out for request {request_id}");
-                            zeroclaw::approval::ApprovalResponse::No
+                // Create a oneshot channel
+                let (resp_tx, resp_rx) = tokio::sync::oneshot::channel();
+
+                // Store in legacy slot for FRB single-argument respond_to_tool_approval
+                {
+                    let mut legacy = legacy_pending_approval().lock().await;
+                    *legacy = Some((
+                        request_id.clone(),
+                        PendingApproval {
+                            response_tx: resp_tx,
+                            session_id,
+                            tool_name,
+                            tool_args: args_str,
+                        },
+                    ));
+                }
+
+                // Wait for Flutter to respond (with a generous timeout)
+                match tokio::time::timeout(Duration::from_secs(300), resp_rx).await {
+                    Ok(Ok(decision)) => {
+                        awaiting_approval.store(false, Ordering::Relaxed);
+                        mark_turn_activity(activity_epoch.as_ref(), last_activity_ms.as_ref());
+                        decision
+                    }
+                    Ok(Err(_)) => {
+                        awaiting_approval.store(false, Ordering::Relaxed);
+                        // Channel was dropped — treat as denied
+                        zeroclaw::approval::ApprovalResponse::No
+                    }
+                    Err(_) => {
+                        awaiting_approval.store(false, Ordering::Relaxed);
+                        // Timeout — clean up and treat as denied
+                        {
+                            let mut legacy = legacy_pending_approval().lock().await;
+                            *legacy = None;
                         }
+                        tracing::warn!("Tool approval timed out for request {request_id}");
+                        zeroclaw::approval::ApprovalResponse::No
                     }
-                })
-            },
-        ))
-    } else {
-        None
-    };
+                }
+            })
+        },
+    ));
+    let on_approval_fn = (!trust_me).then(|| approver.clone());
 
     // Lock only this session's agent — other sessions remain unblocked
-    let turn_result = {
+    let (turn_result, turn_messages, snapshot) = {
         let mut session_agent = agent_arc.lock().await;
         session_agent.last_used = Instant::now();
         let agent = &mut session_agent.agent;
-        agent
+        let snapshot = if super::project_timeline_api::can_write_files(agent) {
+            super::project_git_api::snapshot_before_turn(&session_id).await
+        } else {
+            None
+        };
+        let history_before = agent.history().len();
+        turn_approvers()
+            .lock()
+            .await
+            .insert(session_id.clone(), approver);
+        let result = agent
             .turn_streaming(
                 &enriched_message,
                 tx,
                 Some(stream_cancel_token.clone()),
-                on_approval_fn.as_ref(),
+                on_approval_fn.as_deref(),
             )
-            .await
+            .await;
+        turn_approvers().lock().await.remove(&session_id);
+        // Denial reasons only steer retries within the turn they were given in
+        denial_feedback().lock().await.remove(&session_id);
+        let messages = agent
+            .history()
+            .get(history_before..)
+            .unwrap_or_default()
+            .to_vec();
+        (result, messages, snapshot)
     };
+    record_turn_file_changes(&session_id, &turn_messages, snapshot).await;
 
     watchdog_done_token.cancel();
     let _ = watchdog_handle.await;
@@ -2687,3 +3121,205 @@ fn truncate_str(s: &str, max_len: usize) -> String {
         s.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn denial_reason_denies_identical_retries() {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        pending_approvals().lock().await.insert(
+            "req-denial".into(),
+            PendingApproval {
+                response_tx: tx,
+                session_id: "session-denial".into(),
+                tool_name: "shell".into(),
+                tool_args: r#"{"command":"psql prod"}"#.into(),
+            },
+        );
+
+        let result = respond_to_tool_approval_by_id(
+            "req-denial".into(),
+            "no".into(),
+            Some("  use the staging DB ".into()),
+        )
+        .await;
+        assert_eq!(result, "ok");
+        assert!(matches!(
+            rx.await,
+            Ok(zeroclaw::approval::ApprovalResponse::DenyWithReason(r)) if r == "use the staging DB"
+        ));
+
+        let reason =
+            repeated_denial_reason("session-denial", "shell", r#"{"command":"psql prod"}"#).await;
+        assert_eq!(reason.as_deref(), Some("use the staging DB"));
+        assert!(
+            repeated_denial_reason("session-denial", "shell", r#"{"command":"ls"}"#)
+                .await
+                .is_none()
+        );
+        denial_feedback().lock().await.remove("session-denial");
+    }
+
+    /// Minimal OpenAI-compatible endpoint: the first completion asks for a
+    /// `shell` call, later ones answer in text. Keeps every request body so
+    /// tests can check what the model was sent.
+    async fn serve_scripted_completions(
+        listener: tokio::net::TcpListener,
+        requests: Arc<std::sync::Mutex<Vec<serde_json::Value>>>,
+    ) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut buf = Vec::new();
+            let mut chunk = [0u8; 4096];
+            let body_start = loop {
+                let Ok(n) = stream.read(&mut chunk).await else {
+                    break None;
+                };
+                if n == 0 {
+                    break None;
+                }
+                buf.extend_from_slice(&chunk[..n]);
+                if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break Some(pos + 4);
+                }
+            };
+            let Some(body_start) = body_start else {
+                continue;
+            };
+            let head = String::from_utf8_lossy(&buf[..body_start]).to_lowercase();
+            let content_length = head
+                .lines()
+                .find_map(|l| l.strip_prefix("content-length:"))
+                .and_then(|v| v.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            while buf.len() < body_start + content_length {
+                match stream.read(&mut chunk).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                }
+            }
+            let body = serde_json::from_slice(&buf[body_start..]).unwrap_or_default();
+            let first = {
+                let mut requests = requests.lock().unwrap();
+                requests.push(body);
+                requests.len() == 1
+            };
+
+            let message = if first {
+                serde_json::json!({
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call_1",
+                        "type": "function",
+                        "function": {
+                            "name": "shell",
+                            "arguments": r#"{"command":"psql prod"}"#,
+                        },
+                    }],
+                })
+            } else {
+                serde_json::json!({ "role": "assistant", "content": "Using staging instead." })
+            };
+            let response = serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "choices": [{ "index": 0, "message": message, "finish_reason": "stop" }],
+            })
+            .to_string();
+            let _ = stream
+                .write_all(
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                        response.len()
+                    )
+                    .as_bytes(),
+                )
+                .await;
+        }
+    }
+
+    #[tokio::test]
+    async fn denial_reason_reaches_the_model() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        tokio::spawn(serve_scripted_completions(listener, requests.clone()));
+
+        let workspace =
+            std::env::temp_dir().join(format!("coraldesk-denial-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&workspace).unwrap();
+        let mut config = zeroclaw::Config::default();
+        config.workspace_dir = workspace.clone();
+        config.default_provider = Some(format!("custom:{base_url}"));
+        config.default_model = Some("test-model".into());
+        config.api_key = Some("test".into());
+        config.memory.backend = "none".into();
+        config.autonomy.trust_me = false;
+        config.autonomy.always_ask = vec!["shell".into()];
+        let mut agent = zeroclaw::agent::Agent::from_config(&config).unwrap();
+
+        // Stand in for the Flutter dialog: deny through the public API
+        let on_approval: zeroclaw::agent::loop_::OnApprovalFn =
+            Box::new(|tool_name: String, tool_args: serde_json::Value| {
+                Box::pin(async move {
+                    let (tx, rx) = tokio::sync::oneshot::channel();
+                    pending_approvals().lock().await.insert(
+                        "req-history".into(),
+                        PendingApproval {
+                            response_tx: tx,
+                            session_id: "session-history".into(),
+                            tool_name,
+                            tool_args: serde_json::to_string(&tool_args).unwrap_or_default(),
+                        },
+                    );
+                    respond_to_tool_approval_by_id(
+                        "req-history".into(),
+                        "no".into(),
+                        Some("use the staging DB".into()),
+                    )
+                    .await;
+                    rx.await.unwrap_or(zeroclaw::approval::ApprovalResponse::No)
+                })
+            });
+
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(256);
+        tokio::spawn(async move { while rx.recv().await.is_some() {} });
+        agent
+            .turn_streaming("connect to the database", tx, None, Some(&on_approval))
+            .await
+            .unwrap();
+        denial_feedback().lock().await.remove("session-history");
+        let _ = std::fs::remove_dir_all(&workspace);
+
+        let denied = agent.history().iter().find_map(|m| match m {
+            zeroclaw::providers::ConversationMessage::ToolResults(results) => results
+                .iter()
+                .find(|r| r.tool_call_id == "call_1")
+                .map(|r| r.content.clone()),
+            _ => None,
+        });
+        assert!(
+            denied
+                .as_deref()
+                .is_some_and(|c| c.contains("use the staging DB")),
+            "denied tool result: {denied:?}"
+        );
+
+        // The follow-up completion must carry the reason back to the model
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        let tool_message = requests[1]["messages"]
+            .as_array()
+            .and_then(|messages| messages.iter().find(|m| m["role"] == "tool"))
+            .cloned()
+            .unwrap_or_default();
+        assert_eq!(tool_message["tool_call_id"], "call_1");
+        assert!(tool_message["content"]
+            .as_str()
+            .is_some_and(|c| c.contains("use the staging DB")));
+    }
+}
@@ -551,6 +551,57 @@ pub(crate) async fn get_workspace_identity(workspace_id: &str) -> Option<Workspa
         })
 }
 
+// ──────────────────── Project Bundles ────────────────────────
+
+/// Persisted form of the given workspaces, for project bundles
+pub(crate) async fn export_workspaces(workspace_ids: &[String]) -> Vec<serde_json::Value> {
+    let store = workspace_store().lock().await;
+    store
+        .workspaces
+        .iter()
+        .filter(|w| workspace_ids.contains(&w.id))
+        .filter_map(|w| serde_json::to_value(w).ok())
+        .collect()
+}
+
+/// Add workspaces from a project bundle. Workspaces that already exist here
+/// are kept as they are (other projects may use them); new ones get a fresh
+/// id rather than the bundle's. Returns the id each workspace is stored
+/// under, keyed by its id in the bundle.
+pub(crate) async fn import_workspaces(
+    workspaces: Vec<serde_json::Value>,
+) -> Result<std::collections::HashMap<String, String>, String> {
+    let mut ids = std::collections::HashMap::new();
+    let mut store = workspace_store().lock().await;
+    for value in workspaces {
+        let mut workspace: PersistedAgentWorkspace =
+            serde_json::from_value(value).map_err(|e| format!("invalid role: {e}"))?;
+        let original_id = workspace.id.clone();
+        if original_id.trim().is_empty() {
+            continue;
+        }
+        if store.workspaces.iter().any(|w| w.id == original_id) {
+            ids.insert(original_id.clone(), original_id);
+            continue;
+        }
+        workspace.id = uuid::Uuid::new_v4().to_string();
+        let ws_dir = agent_workspace_base_dir().join(&workspace.id);
+        std::fs::create_dir_all(&ws_dir)
+            .map_err(|e| format!("failed to create workspace dir: {e}"))?;
+        write_identity_file(&ws_dir, "SOUL.md", &workspace.soul_md);
+        write_identity_file(&ws_dir, "AGENTS.md", &workspace.agents_md);
+        write_identity_file(&ws_dir, "USER.md", &workspace.user_md);
+        write_identity_file(&ws_dir, "IDENTITY.md", &workspace.identity_md);
+        ids.insert(original_id, workspace.id.clone());
+        store.workspaces.push(workspace);
+    }
+    drop(store);
+    match persist_store().await.as_str() {
+        "ok" => Ok(ids),
+        err => Err(err.to_string()),
+    }
+}
+
 // ──────────────────── Helpers ─────────────────────────────────
 
 fn write_identity_file(dir: &std::path::Path, filename: &str, content: &str) {
@@ -1,5 +1,13 @@
+use chrono::TimeZone;
+use flutter_rust_bridge::frb;
 use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use tokio::sync::Mutex as TokioMutex;
+use tokio::task::JoinHandle;
 
 // ──────────────────────── DTOs ────────────────────────────
 
@@ -9,8 +17,10 @@ pub struct CronJobDto {
     pub id: String,
     pub name: String,
     pub expression: String,
-    pub schedule_type: String, // "cron", "at", "every"
+    pub schedule_type: String, // "cron", "at", "every", "watch" or "webhook"
     pub schedule_display: String,
+    /// IANA timezone the schedule is evaluated in (empty = system local time)
+    pub timezone: String,
     pub command: String,
     pub prompt: String,
     pub job_type: String,          // "shell" or "agent"
@@ -26,6 +36,115 @@ pub struct CronJobDto {
     pub last_run: Option<i64>,
     pub last_status: String,
     pub last_output: String,
+    /// What to do with runs missed while the app was closed:
+    /// "skip", "run_once" or "run_all"
+    pub misfire_policy: String,
+    /// Don't start a new run while the previous one is still executing
+    pub no_overlap: bool,
+    /// Kill the run after this many seconds (0 = no limit)
+    pub timeout_secs: u32,
+    /// Extra attempts after a failed run
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on each further retry
+    pub retry_backoff_secs: u32,
+    /// Job to run after this one succeeds (empty = none)
+    pub on_success: String,
+    /// Job to run after this one fails (empty = none)
+    pub on_failure: String,
+    /// Where run results are sent besides the desktop notification
+    pub delivery: Vec<CronDeliveryDto>,
+    /// When scheduled runs may start; None = the global window applies
+    pub active_window: Option<CronActiveWindowDto>,
+    /// Working directory, environment and resource limits (shell jobs)
+    pub sandbox: CronShellSandboxDto,
+}
+
+/// How a shell job's process is started
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CronShellSandboxDto {
+    /// Empty = the project's directory, or the workspace for global jobs
+    pub working_dir: String,
+    /// Pass the app's environment through; otherwise only PATH, HOME,
+    /// USER, LANG and a few other basics are kept
+    pub inherit_env: bool,
+    pub env: Vec<CronEnvVarDto>,
+    /// CPU time limit in seconds (0 = none; Unix only)
+    pub cpu_limit_secs: u64,
+    /// Address space limit in MB (0 = none; Unix only)
+    pub memory_limit_mb: u64,
+}
+
+impl Default for CronShellSandboxDto {
+    fn default() -> Self {
+        Self {
+            working_dir: String::new(),
+            inherit_env: true,
+            env: vec![],
+            cpu_limit_secs: 0,
+            memory_limit_mb: 0,
+        }
+    }
+}
+
+/// An environment variable for a shell job
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CronEnvVarDto {
+    pub name: String,
+    /// The value, or the name of a stored cron secret when `secret` is set
+    pub value: String,
+    pub secret: bool,
+}
+
+/// Limits on when scheduled runs may start. Runs that fall due outside the
+/// window are deferred until it opens. All fields empty = no limits.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CronActiveWindowDto {
+    /// Start and end of quiet hours as "HH:MM" in the job's timezone;
+    /// may wrap midnight (e.g. "22:00"–"07:00"). Empty = no quiet hours.
+    pub quiet_start: String,
+    pub quiet_end: String,
+    /// Days runs may start on, 1 = Monday … 7 = Sunday. Empty = every day.
+    pub active_days: Vec<u8>,
+    /// Local .ics file whose events are treated as holidays. Empty = none.
+    pub holidays_ics: String,
+}
+
+/// A destination for a job's run results
+#[derive(Debug, Clone)]
+pub struct CronDeliveryDto {
+    /// "telegram", "slack", "discord", "email" or "webhook"
+    pub channel: String,
+    /// Chat id, channel id, email address or URL, depending on `channel`.
+    /// May be empty for Slack to use the configured default channel.
+    pub to: String,
+    pub on_success: bool,
+    pub on_failure: bool,
+    /// Message body; supports {{job}}, {{status}}, {{icon}}, {{output}},
+    /// {{prompt}}, {{duration}} and {{finished_at}}. Empty = default template.
+    pub template: String,
+}
+
+/// Health summary of a job's recent runs
+#[derive(Debug, Clone)]
+pub struct CronJobStatsDto {
+    pub job_id: String,
+    /// Runs in the window; a run that succeeded after retries counts once
+    pub total_runs: u32,
+    pub succeeded: u32,
+    pub failed: u32,
+    /// succeeded / total_runs, 0.0 when there were no runs
+    pub success_rate: f64,
+    /// Median attempt duration in milliseconds
+    pub p50_duration_ms: i64,
+    /// 95th percentile attempt duration in milliseconds
+    pub p95_duration_ms: i64,
+    /// Failed runs in a row since the last success
+    pub consecutive_failures: u32,
+    /// UTC epoch seconds of the last successful run in the kept history
+    pub last_success: Option<i64>,
 }
 
 /// Cron run history entry
@@ -38,6 +157,94 @@ pub struct CronRunDto {
     pub status: String,
     pub output: String,
     pub duration_ms: i64,
+    /// 1 for the first try, incremented on each retry
+    pub attempt: u32,
+    /// Process exit code (shell jobs only; None if killed or not started)
+    pub exit_code: Option<i32>,
+    /// Size of the full captured stdout / stderr in bytes
+    pub stdout_len: u64,
+    pub stderr_len: u64,
+    /// Set when a stream outgrew the capture limit and its end was dropped
+    pub stdout_truncated: bool,
+    pub stderr_truncated: bool,
+    /// Shared by every run triggered from the same root run via on_success/on_failure
+    pub chain_id: String,
+}
+
+/// A page of a run's captured output
+#[derive(Debug, Clone)]
+pub struct CronRunOutput {
+    pub run_id: i64,
+    pub stdout: String,
+    pub stderr: String,
+    /// Total sizes in bytes, for paging
+    pub stdout_len: u64,
+    pub stderr_len: u64,
+    /// Set when a stream outgrew the capture limit and its end was dropped
+    pub stdout_truncated: bool,
+    pub stderr_truncated: bool,
+    pub exit_code: Option<i32>,
+}
+
+/// Result of previewing a cron expression
+#[derive(Debug, Clone)]
+pub struct CronSchedulePreview {
+    /// Empty when the expression and timezone are valid
+    pub error: String,
+    /// Upcoming fire times (UTC epoch seconds)
+    pub fire_times: Vec<i64>,
+}
+
+/// A job run currently in flight
+#[derive(Debug, Clone)]
+pub struct CronExecutingJob {
+    pub job_id: String,
+    pub job_name: String,
+    /// Start of the earliest in-flight run (epoch seconds)
+    pub started_at: i64,
+    /// Number of in-flight runs (> 1 only when overlap is allowed)
+    pub runs: u32,
+}
+
+/// Live state of the background scheduler
+#[derive(Debug, Clone)]
+pub struct CronSchedulerStatus {
+    /// Persisted on/off switch
+    pub enabled: bool,
+    /// Whether the polling loop is alive
+    pub running: bool,
+    /// Time of the last scheduler pass (epoch seconds)
+    pub last_tick: Option<i64>,
+    pub executing: Vec<CronExecutingJob>,
+}
+
+/// Planned (dry run) or applied change to one job in an import
+#[derive(Debug, Clone)]
+pub struct CronImportChange {
+    pub name: String,
+    /// "create", "update" or "unchanged"
+    pub action: String,
+    /// Fields that differ from the existing job (updates only)
+    pub changed_fields: Vec<String>,
+}
+
+/// Outcome of importing a cron file
+#[derive(Debug, Clone)]
+pub struct CronImportResult {
+    /// Validation errors; nothing is written when non-empty
+    pub errors: Vec<String>,
+    /// False for dry runs and failed imports
+    pub applied: bool,
+    pub changes: Vec<CronImportChange>,
+}
+
+/// Endpoint for triggering a webhook job
+#[derive(Debug, Clone)]
+pub struct CronWebhookInfo {
+    /// Local URL to POST to
+    pub url: String,
+    /// Sent as the `X-Cron-Secret` header (or `?secret=` query parameter)
+    pub secret: String,
 }
 
 /// Cron system configuration
@@ -45,6 +252,10 @@ pub struct CronRunDto {
 pub struct CronConfigDto {
     pub enabled: bool,
     pub max_run_history: u32,
+    /// Maximum number of jobs the scheduler runs at the same time
+    pub max_concurrency: u32,
+    /// Disable a job after this many failed runs in a row (0 = never)
+    pub auto_disable_after: u32,
     pub total_jobs: u32,
     pub active_jobs: u32,
     pub paused_jobs: u32,
@@ -57,6 +268,10 @@ fn db_path() -> PathBuf {
     state_dir.join("workspace").join("cron").join("jobs.db")
 }
 
+/// Schema version recorded in the database's `user_version` once `migrate`
+/// has run. Bump it when adding a migration so existing databases pick it up.
+const SCHEMA_VERSION: i64 = 2;
+
 fn open_db() -> Result<Connection, String> {
     let path = db_path();
     if !path.exists() {
@@ -68,6 +283,21 @@ fn open_db() -> Result<Connection, String> {
     let conn = Connection::open(&path).map_err(|e| e.to_string())?;
     conn.execute_batch("PRAGMA foreign_keys = ON;")
         .map_err(|e| e.to_string())?;
+    let version = conn
+        .query_row("PRAGMA user_version", [], |row| row.get::<_, i64>(0))
+        .map_err(|e| e.to_string())?;
+    if version < SCHEMA_VERSION {
+        migrate(&conn)?;
+        conn.execute_batch(&format!("PRAGMA user_version = {SCHEMA_VERSION};"))
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(conn)
+}
+
+/// Create the tables and bring a database from an earlier version up to
+/// date. Every step is idempotent, so databases created before
+/// `SCHEMA_VERSION` existed go through all of them once.
+fn migrate(conn: &Connection) -> Result<(), String> {
     // Ensure tables exist
     conn.execute_batch(
         "CREATE TABLE IF NOT EXISTS cron_jobs (
@@ -114,7 +344,146 @@ fn open_db() -> Result<Connection, String> {
         [],
     );
 
-    Ok(conn)
+    // Migration: misfire policy and overlap prevention
+    let _ = conn.execute(
+        "ALTER TABLE cron_jobs ADD COLUMN misfire_policy TEXT DEFAULT 'run_once'",
+        [],
+    );
+    let _ = conn.execute(
+        "ALTER TABLE cron_jobs ADD COLUMN no_overlap INTEGER DEFAULT 1",
+        [],
+    );
+
+    // Migration: timeouts and retries. Jobs from before the migration keep
+    // running without a limit; new jobs get DEFAULT_TIMEOUT_SECS.
+    let _ = conn.execute(
+        "ALTER TABLE cron_jobs ADD COLUMN timeout_secs INTEGER DEFAULT 0",
+        [],
+    );
+    let _ = conn.execute(
+        "ALTER TABLE cron_jobs ADD COLUMN max_retries INTEGER DEFAULT 0",
+        [],
+    );
+    let _ = conn.execute(
+        "ALTER TABLE cron_jobs ADD COLUMN retry_backoff_secs INTEGER DEFAULT 30",
+        [],
+    );
+    let _ = conn.execute(
+        "ALTER TABLE cron_runs ADD COLUMN attempt INTEGER DEFAULT 1",
+        [],
+    );
+
+    // Migration: full output capture
+    let _ = conn.execute("ALTER TABLE cron_runs ADD COLUMN stdout TEXT", []);
+    let _ = conn.execute("ALTER TABLE cron_runs ADD COLUMN stderr TEXT", []);
+    let _ = conn.execute("ALTER TABLE cron_runs ADD COLUMN exit_code INTEGER", []);
+
+    // Migration: job chaining
+    let _ = conn.execute(
+        "ALTER TABLE cron_jobs ADD COLUMN on_success_job_id TEXT DEFAULT ''",
+        [],
+    );
+    let _ = conn.execute(
+        "ALTER TABLE cron_jobs ADD COLUMN on_failure_job_id TEXT DEFAULT ''",
+        [],
+    );
+    let _ = conn.execute("ALTER TABLE cron_runs ADD COLUMN chain_id TEXT", []);
+
+    // Migration: per-job quiet hours / active days / holidays
+    let _ = conn.execute("ALTER TABLE cron_jobs ADD COLUMN active_window TEXT", []);
+
+    // Migration: marks jobs paused because their project was archived
+    let _ = conn.execute(
+        "ALTER TABLE cron_jobs ADD COLUMN paused_by_project INTEGER DEFAULT 0",
+        [],
+    );
+
+    // Migration: failed runs in a row, for auto-disabling unhealthy jobs
+    let _ = conn.execute(
+        "ALTER TABLE cron_jobs ADD COLUMN consecutive_failures INTEGER DEFAULT 0",
+        [],
+    );
+
+    // Migration: shell job working dir / env / resource limits
+    let _ = conn.execute("ALTER TABLE cron_jobs ADD COLUMN sandbox TEXT", []);
+
+    // Migration: marks output cut off at MAX_CAPTURED_OUTPUT_BYTES
+    let _ = conn.execute(
+        "ALTER TABLE cron_runs ADD COLUMN stdout_truncated INTEGER DEFAULT 0",
+        [],
+    );
+    let _ = conn.execute(
+        "ALTER TABLE cron_runs ADD COLUMN stderr_truncated INTEGER DEFAULT 0",
+        [],
+    );
+
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS cron_settings (
+            key   TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS cron_secrets (
+            name  TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );",
+    )
+    .map_err(|e| e.to_string())?;
+
+    // Migration: encrypt secrets stored in plaintext by earlier versions
+    encrypt_plaintext_secrets(conn);
+
+    Ok(())
+}
+
+fn get_setting(conn: &Connection, key: &str) -> Option<String> {
+    conn.query_row(
+        "SELECT value FROM cron_settings WHERE key = ?1",
+        params![key],
+        |row| row.get(0),
+    )
+    .ok()
+}
+
+fn set_setting(conn: &Connection, key: &str, value: &str) -> Result<(), String> {
+    conn.execute(
+        "INSERT INTO cron_settings (key, value) VALUES (?1, ?2) \
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        params![key, value],
+    )
+    .map(|_| ())
+    .map_err(|e| e.to_string())
+}
+
+fn scheduler_enabled(conn: &Connection) -> bool {
+    get_setting(conn, "enabled").as_deref() != Some("false")
+}
+
+fn max_run_history(conn: &Connection) -> u32 {
+    get_setting(conn, "max_run_history")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MAX_RUN_HISTORY)
+}
+
+/// Keep only the newest `max_run_history` runs of a job
+fn prune_run_history(conn: &Connection, job_id: &str) {
+    let keep = max_run_history(conn);
+    let _ = conn.execute(
+        "DELETE FROM cron_runs WHERE job_id = ?1 AND id NOT IN \
+         (SELECT id FROM cron_runs WHERE job_id = ?1 ORDER BY id DESC LIMIT ?2)",
+        params![job_id, keep],
+    );
+}
+
+fn max_concurrency(conn: &Connection) -> u32 {
+    get_setting(conn, "max_concurrency")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MAX_CONCURRENCY)
+}
+
+fn auto_disable_after(conn: &Connection) -> u32 {
+    get_setting(conn, "auto_disable_after")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0)
 }
 
 fn parse_rfc3339_to_ts(s: &str) -> i64 {
@@ -148,6 +517,14 @@ fn decode_schedule_info(schedule_json: Option<String>, expression: &str) -> (Str
                     let ms = val.get("every_ms").and_then(|v| v.as_u64()).unwrap_or(0);
                     return ("every".into(), format_interval(ms));
                 }
+                Some("watch") => {
+                    let path = val
+                        .get("path")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or(expression);
+                    return ("watch".into(), format!("监听 {path}"));
+                }
+                Some("webhook") => return ("webhook".into(), "Webhook 触发".into()),
                 _ => {}
             }
         }
@@ -156,15 +533,67 @@ fn decode_schedule_info(schedule_json: Option<String>, expression: &str) -> (Str
     ("cron".into(), expression.to_string())
 }
 
+fn decode_schedule_timezone(schedule_json: Option<&str>) -> String {
+    schedule_json
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok())
+        .and_then(|val| val.get("tz").and_then(|v| v.as_str()).map(String::from))
+        .unwrap_or_default()
+}
+
+/// Column list matching the indices read by `row_to_dto`
+const JOB_COLUMNS: &str = "id, expression, command, schedule, job_type, prompt, name, \
+     session_target, model, enabled, delivery, delete_after_run, \
+     created_at, next_run, last_run, last_status, last_output, target_session_id, \
+     project_id, misfire_policy, no_overlap, timeout_secs, max_retries, retry_backoff_secs, \
+     on_success_job_id, on_failure_job_id, active_window, sandbox";
+
+fn load_job(conn: &Connection, job_id: &str) -> Result<CronJobDto, rusqlite::Error> {
+    let sql = format!("SELECT {JOB_COLUMNS} FROM cron_jobs WHERE id = ?1");
+    conn.query_row(&sql, params![job_id], row_to_dto)
+}
+
+/// Column list matching the indices read by `run_row_to_dto`
+const RUN_COLUMNS: &str = "id, job_id, started_at, finished_at, status, output, duration_ms, \
+     attempt, exit_code, COALESCE(LENGTH(CAST(stdout AS BLOB)), 0), \
+     COALESCE(LENGTH(CAST(stderr AS BLOB)), 0), chain_id, stdout_truncated, stderr_truncated";
+
+fn run_row_to_dto(row: &rusqlite::Row<'_>) -> Result<CronRunDto, rusqlite::Error> {
+    let started: String = row.get(2)?;
+    let finished: String = row.get(3)?;
+    Ok(CronRunDto {
+        id: row.get(0)?,
+        job_id: row.get(1)?,
+        started_at: parse_rfc3339_to_ts(&started),
+        finished_at: parse_rfc3339_to_ts(&finished),
+        status: row.get(4)?,
+        output: row.get::<_, Option<String>>(5)?.unwrap_or_default(),
+        duration_ms: row.get::<_, Option<i64>>(6)?.unwrap_or(0),
+        attempt: row.get::<_, Option<u32>>(7)?.unwrap_or(1),
+        exit_code: row.get(8)?,
+        stdout_len: row.get::<_, i64>(9)? as u64,
+        stderr_len: row.get::<_, i64>(10)? as u64,
+        chain_id: row.get::<_, Option<String>>(11)?.unwrap_or_default(),
+        stdout_truncated: row.get::<_, Option<bool>>(12)?.unwrap_or(false),
+        stderr_truncated: row.get::<_, Option<bool>>(13)?.unwrap_or(false),
+    })
+}
+
 fn row_to_dto(row: &rusqlite::Row<'_>) -> Result<CronJobDto, rusqlite::Error> {
     let expression: String = row.get(1)?;
     let schedule_json: Option<String> = row.get(3)?;
+    let timezone = decode_schedule_timezone(schedule_json.as_deref());
     let (schedule_type, schedule_display) = decode_schedule_info(schedule_json, &expression);
     let enabled_int: i32 = row.get(9)?;
     let delete_after_run_int: i32 = row.get(11)?;
     let created_at_str: String = row.get(12)?;
     let next_run_str: String = row.get(13)?;
     let last_run_str: Option<String> = row.get(14)?;
+    // Event-triggered jobs have no upcoming run time
+    let next_run = if is_event_trigger(&schedule_type) {
+        0
+    } else {
+        parse_rfc3339_to_ts(&next_run_str)
+    };
 
     Ok(CronJobDto {
         id: row.get(0)?,
@@ -172,6 +601,7 @@ fn row_to_dto(row: &rusqlite::Row<'_>) -> Result<CronJobDto, rusqlite::Error> {
         expression,
         schedule_type,
         schedule_display,
+        timezone,
         command: row.get(2)?,
         prompt: row.get::<_, Option<String>>(5)?.unwrap_or_default(),
         job_type: row
@@ -184,12 +614,29 @@ fn row_to_dto(row: &rusqlite::Row<'_>) -> Result<CronJobDto, rusqlite::Error> {
         enabled: enabled_int != 0,
         delete_after_run: delete_after_run_int != 0,
         created_at: parse_rfc3339_to_ts(&created_at_str),
-        next_run: parse_rfc3339_to_ts(&next_run_str),
+        next_run,
         last_run: last_run_str.map(|s| parse_rfc3339_to_ts(&s)),
         last_status: row.get::<_, Option<String>>(15)?.unwrap_or_default(),
         last_output: row.get::<_, Option<String>>(16)?.unwrap_or_default(),
         target_session_id: row.get::<_, Option<String>>(17)?.unwrap_or_default(),
         project_id: row.get::<_, Option<String>>(18)?.unwrap_or_default(),
+        misfire_policy: row
+            .get::<_, Option<String>>(19)?
+            .unwrap_or_else(|| "run_once".into()),
+        no_overlap: row.get::<_, Option<i32>>(20)?.unwrap_or(1) != 0,
+        timeout_secs: row.get::<_, Option<u32>>(21)?.unwrap_or(0),
+        max_retries: row.get::<_, Option<u32>>(22)?.unwrap_or(0),
+        retry_backoff_secs: row.get::<_, Option<u32>>(23)?.unwrap_or(30),
+        on_success: row.get::<_, Option<String>>(24)?.unwrap_or_default(),
+        on_failure: row.get::<_, Option<String>>(25)?.unwrap_or_default(),
+        delivery: parse_delivery(row.get::<_, Option<String>>(10)?.as_deref()),
+        active_window: row
+            .get::<_, Option<String>>(26)?
+            .and_then(|json| serde_json::from_str(&json).ok()),
+        sandbox: row
+            .get::<_, Option<String>>(27)?
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default(),
     })
 }
 
@@ -202,7 +649,9 @@ pub fn get_cron_config() -> CronConfigDto {
         Err(_) => {
             return CronConfigDto {
                 enabled: true,
-                max_run_history: 50,
+                max_run_history: DEFAULT_MAX_RUN_HISTORY,
+                max_concurrency: DEFAULT_MAX_CONCURRENCY,
+                auto_disable_after: 0,
                 total_jobs: 0,
                 active_jobs: 0,
                 paused_jobs: 0,
@@ -222,8 +671,10 @@ pub fn get_cron_config() -> CronConfigDto {
         .unwrap_or(0);
 
     CronConfigDto {
-        enabled: true,
-        max_run_history: 50,
+        enabled: scheduler_enabled(&conn),
+        max_run_history: max_run_history(&conn),
+        max_concurrency: max_concurrency(&conn),
+        auto_disable_after: auto_disable_after(&conn),
         total_jobs: total,
         active_jobs: active,
         paused_jobs: total - active,
@@ -240,13 +691,9 @@ pub fn list_cron_jobs() -> Vec<CronJobDto> {
         }
     };
 
-    let sql = "SELECT id, expression, command, schedule, job_type, prompt, name, \
-               session_target, model, enabled, delivery, delete_after_run, \
-               created_at, next_run, last_run, last_status, last_output, target_session_id, \
-               project_id \
-               FROM cron_jobs ORDER BY next_run ASC";
+    let sql = format!("SELECT {JOB_COLUMNS} FROM cron_jobs ORDER BY next_run ASC");
 
-    let mut stmt = match conn.prepare(sql) {
+    let mut stmt = match conn.prepare(&sql) {
         Ok(s) => s,
         Err(e) => {
             tracing::error!("Failed to prepare list query: {e}");
@@ -254,7 +701,7 @@ pub fn list_cron_jobs() -> Vec<CronJobDto> {
         }
     };
 
-    let rows = stmt.query_map([], |row| row_to_dto(row));
+    let rows = stmt.query_map([], row_to_dto);
     match rows {
         Ok(mapped) => mapped.filter_map(|r| r.ok()).collect(),
         Err(e) => {
@@ -264,13 +711,58 @@ pub fn list_cron_jobs() -> Vec<CronJobDto> {
     }
 }
 
-/// Add a new shell cron job
+/// List the cron jobs that belong to a project
+pub fn list_cron_jobs_for_project(project_id: String) -> Vec<CronJobDto> {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => {
+            tracing::error!("Failed to open cron db: {e}");
+            return vec![];
+        }
+    };
+
+    let sql =
+        format!("SELECT {JOB_COLUMNS} FROM cron_jobs WHERE project_id = ?1 ORDER BY next_run ASC");
+    let mut stmt = match conn.prepare(&sql) {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!("Failed to prepare list query: {e}");
+            return vec![];
+        }
+    };
+    let rows = stmt.query_map(params![project_id], row_to_dto);
+    match rows {
+        Ok(mapped) => mapped.filter_map(|r| r.ok()).collect(),
+        Err(e) => {
+            tracing::error!("Failed to list project cron jobs: {e}");
+            vec![]
+        }
+    }
+}
+
+/// Add a new shell cron job.
+/// `schedule_type` "watch" takes the path to watch as `expression`; "webhook"
+/// ignores it and generates a secret (see `get_cron_webhook_info`).
+/// `timezone` is an IANA name (e.g. "Asia/Shanghai"); None = system local time.
+/// `project_id` scopes the job to a project; None = global.
 pub fn add_shell_cron_job(
     name: Option<String>,
     schedule_type: String,
     expression: String,
     command: String,
+    timezone: Option<String>,
+    project_id: Option<String>,
 ) -> String {
+    let timezone = normalize_timezone(timezone);
+    let next_run = match compute_next_run(
+        &schedule_type,
+        &expression,
+        timezone.as_deref(),
+        &chrono::Utc::now(),
+    ) {
+        Ok(t) => t,
+        Err(e) => return format!("error: {e}"),
+    };
     let conn = match open_db() {
         Ok(c) => c,
         Err(e) => return format!("error: {e}"),
@@ -278,13 +770,13 @@ pub fn add_shell_cron_job(
 
     let id = uuid::Uuid::new_v4().to_string();
     let now = chrono::Utc::now();
-    let schedule_json = build_schedule_json(&schedule_type, &expression);
-    let next_run = compute_next_run(&schedule_type, &expression, &now);
+    let schedule_json = build_schedule_json(&schedule_type, &expression, timezone.as_deref());
 
     let r = conn.execute(
         "INSERT INTO cron_jobs (id, expression, command, schedule, job_type, prompt, name, \
-         session_target, model, enabled, delivery, delete_after_run, created_at, next_run) \
-         VALUES (?1,?2,?3,?4,'shell',NULL,?5,'isolated',NULL,1,NULL,0,?6,?7)",
+         session_target, model, enabled, delivery, delete_after_run, created_at, next_run, \
+         project_id, timeout_secs) \
+         VALUES (?1,?2,?3,?4,'shell',NULL,?5,'isolated',NULL,1,NULL,0,?6,?7,?8,?9)",
         params![
             id,
             expression,
@@ -293,6 +785,8 @@ pub fn add_shell_cron_job(
             name,
             now.to_rfc3339(),
             next_run.to_rfc3339(),
+            project_id.unwrap_or_default(),
+            DEFAULT_TIMEOUT_SECS,
         ],
     );
 
@@ -302,7 +796,10 @@ pub fn add_shell_cron_job(
     }
 }
 
-/// Add a new agent cron job
+/// Add a new agent cron job.
+/// `timezone` is an IANA name (e.g. "Asia/Shanghai"); None = system local time.
+/// `project_id` scopes the job to a project; its runs use the project's
+/// directory and context. None = global.
 pub fn add_agent_cron_job(
     name: Option<String>,
     schedule_type: String,
@@ -312,7 +809,19 @@ pub fn add_agent_cron_job(
     model: Option<String>,
     delete_after_run: bool,
     target_session_id: Option<String>,
+    timezone: Option<String>,
+    project_id: Option<String>,
 ) -> String {
+    let timezone = normalize_timezone(timezone);
+    let next_run = match compute_next_run(
+        &schedule_type,
+        &expression,
+        timezone.as_deref(),
+        &chrono::Utc::now(),
+    ) {
+        Ok(t) => t,
+        Err(e) => return format!("error: {e}"),
+    };
     let conn = match open_db() {
         Ok(c) => c,
         Err(e) => return format!("error: {e}"),
@@ -320,8 +829,7 @@ pub fn add_agent_cron_job(
 
     let id = uuid::Uuid::new_v4().to_string();
     let now = chrono::Utc::now();
-    let schedule_json = build_schedule_json(&schedule_type, &expression);
-    let next_run = compute_next_run(&schedule_type, &expression, &now);
+    let schedule_json = build_schedule_json(&schedule_type, &expression, timezone.as_deref());
     let target = if session_target == "main" {
         "main"
     } else {
@@ -338,8 +846,8 @@ pub fn add_agent_cron_job(
     let r = conn.execute(
         "INSERT INTO cron_jobs (id, expression, command, schedule, job_type, prompt, name, \
          session_target, model, enabled, delivery, delete_after_run, created_at, next_run, \
-         target_session_id) \
-         VALUES (?1,?2,'',?3,'agent',?4,?5,?6,?7,1,NULL,?8,?9,?10,?11)",
+         target_session_id, project_id, timeout_secs) \
+         VALUES (?1,?2,'',?3,'agent',?4,?5,?6,?7,1,NULL,?8,?9,?10,?11,?12,?13)",
         params![
             id,
             expression,
@@ -352,6 +860,8 @@ pub fn add_agent_cron_job(
             now.to_rfc3339(),
             next_run.to_rfc3339(),
             stored_session_id,
+            project_id.unwrap_or_default(),
+            DEFAULT_TIMEOUT_SECS,
         ],
     );
 
@@ -368,11 +878,184 @@ pub fn remove_cron_job(job_id: String) -> String {
         Err(e) => return format!("error: {e}"),
     };
     match conn.execute("DELETE FROM cron_jobs WHERE id = ?1", params![job_id]) {
+        Ok(_) => {
+            clear_chain_references(&conn, &job_id);
+            "ok".into()
+        }
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Pause a project's enabled jobs when it is archived, or resume the jobs
+/// that archiving paused when it is restored. Jobs paused by hand stay paused.
+pub(crate) fn set_project_cron_jobs_paused(project_id: &str, paused: bool) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    let sql = if paused {
+        "UPDATE cron_jobs SET enabled = 0, paused_by_project = 1 \
+         WHERE project_id = ?1 AND enabled = 1"
+    } else {
+        "UPDATE cron_jobs SET enabled = 1, paused_by_project = 0 \
+         WHERE project_id = ?1 AND paused_by_project = 1"
+    };
+    match conn.execute(sql, params![project_id]) {
         Ok(_) => "ok".into(),
         Err(e) => format!("error: {e}"),
     }
 }
 
+/// Delete every job of a deleted project, along with its run history
+pub(crate) fn delete_project_cron_jobs(project_id: &str) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    let ids: Vec<String> = conn
+        .prepare("SELECT id FROM cron_jobs WHERE project_id = ?1")
+        .and_then(|mut stmt| {
+            stmt.query_map(params![project_id], |row| row.get(0))
+                .map(|rows| rows.filter_map(|r| r.ok()).collect())
+        })
+        .unwrap_or_default();
+    for id in &ids {
+        let _ = conn.execute("DELETE FROM cron_runs WHERE job_id = ?1", params![id]);
+        let _ = conn.execute("DELETE FROM cron_jobs WHERE id = ?1", params![id]);
+        clear_chain_references(&conn, id);
+    }
+    if !ids.is_empty() {
+        tracing::info!("Deleted {} cron job(s) of project {project_id}", ids.len());
+    }
+    "ok".into()
+}
+
+/// A project's jobs as a TOML cron file, for project bundles. Jobs paused by
+/// archiving the project are written as enabled, the way they'd run once
+/// the project is restored.
+pub(crate) fn export_project_cron_jobs(project_id: &str) -> Result<String, String> {
+    let conn = open_db()?;
+    let paused: Vec<String> = conn
+        .prepare("SELECT id FROM cron_jobs WHERE project_id = ?1 AND paused_by_project = 1")
+        .and_then(|mut stmt| {
+            stmt.query_map(params![project_id], |row| row.get(0))
+                .map(|rows| rows.filter_map(|r| r.ok()).collect())
+        })
+        .map_err(|e| e.to_string())?;
+    let all = list_cron_jobs();
+    let names: HashMap<String, String> =
+        all.iter().map(|j| (j.id.clone(), export_name(j))).collect();
+    let mut jobs: Vec<CronJobSpec> = all
+        .iter()
+        .filter(|j| j.project_id == project_id)
+        .map(|j| {
+            let mut spec = job_to_spec(&conn, j, &names);
+            spec.enabled |= paused.contains(&j.id);
+            spec
+        })
+        .collect();
+    jobs.sort_by(|a, b| a.name.cmp(&b.name));
+    toml::to_string_pretty(&CronJobFile { jobs }).map_err(|e| e.to_string())
+}
+
+/// Import the cron file of a project bundle into `project_id`. Jobs whose
+/// name is already taken get a numbered suffix, target sessions are renamed
+/// through `session_ids`, follow-ups outside the bundle are dropped and
+/// every job is created disabled.
+/// Returns the number of jobs created.
+pub(crate) fn import_project_cron_jobs(
+    content: &str,
+    project_id: &str,
+    session_ids: &HashMap<String, String>,
+) -> Result<u32, String> {
+    let mut file: CronJobFile =
+        toml::from_str(content).map_err(|e| format!("invalid cron file: {e}"))?;
+    let mut taken: std::collections::HashSet<String> =
+        list_cron_jobs().iter().map(export_name).collect();
+    let mut renames = HashMap::new();
+    for spec in &file.jobs {
+        let mut name = spec.name.clone();
+        let mut n = 2;
+        while taken.contains(&name) {
+            name = format!("{} ({n})", spec.name);
+            n += 1;
+        }
+        taken.insert(name.clone());
+        renames.insert(spec.name.clone(), name);
+    }
+    for spec in &mut file.jobs {
+        spec.name = renames[&spec.name].clone();
+        spec.project_id = project_id.to_string();
+        // Jobs from elsewhere run commands here; they wait for the user
+        spec.enabled = false;
+        if let Some(id) = session_ids.get(&spec.target_session_id) {
+            spec.target_session_id = id.clone();
+        }
+        for target in [&mut spec.on_success, &mut spec.on_failure] {
+            *target = renames.get(target.as_str()).cloned().unwrap_or_default();
+        }
+    }
+
+    let content = toml::to_string_pretty(&file).map_err(|e| e.to_string())?;
+    let result = import_cron_jobs(content, "toml".into(), false);
+    if !result.errors.is_empty() {
+        return Err(result.errors.join("; "));
+    }
+    Ok(result
+        .changes
+        .iter()
+        .filter(|c| c.action == "create")
+        .count() as u32)
+}
+
+/// Runs of a project's jobs that started before `before` (epoch seconds;
+/// None = now), newest first: the latest `limit`, plus every other run
+/// within the same second as the oldest of them, so that a page cut at that
+/// second doesn't split it
+pub(crate) fn project_cron_runs(
+    project_id: &str,
+    before: Option<i64>,
+    limit: u32,
+) -> Vec<CronRunDto> {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => {
+            tracing::error!("Failed to open cron db: {e}");
+            return vec![];
+        }
+    };
+    let before = before
+        .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+        .map(|d| d.to_rfc3339())
+        .unwrap_or_else(|| "9999".into());
+    let oldest: Option<String> = conn
+        .query_row(
+            "SELECT started_at FROM cron_runs \
+             WHERE job_id IN (SELECT id FROM cron_jobs WHERE project_id = ?1) AND started_at < ?2 \
+             ORDER BY started_at DESC, id DESC LIMIT 1 OFFSET ?3",
+            params![project_id, before, limit.max(1) - 1],
+            |row| row.get(0),
+        )
+        .ok();
+    // Whole seconds sort before fractional ones in RFC 3339
+    let since = oldest
+        .and_then(|s| chrono::DateTime::from_timestamp(parse_rfc3339_to_ts(&s), 0))
+        .map(|d| d.to_rfc3339())
+        .unwrap_or_default();
+    let sql = format!(
+        "SELECT {RUN_COLUMNS} FROM cron_runs \
+         WHERE job_id IN (SELECT id FROM cron_jobs WHERE project_id = ?1) \
+         AND started_at < ?2 AND started_at >= ?3 \
+         ORDER BY started_at DESC, id DESC"
+    );
+    conn.prepare(&sql)
+        .and_then(|mut stmt| {
+            stmt.query_map(params![project_id, before, since], run_row_to_dto)
+                .map(|rows| rows.filter_map(|r| r.ok()).collect())
+        })
+        .unwrap_or_default()
+}
+
 /// Pause a cron job
 pub fn pause_cron_job(job_id: String) -> String {
     let conn = match open_db() {
@@ -380,7 +1063,7 @@ pub fn pause_cron_job(job_id: String) -> String {
         Err(e) => return format!("error: {e}"),
     };
     match conn.execute(
-        "UPDATE cron_jobs SET enabled = 0 WHERE id = ?1",
+        "UPDATE cron_jobs SET enabled = 0, paused_by_project = 0 WHERE id = ?1",
         params![job_id],
     ) {
         Ok(_) => "ok".into(),
@@ -395,7 +1078,8 @@ pub fn resume_cron_job(job_id: String) -> String {
         Err(e) => return format!("error: {e}"),
     };
     match conn.execute(
-        "UPDATE cron_jobs SET enabled = 1 WHERE id = ?1",
+        "UPDATE cron_jobs SET enabled = 1, paused_by_project = 0, consecutive_failures = 0 \
+         WHERE id = ?1",
         params![job_id],
     ) {
         Ok(_) => "ok".into(),
@@ -413,11 +1097,12 @@ pub fn list_cron_runs(job_id: String, limit: u32) -> Vec<CronRunDto> {
         }
     };
 
-    let sql = "SELECT id, job_id, started_at, finished_at, status, output, duration_ms \
-               FROM cron_runs WHERE job_id = ?1 \
-               ORDER BY started_at DESC, id DESC LIMIT ?2";
+    let sql = format!(
+        "SELECT {RUN_COLUMNS} FROM cron_runs WHERE job_id = ?1 \
+         ORDER BY started_at DESC, id DESC LIMIT ?2"
+    );
 
-    let mut stmt = match conn.prepare(sql) {
+    let mut stmt = match conn.prepare(&sql) {
         Ok(s) => s,
         Err(e) => {
             tracing::error!("Failed to prepare runs query: {e}");
@@ -425,19 +1110,7 @@ pub fn list_cron_runs(job_id: String, limit: u32) -> Vec<CronRunDto> {
         }
     };
 
-    let rows = stmt.query_map(params![job_id, limit], |row| {
-        let started: String = row.get(2)?;
-        let finished: String = row.get(3)?;
-        Ok(CronRunDto {
-            id: row.get(0)?,
-            job_id: row.get(1)?,
-            started_at: parse_rfc3339_to_ts(&started),
-            finished_at: parse_rfc3339_to_ts(&finished),
-            status: row.get(4)?,
-            output: row.get::<_, Option<String>>(5)?.unwrap_or_default(),
-            duration_ms: row.get::<_, Option<i64>>(6)?.unwrap_or(0),
-        })
-    });
+    let rows = stmt.query_map(params![job_id, limit], run_row_to_dto);
 
     match rows {
         Ok(mapped) => mapped.filter_map(|r| r.ok()).collect(),
@@ -448,42 +1121,146 @@ pub fn list_cron_runs(job_id: String, limit: u32) -> Vec<CronRunDto> {
     }
 }
 
-/// Update a cron job
-pub fn update_cron_job(
-    job_id: String,
-    name: Option<String>,
-    schedule_type: Option<String>,
-    expression: Option<String>,
-    command: Option<String>,
-    prompt: Option<String>,
-    enabled: Option<bool>,
-) -> String {
+/// List every run that belongs to one chain execution, in execution order
+pub fn list_cron_chain_runs(chain_id: String) -> Vec<CronRunDto> {
     let conn = match open_db() {
         Ok(c) => c,
-        Err(e) => return format!("error: {e}"),
+        Err(e) => {
+            tracing::error!("Failed to open cron db: {e}");
+            return vec![];
+        }
     };
 
-    // Read current job first
-    let sql = "SELECT id, expression, command, schedule, job_type, prompt, name, \
-               session_target, model, enabled, delivery, delete_after_run, \
-               created_at, next_run, last_run, last_status, last_output, target_session_id, \
-               project_id \
-               FROM cron_jobs WHERE id = ?1";
-    let current = conn.query_row(sql, params![job_id], |row| row_to_dto(row));
-    let current = match current {
+    let sql = format!("SELECT {RUN_COLUMNS} FROM cron_runs WHERE chain_id = ?1 ORDER BY id ASC");
+    let mut stmt = match conn.prepare(&sql) {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!("Failed to prepare chain runs query: {e}");
+            return vec![];
+        }
+    };
+    let rows = stmt.query_map(params![chain_id], run_row_to_dto);
+    match rows {
+        Ok(mapped) => mapped.filter_map(|r| r.ok()).collect(),
+        Err(e) => {
+            tracing::error!("Failed to list chain runs: {e}");
+            vec![]
+        }
+    }
+}
+
+/// Set the jobs that run after this one succeeds or fails (empty = none).
+/// Follow-ups run even while paused, so a paused job can serve as a
+/// chain-only step. Their prompt or command may reference the previous run
+/// with `{{prev_output}}` (stdout, or stderr when it failed),
+/// `{{prev_status}}` and `{{prev_job}}`. Shell follow-ups also get the
+/// previous output as a file named by `CRON_PREV_OUTPUT_FILE`.
+pub fn set_cron_job_chain(job_id: String, on_success: String, on_failure: String) -> String {
+    let conn = match open_db() {
         Ok(c) => c,
-        Err(e) => return format!("error: job not found: {e}"),
+        Err(e) => return format!("error: {e}"),
     };
 
-    let new_name = name.unwrap_or(current.name);
-    let new_command = command.unwrap_or(current.command);
-    let new_prompt = prompt.or(Some(current.prompt));
-    let new_enabled = enabled.unwrap_or(current.enabled);
+    let mut edges = load_chain_edges(&conn);
+    if !edges.contains_key(&job_id) {
+        return "error: job not found".into();
+    }
+    for target in [&on_success, &on_failure] {
+        if !target.is_empty() && !edges.contains_key(target) {
+            return format!("error: follow-up job not found: {target}");
+        }
+    }
+    edges.insert(
+        job_id.clone(),
+        [&on_success, &on_failure]
+            .into_iter()
+            .filter(|t| !t.is_empty())
+            .cloned()
+            .collect(),
+    );
+    if chain_has_cycle(&edges, &job_id) {
+        return "error: chain would form a cycle".into();
+    }
+
+    match conn.execute(
+        "UPDATE cron_jobs SET on_success_job_id=?1, on_failure_job_id=?2 WHERE id=?3",
+        params![on_success, on_failure, job_id],
+    ) {
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Read a page of a run's full stdout and stderr. `offset` and `len` are
+/// byte positions applied to each stream and snapped to UTF-8 boundaries.
+pub fn get_cron_run_output(run_id: i64, offset: u64, len: u64) -> Option<CronRunOutput> {
+    let conn = open_db().ok()?;
+    let (stdout, stderr, exit_code, stdout_truncated, stderr_truncated) = conn
+        .query_row(
+            "SELECT stdout, stderr, exit_code, stdout_truncated, stderr_truncated \
+             FROM cron_runs WHERE id = ?1",
+            params![run_id],
+            |row| {
+                Ok((
+                    row.get::<_, Option<String>>(0)?.unwrap_or_default(),
+                    row.get::<_, Option<String>>(1)?.unwrap_or_default(),
+                    row.get::<_, Option<i32>>(2)?,
+                    row.get::<_, Option<bool>>(3)?.unwrap_or(false),
+                    row.get::<_, Option<bool>>(4)?.unwrap_or(false),
+                ))
+            },
+        )
+        .ok()?;
+
+    Some(CronRunOutput {
+        run_id,
+        stdout: output_page(&stdout, offset, len).to_string(),
+        stderr: output_page(&stderr, offset, len).to_string(),
+        stdout_len: stdout.len() as u64,
+        stderr_len: stderr.len() as u64,
+        stdout_truncated,
+        stderr_truncated,
+        exit_code,
+    })
+}
+
+/// Update a cron job
+pub fn update_cron_job(
+    job_id: String,
+    name: Option<String>,
+    schedule_type: Option<String>,
+    expression: Option<String>,
+    command: Option<String>,
+    prompt: Option<String>,
+    enabled: Option<bool>,
+) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+
+    // Read current job first
+    let current = match load_job(&conn, &job_id) {
+        Ok(c) => c,
+        Err(e) => return format!("error: job not found: {e}"),
+    };
+
+    let new_name = name.unwrap_or(current.name);
+    let new_command = command.unwrap_or(current.command);
+    let new_prompt = prompt.or(Some(current.prompt));
+    let new_enabled = enabled.unwrap_or(current.enabled);
 
     let (new_expression, new_schedule_json, new_next_run) =
         if let (Some(st), Some(expr)) = (&schedule_type, &expression) {
-            let sj = build_schedule_json(st, expr);
-            let nr = compute_next_run(st, expr, &chrono::Utc::now());
+            let tz = normalize_timezone(Some(current.timezone.clone()));
+            let nr = match compute_next_run(st, expr, tz.as_deref(), &chrono::Utc::now()) {
+                Ok(t) => t,
+                Err(e) => return format!("error: {e}"),
+            };
+            let mut sj = build_schedule_json(st, expr, tz.as_deref());
+            if *st == current.schedule_type {
+                sj = carry_trigger_settings(&conn, &job_id, sj);
+            }
             (expr.clone(), sj, nr.to_rfc3339())
         } else {
             // Keep existing
@@ -518,269 +1295,4087 @@ pub fn update_cron_job(
     }
 }
 
-// ──────────────────── Helpers ─────────────────────────────────
+/// Change the timezone a job's schedule is evaluated in and recompute its
+/// next run. `timezone` is an IANA name; empty = system local time.
+pub fn set_cron_job_timezone(job_id: String, timezone: String) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
 
-fn build_schedule_json(schedule_type: &str, expression: &str) -> Option<String> {
-    let val = match schedule_type {
-        "cron" => serde_json::json!({
-            "kind": "cron",
-            "expr": expression,
-            "tz": null
-        }),
-        "at" => serde_json::json!({
-            "kind": "at",
-            "at": expression
-        }),
-        "every" => {
-            let ms: u64 = expression.parse().unwrap_or(60000);
-            serde_json::json!({
-                "kind": "every",
-                "every_ms": ms
-            })
-        }
-        _ => return None,
+    let current = match load_job(&conn, &job_id) {
+        Ok(c) => c,
+        Err(e) => return format!("error: job not found: {e}"),
     };
-    Some(val.to_string())
-}
 
-fn compute_next_run(
-    schedule_type: &str,
-    expression: &str,
-    now: &chrono::DateTime<chrono::Utc>,
-) -> chrono::DateTime<chrono::Utc> {
-    match schedule_type {
-        "at" => chrono::DateTime::parse_from_rfc3339(expression)
-            .map(|d| d.with_timezone(&chrono::Utc))
-            .unwrap_or(*now),
-        "every" => {
-            let ms: i64 = expression.parse().unwrap_or(60000);
-            *now + chrono::Duration::milliseconds(ms)
-        }
-        _ => {
-            // For cron expressions, just use now + 1 minute as approximation
-            // The actual scheduler in zeroclaw will compute precise next-run
-            *now + chrono::Duration::minutes(1)
-        }
+    if is_event_trigger(&current.schedule_type) {
+        return "error: event-triggered jobs have no timezone".into();
     }
-}
 
-fn format_interval(ms: u64) -> String {
-    if ms >= 86_400_000 {
-        format!("每 {} 天", ms / 86_400_000)
-    } else if ms >= 3_600_000 {
-        format!("每 {} 小时", ms / 3_600_000)
-    } else if ms >= 60_000 {
-        format!("每 {} 分钟", ms / 60_000)
-    } else {
-        format!("每 {} 秒", ms / 1000)
+    let tz = normalize_timezone(Some(timezone));
+    let next_run = match compute_next_run(
+        &current.schedule_type,
+        &current.expression,
+        tz.as_deref(),
+        &chrono::Utc::now(),
+    ) {
+        Ok(t) => t,
+        Err(e) => return format!("error: {e}"),
+    };
+    let schedule_json =
+        build_schedule_json(&current.schedule_type, &current.expression, tz.as_deref());
+
+    match conn.execute(
+        "UPDATE cron_jobs SET schedule=?1, next_run=?2 WHERE id=?3",
+        params![schedule_json, next_run.to_rfc3339(), job_id],
+    ) {
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
     }
 }
 
-// ──────────────────── Execution ──────────────────────────────
+/// Set how a job handles missed runs and whether runs may overlap.
+/// `misfire_policy`: "skip", "run_once" or "run_all".
+pub fn set_cron_job_run_policy(job_id: String, misfire_policy: String, no_overlap: bool) -> String {
+    if !MISFIRE_POLICIES.contains(&misfire_policy.as_str()) {
+        return format!("error: unknown misfire policy: {misfire_policy}");
+    }
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    match conn.execute(
+        "UPDATE cron_jobs SET misfire_policy=?1, no_overlap=?2 WHERE id=?3",
+        params![misfire_policy, no_overlap as i32, job_id],
+    ) {
+        Ok(0) => "error: job not found".into(),
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
 
-/// Execute a cron job immediately (manual trigger)
-pub async fn run_cron_job_now(job_id: String) -> String {
-    // 1. Load job from DB
+/// Set a job's timeout (0 = no limit) and how often a failed run is retried.
+/// Retries wait `retry_backoff_secs`, doubling after each further failure.
+pub fn set_cron_job_retry_policy(
+    job_id: String,
+    timeout_secs: u32,
+    max_retries: u32,
+    retry_backoff_secs: u32,
+) -> String {
+    if max_retries > MAX_RETRIES {
+        return format!("error: max retries must be at most {MAX_RETRIES}");
+    }
     let conn = match open_db() {
         Ok(c) => c,
         Err(e) => return format!("error: {e}"),
     };
+    match conn.execute(
+        "UPDATE cron_jobs SET timeout_secs=?1, max_retries=?2, retry_backoff_secs=?3 WHERE id=?4",
+        params![timeout_secs, max_retries, retry_backoff_secs, job_id],
+    ) {
+        Ok(0) => "error: job not found".into(),
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
 
-    let sql = "SELECT id, expression, command, schedule, job_type, prompt, name, \
-               session_target, model, enabled, delivery, delete_after_run, \
-               created_at, next_run, last_run, last_status, last_output, target_session_id, \
-               project_id \
-               FROM cron_jobs WHERE id = ?1";
-    let job = match conn.query_row(sql, params![job_id], |row| row_to_dto(row)) {
-        Ok(j) => j,
-        Err(e) => return format!("error: job not found: {e}"),
+/// Set how many runs are kept per job, pruning older history right away
+pub fn set_cron_max_run_history(max_run_history: u32) -> String {
+    if max_run_history == 0 {
+        return "error: run history must keep at least 1 entry".into();
+    }
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
     };
-    drop(conn);
+    if let Err(e) = set_setting(&conn, "max_run_history", &max_run_history.to_string()) {
+        return format!("error: {e}");
+    }
 
-    let started = chrono::Utc::now();
-    let started_rfc = started.to_rfc3339();
+    let job_ids: Vec<String> = conn
+        .prepare("SELECT DISTINCT job_id FROM cron_runs")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| row.get(0))
+                .map(|rows| rows.filter_map(|r| r.ok()).collect())
+        })
+        .unwrap_or_default();
+    for job_id in job_ids {
+        prune_run_history(&conn, &job_id);
+    }
+    "ok".into()
+}
+
+/// Disable jobs after this many failed runs in a row (0 = never)
+pub fn set_cron_auto_disable_after(failures: u32) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    match set_setting(&conn, "auto_disable_after", &failures.to_string()) {
+        Ok(()) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
 
-    // 2. Execute based on job type
-    let (status, output) = if job.job_type == "agent" {
-        run_agent_job(&job).await
+/// Success rate, duration percentiles and failure streak of a job over the
+/// last `window_hours` hours (0 = all kept history)
+pub fn get_cron_job_stats(job_id: String, window_hours: u32) -> Option<CronJobStatsDto> {
+    let conn = open_db().ok()?;
+    let consecutive_failures: u32 = conn
+        .query_row(
+            "SELECT COALESCE(consecutive_failures, 0) FROM cron_jobs WHERE id = ?1",
+            params![job_id],
+            |row| row.get(0),
+        )
+        .ok()?;
+    let since = if window_hours == 0 {
+        String::new()
     } else {
-        run_shell_job(&job).await
+        (chrono::Utc::now() - chrono::Duration::hours(window_hours as i64)).to_rfc3339()
     };
+    // Runs skipped by the misfire policy never ran, so they count neither
+    // as failures nor towards the duration percentiles
+    let attempts: Vec<RunAttempt> = conn
+        .prepare(
+            "SELECT status, COALESCE(duration_ms, 0), COALESCE(attempt, 1) FROM cron_runs \
+             WHERE job_id = ?1 AND started_at >= ?2 AND status != 'skipped' ORDER BY id ASC",
+        )
+        .and_then(|mut stmt| {
+            stmt.query_map(params![job_id, since], |row| {
+                Ok(RunAttempt {
+                    ok: row.get::<_, String>(0)? == "ok",
+                    duration_ms: row.get(1)?,
+                    attempt: row.get(2)?,
+                })
+            })
+            .map(|rows| rows.filter_map(|r| r.ok()).collect())
+        })
+        .unwrap_or_default();
+    let last_success: Option<String> = conn
+        .query_row(
+            "SELECT MAX(finished_at) FROM cron_runs WHERE job_id = ?1 AND status = 'ok'",
+            params![job_id],
+            |row| row.get(0),
+        )
+        .ok()
+        .flatten();
 
-    let finished = chrono::Utc::now();
-    let duration_ms = (finished - started).num_milliseconds();
+    let (succeeded, failed) = count_runs(&attempts);
+    let total_runs = succeeded + failed;
+    let mut durations: Vec<i64> = attempts.iter().map(|a| a.duration_ms).collect();
+    durations.sort_unstable();
+    Some(CronJobStatsDto {
+        job_id,
+        total_runs,
+        succeeded,
+        failed,
+        success_rate: if total_runs == 0 {
+            0.0
+        } else {
+            succeeded as f64 / total_runs as f64
+        },
+        p50_duration_ms: percentile(&durations, 50),
+        p95_duration_ms: percentile(&durations, 95),
+        consecutive_failures,
+        last_success: last_success.map(|s| parse_rfc3339_to_ts(&s)),
+    })
+}
 
-    // 3. Record run result
+/// Set how many cron jobs the scheduler may run at the same time
+pub fn set_cron_max_concurrency(max_concurrency: u32) -> String {
+    if max_concurrency == 0 {
+        return "error: max concurrency must be at least 1".into();
+    }
     let conn = match open_db() {
         Ok(c) => c,
         Err(e) => return format!("error: {e}"),
     };
+    match set_setting(&conn, "max_concurrency", &max_concurrency.to_string()) {
+        Ok(()) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
 
-    let _ = conn.execute(
-        "INSERT INTO cron_runs (job_id, started_at, finished_at, status, output, duration_ms) \
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-        params![
-            job_id,
-            started_rfc,
-            finished.to_rfc3339(),
-            &status,
-            &output,
-            duration_ms,
-        ],
-    );
+/// Export all cron jobs, or a project's, as a "toml" or "yaml" document.
+/// Chain targets are written by job name; webhook secrets are left out.
+pub fn export_cron_jobs(project_id: Option<String>, format: String) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    let all = list_cron_jobs();
+    let names: HashMap<String, String> =
+        all.iter().map(|j| (j.id.clone(), export_name(j))).collect();
+    let mut jobs: Vec<CronJobSpec> = all
+        .iter()
+        .filter(|j| project_id.as_deref().is_none_or(|p| j.project_id == p))
+        .map(|j| job_to_spec(&conn, j, &names))
+        .collect();
+    jobs.sort_by(|a, b| a.name.cmp(&b.name));
 
-    // 4. Update job with last run info and compute next run
-    let now = chrono::Utc::now();
-    let (schedule_type, _) = decode_schedule_info(
-        conn.query_row(
-            "SELECT schedule FROM cron_jobs WHERE id = ?1",
-            params![job_id],
-            |row| row.get::<_, Option<String>>(0),
-        )
-        .unwrap_or(None),
-        &job.expression,
-    );
-    let next_run = compute_next_run(&schedule_type, &job.expression, &now);
+    let file = CronJobFile { jobs };
+    let result = match format.as_str() {
+        "toml" => toml::to_string_pretty(&file).map_err(|e| e.to_string()),
+        "yaml" | "yml" => serde_yaml_ng::to_string(&file).map_err(|e| e.to_string()),
+        other => Err(format!("unsupported format: {other}")),
+    };
+    result.unwrap_or_else(|e| format!("error: {e}"))
+}
 
-    let _ = conn.execute(
-        "UPDATE cron_jobs SET last_run=?1, last_status=?2, last_output=?3, next_run=?4 WHERE id=?5",
-        params![started_rfc, &status, &output, next_run.to_rfc3339(), job_id,],
-    );
+/// Import a "toml" or "yaml" cron file, creating or updating jobs matched by
+/// name. Jobs missing from the file are left alone, so importing the same
+/// file twice changes nothing. With `dry_run`, only reports the changes.
+pub fn import_cron_jobs(content: String, format: String, dry_run: bool) -> CronImportResult {
+    let failed = |errors: Vec<String>| CronImportResult {
+        errors,
+        applied: false,
+        changes: vec![],
+    };
 
-    // 5. Emit notification to Flutter UI
-    super::cron_notification_api::emit_notification(
-        super::cron_notification_api::CronNotification {
-            job_id: job_id.clone(),
-            job_name: job.name.clone(),
-            job_type: job.job_type.clone(),
-            session_target: job.session_target.clone(),
-            target_session_id: job.target_session_id.clone(),
-            status: status.clone(),
-            output: output.clone(),
-            prompt: job.prompt.clone(),
-            duration_ms,
-            finished_at: finished.timestamp(),
-        },
-    );
+    let parsed: Result<CronJobFile, String> = match format.as_str() {
+        "toml" => toml::from_str(&content).map_err(|e| e.to_string()),
+        "yaml" | "yml" => serde_yaml_ng::from_str(&content).map_err(|e| e.to_string()),
+        other => Err(format!("unsupported format: {other}")),
+    };
+    let file = match parsed {
+        Ok(f) => f,
+        Err(e) => return failed(vec![format!("invalid {format} file: {e}")]),
+    };
 
-    // 6. Handle delete_after_run
-    if job.delete_after_run {
-        let _ = conn.execute("DELETE FROM cron_jobs WHERE id = ?1", params![job_id]);
+    let mut conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return failed(vec![e]),
+    };
+    let existing = list_cron_jobs();
+    let names: HashMap<String, String> = existing
+        .iter()
+        .map(|j| (j.id.clone(), export_name(j)))
+        .collect();
+    let mut by_name: HashMap<String, Vec<&CronJobDto>> = HashMap::new();
+    for job in &existing {
+        by_name.entry(export_name(job)).or_default().push(job);
     }
 
-    if status == "ok" {
-        format!("ok: {output}")
-    } else {
-        format!("error: {output}")
+    // Validate every job before touching the database
+    let mut errors = Vec::new();
+    let mut file_names = std::collections::HashSet::new();
+    for spec in &file.jobs {
+        if let Err(e) = validate_spec(spec) {
+            errors.push(format!("{}: {e}", spec.name));
+        }
+        if !file_names.insert(spec.name.as_str()) {
+            errors.push(format!("{}: duplicate job name", spec.name));
+        }
+        if by_name.get(&spec.name).is_some_and(|jobs| jobs.len() > 1) {
+            errors.push(format!(
+                "{}: several existing jobs have this name",
+                spec.name
+            ));
+        }
+    }
+    for spec in &file.jobs {
+        for target in [&spec.on_success, &spec.on_failure] {
+            if !target.is_empty()
+                && !file_names.contains(target.as_str())
+                && !by_name.contains_key(target)
+            {
+                errors.push(format!("{}: unknown follow-up job: {target}", spec.name));
+            }
+        }
     }
-}
-
-/// Execute a shell command job
-async fn run_shell_job(job: &CronJobDto) -> (String, String) {
-    use tokio::process::Command;
 
-    let result = Command::new("sh")
-        .arg("-lc")
-        .arg(&job.command)
-        .output()
-        .await;
+    // Chains as they would be after the import, keyed by name
+    let mut edges: HashMap<String, Vec<String>> = existing
+        .iter()
+        .map(|j| {
+            let targets = [&j.on_success, &j.on_failure]
+                .into_iter()
+                .filter_map(|id| names.get(id).cloned())
+                .collect();
+            (export_name(j), targets)
+        })
+        .collect();
+    for spec in &file.jobs {
+        let targets = [&spec.on_success, &spec.on_failure]
+            .into_iter()
+            .filter(|t| !t.is_empty())
+            .cloned()
+            .collect();
+        edges.insert(spec.name.clone(), targets);
+    }
+    for spec in &file.jobs {
+        if chain_has_cycle(&edges, &spec.name) {
+            errors.push(format!("{}: chain would form a cycle", spec.name));
+        }
+    }
+    if !errors.is_empty() {
+        return failed(errors);
+    }
 
-    match result {
-        Ok(output) => {
-            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-            let combined = if stderr.is_empty() {
-                stdout
-            } else if stdout.is_empty() {
-                stderr
-            } else {
-                format!("{stdout}\n{stderr}")
+    let plan: Vec<(Option<&CronJobDto>, CronImportChange)> = file
+        .jobs
+        .iter()
+        .map(|spec| {
+            let current = by_name
+                .get(&spec.name)
+                .and_then(|jobs| jobs.first().copied());
+            let changed_fields = current
+                .map(|job| spec_diff(&job_to_spec(&conn, job, &names), spec))
+                .unwrap_or_default();
+            let action = match current {
+                None => "create",
+                Some(_) if changed_fields.is_empty() => "unchanged",
+                Some(_) => "update",
             };
-            let truncated = if combined.len() > 2000 {
-                format!("{}...(truncated)", &combined[..2000])
-            } else {
-                combined
+            (
+                current,
+                CronImportChange {
+                    name: spec.name.clone(),
+                    action: action.into(),
+                    changed_fields,
+                },
+            )
+        })
+        .collect();
+    let changes: Vec<CronImportChange> = plan.iter().map(|(_, c)| c.clone()).collect();
+    if dry_run {
+        return CronImportResult {
+            errors: vec![],
+            applied: false,
+            changes,
+        };
+    }
+
+    let ids: HashMap<String, String> = by_name
+        .iter()
+        .filter_map(|(name, jobs)| jobs.first().map(|j| (name.clone(), j.id.clone())))
+        .collect();
+    let result = (|| -> Result<(), String> {
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        let mut ids = ids;
+        for (spec, (current, change)) in file.jobs.iter().zip(&plan) {
+            let job_id = match (current, change.action.as_str()) {
+                (_, "unchanged") => continue,
+                (Some(job), _) => job.id.clone(),
+                (None, _) => {
+                    let id = uuid::Uuid::new_v4().to_string();
+                    let now = chrono::Utc::now().to_rfc3339();
+                    tx.execute(
+                        "INSERT INTO cron_jobs (id, expression, command, created_at, next_run) \
+                         VALUES (?1, '', '', ?2, ?2)",
+                        params![id, now],
+                    )
+                    .map_err(|e| e.to_string())?;
+                    id
+                }
             };
-            if output.status.success() {
-                ("ok".into(), truncated)
-            } else {
-                ("error".into(), truncated)
+            let reschedule = current.is_none()
+                || ["schedule_type", "expression", "timezone"]
+                    .iter()
+                    .any(|f| change.changed_fields.iter().any(|c| c == f));
+            save_spec(&tx, &job_id, spec, reschedule)?;
+            ids.insert(spec.name.clone(), job_id);
+        }
+        // Resolve chain targets once every job has an id
+        for (spec, (_, change)) in file.jobs.iter().zip(&plan) {
+            if change.action == "unchanged" {
+                continue;
             }
+            let resolve = |name: &String| ids.get(name).cloned().unwrap_or_default();
+            tx.execute(
+                "UPDATE cron_jobs SET on_success_job_id=?1, on_failure_job_id=?2 WHERE id=?3",
+                params![
+                    resolve(&spec.on_success),
+                    resolve(&spec.on_failure),
+                    ids[&spec.name]
+                ],
+            )
+            .map_err(|e| e.to_string())?;
         }
-        Err(e) => ("error".into(), format!("failed to execute: {e}")),
+        tx.commit().map_err(|e| e.to_string())
+    })();
+
+    match result {
+        Ok(()) => CronImportResult {
+            errors: vec![],
+            applied: true,
+            changes,
+        },
+        Err(e) => failed(vec![e]),
     }
 }
 
-/// Execute an agent job
-async fn run_agent_job(job: &CronJobDto) -> (String, String) {
-    // Use the existing agent infrastructure
-    let config = {
-        let cs = super::agent_api::config_state().read().await;
-        match &cs.config {
-            Some(c) => c.clone(),
-            None => return ("error".into(), "runtime not initialized".into()),
+/// Replace a job's delivery targets (empty = desktop notification only)
+pub fn set_cron_job_delivery(job_id: String, targets: Vec<CronDeliveryDto>) -> String {
+    for target in &targets {
+        if let Err(e) = validate_delivery(target) {
+            return format!("error: {e}");
         }
+    }
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
     };
+    match conn.execute(
+        "UPDATE cron_jobs SET delivery=?1 WHERE id=?2",
+        params![delivery_json(&targets), job_id],
+    ) {
+        Ok(0) => "error: job not found".into(),
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
 
-    // Create a temporary agent for this job
-    let mut agent = match zeroclaw::agent::Agent::from_config(&config) {
-        Ok(a) => a,
-        Err(e) => return ("error".into(), format!("failed to create agent: {e}")),
+/// Send a sample message to a delivery target to check its settings
+pub async fn test_cron_delivery(target: CronDeliveryDto) -> String {
+    if let Err(e) = validate_delivery(&target) {
+        return format!("error: {e}");
+    }
+    let sample = super::cron_notification_api::CronNotification {
+        job_id: String::new(),
+        job_name: "Test".into(),
+        job_type: "shell".into(),
+        session_target: "isolated".into(),
+        target_session_id: String::new(),
+        status: "ok".into(),
+        output: "This is a test message from CoralDesk.".into(),
+        prompt: String::new(),
+        duration_ms: 0,
+        finished_at: chrono::Utc::now().timestamp(),
+        auto_disabled: false,
     };
-
-    match agent.turn(&job.prompt).await {
-        Ok(response) => {
-            let truncated = if response.len() > 2000 {
-                format!("{}...(truncated)", &response[..2000])
-            } else {
-                response
-            };
-            ("ok".into(), truncated)
-        }
-        Err(e) => ("error".into(), format!("agent error: {e}")),
+    let config = match super::agent_api::config_state().read().await.config.clone() {
+        Some(c) => c,
+        None => return "error: runtime not initialized".into(),
+    };
+    match send_delivery(&config, &target, &sample).await {
+        Ok(()) => "ok".into(),
+        Err(e) => format!("error: {e}"),
     }
 }
 
-/// Start a background cron scheduler that polls for due jobs
-pub async fn start_cron_scheduler() -> String {
-    use std::sync::atomic::{AtomicBool, Ordering};
-    use std::sync::OnceLock;
+/// Set how long a "watch" job waits for changes to settle before running
+pub fn set_cron_job_watch_debounce(job_id: String, debounce_ms: u64) -> String {
+    update_trigger_settings(&job_id, "watch", |val| {
+        val["debounce_ms"] = serde_json::json!(debounce_ms.max(MIN_WATCH_DEBOUNCE_MS));
+    })
+}
 
-    static RUNNING: OnceLock<AtomicBool> = OnceLock::new();
-    let flag = RUNNING.get_or_init(|| AtomicBool::new(false));
+/// Replace a "webhook" job's secret; callers using the old one are rejected
+pub fn regenerate_cron_webhook_secret(job_id: String) -> String {
+    update_trigger_settings(&job_id, "webhook", |val| {
+        val["secret"] = serde_json::json!(new_webhook_secret());
+    })
+}
 
-    if flag.load(Ordering::SeqCst) {
-        return "already running".into();
+/// Get the URL and secret for triggering a "webhook" job
+pub fn get_cron_webhook_info(job_id: String) -> Option<CronWebhookInfo> {
+    let conn = open_db().ok()?;
+    let schedule = load_schedule_json(&conn, &job_id)?;
+    if schedule.get("kind").and_then(|v| v.as_str()) != Some("webhook") {
+        return None;
     }
-    flag.store(true, Ordering::SeqCst);
-
-    tokio::spawn(async move {
-        loop {
-            tokio::time::sleep(tokio::time::Duration::from_secs(30)).await;
-            let now = chrono::Utc::now();
-            // Find due jobs
-            let conn = match open_db() {
-                Ok(c) => c,
-                Err(_) => continue,
-            };
-            let sql = "SELECT id FROM cron_jobs WHERE enabled = 1 AND next_run <= ?1";
-            let due_ids: Vec<String> = {
-                let mut stmt = match conn.prepare(sql) {
-                    Ok(s) => s,
-                    Err(_) => continue,
-                };
-                stmt.query_map(params![now.to_rfc3339()], |row| row.get::<_, String>(0))
-                    .ok()
-                    .map(|rows| rows.filter_map(|r| r.ok()).collect())
-                    .unwrap_or_default()
-            };
-            drop(conn);
+    Some(CronWebhookInfo {
+        url: format!(
+            "http://127.0.0.1:{}/cron/trigger/{job_id}",
+            webhook_port(&conn)
+        ),
+        secret: schedule
+            .get("secret")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
 
-            for id in due_ids {
-                let _ = run_cron_job_now(id).await;
-            }
-        }
-    });
+/// Set the local port the webhook listener binds to.
+/// Takes effect the next time the scheduler starts.
+pub fn set_cron_webhook_port(port: u16) -> String {
+    if port == 0 {
+        return "error: port must be greater than 0".into();
+    }
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    match set_setting(&conn, "webhook_port", &port.to_string()) {
+        Ok(()) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
 
-    "started".into()
+/// Set a shell job's working directory, environment and resource limits
+pub fn set_cron_job_sandbox(job_id: String, sandbox: CronShellSandboxDto) -> String {
+    if let Some(var) = sandbox.env.iter().find(|v| !is_valid_env_name(&v.name)) {
+        return format!("error: invalid environment variable name: {}", var.name);
+    }
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    if let Some(var) = sandbox
+        .env
+        .iter()
+        .find(|v| v.secret && load_secret(&conn, &v.value).is_none())
+    {
+        return format!("error: unknown secret: {}", var.value);
+    }
+    let json = serde_json::to_string(&sandbox).unwrap_or_default();
+    match conn.execute(
+        "UPDATE cron_jobs SET sandbox=?1 WHERE id=?2",
+        params![json, job_id],
+    ) {
+        Ok(0) => "error: job not found".into(),
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Store a secret that shell jobs can reference from their environment
+/// without the value appearing in the job itself
+pub fn set_cron_secret(name: String, value: String) -> String {
+    if name.trim().is_empty() {
+        return "error: secret name must not be empty".into();
+    }
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    let encrypted = match secret_store().encrypt(&value) {
+        Ok(v) => v,
+        Err(e) => return format!("error: failed to encrypt secret: {e}"),
+    };
+    match conn.execute(
+        "INSERT INTO cron_secrets (name, value) VALUES (?1, ?2) \
+         ON CONFLICT(name) DO UPDATE SET value = excluded.value",
+        params![name.trim(), encrypted],
+    ) {
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+pub fn remove_cron_secret(name: String) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    match conn.execute("DELETE FROM cron_secrets WHERE name = ?1", params![name]) {
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Names of the stored cron secrets (values are never returned)
+pub fn list_cron_secrets() -> Vec<String> {
+    let Ok(conn) = open_db() else {
+        return vec![];
+    };
+    let Ok(mut stmt) = conn.prepare("SELECT name FROM cron_secrets ORDER BY name") else {
+        return vec![];
+    };
+    let rows = stmt.query_map([], |row| row.get(0));
+    rows.map(|mapped| mapped.filter_map(|r| r.ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Get the active window that applies to jobs without their own
+pub fn get_cron_active_window() -> CronActiveWindowDto {
+    open_db()
+        .map(|conn| global_active_window(&conn))
+        .unwrap_or_default()
+}
+
+/// Set the active window that applies to jobs without their own
+pub fn set_cron_active_window(window: CronActiveWindowDto) -> String {
+    if let Err(e) = validate_active_window(&window) {
+        return format!("error: {e}");
+    }
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    let json = serde_json::to_string(&window).unwrap_or_default();
+    match set_setting(&conn, "active_window", &json) {
+        Ok(()) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Give a job its own active window, replacing the global one for it.
+/// None = follow the global window again.
+pub fn set_cron_job_active_window(job_id: String, window: Option<CronActiveWindowDto>) -> String {
+    if let Some(w) = &window {
+        if let Err(e) = validate_active_window(w) {
+            return format!("error: {e}");
+        }
+    }
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    let json = window.map(|w| serde_json::to_string(&w).unwrap_or_default());
+    match conn.execute(
+        "UPDATE cron_jobs SET active_window=?1 WHERE id=?2",
+        params![json, job_id],
+    ) {
+        Ok(0) => "error: job not found".into(),
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Validate a cron expression and list its next `count` fire times.
+/// `timezone` is an IANA name; None or empty = system local time.
+pub fn preview_cron_schedule(
+    expression: String,
+    timezone: Option<String>,
+    count: u32,
+) -> CronSchedulePreview {
+    let timezone = normalize_timezone(timezone);
+    let mut fire_times = Vec::new();
+    let mut after = chrono::Utc::now();
+    for _ in 0..count.min(100) {
+        match compute_next_run("cron", &expression, timezone.as_deref(), &after) {
+            Ok(t) => {
+                fire_times.push(t.timestamp());
+                after = t;
+            }
+            Err(e) => {
+                return CronSchedulePreview {
+                    error: e,
+                    fire_times: vec![],
+                }
+            }
+        }
+    }
+    CronSchedulePreview {
+        error: String::new(),
+        fire_times,
+    }
+}
+
+// ──────────────────── Helpers ─────────────────────────────────
+
+fn build_schedule_json(
+    schedule_type: &str,
+    expression: &str,
+    timezone: Option<&str>,
+) -> Option<String> {
+    let val = match schedule_type {
+        "cron" => serde_json::json!({
+            "kind": "cron",
+            "expr": expression,
+            "tz": timezone
+        }),
+        "at" => serde_json::json!({
+            "kind": "at",
+            "at": expression,
+            "tz": timezone
+        }),
+        "every" => {
+            let ms: u64 = expression.parse().unwrap_or(60000);
+            serde_json::json!({
+                "kind": "every",
+                "every_ms": ms
+            })
+        }
+        "watch" => serde_json::json!({
+            "kind": "watch",
+            "path": expression.trim(),
+            "debounce_ms": DEFAULT_WATCH_DEBOUNCE_MS
+        }),
+        "webhook" => serde_json::json!({
+            "kind": "webhook",
+            "secret": new_webhook_secret()
+        }),
+        _ => return None,
+    };
+    Some(val.to_string())
+}
+
+/// Treat empty / whitespace-only timezone names as "system local time".
+fn normalize_timezone(timezone: Option<String>) -> Option<String> {
+    timezone
+        .map(|tz| tz.trim().to_string())
+        .filter(|tz| !tz.is_empty())
+}
+
+fn parse_timezone(timezone: &str) -> Result<chrono_tz::Tz, String> {
+    chrono_tz::Tz::from_str(timezone).map_err(|_| format!("unknown timezone: {timezone}"))
+}
+
+fn compute_next_run(
+    schedule_type: &str,
+    expression: &str,
+    timezone: Option<&str>,
+    after: &chrono::DateTime<chrono::Utc>,
+) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    match (schedule_type, timezone) {
+        ("every", _) => {
+            let ms: i64 = expression
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid interval: {expression}"))?;
+            if ms <= 0 {
+                return Err(format!("invalid interval: {expression}"));
+            }
+            Ok(*after + chrono::Duration::milliseconds(ms))
+        }
+        ("at", Some(tz)) => parse_at_time(expression, &parse_timezone(tz)?),
+        ("at", None) => parse_at_time(expression, &chrono::Local),
+        ("cron", Some(tz)) => next_cron_fire(expression, &parse_timezone(tz)?, after),
+        ("cron", None) => next_cron_fire(expression, &chrono::Local, after),
+        // Event triggers aren't time-based; the scheduler never picks them up
+        ("watch", _) if expression.trim().is_empty() => Err("watch path is required".into()),
+        ("watch", _) | ("webhook", _) => Ok(*after),
+        (other, _) => Err(format!("unknown schedule type: {other}")),
+    }
+}
+
+/// Parse a one-shot "at" time. RFC 3339 timestamps carry their own offset;
+/// naive date-times are interpreted in the job's timezone.
+fn parse_at_time<Z: TimeZone>(
+    expression: &str,
+    tz: &Z,
+) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    let expression = expression.trim();
+    if let Ok(d) = chrono::DateTime::parse_from_rfc3339(expression) {
+        return Ok(d.with_timezone(&chrono::Utc));
+    }
+    [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|fmt| chrono::NaiveDateTime::parse_from_str(expression, fmt).ok())
+    .and_then(|naive| tz.from_local_datetime(&naive).earliest())
+    .map(|d| d.with_timezone(&chrono::Utc))
+    .ok_or_else(|| format!("invalid time: {expression}"))
+}
+
+/// Normalize a user-supplied cron expression into the 6/7-field form the
+/// `cron` crate understands. Standard 5-field expressions get a seconds
+/// field, and numeric day-of-week values are rewritten from Unix numbering
+/// (0/7 = Sunday) to names so they keep their usual meaning.
+fn normalize_cron_expression(expression: &str) -> Result<String, String> {
+    let expression = expression.trim();
+    match expression {
+        "@annually" => return Ok("@yearly".into()),
+        "@midnight" => return Ok("@daily".into()),
+        e if e.starts_with('@') => return Ok(e.into()),
+        _ => {}
+    }
+
+    let mut fields: Vec<String> = expression.split_whitespace().map(String::from).collect();
+    match fields.len() {
+        5 => fields.insert(0, "0".into()),
+        6 | 7 => {}
+        n => return Err(format!("expected 5, 6 or 7 fields, got {n}")),
+    }
+    fields[5] = normalize_day_of_week(&fields[5])?;
+    Ok(fields.join(" "))
+}
+
+fn normalize_day_of_week(field: &str) -> Result<String, String> {
+    const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    let parse_day = |s: &str| -> Result<usize, String> {
+        match s.parse::<usize>() {
+            Ok(d) if d <= 7 => Ok(d % 7),
+            _ => Err(format!("invalid day of week: {s}")),
+        }
+    };
+
+    let mut out = Vec::new();
+    for part in field.split(',') {
+        // Steps are expanded here, before days are renamed: "0/2" is every
+        // other day from Sunday, not just Sunday
+        let (range, step) = match part.split_once('/') {
+            Some((r, s)) => (
+                r,
+                s.parse::<usize>()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(|| format!("invalid step: {part}"))?,
+            ),
+            None => (part, 1),
+        };
+        let bounds = if range == "*" || range == "?" {
+            if step == 1 {
+                out.push(range.to_string());
+                continue;
+            }
+            Some((0, 6))
+        } else if let Some((a, b)) = range.split_once('-') {
+            match (a.parse::<usize>(), b.parse::<usize>()) {
+                (Ok(_), Ok(_)) => {
+                    let start = parse_day(a)?;
+                    // "5-7" means Friday through Sunday
+                    let end = if b == "7" { 7 } else { parse_day(b)? };
+                    Some((start, end))
+                }
+                _ => None,
+            }
+        } else if range.chars().all(|c| c.is_ascii_digit()) {
+            let start = parse_day(range)?;
+            // "N/step" runs from N to the end of the week
+            Some((start, if part.contains('/') { 6 } else { start }))
+        } else {
+            None
+        };
+
+        match bounds {
+            Some((start, end)) if start <= end => {
+                out.extend((start..=end).step_by(step).map(|d| DAYS[d % 7].to_string()));
+            }
+            Some(_) => return Err(format!("invalid day-of-week range: {part}")),
+            // Named days (e.g. MON-FRI) already mean the same thing in both dialects
+            None => out.push(part.to_string()),
+        }
+    }
+    out.dedup();
+    Ok(out.join(","))
+}
+
+fn parse_cron_schedule(expression: &str) -> Result<cron::Schedule, String> {
+    let normalized = normalize_cron_expression(expression)?;
+    cron::Schedule::from_str(&normalized).map_err(|e| format!("invalid cron expression: {e}"))
+}
+
+/// Find the next fire time strictly after `after`, evaluating the expression
+/// in wall-clock time of `tz`. Across DST transitions, times that fall in a
+/// spring-forward gap fire as soon as the clock resumes, and fixed-hour jobs
+/// fire only once during a fall-back overlap.
+fn next_cron_fire<Z: TimeZone>(
+    expression: &str,
+    tz: &Z,
+    after: &chrono::DateTime<chrono::Utc>,
+) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    use chrono::LocalResult;
+
+    let schedule = parse_cron_schedule(expression)?;
+    let normalized = normalize_cron_expression(expression)?;
+    let repeats_hourly = match normalized.split_whitespace().nth(2) {
+        Some(hour) => hour == "*" || hour.contains('/') || hour.contains('-'),
+        None => normalized == "@hourly",
+    };
+
+    // Walk candidate wall-clock times; the schedule is evaluated on naive
+    // local time by pretending it is UTC.
+    let local_after = after.with_timezone(tz).naive_local();
+    let mut cursor = chrono::Utc.from_utc_datetime(&local_after) - chrono::Duration::hours(3);
+    for _ in 0..1000 {
+        let naive = match schedule.after(&cursor).next() {
+            Some(t) => t.naive_utc(),
+            None => return Err("cron expression never fires".into()),
+        };
+        cursor = chrono::Utc.from_utc_datetime(&naive);
+
+        let candidate = match tz.from_local_datetime(&naive) {
+            LocalResult::Single(t) => Some(t),
+            LocalResult::Ambiguous(early, late) => {
+                if early.with_timezone(&chrono::Utc) > *after {
+                    Some(early)
+                } else if repeats_hourly {
+                    Some(late)
+                } else {
+                    None
+                }
+            }
+            LocalResult::None => tz
+                .from_local_datetime(&(naive + chrono::Duration::hours(1)))
+                .earliest(),
+        };
+        if let Some(t) = candidate {
+            let utc = t.with_timezone(&chrono::Utc);
+            if utc > *after {
+                return Ok(utc);
+            }
+        }
+    }
+    Err("could not find next fire time".into())
+}
+
+/// Follow-up edges of every job: job id → [on_success, on_failure] targets
+fn load_chain_edges(conn: &Connection) -> HashMap<String, Vec<String>> {
+    conn.prepare("SELECT id, on_success_job_id, on_failure_job_id FROM cron_jobs")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| {
+                let targets = [
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                ];
+                Ok((
+                    row.get::<_, String>(0)?,
+                    targets
+                        .into_iter()
+                        .flatten()
+                        .filter(|t| !t.is_empty())
+                        .collect(),
+                ))
+            })
+            .map(|rows| rows.filter_map(|r| r.ok()).collect())
+        })
+        .unwrap_or_default()
+}
+
+/// Whether `start` can reach itself by following chain edges
+fn chain_has_cycle(edges: &HashMap<String, Vec<String>>, start: &str) -> bool {
+    let mut stack: Vec<&str> = edges
+        .get(start)
+        .map(|t| t.iter().map(String::as_str).collect())
+        .unwrap_or_default();
+    let mut seen = std::collections::HashSet::new();
+    while let Some(id) = stack.pop() {
+        if id == start {
+            return true;
+        }
+        if seen.insert(id) {
+            if let Some(targets) = edges.get(id) {
+                stack.extend(targets.iter().map(String::as_str));
+            }
+        }
+    }
+    false
+}
+
+fn clear_chain_references(conn: &Connection, job_id: &str) {
+    let _ = conn.execute(
+        "UPDATE cron_jobs SET on_success_job_id='' WHERE on_success_job_id=?1",
+        params![job_id],
+    );
+    let _ = conn.execute(
+        "UPDATE cron_jobs SET on_failure_job_id='' WHERE on_failure_job_id=?1",
+        params![job_id],
+    );
+}
+
+/// Quote a value for safe interpolation into an `sh` command line
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Largest char boundary in `s` that is <= `index`
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn output_page(s: &str, offset: u64, len: u64) -> &str {
+    let start = floor_char_boundary(s, offset as usize);
+    let end = floor_char_boundary(s, (offset as usize).saturating_add(len as usize));
+    &s[start..end.max(start)]
+}
+
+/// Cut `s` to at most `max` bytes without splitting a UTF-8 character
+fn truncate_output(mut s: String, max: usize, marker: &str) -> String {
+    if s.len() > max {
+        s.truncate(floor_char_boundary(&s, max));
+        s.push_str(marker);
+    }
+    s
+}
+
+fn format_interval(ms: u64) -> String {
+    if ms >= 86_400_000 {
+        format!("每 {} 天", ms / 86_400_000)
+    } else if ms >= 3_600_000 {
+        format!("每 {} 小时", ms / 3_600_000)
+    } else if ms >= 60_000 {
+        format!("每 {} 分钟", ms / 60_000)
+    } else {
+        format!("每 {} 秒", ms / 1000)
+    }
+}
+
+// ──────────────────── Execution ──────────────────────────────
+
+/// Execute a cron job immediately (manual trigger).
+/// The job's schedule is left untouched.
+pub async fn run_cron_job_now(job_id: String) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+
+    let job = match load_job(&conn, &job_id) {
+        Ok(j) => j,
+        Err(e) => return format!("error: job not found: {e}"),
+    };
+    drop(conn);
+
+    let Some(_guard) = try_begin_execution(&job) else {
+        return "error: job is already running".into();
+    };
+
+    let outcome = run_chain(&job).await;
+    if outcome.status == "ok" {
+        format!("ok: {}", outcome.output)
+    } else {
+        format!("error: {}", outcome.output)
+    }
+}
+
+/// The previous step of a chain, exposed to the next job
+struct ChainContext {
+    job_name: String,
+    status: String,
+    output: String,
+    /// `output` written to a file for shell follow-ups, whose command line
+    /// is too small to hold it
+    output_file: Option<PathBuf>,
+}
+
+/// Environment variable naming the file that holds the previous output
+const PREV_OUTPUT_FILE_VAR: &str = "CRON_PREV_OUTPUT_FILE";
+
+/// Fill `{{prev_*}}` placeholders in a follow-up job's prompt and command
+fn apply_chain_context(job: &CronJobDto, ctx: &ChainContext) -> CronJobDto {
+    let mut job = job.clone();
+    let vars = [
+        ("{{prev_status}}", &ctx.status),
+        ("{{prev_job}}", &ctx.job_name),
+        ("{{prev_output}}", &ctx.output),
+    ];
+    for (name, value) in vars {
+        job.prompt = job.prompt.replace(name, value);
+    }
+    for (name, value) in &vars[..2] {
+        job.command = job.command.replace(name, &shell_quote(value));
+    }
+    match &ctx.output_file {
+        Some(path) => {
+            job.command = job.command.replace(
+                "{{prev_output}}",
+                &format!("\"$(cat \"${PREV_OUTPUT_FILE_VAR}\")\""),
+            );
+            job.sandbox.env.push(CronEnvVarDto {
+                name: PREV_OUTPUT_FILE_VAR.into(),
+                value: path.to_string_lossy().to_string(),
+                secret: false,
+            });
+        }
+        None => job.command = job.command.replace("{{prev_output}}", "''"),
+    }
+    job
+}
+
+/// What a step hands to the next one: stdout, or stderr when it failed
+fn chain_output(outcome: &JobOutcome) -> String {
+    if outcome.status != "ok" && !outcome.stderr.trim().is_empty() {
+        outcome.stderr.clone()
+    } else {
+        outcome.stdout.clone()
+    }
+}
+
+/// Write the previous output for a shell follow-up. The file lives next to
+/// jobs.db, is readable by the owner only and is removed once the follow-up
+/// has run.
+fn write_chain_output(chain_id: &str, output: &str) -> Option<PathBuf> {
+    let path = db_path()
+        .with_file_name("chain")
+        .join(format!("{chain_id}.out"));
+    let written = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| {
+            use std::io::Write;
+            let mut options = std::fs::OpenOptions::new();
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::OpenOptionsExt;
+                options.mode(0o600);
+            }
+            options.open(&path)?.write_all(output.as_bytes())
+        });
+    match written {
+        Ok(()) => Some(path),
+        Err(e) => {
+            tracing::warn!("Cron chain {chain_id}: failed to write previous output: {e}");
+            let _ = std::fs::remove_file(&path);
+            None
+        }
+    }
+}
+
+/// Run `root` and then its on_success/on_failure follow-ups, all under one
+/// chain id. Returns the root job's outcome. The caller holds the root's
+/// execution guard; follow-ups that are still running elsewhere (with
+/// no_overlap) end the chain.
+async fn run_chain(root: &CronJobDto) -> JobOutcome {
+    let chain_id = uuid::Uuid::new_v4().to_string();
+    let root_outcome = execute_job(root, &chain_id).await;
+
+    let mut visited = std::collections::HashSet::from([root.id.clone()]);
+    let mut prev = root.clone();
+    let mut outcome_status = root_outcome.status.clone();
+    let mut outcome_output = chain_output(&root_outcome);
+    loop {
+        let next_id = if outcome_status == "ok" {
+            &prev.on_success
+        } else {
+            &prev.on_failure
+        };
+        // Cycles are rejected when chains are configured; this guards
+        // against anything that slipped into the database directly.
+        if next_id.is_empty() || !visited.insert(next_id.clone()) {
+            break;
+        }
+        let next =
+            match open_db().and_then(|conn| load_job(&conn, next_id).map_err(|e| e.to_string())) {
+                Ok(j) => j,
+                Err(e) => {
+                    tracing::warn!("Cron chain {chain_id}: follow-up {next_id} unavailable: {e}");
+                    break;
+                }
+            };
+        let Some(_guard) = try_begin_execution(&next) else {
+            tracing::warn!("Cron chain {chain_id}: follow-up {next_id} is already running");
+            break;
+        };
+
+        let ctx = ChainContext {
+            job_name: if prev.name.is_empty() {
+                prev.id.clone()
+            } else {
+                prev.name.clone()
+            },
+            output_file: if next.job_type == "agent" {
+                None
+            } else {
+                write_chain_output(&chain_id, &outcome_output)
+            },
+            status: outcome_status,
+            output: outcome_output,
+        };
+        let outcome = execute_job(&apply_chain_context(&next, &ctx), &chain_id).await;
+        if let Some(path) = &ctx.output_file {
+            let _ = std::fs::remove_file(path);
+        }
+        outcome_output = chain_output(&outcome);
+        outcome_status = outcome.status;
+        prev = next;
+    }
+
+    root_outcome
+}
+
+/// Run a job, retrying failed attempts with exponential backoff.
+/// Every attempt is recorded in `cron_runs`; the UI is notified of the final result.
+async fn execute_job(job: &CronJobDto, chain_id: &str) -> JobOutcome {
+    let mut attempt = 1;
+    let (outcome, started, finished) = loop {
+        let started = chrono::Utc::now();
+
+        // Execute based on job type
+        let outcome = if job.job_type == "agent" {
+            run_agent_job(job).await
+        } else {
+            run_shell_job(job).await
+        };
+
+        let finished = chrono::Utc::now();
+        record_attempt(job, chain_id, attempt, &outcome, &started, &finished);
+
+        if outcome.status == "ok" || attempt > job.max_retries {
+            break (outcome, started, finished);
+        }
+        let delay = retry_delay_secs(job.retry_backoff_secs, attempt);
+        tracing::info!(
+            "Cron job {} attempt {attempt} failed, retrying in {delay}s",
+            job.id
+        );
+        tokio::time::sleep(tokio::time::Duration::from_secs(delay)).await;
+        attempt += 1;
+    };
+
+    if job.job_type == "agent" {
+        append_to_target_session(job, &outcome.status, &outcome.output).await;
+    }
+
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => {
+            tracing::error!("Failed to update cron job {}: {e}", job.id);
+            return outcome;
+        }
+    };
+
+    // Update job with last run info
+    let _ = conn.execute(
+        "UPDATE cron_jobs SET last_run=?1, last_status=?2, last_output=?3, \
+         consecutive_failures = CASE WHEN ?2 = 'ok' THEN 0 \
+         ELSE COALESCE(consecutive_failures, 0) + 1 END WHERE id=?4",
+        params![
+            started.to_rfc3339(),
+            &outcome.status,
+            &outcome.output,
+            job.id
+        ],
+    );
+    let auto_disabled = outcome.status != "ok" && auto_disable_if_failing(&conn, &job.id);
+
+    // Emit notification to Flutter UI and the job's delivery targets
+    let notification = super::cron_notification_api::CronNotification {
+        job_id: job.id.clone(),
+        job_name: job.name.clone(),
+        job_type: job.job_type.clone(),
+        session_target: job.session_target.clone(),
+        target_session_id: job.target_session_id.clone(),
+        status: outcome.status.clone(),
+        output: outcome.output.clone(),
+        prompt: job.prompt.clone(),
+        duration_ms: (finished - started).num_milliseconds(),
+        finished_at: finished.timestamp(),
+        auto_disabled,
+    };
+    if !job.delivery.is_empty() {
+        tokio::spawn(deliver_results(job.delivery.clone(), notification.clone()));
+    }
+    super::cron_notification_api::emit_notification(notification);
+
+    // Handle delete_after_run
+    if job.delete_after_run {
+        let _ = conn.execute("DELETE FROM cron_jobs WHERE id = ?1", params![job.id]);
+        clear_chain_references(&conn, &job.id);
+    }
+
+    outcome
+}
+
+/// Disable a job whose failure streak reached the configured limit.
+/// Returns true if this call disabled it.
+fn auto_disable_if_failing(conn: &Connection, job_id: &str) -> bool {
+    let limit = auto_disable_after(conn);
+    if limit == 0 {
+        return false;
+    }
+    let disabled = conn
+        .execute(
+            "UPDATE cron_jobs SET enabled = 0 \
+             WHERE id = ?1 AND enabled = 1 AND consecutive_failures >= ?2",
+            params![job_id, limit],
+        )
+        .unwrap_or(0)
+        > 0;
+    if disabled {
+        tracing::warn!("Cron job {job_id} disabled after {limit} consecutive failures");
+    }
+    disabled
+}
+
+fn record_attempt(
+    job: &CronJobDto,
+    chain_id: &str,
+    attempt: u32,
+    outcome: &JobOutcome,
+    started: &chrono::DateTime<chrono::Utc>,
+    finished: &chrono::DateTime<chrono::Utc>,
+) {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => {
+            tracing::error!("Failed to record cron run for {}: {e}", job.id);
+            return;
+        }
+    };
+    let _ = conn.execute(
+        "INSERT INTO cron_runs (job_id, started_at, finished_at, status, output, duration_ms, \
+         attempt, stdout, stderr, exit_code, chain_id, stdout_truncated, stderr_truncated) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        params![
+            job.id,
+            started.to_rfc3339(),
+            finished.to_rfc3339(),
+            outcome.status,
+            outcome.output,
+            (*finished - *started).num_milliseconds(),
+            attempt,
+            outcome.stdout,
+            outcome.stderr,
+            outcome.exit_code,
+            chain_id,
+            outcome.stdout_truncated,
+            outcome.stderr_truncated,
+        ],
+    );
+    prune_run_history(&conn, &job.id);
+}
+
+/// One row of `cron_runs`, as far as run statistics are concerned
+struct RunAttempt {
+    ok: bool,
+    duration_ms: i64,
+    attempt: u32,
+}
+
+/// Count (succeeded, failed) runs from chronologically ordered attempts.
+/// A run ends at its last attempt, i.e. the one before the next first
+/// attempt. A trailing failure may still be retried, but counts as failed.
+fn count_runs(attempts: &[RunAttempt]) -> (u32, u32) {
+    let mut succeeded = 0;
+    let mut failed = 0;
+    for (i, attempt) in attempts.iter().enumerate() {
+        let last_of_run = attempts
+            .get(i + 1)
+            .is_none_or(|next| next.attempt <= attempt.attempt);
+        if attempt.ok {
+            succeeded += 1;
+        } else if last_of_run {
+            failed += 1;
+        }
+    }
+    (succeeded, failed)
+}
+
+/// Nearest-rank percentile of sorted values (0 when empty)
+fn percentile(sorted: &[i64], pct: usize) -> i64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Backoff before retrying after `attempt` failed: base, 2×base, 4×base, …
+fn retry_delay_secs(base_secs: u32, attempt: u32) -> u64 {
+    let factor = 1u64 << (attempt.saturating_sub(1)).min(16);
+    (base_secs as u64 * factor).min(MAX_RETRY_DELAY_SECS)
+}
+
+/// Result of a single job attempt
+struct JobOutcome {
+    status: String,
+    /// Short summary kept on the job and shown in run lists and notifications
+    output: String,
+    stdout: String,
+    stderr: String,
+    exit_code: Option<i32>,
+    /// Whether stdout / stderr were cut off at `MAX_CAPTURED_OUTPUT_BYTES`
+    stdout_truncated: bool,
+    stderr_truncated: bool,
+}
+
+impl JobOutcome {
+    fn failed(message: String) -> Self {
+        Self {
+            status: "error".into(),
+            output: message.clone(),
+            stdout: String::new(),
+            stderr: message,
+            exit_code: None,
+            stdout_truncated: false,
+            stderr_truncated: false,
+        }
+    }
+}
+
+/// Read a child's output stream to the end, keeping at most
+/// `MAX_CAPTURED_OUTPUT_BYTES` so a chatty job can't exhaust memory.
+/// Returns the captured text and whether anything past the limit was dropped.
+async fn capture_stream<R: tokio::io::AsyncRead + Unpin>(reader: Option<R>) -> (String, bool) {
+    use tokio::io::AsyncReadExt;
+
+    let Some(mut reader) = reader else {
+        return (String::new(), false);
+    };
+    let mut captured = Vec::new();
+    let mut buf = [0u8; 8192];
+    let mut dropped = false;
+    loop {
+        match reader.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => {
+                let room = MAX_CAPTURED_OUTPUT_BYTES.saturating_sub(captured.len());
+                captured.extend_from_slice(&buf[..n.min(room)]);
+                dropped |= n > room;
+            }
+        }
+    }
+    (String::from_utf8_lossy(&captured).into_owned(), dropped)
+}
+
+/// Execute a shell command job. The command runs in its own process group
+/// so a timeout kills everything it spawned, not just the shell.
+async fn run_shell_job(job: &CronJobDto) -> JobOutcome {
+    match prepare_shell_launch(job).await {
+        Ok(launch) => execute_shell(job, &launch).await,
+        Err(e) => JobOutcome::failed(e),
+    }
+}
+
+/// Environment variables kept when a job doesn't inherit the app's environment
+const BASE_ENV_VARS: [&str; 9] = [
+    "PATH", "HOME", "USER", "LOGNAME", "SHELL", "LANG", "LC_ALL", "TMPDIR", "TZ",
+];
+
+/// Where and how to start a shell job's process
+#[frb(ignore)]
+#[derive(Debug, Default)]
+struct ShellLaunch {
+    working_dir: Option<PathBuf>,
+    inherit_env: bool,
+    env: Vec<(String, String)>,
+    cpu_limit_secs: u64,
+    memory_limit_mb: u64,
+}
+
+/// Resolve a shell job's working directory and environment, and apply the
+/// autonomy policy the interactive shell tool uses: allowed_commands,
+/// forbidden_paths and allowed roots.
+async fn prepare_shell_launch(job: &CronJobDto) -> Result<ShellLaunch, String> {
+    let mut config = super::agent_api::config_state()
+        .read()
+        .await
+        .config
+        .clone()
+        .ok_or("runtime not initialized")?;
+
+    let project = if job.project_id.is_empty() {
+        None
+    } else {
+        super::project_api::project_info(&job.project_id).await
+    };
+    if let Some(project) = &project {
+        super::project_api::apply_autonomy_overrides(&mut config, &project.autonomy);
+    }
+    let project_dir = project
+        .map(|p| p.project_dir)
+        .filter(|dir| !dir.trim().is_empty());
+    if let Some(dir) = &project_dir {
+        if !config.autonomy.allowed_roots.contains(dir) {
+            config.autonomy.allowed_roots.push(dir.clone());
+        }
+    }
+    let working_dir = match job.sandbox.working_dir.trim() {
+        "" => project_dir
+            .map(PathBuf::from)
+            .unwrap_or_else(|| config.workspace_dir.clone()),
+        dir => expand_home(dir),
+    };
+
+    let policy =
+        zeroclaw::security::SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir);
+    if !policy.is_command_allowed(&job.command) {
+        return Err("command blocked by security policy (not in allowed_commands)".into());
+    }
+    if let Some(path) = policy.forbidden_path_argument(&job.command) {
+        return Err(format!("path blocked by security policy: {path}"));
+    }
+    if !policy.is_path_allowed(&working_dir.to_string_lossy()) {
+        return Err(format!(
+            "working directory blocked by security policy: {}",
+            working_dir.display()
+        ));
+    }
+    if !working_dir.is_dir() {
+        return Err(format!(
+            "working directory not found: {}",
+            working_dir.display()
+        ));
+    }
+
+    let conn = open_db()?;
+    let mut env = Vec::with_capacity(job.sandbox.env.len());
+    for var in &job.sandbox.env {
+        let value = if var.secret {
+            load_secret(&conn, &var.value)
+                .ok_or_else(|| format!("unknown secret: {}", var.value))?
+        } else {
+            var.value.clone()
+        };
+        env.push((var.name.clone(), value));
+    }
+
+    Ok(ShellLaunch {
+        working_dir: Some(working_dir),
+        inherit_env: job.sandbox.inherit_env,
+        env,
+        cpu_limit_secs: job.sandbox.cpu_limit_secs,
+        memory_limit_mb: job.sandbox.memory_limit_mb,
+    })
+}
+
+/// Cron secrets are encrypted with the same key as the provider API keys in
+/// config.toml, so a copy of jobs.db alone doesn't reveal them
+fn secret_store() -> zeroclaw::security::SecretStore {
+    let coraldesk_dir = dirs::home_dir().unwrap_or_default().join(".coraldesk");
+    zeroclaw::security::SecretStore::new(&coraldesk_dir, true)
+}
+
+fn load_secret(conn: &Connection, name: &str) -> Option<String> {
+    let stored: String = conn
+        .query_row(
+            "SELECT value FROM cron_secrets WHERE name = ?1",
+            params![name],
+            |row| row.get(0),
+        )
+        .ok()?;
+    match secret_store().decrypt(&stored) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            tracing::warn!("Failed to decrypt cron secret {name}: {e}");
+            None
+        }
+    }
+}
+
+fn encrypt_plaintext_secrets(conn: &Connection) {
+    let plaintext: Vec<(String, String)> = conn
+        .prepare("SELECT name, value FROM cron_secrets")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map(|rows| rows.filter_map(|r| r.ok()).collect())
+        })
+        .unwrap_or_default();
+    let plaintext: Vec<_> = plaintext
+        .into_iter()
+        .filter(|(_, value)| !zeroclaw::security::SecretStore::is_encrypted(value))
+        .collect();
+    if plaintext.is_empty() {
+        return;
+    }
+    let store = secret_store();
+    for (name, value) in plaintext {
+        match store.encrypt(&value) {
+            Ok(encrypted) => {
+                let _ = conn.execute(
+                    "UPDATE cron_secrets SET value = ?1 WHERE name = ?2",
+                    params![encrypted, name],
+                );
+            }
+            Err(e) => tracing::warn!("Failed to encrypt cron secret {name}: {e}"),
+        }
+    }
+}
+
+fn is_valid_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+async fn execute_shell(job: &CronJobDto, launch: &ShellLaunch) -> JobOutcome {
+    use tokio::process::Command;
+
+    let mut cmd = Command::new("sh");
+    cmd.arg("-lc")
+        .arg(&job.command)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true);
+    if let Some(dir) = &launch.working_dir {
+        cmd.current_dir(dir);
+    }
+    if !launch.inherit_env {
+        cmd.env_clear();
+        for name in BASE_ENV_VARS {
+            if let Some(value) = std::env::var_os(name) {
+                cmd.env(name, value);
+            }
+        }
+    }
+    cmd.envs(launch.env.iter().map(|(k, v)| (k, v)));
+    #[cfg(unix)]
+    {
+        cmd.process_group(0);
+        apply_rlimits(&mut cmd, launch.cpu_limit_secs, launch.memory_limit_mb);
+    }
+
+    let mut child = match cmd.spawn() {
+        Ok(c) => c,
+        Err(e) => return JobOutcome::failed(format!("failed to execute: {e}")),
+    };
+    let pid = child.id();
+    let stdout_task = tokio::spawn(capture_stream(child.stdout.take()));
+    let stderr_task = tokio::spawn(capture_stream(child.stderr.take()));
+
+    let status = if job.timeout_secs > 0 {
+        let limit = tokio::time::Duration::from_secs(job.timeout_secs as u64);
+        match tokio::time::timeout(limit, child.wait()).await {
+            Ok(r) => Some(r),
+            Err(_) => {
+                kill_process_group(pid);
+                let _ = child.kill().await;
+                None
+            }
+        }
+    } else {
+        Some(child.wait().await)
+    };
+
+    // Killing the process group closes the pipes, so the readers finish
+    // promptly even after a timeout; keep whatever was captured.
+    let (stdout, stdout_truncated) = stdout_task.await.unwrap_or_default();
+    let (stderr, stderr_truncated) = stderr_task.await.unwrap_or_default();
+
+    let (status, exit_code, mut summary) = match status {
+        Some(Ok(exit)) => {
+            let combined = if stderr.is_empty() {
+                stdout.clone()
+            } else if stdout.is_empty() {
+                stderr.clone()
+            } else {
+                format!("{stdout}\n{stderr}")
+            };
+            let status = if exit.success() { "ok" } else { "error" };
+            (status, exit.code(), combined)
+        }
+        Some(Err(e)) => ("error", None, format!("failed to execute: {e}")),
+        None => (
+            "error",
+            None,
+            format!("timed out after {}s", job.timeout_secs),
+        ),
+    };
+    summary = truncate_output(summary, OUTPUT_SUMMARY_BYTES, "...(truncated)");
+
+    JobOutcome {
+        status: status.into(),
+        output: summary,
+        stdout,
+        stderr,
+        exit_code,
+        stdout_truncated,
+        stderr_truncated,
+    }
+}
+
+/// Limit CPU time and address space of the child process
+#[cfg(unix)]
+fn apply_rlimits(cmd: &mut tokio::process::Command, cpu_limit_secs: u64, memory_limit_mb: u64) {
+    if cpu_limit_secs == 0 && memory_limit_mb == 0 {
+        return;
+    }
+    let memory_bytes = memory_limit_mb.saturating_mul(1024 * 1024);
+    // SAFETY: the hook runs in the forked child before exec and only calls
+    // setrlimit, which is async-signal-safe.
+    unsafe {
+        cmd.pre_exec(move || {
+            if cpu_limit_secs > 0 {
+                let limit = libc::rlimit {
+                    rlim_cur: cpu_limit_secs as libc::rlim_t,
+                    rlim_max: cpu_limit_secs as libc::rlim_t,
+                };
+                if libc::setrlimit(libc::RLIMIT_CPU, &limit) != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            if memory_bytes > 0 {
+                let limit = libc::rlimit {
+                    rlim_cur: memory_bytes as libc::rlim_t,
+                    rlim_max: memory_bytes as libc::rlim_t,
+                };
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(unix)]
+fn kill_process_group(pid: Option<u32>) {
+    if let Some(pid) = pid {
+        // SAFETY: killpg has no memory-safety preconditions; the group id is
+        // the pid of the shell we spawned as a group leader.
+        unsafe {
+            libc::killpg(pid as libc::pid_t, libc::SIGKILL);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_process_group(_pid: Option<u32>) {}
+
+/// The session a "main" agent job reports into, if any
+fn main_target_session(job: &CronJobDto) -> Option<&str> {
+    (job.session_target == "main" && !job.target_session_id.is_empty())
+        .then_some(job.target_session_id.as_str())
+}
+
+/// Execute an agent job with the same setup as an interactive session:
+/// project directory, agent-workspace binding, MCP tools and the job's model
+/// override. "main" jobs share the target session's workspace, attached
+/// files and project binding, but start from an empty conversation: the
+/// session's chat history isn't replayed, only the job's prompt and result
+/// are appended to it afterwards. Isolated jobs get a `cron_<job_id>`
+/// workspace of their own.
+async fn run_agent_job(job: &CronJobDto) -> JobOutcome {
+    let (session_id, session_files) = match main_target_session(job) {
+        Some(sid) => (
+            sid.to_string(),
+            super::sessions_api::get_session_files(sid.to_string()).await,
+        ),
+        None => (format!("cron_{}", job.id), vec![]),
+    };
+    let project_id = (!job.project_id.is_empty()).then_some(job.project_id.as_str());
+
+    let mut config =
+        match super::agent_api::build_session_config(&session_id, &session_files, project_id).await
+        {
+            Ok(c) => c,
+            Err(e) => return JobOutcome::failed(e),
+        };
+    if !job.model.is_empty() {
+        config.default_model = Some(job.model.clone());
+    }
+
+    let project_id = match project_id {
+        Some(id) => Some(id.to_string()),
+        None => super::project_api::get_session_project(session_id.clone()).await,
+    };
+    let mut agent = match super::agent_api::build_agent(&config, project_id.as_deref(), None).await
+    {
+        Ok(a) => a,
+        Err(e) => return JobOutcome::failed(e),
+    };
+
+    // Nobody is around to answer approval prompts, so tool calls that would
+    // need one are denied unless the user runs in trust-me mode.
+    let deny_approval: zeroclaw::agent::loop_::OnApprovalFn =
+        Box::new(|tool_name: String, _tool_args: serde_json::Value| {
+            Box::pin(async move {
+                tracing::info!(tool = %tool_name, "Denying tool call in unattended cron run");
+                zeroclaw::approval::ApprovalResponse::No
+            })
+        });
+    let on_approval = (!config.autonomy.trust_me).then_some(&deny_approval);
+
+    // Progress deltas aren't shown anywhere; drain them so the agent never blocks.
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(64);
+    tokio::spawn(async move { while rx.recv().await.is_some() {} });
+
+    let turn = agent.turn_streaming(&job.prompt, tx, None, on_approval);
+    let result = if job.timeout_secs > 0 {
+        let limit = tokio::time::Duration::from_secs(job.timeout_secs as u64);
+        match tokio::time::timeout(limit, turn).await {
+            Ok(r) => r.map_err(|e| format!("agent error: {e}")),
+            Err(_) => Err(format!("timed out after {}s", job.timeout_secs)),
+        }
+    } else {
+        turn.await.map_err(|e| format!("agent error: {e}"))
+    };
+
+    // A timed-out turn may already have written files; record them too
+    if let Some(project_id) = &project_id {
+        super::project_timeline_api::record_agent_file_changes(
+            project_id,
+            &session_id,
+            agent.history(),
+        )
+        .await;
+    }
+
+    match result {
+        Ok(response) => JobOutcome {
+            status: "ok".into(),
+            output: truncate_output(response.clone(), OUTPUT_SUMMARY_BYTES, "...(truncated)"),
+            stdout: response,
+            stderr: String::new(),
+            exit_code: None,
+            stdout_truncated: false,
+            stderr_truncated: false,
+        },
+        Err(e) => JobOutcome::failed(e),
+    }
+}
+
+/// Append a "main" agent job's prompt and result to its target session
+async fn append_to_target_session(job: &CronJobDto, status: &str, output: &str) {
+    let Some(session_id) = main_target_session(job) else {
+        return;
+    };
+    let now = chrono::Utc::now();
+    let name = if job.name.is_empty() {
+        &job.id
+    } else {
+        &job.name
+    };
+    let status_icon = if status == "ok" { "✅" } else { "❌" };
+    let message = |role: &str, content: String| super::sessions_api::SessionMessage {
+        id: format!("cron_{}_{}_{role}", job.id, now.timestamp_millis()),
+        role: role.into(),
+        content,
+        timestamp: now.timestamp(),
+        tool_calls_json: String::new(),
+        parts_json: String::new(),
+        agent_role: String::new(),
+        agent_color: String::new(),
+        agent_icon: String::new(),
+    };
+
+    let result = super::sessions_api::append_session_messages(
+        session_id,
+        vec![
+            message("user", format!("⏰ [{name}] {}", job.prompt)),
+            message("assistant", format!("{status_icon} [{name}]\n\n{output}")),
+        ],
+    )
+    .await;
+    if result != "ok" {
+        tracing::warn!("Cron job {}: failed to append to session: {result}", job.id);
+    }
+}
+
+// ──────────────────── Scheduler ──────────────────────────────
+
+const SCHEDULER_POLL_SECS: u64 = 30;
+/// A due job counts as missed once it is this many seconds late
+const MISFIRE_GRACE_SECS: i64 = 90;
+/// Upper bound on catch-up runs for the "run_all" misfire policy
+const MAX_CATCH_UP_RUNS: usize = 100;
+const DEFAULT_MAX_CONCURRENCY: u32 = 4;
+const DEFAULT_MAX_RUN_HISTORY: u32 = 50;
+const MISFIRE_POLICIES: [&str; 3] = ["skip", "run_once", "run_all"];
+const MAX_RETRIES: u32 = 10;
+/// Timeout given to newly created jobs
+const DEFAULT_TIMEOUT_SECS: u32 = 3600;
+const MAX_RETRY_DELAY_SECS: u64 = 3600;
+/// Size of the output summary stored on the job and sent with notifications
+const OUTPUT_SUMMARY_BYTES: usize = 2000;
+/// Per-stream cap on captured output
+const MAX_CAPTURED_OUTPUT_BYTES: usize = 10 * 1024 * 1024;
+
+struct ExecutingEntry {
+    job_name: String,
+    started_at: i64,
+    runs: usize,
+}
+
+/// In-flight runs per job id (scheduled and manual)
+fn executing_jobs() -> &'static std::sync::Mutex<HashMap<String, ExecutingEntry>> {
+    static EXECUTING: OnceLock<std::sync::Mutex<HashMap<String, ExecutingEntry>>> = OnceLock::new();
+    EXECUTING.get_or_init(|| std::sync::Mutex::new(HashMap::new()))
+}
+
+/// Marks a job as executing until dropped
+struct ExecutionGuard {
+    job_id: String,
+}
+
+impl Drop for ExecutionGuard {
+    fn drop(&mut self) {
+        let mut map = executing_jobs().lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = map.get_mut(&self.job_id) {
+            entry.runs -= 1;
+            if entry.runs == 0 {
+                map.remove(&self.job_id);
+            }
+        }
+    }
+}
+
+/// Register a run of `job`, or return None if the job forbids overlapping
+/// runs and one is already in flight.
+fn try_begin_execution(job: &CronJobDto) -> Option<ExecutionGuard> {
+    let mut map = executing_jobs().lock().unwrap_or_else(|e| e.into_inner());
+    let entry = map.entry(job.id.clone()).or_insert_with(|| ExecutingEntry {
+        job_name: job.name.clone(),
+        started_at: chrono::Utc::now().timestamp(),
+        runs: 0,
+    });
+    if job.no_overlap && entry.runs > 0 {
+        return None;
+    }
+    entry.runs += 1;
+    Some(ExecutionGuard {
+        job_id: job.id.clone(),
+    })
+}
+
+fn executing_count() -> usize {
+    executing_jobs()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .values()
+        .map(|e| e.runs)
+        .sum()
+}
+
+/// How many times a due job should run now, according to its misfire policy
+fn planned_runs(job: &CronJobDto, now: &chrono::DateTime<chrono::Utc>) -> usize {
+    let scheduled = chrono::DateTime::from_timestamp(job.next_run, 0).unwrap_or(*now);
+    if (*now - scheduled).num_seconds() <= MISFIRE_GRACE_SECS {
+        return 1;
+    }
+    match job.misfire_policy.as_str() {
+        "skip" => 0,
+        "run_all" if !job.delete_after_run => count_missed_runs(job, scheduled, now),
+        _ => 1,
+    }
+}
+
+fn count_missed_runs(
+    job: &CronJobDto,
+    first: chrono::DateTime<chrono::Utc>,
+    now: &chrono::DateTime<chrono::Utc>,
+) -> usize {
+    let timezone = normalize_timezone(Some(job.timezone.clone()));
+    let mut count = 1;
+    let mut last = first;
+    while count < MAX_CATCH_UP_RUNS {
+        match compute_next_run(
+            &job.schedule_type,
+            &job.expression,
+            timezone.as_deref(),
+            &last,
+        ) {
+            Ok(next) if next > last && next <= *now => {
+                count += 1;
+                last = next;
+            }
+            _ => break,
+        }
+    }
+    count
+}
+
+/// Move a due job's next_run past `now`. One-shot "at" jobs are disabled
+/// once they have fired; jobs whose schedule can no longer be evaluated are
+/// disabled instead of retried on every tick.
+fn advance_schedule(conn: &Connection, job: &CronJobDto, now: &chrono::DateTime<chrono::Utc>) {
+    let timezone = normalize_timezone(Some(job.timezone.clone()));
+    let (next_run, enabled) = if job.schedule_type == "at" {
+        (*now, false)
+    } else {
+        match compute_next_run(
+            &job.schedule_type,
+            &job.expression,
+            timezone.as_deref(),
+            now,
+        ) {
+            Ok(t) => (t, true),
+            Err(e) => {
+                tracing::warn!(
+                    "Cron job {}: cannot compute next run, disabling: {e}",
+                    job.id
+                );
+                (*now, false)
+            }
+        }
+    };
+    let _ = conn.execute(
+        "UPDATE cron_jobs SET next_run=?1, enabled=?2 WHERE id=?3",
+        params![next_run.to_rfc3339(), enabled as i32, job.id],
+    );
+}
+
+fn record_skipped_run(conn: &Connection, job: &CronJobDto, now: &chrono::DateTime<chrono::Utc>) {
+    let now_rfc = now.to_rfc3339();
+    let _ = conn.execute(
+        "INSERT INTO cron_runs (job_id, started_at, finished_at, status, output, duration_ms) \
+         VALUES (?1, ?2, ?2, 'skipped', ?3, 0)",
+        params![job.id, now_rfc, "missed run skipped by misfire policy"],
+    );
+    prune_run_history(conn, &job.id);
+}
+
+/// One scheduler pass: claim due jobs and spawn them, up to the concurrency limit.
+/// Jobs that can't start yet (limit reached, or still running with no_overlap)
+/// stay due and are picked up on a later tick.
+fn scheduler_tick() {
+    let now = chrono::Utc::now();
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => {
+            tracing::error!("Failed to open cron db: {e}");
+            return;
+        }
+    };
+
+    let sql = format!(
+        "SELECT {JOB_COLUMNS} FROM cron_jobs WHERE enabled = 1 AND next_run <= ?1 \
+         AND COALESCE(json_extract(schedule, '$.kind'), 'cron') NOT IN ('watch', 'webhook') \
+         ORDER BY next_run ASC"
+    );
+    let due: Vec<CronJobDto> = match conn.prepare(&sql) {
+        Ok(mut stmt) => stmt
+            .query_map(params![now.to_rfc3339()], row_to_dto)
+            .map(|rows| rows.filter_map(|r| r.ok()).collect())
+            .unwrap_or_default(),
+        Err(e) => {
+            tracing::error!("Failed to query due cron jobs: {e}");
+            return;
+        }
+    };
+
+    let global_window = global_active_window(&conn);
+    let mut slots = (max_concurrency(&conn) as usize).saturating_sub(executing_count());
+    for job in due {
+        if slots == 0 {
+            break;
+        }
+        let window = job.active_window.as_ref().unwrap_or(&global_window);
+        match check_active_window(&job, window, &now) {
+            WindowCheck::Open => {}
+            WindowCheck::OpensAt(opens) => {
+                let _ = conn.execute(
+                    "UPDATE cron_jobs SET next_run=?1 WHERE id=?2",
+                    params![opens.to_rfc3339(), job.id],
+                );
+                continue;
+            }
+            // Left due; checked again on the next tick
+            WindowCheck::Closed => continue,
+        }
+        let Some(guard) = try_begin_execution(&job) else {
+            continue;
+        };
+
+        let runs = planned_runs(&job, &now);
+        advance_schedule(&conn, &job, &now);
+        if runs == 0 {
+            record_skipped_run(&conn, &job, &now);
+            continue;
+        }
+
+        slots -= 1;
+        tokio::spawn(async move {
+            let _guard = guard;
+            for _ in 0..runs {
+                run_chain(&job).await;
+            }
+        });
+    }
+}
+
+struct CronScheduler {
+    /// Handle to the polling loop
+    handle: Option<JoinHandle<()>>,
+    /// File watcher and webhook listener, started and stopped with the loop
+    triggers: Vec<JoinHandle<()>>,
+    last_tick: Option<i64>,
+}
+
+fn cron_scheduler() -> &'static TokioMutex<CronScheduler> {
+    static SCHEDULER: OnceLock<TokioMutex<CronScheduler>> = OnceLock::new();
+    SCHEDULER.get_or_init(|| {
+        TokioMutex::new(CronScheduler {
+            handle: None,
+            triggers: Vec::new(),
+            last_tick: None,
+        })
+    })
+}
+
+/// Start the background cron scheduler that polls for due jobs.
+/// Does nothing if the scheduler has been disabled in the cron settings.
+pub async fn start_cron_scheduler() -> String {
+    let (enabled, port) = match open_db() {
+        Ok(conn) => (scheduler_enabled(&conn), webhook_port(&conn)),
+        Err(e) => return format!("error: {e}"),
+    };
+    if !enabled {
+        return "disabled".into();
+    }
+
+    let mut scheduler = cron_scheduler().lock().await;
+    if scheduler
+        .handle
+        .as_ref()
+        .is_some_and(|handle| !handle.is_finished())
+    {
+        return "already running".into();
+    }
+
+    scheduler.handle = Some(tokio::spawn(async move {
+        loop {
+            scheduler_tick();
+            cron_scheduler().lock().await.last_tick = Some(chrono::Utc::now().timestamp());
+            tokio::time::sleep(tokio::time::Duration::from_secs(SCHEDULER_POLL_SECS)).await;
+        }
+    }));
+    for handle in scheduler.triggers.drain(..) {
+        handle.abort();
+    }
+    scheduler.triggers = vec![tokio::spawn(watch_loop()), tokio::spawn(webhook_loop(port))];
+
+    tracing::info!("Cron scheduler started");
+    "started".into()
+}
+
+/// Stop the polling loop. Runs already in flight are left to finish.
+pub async fn stop_cron_scheduler() -> String {
+    let mut scheduler = cron_scheduler().lock().await;
+    for handle in scheduler.triggers.drain(..) {
+        handle.abort();
+    }
+    if let Some(handle) = scheduler.handle.take() {
+        handle.abort();
+        let _ = handle.await;
+        tracing::info!("Cron scheduler stopped");
+    }
+    "ok".into()
+}
+
+/// Persist the scheduler on/off switch and start or stop it accordingly
+pub async fn set_cron_scheduler_enabled(enabled: bool) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    if let Err(e) = set_setting(&conn, "enabled", if enabled { "true" } else { "false" }) {
+        return format!("error: {e}");
+    }
+    drop(conn);
+
+    if enabled {
+        start_cron_scheduler().await;
+    } else {
+        stop_cron_scheduler().await;
+    }
+    "ok".into()
+}
+
+/// Get the scheduler's live state and the runs currently in flight
+pub async fn get_cron_scheduler_status() -> CronSchedulerStatus {
+    let enabled = open_db()
+        .map(|conn| scheduler_enabled(&conn))
+        .unwrap_or(true);
+    let (running, last_tick) = {
+        let scheduler = cron_scheduler().lock().await;
+        let running = scheduler
+            .handle
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished());
+        (running, scheduler.last_tick)
+    };
+
+    let mut executing: Vec<CronExecutingJob> = executing_jobs()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .map(|(job_id, entry)| CronExecutingJob {
+            job_id: job_id.clone(),
+            job_name: entry.job_name.clone(),
+            started_at: entry.started_at,
+            runs: entry.runs as u32,
+        })
+        .collect();
+    executing.sort_by_key(|e| e.started_at);
+
+    CronSchedulerStatus {
+        enabled,
+        running,
+        last_tick,
+        executing,
+    }
+}
+
+// ──────────────────── Import / Export ────────────────────────
+
+/// A cron file: the jobs to create or update
+#[frb(ignore)]
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CronJobFile {
+    #[serde(default)]
+    jobs: Vec<CronJobSpec>,
+}
+
+/// One job in a cron file. Omitted fields take the same defaults as jobs
+/// created in the app.
+#[frb(ignore)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct CronJobSpec {
+    name: String,
+    job_type: String,
+    schedule_type: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    expression: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    timezone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    watch_debounce_ms: Option<u64>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    command: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    prompt: String,
+    session_target: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    target_session_id: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    model: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    project_id: String,
+    enabled: bool,
+    delete_after_run: bool,
+    misfire_policy: String,
+    no_overlap: bool,
+    timeout_secs: u32,
+    max_retries: u32,
+    retry_backoff_secs: u32,
+    /// Follow-up job names
+    #[serde(skip_serializing_if = "String::is_empty")]
+    on_success: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    on_failure: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    delivery: Vec<CronDeliverySpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active_window: Option<CronActiveWindowDto>,
+    #[serde(skip_serializing_if = "is_default_sandbox")]
+    sandbox: CronShellSandboxDto,
+}
+
+fn is_default_sandbox(sandbox: &CronShellSandboxDto) -> bool {
+    *sandbox == CronShellSandboxDto::default()
+}
+
+impl Default for CronJobSpec {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            job_type: "shell".into(),
+            schedule_type: "cron".into(),
+            expression: String::new(),
+            timezone: String::new(),
+            watch_debounce_ms: None,
+            command: String::new(),
+            prompt: String::new(),
+            session_target: "isolated".into(),
+            target_session_id: String::new(),
+            model: String::new(),
+            project_id: String::new(),
+            enabled: true,
+            delete_after_run: false,
+            misfire_policy: "run_once".into(),
+            no_overlap: true,
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+            max_retries: 0,
+            retry_backoff_secs: 30,
+            on_success: String::new(),
+            on_failure: String::new(),
+            delivery: vec![],
+            active_window: None,
+            sandbox: CronShellSandboxDto::default(),
+        }
+    }
+}
+
+#[frb(ignore)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct CronDeliverySpec {
+    channel: String,
+    to: String,
+    on_success: bool,
+    on_failure: bool,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    template: String,
+}
+
+impl Default for CronDeliverySpec {
+    fn default() -> Self {
+        Self {
+            channel: String::new(),
+            to: String::new(),
+            on_success: true,
+            on_failure: true,
+            template: String::new(),
+        }
+    }
+}
+
+impl From<&CronDeliverySpec> for CronDeliveryDto {
+    fn from(spec: &CronDeliverySpec) -> Self {
+        Self {
+            channel: spec.channel.clone(),
+            to: spec.to.clone(),
+            on_success: spec.on_success,
+            on_failure: spec.on_failure,
+            template: spec.template.clone(),
+        }
+    }
+}
+
+/// Name a job is exported and matched under; unnamed jobs use their id
+fn export_name(job: &CronJobDto) -> String {
+    if job.name.is_empty() {
+        job.id.clone()
+    } else {
+        job.name.clone()
+    }
+}
+
+/// `names` maps job ids to export names, for chain targets
+fn job_to_spec(
+    conn: &Connection,
+    job: &CronJobDto,
+    names: &HashMap<String, String>,
+) -> CronJobSpec {
+    let watch_debounce_ms = if job.schedule_type == "watch" {
+        load_schedule_json(conn, &job.id)
+            .and_then(|val| val.get("debounce_ms").and_then(|v| v.as_u64()))
+    } else {
+        None
+    };
+    let name_of = |id: &String| names.get(id).cloned().unwrap_or_default();
+    CronJobSpec {
+        name: export_name(job),
+        job_type: job.job_type.clone(),
+        schedule_type: job.schedule_type.clone(),
+        expression: job.expression.clone(),
+        timezone: job.timezone.clone(),
+        watch_debounce_ms,
+        command: job.command.clone(),
+        prompt: job.prompt.clone(),
+        session_target: job.session_target.clone(),
+        target_session_id: job.target_session_id.clone(),
+        model: job.model.clone(),
+        project_id: job.project_id.clone(),
+        enabled: job.enabled,
+        delete_after_run: job.delete_after_run,
+        misfire_policy: job.misfire_policy.clone(),
+        no_overlap: job.no_overlap,
+        timeout_secs: job.timeout_secs,
+        max_retries: job.max_retries,
+        retry_backoff_secs: job.retry_backoff_secs,
+        on_success: name_of(&job.on_success),
+        on_failure: name_of(&job.on_failure),
+        delivery: job
+            .delivery
+            .iter()
+            .map(|d| CronDeliverySpec {
+                channel: d.channel.clone(),
+                to: d.to.clone(),
+                on_success: d.on_success,
+                on_failure: d.on_failure,
+                template: d.template.clone(),
+            })
+            .collect(),
+        active_window: job.active_window.clone(),
+        sandbox: job.sandbox.clone(),
+    }
+}
+
+fn validate_spec(spec: &CronJobSpec) -> Result<(), String> {
+    if spec.name.trim().is_empty() {
+        return Err("job name is required".into());
+    }
+    match spec.job_type.as_str() {
+        "shell" if spec.command.trim().is_empty() => return Err("command is required".into()),
+        "agent" if spec.prompt.trim().is_empty() => return Err("prompt is required".into()),
+        "shell" | "agent" => {}
+        other => return Err(format!("unknown job type: {other}")),
+    }
+    if !["isolated", "main"].contains(&spec.session_target.as_str()) {
+        return Err(format!("unknown session target: {}", spec.session_target));
+    }
+    let timezone = normalize_timezone(Some(spec.timezone.clone()));
+    compute_next_run(
+        &spec.schedule_type,
+        &spec.expression,
+        timezone.as_deref(),
+        &chrono::Utc::now(),
+    )?;
+    if !MISFIRE_POLICIES.contains(&spec.misfire_policy.as_str()) {
+        return Err(format!("unknown misfire policy: {}", spec.misfire_policy));
+    }
+    if spec.max_retries > MAX_RETRIES {
+        return Err(format!("max_retries must be at most {MAX_RETRIES}"));
+    }
+    for delivery in &spec.delivery {
+        validate_delivery(&delivery.into())?;
+    }
+    if let Some(window) = &spec.active_window {
+        validate_active_window(window)?;
+    }
+    if let Some(var) = spec
+        .sandbox
+        .env
+        .iter()
+        .find(|v| !is_valid_env_name(&v.name))
+    {
+        return Err(format!("invalid environment variable name: {}", var.name));
+    }
+    Ok(())
+}
+
+/// Names of the fields that differ between two specs
+fn spec_diff(current: &CronJobSpec, incoming: &CronJobSpec) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(a)), Ok(serde_json::Value::Object(b))) = (
+        serde_json::to_value(current),
+        serde_json::to_value(incoming),
+    ) else {
+        return vec![];
+    };
+    let mut fields: Vec<String> = a
+        .keys()
+        .chain(b.keys())
+        .filter(|k| a.get(*k) != b.get(*k))
+        .cloned()
+        .collect();
+    fields.sort();
+    fields.dedup();
+    fields
+}
+
+/// Write everything but the chain targets of an imported job
+fn save_spec(
+    conn: &Connection,
+    job_id: &str,
+    spec: &CronJobSpec,
+    reschedule: bool,
+) -> Result<(), String> {
+    let timezone = normalize_timezone(Some(spec.timezone.clone()));
+    let mut schedule_json =
+        build_schedule_json(&spec.schedule_type, &spec.expression, timezone.as_deref());
+    let previous_kind = load_schedule_json(conn, job_id)
+        .and_then(|val| val.get("kind").and_then(|k| k.as_str()).map(String::from));
+    if previous_kind.as_deref() == Some(spec.schedule_type.as_str()) {
+        schedule_json = carry_trigger_settings(conn, job_id, schedule_json);
+    }
+    if let (Some(ms), Some(json)) = (spec.watch_debounce_ms, schedule_json.as_deref()) {
+        if let Ok(mut val) = serde_json::from_str::<serde_json::Value>(json) {
+            val["debounce_ms"] = serde_json::json!(ms.max(MIN_WATCH_DEBOUNCE_MS));
+            schedule_json = Some(val.to_string());
+        }
+    }
+    let delivery: Vec<CronDeliveryDto> = spec.delivery.iter().map(Into::into).collect();
+
+    conn.execute(
+        "UPDATE cron_jobs SET expression=?1, command=?2, schedule=?3, job_type=?4, prompt=?5, \
+         name=?6, session_target=?7, model=?8, enabled=?9, delivery=?10, delete_after_run=?11, \
+         target_session_id=?12, project_id=?13, misfire_policy=?14, no_overlap=?15, \
+         timeout_secs=?16, max_retries=?17, retry_backoff_secs=?18, active_window=?19, \
+         sandbox=?20, paused_by_project=0 WHERE id=?21",
+        params![
+            spec.expression,
+            spec.command,
+            schedule_json,
+            spec.job_type,
+            spec.prompt,
+            spec.name,
+            spec.session_target,
+            spec.model,
+            spec.enabled as i32,
+            delivery_json(&delivery),
+            spec.delete_after_run as i32,
+            spec.target_session_id,
+            spec.project_id,
+            spec.misfire_policy,
+            spec.no_overlap as i32,
+            spec.timeout_secs,
+            spec.max_retries,
+            spec.retry_backoff_secs,
+            spec.active_window
+                .as_ref()
+                .map(|w| serde_json::to_string(w).unwrap_or_default()),
+            serde_json::to_string(&spec.sandbox).unwrap_or_default(),
+            job_id,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    if reschedule {
+        let next_run = compute_next_run(
+            &spec.schedule_type,
+            &spec.expression,
+            timezone.as_deref(),
+            &chrono::Utc::now(),
+        )?;
+        conn.execute(
+            "UPDATE cron_jobs SET next_run=?1 WHERE id=?2",
+            params![next_run.to_rfc3339(), job_id],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// ──────────────────── Delivery ───────────────────────────────
+
+const DELIVERY_CHANNELS: [&str; 12] = [
+    "telegram", "slack", "discord", "email", "matrix", "signal", "whatsapp", "irc", "lark",
+    "feishu", "dingtalk", "webhook",
+];
+const DEFAULT_DELIVERY_TEMPLATE: &str = "{{icon}} [{{job}}] {{status}}\n\n{{output}}";
+/// Message size limits of the chat APIs, in bytes to stay on the safe side
+const TELEGRAM_MAX_MESSAGE: usize = 4096;
+const DISCORD_MAX_MESSAGE: usize = 2000;
+const SLACK_MAX_MESSAGE: usize = 40_000;
+
+fn parse_delivery(json: Option<&str>) -> Vec<CronDeliveryDto> {
+    let Some(val) = json.and_then(|j| serde_json::from_str::<serde_json::Value>(j).ok()) else {
+        return vec![];
+    };
+    val.get("targets")
+        .and_then(|t| t.as_array())
+        .map(|targets| {
+            targets
+                .iter()
+                .map(|t| {
+                    let text = |key: &str| {
+                        t.get(key)
+                            .and_then(|v| v.as_str())
+                            .unwrap_or_default()
+                            .to_string()
+                    };
+                    let flag = |key: &str| t.get(key).and_then(|v| v.as_bool()).unwrap_or(true);
+                    CronDeliveryDto {
+                        channel: text("channel"),
+                        to: text("to"),
+                        on_success: flag("on_success"),
+                        on_failure: flag("on_failure"),
+                        template: text("template"),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn delivery_json(targets: &[CronDeliveryDto]) -> Option<String> {
+    if targets.is_empty() {
+        return None;
+    }
+    let targets: Vec<serde_json::Value> = targets
+        .iter()
+        .map(|t| {
+            serde_json::json!({
+                "channel": t.channel,
+                "to": t.to.trim(),
+                "on_success": t.on_success,
+                "on_failure": t.on_failure,
+                "template": t.template,
+            })
+        })
+        .collect();
+    Some(serde_json::json!({ "targets": targets }).to_string())
+}
+
+fn validate_delivery(target: &CronDeliveryDto) -> Result<(), String> {
+    if !DELIVERY_CHANNELS.contains(&target.channel.as_str()) {
+        return Err(format!("unknown delivery channel: {}", target.channel));
+    }
+    let to = target.to.trim();
+    // Slack and Matrix fall back to the channel's configured room
+    if to.is_empty() && !matches!(target.channel.as_str(), "slack" | "matrix") {
+        return Err(format!("{} delivery needs a recipient", target.channel));
+    }
+    if target.channel == "webhook" && !(to.starts_with("https://") || to.starts_with("http://")) {
+        return Err(format!("invalid webhook URL: {to}"));
+    }
+    if target.channel == "email" && !to.contains('@') {
+        return Err(format!("invalid email address: {to}"));
+    }
+    if !target.on_success && !target.on_failure {
+        return Err("delivery must be enabled for success, failure or both".into());
+    }
+    Ok(())
+}
+
+fn render_delivery_template(
+    template: &str,
+    notification: &super::cron_notification_api::CronNotification,
+) -> String {
+    let template = if template.trim().is_empty() {
+        DEFAULT_DELIVERY_TEMPLATE
+    } else {
+        template
+    };
+    let job = if notification.job_name.is_empty() {
+        &notification.job_id
+    } else {
+        &notification.job_name
+    };
+    let icon = if notification.status == "ok" {
+        "✅"
+    } else {
+        "❌"
+    };
+    let finished_at = chrono::DateTime::from_timestamp(notification.finished_at, 0)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default();
+    let duration = format!("{:.1}s", notification.duration_ms as f64 / 1000.0);
+    let status = if notification.auto_disabled {
+        format!(
+            "{} (job disabled after repeated failures)",
+            notification.status
+        )
+    } else {
+        notification.status.clone()
+    };
+    // Single pass, so placeholders inside the prompt or output are left alone
+    let mut rendered = String::with_capacity(template.len() + notification.output.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let Some(end) = tail.find("}}") else {
+            rest = tail;
+            break;
+        };
+        let value = match &tail[2..end] {
+            "job" => Some(job.as_str()),
+            "status" => Some(status.as_str()),
+            "icon" => Some(icon),
+            "prompt" => Some(notification.prompt.as_str()),
+            "duration" => Some(duration.as_str()),
+            "finished_at" => Some(finished_at.as_str()),
+            "output" => Some(notification.output.as_str()),
+            _ => None,
+        };
+        match value {
+            Some(value) => {
+                rendered.push_str(value);
+                rest = &tail[end + 2..];
+            }
+            None => {
+                rendered.push_str("{{");
+                rest = &tail[2..];
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// Send a finished run to every target whose success/failure filter matches.
+/// Failures are logged; they don't affect the run's status.
+async fn deliver_results(
+    targets: Vec<CronDeliveryDto>,
+    notification: super::cron_notification_api::CronNotification,
+) {
+    let succeeded = notification.status == "ok";
+    let targets: Vec<_> = targets
+        .into_iter()
+        .filter(|t| {
+            if succeeded {
+                t.on_success
+            } else {
+                t.on_failure
+            }
+        })
+        .collect();
+    if targets.is_empty() {
+        return;
+    }
+    let Some(config) = super::agent_api::config_state().read().await.config.clone() else {
+        tracing::warn!("Cron delivery skipped: runtime not initialized");
+        return;
+    };
+    for target in &targets {
+        if let Err(e) = send_delivery(&config, target, &notification).await {
+            tracing::warn!(
+                "Cron job {}: delivery to {} {} failed: {e}",
+                notification.job_id,
+                target.channel,
+                target.to
+            );
+        }
+    }
+}
+
+async fn send_delivery(
+    config: &zeroclaw::Config,
+    target: &CronDeliveryDto,
+    notification: &super::cron_notification_api::CronNotification,
+) -> Result<(), String> {
+    use zeroclaw::channels::SendMessage;
+
+    let message = render_delivery_template(&target.template, notification);
+    let to = target.to.trim();
+    if target.channel == "webhook" {
+        return send_delivery_webhook(to, notification, message).await;
+    }
+
+    let (channel, default_to) = delivery_channel(config, &target.channel)?;
+    let to = if to.is_empty() {
+        default_to.ok_or_else(|| format!("{} delivery needs a recipient", target.channel))?
+    } else {
+        to.to_string()
+    };
+    let message = match target.channel.as_str() {
+        "telegram" => truncate_output(message, TELEGRAM_MAX_MESSAGE, "…"),
+        "discord" => truncate_output(message, DISCORD_MAX_MESSAGE, "…"),
+        "slack" => truncate_output(message, SLACK_MAX_MESSAGE, "…"),
+        _ => message,
+    };
+    let message = if target.channel == "email" {
+        let job = if notification.job_name.is_empty() {
+            &notification.job_id
+        } else {
+            &notification.job_name
+        };
+        SendMessage::with_subject(message, to, format!("[{job}] {}", notification.status))
+    } else {
+        SendMessage::new(message, to)
+    };
+    channel.send(&message).await.map_err(|e| e.to_string())
+}
+
+/// Build the zeroclaw channel a delivery target sends through, along with
+/// the recipient to use when the target leaves it empty.
+fn delivery_channel(
+    config: &zeroclaw::Config,
+    name: &str,
+) -> Result<(Box<dyn zeroclaw::channels::Channel>, Option<String>), String> {
+    use zeroclaw::channels::*;
+
+    let channels = &config.channels_config;
+    let not_configured = || format!("{name} channel is not configured");
+    Ok(match name {
+        "telegram" => {
+            let tg = channels.telegram.as_ref().ok_or_else(not_configured)?;
+            (
+                Box::new(TelegramChannel::new(
+                    tg.bot_token.clone(),
+                    tg.allowed_users.clone(),
+                    tg.mention_only,
+                )),
+                None,
+            )
+        }
+        "discord" => {
+            let dc = channels.discord.as_ref().ok_or_else(not_configured)?;
+            (
+                Box::new(DiscordChannel::new(
+                    dc.bot_token.clone(),
+                    dc.guild_id.clone(),
+                    dc.allowed_users.clone(),
+                    dc.listen_to_bots,
+                    dc.mention_only,
+                )),
+                None,
+            )
+        }
+        "slack" => {
+            let sl = channels.slack.as_ref().ok_or_else(not_configured)?;
+            (
+                Box::new(SlackChannel::new(
+                    sl.bot_token.clone(),
+                    sl.app_token.clone(),
+                    sl.channel_id.clone(),
+                    sl.channel_ids.clone(),
+                    sl.allowed_users.clone(),
+                )),
+                sl.channel_id.clone(),
+            )
+        }
+        "email" => {
+            let email = channels.email.as_ref().ok_or_else(not_configured)?;
+            (Box::new(EmailChannel::new(email.clone())), None)
+        }
+        "matrix" => {
+            let mx = channels.matrix.as_ref().ok_or_else(not_configured)?;
+            (
+                Box::new(MatrixChannel::new_with_session_hint(
+                    mx.homeserver.clone(),
+                    mx.access_token.clone(),
+                    mx.room_id.clone(),
+                    mx.allowed_users.clone(),
+                    mx.user_id.clone(),
+                    mx.device_id.clone(),
+                )),
+                Some(mx.room_id.clone()).filter(|r| !r.is_empty()),
+            )
+        }
+        "signal" => {
+            let sg = channels.signal.as_ref().ok_or_else(not_configured)?;
+            (
+                Box::new(SignalChannel::new(
+                    sg.http_url.clone(),
+                    sg.account.clone(),
+                    sg.group_id.clone(),
+                    sg.allowed_from.clone(),
+                    sg.ignore_attachments,
+                    sg.ignore_stories,
+                )),
+                None,
+            )
+        }
+        "whatsapp" => {
+            let wa = channels.whatsapp.as_ref().ok_or_else(not_configured)?;
+            (
+                Box::new(WhatsAppChannel::new(
+                    wa.access_token.clone().unwrap_or_default(),
+                    wa.phone_number_id.clone().unwrap_or_default(),
+                    wa.verify_token.clone().unwrap_or_default(),
+                    wa.allowed_numbers.clone(),
+                )),
+                None,
+            )
+        }
+        "irc" => {
+            let irc = channels.irc.as_ref().ok_or_else(not_configured)?;
+            (
+                Box::new(IrcChannel::new(irc::IrcChannelConfig {
+                    server: irc.server.clone(),
+                    port: irc.port,
+                    nickname: irc.nickname.clone(),
+                    username: irc.username.clone(),
+                    channels: irc.channels.clone(),
+                    allowed_users: irc.allowed_users.clone(),
+                    server_password: irc.server_password.clone(),
+                    nickserv_password: irc.nickserv_password.clone(),
+                    sasl_password: irc.sasl_password.clone(),
+                    verify_tls: irc.verify_tls.unwrap_or(true),
+                })),
+                None,
+            )
+        }
+        "lark" => {
+            let lk = channels.lark.as_ref().ok_or_else(not_configured)?;
+            (Box::new(LarkChannel::from_config(lk)), None)
+        }
+        "feishu" => {
+            let fs = channels.feishu.as_ref().ok_or_else(not_configured)?;
+            (Box::new(LarkChannel::from_feishu_config(fs)), None)
+        }
+        "dingtalk" => {
+            let dt = channels.dingtalk.as_ref().ok_or_else(not_configured)?;
+            (
+                Box::new(DingTalkChannel::new(
+                    dt.client_id.clone(),
+                    dt.client_secret.clone(),
+                    dt.allowed_users.clone(),
+                )),
+                None,
+            )
+        }
+        other => return Err(format!("unknown delivery channel: {other}")),
+    })
+}
+
+/// Outgoing webhooks aren't a zeroclaw channel (its webhook channel only
+/// receives), so they are posted directly.
+async fn send_delivery_webhook(
+    url: &str,
+    notification: &super::cron_notification_api::CronNotification,
+    message: String,
+) -> Result<(), String> {
+    let client =
+        zeroclaw::config::build_runtime_proxy_client_with_timeouts("cron_delivery", 30, 10);
+    let response = client
+        .post(url)
+        .json(&serde_json::json!({
+            "job_id": notification.job_id,
+            "job_name": notification.job_name,
+            "status": notification.status,
+            "output": notification.output,
+            "duration_ms": notification.duration_ms,
+            "finished_at": notification.finished_at,
+            "text": message,
+        }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("HTTP {status}: {body}"));
+    }
+    Ok(())
+}
+
+// ──────────────────── Active Windows ─────────────────────────
+
+/// Give up looking for the next open slot after this many window checks
+const MAX_WINDOW_STEPS: usize = 1000;
+
+fn global_active_window(conn: &Connection) -> CronActiveWindowDto {
+    get_setting(conn, "active_window")
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn parse_window_time(value: &str) -> Result<chrono::NaiveTime, String> {
+    chrono::NaiveTime::parse_from_str(value.trim(), "%H:%M")
+        .map_err(|_| format!("invalid time (expected HH:MM): {value}"))
+}
+
+fn validate_active_window(window: &CronActiveWindowDto) -> Result<(), String> {
+    match (window.quiet_start.trim(), window.quiet_end.trim()) {
+        ("", "") => {}
+        ("", _) | (_, "") => return Err("quiet hours need both a start and an end".into()),
+        (start, end) => {
+            parse_window_time(start)?;
+            parse_window_time(end)?;
+        }
+    }
+    if let Some(day) = window.active_days.iter().find(|d| !(1..=7).contains(*d)) {
+        return Err(format!("invalid day: {day} (expected 1-7)"));
+    }
+    if !window.holidays_ics.trim().is_empty() && !expand_home(window.holidays_ics.trim()).is_file()
+    {
+        return Err(format!(
+            "holiday calendar not found: {}",
+            window.holidays_ics
+        ));
+    }
+    Ok(())
+}
+
+/// A window with its times parsed and its holiday calendar loaded
+struct ActiveWindow {
+    quiet: Option<(chrono::NaiveTime, chrono::NaiveTime)>,
+    active_days: Vec<u8>,
+    holidays: Vec<IcsHoliday>,
+}
+
+impl ActiveWindow {
+    fn load(window: &CronActiveWindowDto) -> Self {
+        let quiet = parse_window_time(&window.quiet_start)
+            .and_then(|start| Ok((start, parse_window_time(&window.quiet_end)?)))
+            .ok()
+            .filter(|(start, end)| start != end);
+        let holidays = if window.holidays_ics.trim().is_empty() {
+            vec![]
+        } else {
+            load_ics_holidays(&expand_home(window.holidays_ics.trim()))
+        };
+        Self {
+            quiet,
+            active_days: window.active_days.clone(),
+            holidays,
+        }
+    }
+
+    fn is_unrestricted(&self) -> bool {
+        self.quiet.is_none() && self.active_days.is_empty() && self.holidays.is_empty()
+    }
+
+    fn allows_day(&self, date: chrono::NaiveDate) -> bool {
+        use chrono::Datelike;
+        let weekday = date.weekday().number_from_monday() as u8;
+        (self.active_days.is_empty() || self.active_days.contains(&weekday))
+            && !self.holidays.iter().any(|h| h.covers(date))
+    }
+
+    fn in_quiet_hours(&self, time: chrono::NaiveTime) -> bool {
+        match self.quiet {
+            Some((start, end)) if start < end => time >= start && time < end,
+            Some((start, end)) => time >= start || time < end,
+            None => false,
+        }
+    }
+
+    /// First local time at or after `from` when runs may start
+    fn next_open(&self, from: chrono::NaiveDateTime) -> Option<chrono::NaiveDateTime> {
+        let mut t = from;
+        for _ in 0..MAX_WINDOW_STEPS {
+            let date = t.date();
+            if !self.allows_day(date) {
+                t = date.succ_opt()?.and_time(chrono::NaiveTime::MIN);
+                continue;
+            }
+            if self.in_quiet_hours(t.time()) {
+                let (_, end) = self.quiet?;
+                t = if t.time() < end {
+                    date.and_time(end)
+                } else {
+                    date.succ_opt()?.and_time(end)
+                };
+                continue;
+            }
+            return Some(t);
+        }
+        None
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum WindowCheck {
+    /// The job may run now
+    Open,
+    /// Defer the run to this time
+    OpensAt(chrono::DateTime<chrono::Utc>),
+    /// The window doesn't open within the search horizon
+    Closed,
+}
+
+/// Check a due job against its active window
+fn check_active_window(
+    job: &CronJobDto,
+    window: &CronActiveWindowDto,
+    now: &chrono::DateTime<chrono::Utc>,
+) -> WindowCheck {
+    let window = ActiveWindow::load(window);
+    if window.is_unrestricted() {
+        return WindowCheck::Open;
+    }
+    match normalize_timezone(Some(job.timezone.clone())) {
+        Some(tz) => match parse_timezone(&tz) {
+            Ok(tz) => window_deferral(&window, &tz, now),
+            Err(_) => window_deferral(&window, &chrono::Local, now),
+        },
+        None => window_deferral(&window, &chrono::Local, now),
+    }
+}
+
+fn window_deferral<Z: TimeZone>(
+    window: &ActiveWindow,
+    tz: &Z,
+    now: &chrono::DateTime<chrono::Utc>,
+) -> WindowCheck {
+    let local = now.with_timezone(tz).naive_local();
+    let Some(opens) = window.next_open(local) else {
+        return WindowCheck::Closed;
+    };
+    if opens == local {
+        return WindowCheck::Open;
+    }
+    // A time in a DST gap resolves to the first valid instant after it
+    tz.from_local_datetime(&opens)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(opens + chrono::Duration::hours(1)))
+                .earliest()
+        })
+        .map_or(WindowCheck::Closed, |t| {
+            WindowCheck::OpensAt(t.with_timezone(&chrono::Utc))
+        })
+}
+
+/// An event from a holiday calendar, as a range of whole days
+#[derive(Debug, Clone, PartialEq)]
+struct IcsHoliday {
+    start: chrono::NaiveDate,
+    /// Exclusive
+    end: chrono::NaiveDate,
+    yearly: bool,
+}
+
+impl IcsHoliday {
+    fn covers(&self, date: chrono::NaiveDate) -> bool {
+        use chrono::Datelike;
+        if !self.yearly {
+            return date >= self.start && date < self.end;
+        }
+        if date < self.start {
+            return false;
+        }
+        let len = self.end - self.start;
+        [date.year() - 1, date.year()].into_iter().any(|year| {
+            self.start
+                .with_year(year)
+                .is_some_and(|start| date >= start && date < start + len)
+        })
+    }
+}
+
+/// Read the events of an iCalendar file. Only DTSTART, DTEND and yearly
+/// RRULEs are considered; other recurrence rules are ignored.
+fn parse_ics_holidays(content: &str) -> Vec<IcsHoliday> {
+    // Undo line folding: continuation lines start with a space or tab
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.trim_end().to_string()),
+        }
+    }
+
+    let parse_date = |value: &str| {
+        value
+            .get(..8)
+            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y%m%d").ok())
+    };
+    let mut holidays = Vec::new();
+    let (mut start, mut end, mut yearly) = (None, None, false);
+    for line in &lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.split(';').next().unwrap_or_default();
+        match name {
+            "BEGIN" if value == "VEVENT" => (start, end, yearly) = (None, None, false),
+            "DTSTART" => start = parse_date(value),
+            "DTEND" => end = parse_date(value),
+            "RRULE" => yearly = value.split(';').any(|part| part == "FREQ=YEARLY"),
+            "END" if value == "VEVENT" => {
+                if let Some(start) = start {
+                    let end = end
+                        .filter(|e| *e > start)
+                        .or_else(|| start.succ_opt())
+                        .unwrap_or(start);
+                    holidays.push(IcsHoliday { start, end, yearly });
+                }
+            }
+            _ => {}
+        }
+    }
+    holidays
+}
+
+/// Holidays from an .ics file, re-read only when the file changes
+fn load_ics_holidays(path: &std::path::Path) -> Vec<IcsHoliday> {
+    type Cache = HashMap<PathBuf, (std::time::SystemTime, Vec<IcsHoliday>)>;
+    static CACHE: OnceLock<std::sync::Mutex<Cache>> = OnceLock::new();
+
+    let Ok(modified) = std::fs::metadata(path).and_then(|m| m.modified()) else {
+        tracing::warn!("Holiday calendar not readable: {}", path.display());
+        return vec![];
+    };
+    let mut cache = CACHE
+        .get_or_init(|| std::sync::Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some((cached_at, holidays)) = cache.get(path) {
+        if *cached_at == modified {
+            return holidays.clone();
+        }
+    }
+    let holidays = std::fs::read_to_string(path)
+        .map(|content| parse_ics_holidays(&content))
+        .unwrap_or_default();
+    cache.insert(path.to_path_buf(), (modified, holidays.clone()));
+    holidays
+}
+
+// ──────────────────── Event Triggers ─────────────────────────
+
+/// How often the watcher applies job changes and checks debounce windows
+const WATCH_POLL_MS: u64 = 500;
+/// Changes this soon after a watch job's own run ends are taken to be its
+/// own late writes
+const WATCH_SELF_WRITE_GRACE_MS: u64 = 1000;
+const DEFAULT_WATCH_DEBOUNCE_MS: u64 = 2000;
+const MIN_WATCH_DEBOUNCE_MS: u64 = 100;
+const DEFAULT_WEBHOOK_PORT: u16 = 42618;
+/// Upper bound on a webhook request (headers + body)
+const MAX_WEBHOOK_REQUEST_BYTES: usize = 64 * 1024;
+const WEBHOOK_READ_TIMEOUT_SECS: u64 = 10;
+
+fn is_event_trigger(schedule_type: &str) -> bool {
+    matches!(schedule_type, "watch" | "webhook")
+}
+
+fn new_webhook_secret() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+fn webhook_port(conn: &Connection) -> u16 {
+    get_setting(conn, "webhook_port")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_WEBHOOK_PORT)
+}
+
+fn load_schedule_json(conn: &Connection, job_id: &str) -> Option<serde_json::Value> {
+    conn.query_row(
+        "SELECT schedule FROM cron_jobs WHERE id = ?1",
+        params![job_id],
+        |row| row.get::<_, Option<String>>(0),
+    )
+    .ok()
+    .flatten()
+    .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+/// Keep a trigger's secret and debounce when its schedule JSON is rebuilt
+fn carry_trigger_settings(
+    conn: &Connection,
+    job_id: &str,
+    rebuilt: Option<String>,
+) -> Option<String> {
+    let (Some(previous), Some(json)) = (load_schedule_json(conn, job_id), rebuilt.as_deref())
+    else {
+        return rebuilt;
+    };
+    let Ok(mut val) = serde_json::from_str::<serde_json::Value>(json) else {
+        return rebuilt;
+    };
+    for key in ["secret", "debounce_ms"] {
+        if let Some(v) = previous.get(key) {
+            val[key] = v.clone();
+        }
+    }
+    Some(val.to_string())
+}
+
+fn update_trigger_settings(
+    job_id: &str,
+    kind: &str,
+    update: impl FnOnce(&mut serde_json::Value),
+) -> String {
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => return format!("error: {e}"),
+    };
+    let Some(mut schedule) = load_schedule_json(&conn, job_id) else {
+        return "error: job not found".into();
+    };
+    if schedule.get("kind").and_then(|v| v.as_str()) != Some(kind) {
+        return format!("error: not a {kind} job");
+    }
+    update(&mut schedule);
+    match conn.execute(
+        "UPDATE cron_jobs SET schedule=?1 WHERE id=?2",
+        params![schedule.to_string(), job_id],
+    ) {
+        Ok(_) => "ok".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Start an event-triggered run through the same pipeline as a manual run.
+/// Returns false if the job is still running and forbids overlap.
+fn fire_trigger(job: CronJobDto) -> bool {
+    let Some(guard) = try_begin_execution(&job) else {
+        return false;
+    };
+    tokio::spawn(async move {
+        let _guard = guard;
+        run_chain(&job).await;
+    });
+    true
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
+/// A watched path and the state of its debounce window
+struct WatchTarget {
+    path: PathBuf,
+    debounce: std::time::Duration,
+    /// Time of the latest change not yet acted on
+    changed_at: Option<std::time::Instant>,
+    /// A run of the job was in flight at the last pass
+    running: bool,
+    /// Changes before this are the job's own writes
+    ignore_until: Option<std::time::Instant>,
+}
+
+/// Enabled "watch" jobs: id → (path, debounce)
+fn load_watch_jobs(conn: &Connection) -> Vec<(String, PathBuf, std::time::Duration)> {
+    let Ok(mut stmt) = conn.prepare(
+        "SELECT id, schedule FROM cron_jobs \
+         WHERE enabled = 1 AND json_extract(schedule, '$.kind') = 'watch'",
+    ) else {
+        return vec![];
+    };
+    stmt.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })
+    .map(|rows| {
+        rows.filter_map(|r| r.ok())
+            .filter_map(|(id, json)| {
+                let val: serde_json::Value = serde_json::from_str(&json).ok()?;
+                let path = val.get("path")?.as_str()?;
+                let debounce_ms = val
+                    .get("debounce_ms")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(DEFAULT_WATCH_DEBOUNCE_MS);
+                Some((
+                    id,
+                    expand_home(path),
+                    std::time::Duration::from_millis(debounce_ms),
+                ))
+            })
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Run "watch" jobs once files under their path have changed and then stayed
+/// quiet for the job's debounce window. Changes made while the job itself
+/// is running are taken to be its own output and don't trigger it again.
+async fn watch_loop() {
+    use notify::Watcher;
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<PathBuf>();
+    let mut watcher =
+        match notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if let Ok(event) = res {
+                if !matches!(event.kind, notify::EventKind::Access(_)) {
+                    for path in event.paths {
+                        let _ = tx.send(path);
+                    }
+                }
+            }
+        }) {
+            Ok(w) => w,
+            Err(e) => {
+                tracing::error!("Failed to start cron file watcher: {e}");
+                return;
+            }
+        };
+
+    // One connection for the loop; the job list is only re-read when the
+    // database has changed since the last pass
+    let conn = match open_db() {
+        Ok(c) => c,
+        Err(e) => {
+            tracing::error!("Failed to open cron database for the file watcher: {e}");
+            return;
+        }
+    };
+    let mut data_version = None;
+
+    let mut targets: HashMap<String, WatchTarget> = HashMap::new();
+    let mut watched: std::collections::HashSet<PathBuf> = std::collections::HashSet::new();
+    loop {
+        // Apply job additions, removals and path edits
+        let version = conn
+            .query_row("PRAGMA data_version", [], |row| row.get::<_, i64>(0))
+            .ok();
+        if version.is_none() || version != data_version {
+            data_version = version;
+            let jobs = load_watch_jobs(&conn);
+            targets.retain(|id, _| jobs.iter().any(|(job_id, ..)| job_id == id));
+            for (id, path, debounce) in jobs {
+                let target = targets.entry(id).or_insert_with(|| WatchTarget {
+                    path: path.clone(),
+                    debounce,
+                    changed_at: None,
+                    running: false,
+                    ignore_until: None,
+                });
+                if target.path != path {
+                    target.path = path;
+                    target.changed_at = None;
+                }
+                target.debounce = debounce;
+            }
+        }
+        let wanted: std::collections::HashSet<PathBuf> =
+            targets.values().map(|t| t.path.clone()).collect();
+        for path in watched.difference(&wanted) {
+            let _ = watcher.unwatch(path);
+        }
+        watched.retain(|p| wanted.contains(p));
+        for path in &wanted {
+            // Paths that don't exist yet are retried on the next pass
+            if !watched.contains(path)
+                && watcher
+                    .watch(path, notify::RecursiveMode::Recursive)
+                    .is_ok()
+            {
+                watched.insert(path.clone());
+            }
+        }
+
+        let now = std::time::Instant::now();
+        {
+            let executing = executing_jobs().lock().unwrap_or_else(|e| e.into_inner());
+            for (id, target) in targets.iter_mut() {
+                let running = executing.contains_key(id);
+                if target.running && !running {
+                    target.ignore_until =
+                        Some(now + std::time::Duration::from_millis(WATCH_SELF_WRITE_GRACE_MS));
+                }
+                target.running = running;
+            }
+        }
+        while let Ok(changed) = rx.try_recv() {
+            for target in targets.values_mut() {
+                let own_write =
+                    target.running || target.ignore_until.is_some_and(|until| now < until);
+                if !own_write && changed.starts_with(&target.path) {
+                    target.changed_at = Some(now);
+                }
+            }
+        }
+
+        for (id, target) in targets.iter_mut() {
+            let Some(changed_at) = target.changed_at else {
+                continue;
+            };
+            if now.duration_since(changed_at) < target.debounce {
+                continue;
+            }
+            let Ok(job) = load_job(&conn, id) else {
+                continue;
+            };
+            if fire_trigger(job) {
+                target.changed_at = None;
+                target.running = true;
+            }
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(WATCH_POLL_MS)).await;
+    }
+}
+
+/// A parsed `POST /cron/trigger/{job_id}` request
+#[derive(Debug, PartialEq)]
+struct TriggerRequest {
+    method: String,
+    job_id: String,
+    secret: Option<String>,
+    content_length: usize,
+}
+
+/// Parse the request line and headers of a webhook call.
+/// Returns None if the path isn't a trigger path.
+fn parse_trigger_request(head: &str) -> Option<TriggerRequest> {
+    let mut lines = head.lines();
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let target = request_line.next()?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let job_id = path.strip_prefix("/cron/trigger/")?.trim_end_matches('/');
+    if job_id.is_empty() || job_id.contains('/') {
+        return None;
+    }
+
+    let mut secret = query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("secret="))
+        .map(String::from);
+    let mut content_length = 0;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("x-cron-secret") {
+            secret = Some(value.to_string());
+        } else if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse().unwrap_or(0);
+        }
+    }
+
+    Some(TriggerRequest {
+        method,
+        job_id: job_id.to_string(),
+        secret,
+        content_length,
+    })
+}
+
+/// Compare secrets without leaking the matching prefix length through timing
+fn secrets_match(expected: &str, given: &str) -> bool {
+    expected.len() == given.len()
+        && expected
+            .bytes()
+            .zip(given.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// Check a webhook call against its job and start the run
+fn dispatch_trigger(request: &TriggerRequest) -> (u16, &'static str) {
+    if request.method != "POST" {
+        return (405, "method not allowed");
+    }
+    let Ok(conn) = open_db() else {
+        return (500, "cron database unavailable");
+    };
+    let Some(schedule) = load_schedule_json(&conn, &request.job_id) else {
+        return (404, "job not found");
+    };
+    if schedule.get("kind").and_then(|v| v.as_str()) != Some("webhook") {
+        return (404, "job not found");
+    }
+    let expected = schedule
+        .get("secret")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    if expected.is_empty() || !secrets_match(expected, request.secret.as_deref().unwrap_or("")) {
+        return (401, "invalid secret");
+    }
+    let job = match load_job(&conn, &request.job_id) {
+        Ok(j) => j,
+        Err(_) => return (404, "job not found"),
+    };
+    drop(conn);
+    if !job.enabled {
+        return (409, "job is paused");
+    }
+    if !fire_trigger(job) {
+        return (409, "job is already running");
+    }
+    (202, "accepted")
+}
+
+async fn handle_webhook(mut stream: tokio::net::TcpStream) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let read = async {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 4096];
+        let head_end = loop {
+            if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                break Some(pos + 4);
+            }
+            if buf.len() >= MAX_WEBHOOK_REQUEST_BYTES {
+                break None;
+            }
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => break None,
+                Ok(n) => buf.extend_from_slice(&chunk[..n]),
+            }
+        }?;
+        let request = parse_trigger_request(&String::from_utf8_lossy(&buf[..head_end]));
+        // Drain the body so closing the socket doesn't reset the connection
+        // before the client reads the response
+        if let Some(req) = &request {
+            let mut remaining = req
+                .content_length
+                .min(MAX_WEBHOOK_REQUEST_BYTES)
+                .saturating_sub(buf.len() - head_end);
+            while remaining > 0 {
+                match stream.read(&mut chunk).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => remaining = remaining.saturating_sub(n),
+                }
+            }
+        }
+        Some(request)
+    };
+    let request = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(WEBHOOK_READ_TIMEOUT_SECS),
+        read,
+    )
+    .await
+    {
+        Ok(Some(request)) => request,
+        _ => return,
+    };
+
+    let (code, body) = match &request {
+        Some(req) => dispatch_trigger(req),
+        None => (404, "not found"),
+    };
+    let reason = match code {
+        202 => "Accepted",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        _ => "Internal Server Error",
+    };
+    let response = format!(
+        "HTTP/1.1 {code} {reason}\r\nContent-Type: text/plain\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Serve webhook triggers on localhost only
+async fn webhook_loop(port: u16) {
+    let listener = match tokio::net::TcpListener::bind(("127.0.0.1", port)).await {
+        Ok(l) => l,
+        Err(e) => {
+            tracing::error!("Failed to bind cron webhook listener on port {port}: {e}");
+            return;
+        }
+    };
+    tracing::info!("Cron webhook listener on 127.0.0.1:{port}");
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                tokio::spawn(handle_webhook(stream));
+            }
+            Err(e) => tracing::warn!("Cron webhook accept failed: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .unwrap()
+            .with_timezone(&chrono::Utc)
+    }
+
+    #[test]
+    fn normalizes_five_field_expressions() {
+        assert_eq!(
+            normalize_cron_expression("*/5 * * * *").unwrap(),
+            "0 */5 * * * *"
+        );
+        assert_eq!(
+            normalize_cron_expression("0 9 * * 1-5").unwrap(),
+            "0 0 9 * * Mon,Tue,Wed,Thu,Fri"
+        );
+        assert_eq!(
+            normalize_cron_expression("0 0 * * 0").unwrap(),
+            "0 0 0 * * Sun"
+        );
+        assert_eq!(
+            normalize_cron_expression("0 0 * * 7").unwrap(),
+            "0 0 0 * * Sun"
+        );
+        assert_eq!(
+            normalize_cron_expression("0 0 * * MON-FRI").unwrap(),
+            "0 0 0 * * MON-FRI"
+        );
+        assert_eq!(normalize_cron_expression("@midnight").unwrap(), "@daily");
+        assert!(normalize_cron_expression("* * *").is_err());
+        assert!(normalize_cron_expression("0 0 * * 8").is_err());
+    }
+
+    #[test]
+    fn expands_day_of_week_steps() {
+        let dow = |expr: &str| normalize_cron_expression(expr).unwrap();
+        assert_eq!(dow("0 0 * * 0/2"), "0 0 0 * * Sun,Tue,Thu,Sat");
+        assert_eq!(dow("0 0 * * */2"), "0 0 0 * * Sun,Tue,Thu,Sat");
+        assert_eq!(dow("0 0 * * 1/3"), "0 0 0 * * Mon,Thu");
+        assert_eq!(dow("0 0 * * 1-7/2"), "0 0 0 * * Mon,Wed,Fri,Sun");
+        assert!(normalize_cron_expression("0 0 * * 0/0").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        let now = chrono::Utc::now();
+        assert!(compute_next_run("cron", "61 * * * *", None, &now).is_err());
+        assert!(compute_next_run("cron", "0 9 * * *", Some("Mars/Base"), &now).is_err());
+        assert!(compute_next_run("every", "abc", None, &now).is_err());
+    }
+
+    #[test]
+    fn evaluates_in_job_timezone() {
+        let after = utc("2025-01-15T00:00:00Z");
+        let next = compute_next_run("cron", "0 9 * * *", Some("Asia/Shanghai"), &after).unwrap();
+        assert_eq!(next, utc("2025-01-15T01:00:00Z"));
+
+        // Sunday in Unix numbering
+        let next = compute_next_run("cron", "0 9 * * 0", Some("UTC"), &after).unwrap();
+        assert_eq!(next, utc("2025-01-19T09:00:00Z"));
+    }
+
+    #[test]
+    fn handles_dst_transitions() {
+        let tz = Some("America/New_York");
+
+        // Spring forward: 02:30 does not exist on 2025-03-09, fires at 03:30 EDT
+        let next =
+            compute_next_run("cron", "30 2 * * *", tz, &utc("2025-03-09T05:00:00Z")).unwrap();
+        assert_eq!(next, utc("2025-03-09T07:30:00Z"));
+
+        // Fall back: 01:30 happens twice on 2025-11-02, fixed-hour job fires once
+        let first =
+            compute_next_run("cron", "30 1 * * *", tz, &utc("2025-11-02T04:00:00Z")).unwrap();
+        assert_eq!(first, utc("2025-11-02T05:30:00Z"));
+        let second = compute_next_run("cron", "30 1 * * *", tz, &first).unwrap();
+        assert_eq!(second, utc("2025-11-03T06:30:00Z"));
+
+        // ...while an every-hour job fires in both passes
+        let second = compute_next_run("cron", "30 * * * *", tz, &first).unwrap();
+        assert_eq!(second, utc("2025-11-02T06:30:00Z"));
+    }
+
+    fn job(expression: &str, next_run: &str, misfire_policy: &str) -> CronJobDto {
+        CronJobDto {
+            id: "job".into(),
+            name: String::new(),
+            expression: expression.into(),
+            schedule_type: "cron".into(),
+            schedule_display: String::new(),
+            timezone: "UTC".into(),
+            command: String::new(),
+            prompt: String::new(),
+            job_type: "shell".into(),
+            session_target: "isolated".into(),
+            target_session_id: String::new(),
+            model: String::new(),
+            enabled: true,
+            delete_after_run: false,
+            project_id: String::new(),
+            created_at: 0,
+            next_run: utc(next_run).timestamp(),
+            last_run: None,
+            last_status: String::new(),
+            last_output: String::new(),
+            misfire_policy: misfire_policy.into(),
+            no_overlap: true,
+            timeout_secs: 3600,
+            max_retries: 0,
+            retry_backoff_secs: 30,
+            on_success: String::new(),
+            on_failure: String::new(),
+            delivery: vec![],
+            active_window: None,
+            sandbox: CronShellSandboxDto::default(),
+        }
+    }
+
+    #[test]
+    fn defers_runs_outside_active_window() {
+        let date = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let window = ActiveWindow {
+            quiet: Some((
+                parse_window_time("22:00").unwrap(),
+                parse_window_time("07:00").unwrap(),
+            )),
+            active_days: vec![1, 2, 3, 4, 5],
+            holidays: parse_ics_holidays(
+                "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20250101\r\n\
+                 RRULE:FREQ=YEARLY\r\nSUMMARY:New Year\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\n\
+                 DTSTART;VALUE=DATE:20250120\r\nDTEND;VALUE=DATE:20250122\r\nEND:VEVENT\r\n\
+                 END:VCALENDAR\r\n",
+            ),
+        };
+        assert_eq!(window.holidays.len(), 2);
+        assert!(window.holidays[0].covers(date("2026-01-01")));
+        assert!(!window.holidays[0].covers(date("2024-01-01")));
+        assert!(window.holidays[1].covers(date("2025-01-21")));
+        assert!(!window.holidays[1].covers(date("2025-01-22")));
+
+        let tz = chrono_tz::Europe::Berlin;
+        // Wednesday 2025-01-15 12:00 local: open
+        assert_eq!(
+            window_deferral(&window, &tz, &utc("2025-01-15T11:00:00Z")),
+            WindowCheck::Open
+        );
+        // Wednesday 23:00 local: deferred to Thursday 07:00
+        assert_eq!(
+            window_deferral(&window, &tz, &utc("2025-01-15T22:00:00Z")),
+            WindowCheck::OpensAt(utc("2025-01-16T06:00:00Z"))
+        );
+        // Friday 23:00 local: weekend skipped, then Monday 2025-01-20 and
+        // Tuesday are holidays, so Wednesday 07:00
+        assert_eq!(
+            window_deferral(&window, &tz, &utc("2025-01-17T22:00:00Z")),
+            WindowCheck::OpensAt(utc("2025-01-22T06:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn round_trips_cron_files() {
+        let spec = CronJobSpec {
+            name: "nightly-backup".into(),
+            expression: "0 2 * * *".into(),
+            timezone: "Europe/Berlin".into(),
+            command: "backup.sh".into(),
+            on_failure: "alert".into(),
+            delivery: vec![CronDeliverySpec {
+                channel: "webhook".into(),
+                to: "https://example.com/hook".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let file = CronJobFile {
+            jobs: vec![spec.clone()],
+        };
+        let parsed: CronJobFile = toml::from_str(&toml::to_string_pretty(&file).unwrap()).unwrap();
+        assert_eq!(parsed.jobs, vec![spec.clone()]);
+        let parsed: CronJobFile =
+            serde_yaml_ng::from_str(&serde_yaml_ng::to_string(&file).unwrap()).unwrap();
+        assert_eq!(parsed.jobs, vec![spec.clone()]);
+
+        // Omitted fields take the defaults
+        let parsed: CronJobFile = toml::from_str(
+            "[[jobs]]\nname = \"ping\"\nexpression = \"*/5 * * * *\"\ncommand = \"true\"\n",
+        )
+        .unwrap();
+        assert!(parsed.jobs[0].enabled && parsed.jobs[0].no_overlap);
+        assert!(validate_spec(&parsed.jobs[0]).is_ok());
+
+        let changed = CronJobSpec {
+            expression: "30 2 * * *".into(),
+            enabled: false,
+            ..spec.clone()
+        };
+        assert_eq!(spec_diff(&spec, &changed), vec!["enabled", "expression"]);
+        assert!(spec_diff(&spec, &spec).is_empty());
+        assert!(validate_spec(&CronJobSpec {
+            expression: "not a schedule".into(),
+            ..spec
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn renders_delivery_templates() {
+        let notification = crate::api::cron_notification_api::CronNotification {
+            job_id: "job-1".into(),
+            job_name: "Daily report".into(),
+            job_type: "agent".into(),
+            session_target: "isolated".into(),
+            target_session_id: String::new(),
+            status: "error".into(),
+            output: "failed with {{job}}".into(),
+            prompt: "Summarize".into(),
+            duration_ms: 1500,
+            finished_at: 0,
+            auto_disabled: false,
+        };
+        assert_eq!(
+            render_delivery_template("", &notification),
+            "❌ [Daily report] error\n\nfailed with {{job}}"
+        );
+        assert_eq!(
+            render_delivery_template("{{prompt}} took {{duration}}", &notification),
+            "Summarize took 1.5s"
+        );
+        let notification = crate::api::cron_notification_api::CronNotification {
+            prompt: "Reply with {{output}}".into(),
+            ..notification
+        };
+        assert_eq!(
+            render_delivery_template("{{prompt}}: {{output}} {{unknown}}", &notification),
+            "Reply with {{output}}: failed with {{job}} {{unknown}}"
+        );
+
+        let targets = vec![CronDeliveryDto {
+            channel: "webhook".into(),
+            to: "https://example.com/hook".into(),
+            on_success: false,
+            on_failure: true,
+            template: String::new(),
+        }];
+        let parsed = parse_delivery(delivery_json(&targets).as_deref());
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].to, "https://example.com/hook");
+        assert!(!parsed[0].on_success && parsed[0].on_failure);
+        assert!(validate_delivery(&parsed[0]).is_ok());
+        assert!(validate_delivery(&CronDeliveryDto {
+            channel: "webhook".into(),
+            to: "example.com".into(),
+            ..parsed[0].clone()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn detects_chain_cycles() {
+        let edges = |pairs: &[(&str, &str)]| {
+            let mut map: HashMap<String, Vec<String>> = HashMap::new();
+            for (from, to) in pairs {
+                map.entry(from.to_string())
+                    .or_default()
+                    .push(to.to_string());
+                map.entry(to.to_string()).or_default();
+            }
+            map
+        };
+        assert!(!chain_has_cycle(&edges(&[("a", "b"), ("b", "c")]), "a"));
+        assert!(chain_has_cycle(
+            &edges(&[("a", "b"), ("b", "c"), ("c", "a")]),
+            "a"
+        ));
+        assert!(chain_has_cycle(&edges(&[("a", "a")]), "a"));
+        // A cycle elsewhere in the graph doesn't involve "a"
+        assert!(!chain_has_cycle(
+            &edges(&[("a", "b"), ("b", "c"), ("c", "b")]),
+            "a"
+        ));
+    }
+
+    #[test]
+    fn passes_previous_output_to_follow_up() {
+        let mut j = job("0 * * * *", "2025-01-15T12:00:00Z", "run_once");
+        j.prompt = "Summarize ({{prev_status}} from {{prev_job}}):\n{{prev_output}}".into();
+        j.command = "echo {{prev_job}} {{prev_output}}".into();
+        let ctx = ChainContext {
+            job_name: "it's".into(),
+            status: "ok".into(),
+            output: "done".into(),
+            output_file: Some(PathBuf::from("/tmp/prev.out")),
+        };
+        let applied = apply_chain_context(&j, &ctx);
+        assert_eq!(applied.prompt, "Summarize (ok from it's):\ndone");
+        // The output itself stays off the command line
+        assert_eq!(
+            applied.command,
+            "echo 'it'\\''s' \"$(cat \"$CRON_PREV_OUTPUT_FILE\")\""
+        );
+        assert!(applied
+            .sandbox
+            .env
+            .iter()
+            .any(|v| v.name == PREV_OUTPUT_FILE_VAR && v.value == "/tmp/prev.out"));
+    }
+
+    #[test]
+    fn parses_webhook_trigger_requests() {
+        let req = parse_trigger_request(
+            "POST /cron/trigger/job-1 HTTP/1.1\r\nHost: localhost\r\n\
+             X-Cron-Secret: s3cret\r\nContent-Length: 12\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(req.method, "POST");
+        assert_eq!(req.job_id, "job-1");
+        assert_eq!(req.secret.as_deref(), Some("s3cret"));
+        assert_eq!(req.content_length, 12);
+
+        let req =
+            parse_trigger_request("POST /cron/trigger/job-1?secret=abc HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(req.secret.as_deref(), Some("abc"));
+
+        assert!(parse_trigger_request("POST /other/job-1 HTTP/1.1\r\n\r\n").is_none());
+        assert!(parse_trigger_request("POST /cron/trigger/ HTTP/1.1\r\n\r\n").is_none());
+        assert!(secrets_match("abc", "abc"));
+        assert!(!secrets_match("abc", "abd"));
+        assert!(!secrets_match("abc", "ab"));
+    }
+
+    #[test]
+    fn backs_off_exponentially() {
+        assert_eq!(retry_delay_secs(30, 1), 30);
+        assert_eq!(retry_delay_secs(30, 2), 60);
+        assert_eq!(retry_delay_secs(30, 4), 240);
+        assert_eq!(retry_delay_secs(30, 10), MAX_RETRY_DELAY_SECS);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn kills_shell_job_on_timeout() {
+        let mut j = job("0 * * * *", "2025-01-15T12:00:00Z", "run_once");
+        j.command = "sleep 30".into();
+        j.timeout_secs = 1;
+        let started = std::time::Instant::now();
+        let outcome = execute_shell(&j, &ShellLaunch::default()).await;
+        assert_eq!(outcome.status, "error");
+        assert_eq!(outcome.output, "timed out after 1s");
+        assert_eq!(outcome.exit_code, None);
+        assert!(started.elapsed().as_secs() < 10);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn captures_full_output_without_splitting_characters() {
+        let mut j = job("0 * * * *", "2025-01-15T12:00:00Z", "run_once");
+        j.command = "for i in $(seq 1 1000); do printf '你好'; done; echo oops >&2; exit 3".into();
+        let outcome = execute_shell(&j, &ShellLaunch::default()).await;
+        assert_eq!(outcome.status, "error");
+        assert_eq!(outcome.exit_code, Some(3));
+        assert_eq!(outcome.stdout.len(), 6000);
+        assert_eq!(outcome.stderr, "oops\n");
+        assert!(outcome.output.ends_with("...(truncated)"));
+        assert!(outcome.output.len() <= OUTPUT_SUMMARY_BYTES + "...(truncated)".len());
+    }
+
+    #[tokio::test]
+    async fn flags_output_past_the_capture_limit() {
+        use tokio::io::AsyncReadExt;
+
+        let (text, truncated) = capture_stream(Some(&b"short"[..])).await;
+        assert_eq!((text.as_str(), truncated), ("short", false));
+
+        let flood = tokio::io::repeat(b'x').take(MAX_CAPTURED_OUTPUT_BYTES as u64 + 10);
+        let (text, truncated) = capture_stream(Some(flood)).await;
+        assert_eq!(text.len(), MAX_CAPTURED_OUTPUT_BYTES);
+        assert!(truncated);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn launches_shell_in_sandbox() {
+        let dir = std::env::temp_dir();
+        let mut j = job("0 * * * *", "2025-01-15T12:00:00Z", "run_once");
+        j.command = "pwd; echo \"$GREETING\"; echo \"${CARGO:-unset}\"; ulimit -t".into();
+        let launch = ShellLaunch {
+            working_dir: Some(dir.clone()),
+            inherit_env: false,
+            env: vec![("GREETING".into(), "hello".into())],
+            cpu_limit_secs: 5,
+            memory_limit_mb: 0,
+        };
+        let outcome = execute_shell(&j, &launch).await;
+        assert_eq!(outcome.status, "ok");
+        let lines: Vec<&str> = outcome.stdout.lines().collect();
+        assert_eq!(
+            std::fs::canonicalize(lines[0]).unwrap(),
+            std::fs::canonicalize(&dir).unwrap()
+        );
+        assert_eq!(&lines[1..], ["hello", "unset", "5"]);
+        assert!(is_valid_env_name("API_TOKEN_2"));
+        assert!(!is_valid_env_name("2TOKEN") && !is_valid_env_name("A-B"));
+    }
+
+    #[test]
+    fn counts_runs_after_retries() {
+        let attempt = |ok, duration_ms, attempt| RunAttempt {
+            ok,
+            duration_ms,
+            attempt,
+        };
+        // ok | error, error, ok | error | error, error
+        let attempts = [
+            attempt(true, 100, 1),
+            attempt(false, 200, 1),
+            attempt(false, 300, 2),
+            attempt(true, 400, 3),
+            attempt(false, 500, 1),
+            attempt(false, 600, 1),
+            attempt(false, 700, 2),
+        ];
+        assert_eq!(count_runs(&attempts), (2, 2));
+        assert_eq!(count_runs(&[]), (0, 0));
+
+        let durations: Vec<i64> = (1..=20).map(|i| i * 10).collect();
+        assert_eq!(percentile(&durations, 50), 100);
+        assert_eq!(percentile(&durations, 95), 190);
+        assert_eq!(percentile(&[42], 95), 42);
+        assert_eq!(percentile(&[], 50), 0);
+    }
+
+    #[test]
+    fn pages_output_on_char_boundaries() {
+        let s = "a你好b";
+        assert_eq!(output_page(s, 0, 2), "a");
+        assert_eq!(output_page(s, 1, 6), "你好");
+        assert_eq!(output_page(s, 2, 100), "你好b");
+        assert_eq!(output_page(s, 100, 10), "");
+    }
+
+    #[test]
+    fn applies_misfire_policy() {
+        let now = utc("2025-01-15T12:00:30Z");
+        // On time: always one run
+        assert_eq!(
+            planned_runs(&job("0 * * * *", "2025-01-15T12:00:00Z", "skip"), &now),
+            1
+        );
+        // Missed the 09:00..12:00 hourly runs
+        let missed = "2025-01-15T09:00:00Z";
+        assert_eq!(planned_runs(&job("0 * * * *", missed, "skip"), &now), 0);
+        assert_eq!(planned_runs(&job("0 * * * *", missed, "run_once"), &now), 1);
+        assert_eq!(planned_runs(&job("0 * * * *", missed, "run_all"), &now), 4);
+    }
+
+    #[test]
+    fn prevents_overlapping_runs() {
+        let mut j = job("0 * * * *", "2025-01-15T12:00:00Z", "run_once");
+        j.id = "overlap-test".into();
+        let first = try_begin_execution(&j);
+        assert!(first.is_some());
+        assert!(try_begin_execution(&j).is_none());
+        j.no_overlap = false;
+        assert!(try_begin_execution(&j).is_some());
+        drop(first);
+    }
+
+    #[test]
+    fn parses_naive_at_time_in_timezone() {
+        let now = chrono::Utc::now();
+        let at = compute_next_run("at", "2030-06-01 08:00", Some("Asia/Shanghai"), &now).unwrap();
+        assert_eq!(at, utc("2030-06-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn migrations_are_repeatable() {
+        let conn = Connection::open_in_memory().unwrap();
+        migrate(&conn).unwrap();
+        migrate(&conn).unwrap();
+        let columns: Vec<String> = conn
+            .prepare("SELECT name FROM pragma_table_info('cron_runs')")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .filter_map(|r| r.ok())
+            .collect();
+        assert!(columns.iter().any(|c| c == "chain_id"));
+    }
 }
@@ -29,6 +29,8 @@ pub struct CronNotification {
     pub duration_ms: i64,
     /// UTC epoch seconds when the job finished
     pub finished_at: i64,
+    /// The job was disabled because it failed too many times in a row
+    pub auto_disabled: bool,
 }
 
 // ──────────────────── Broadcast Channel ──────────────────────
@@ -1,3 +1,11 @@
+//! Knowledge API — browse and edit the agent's long-term memory.
+//!
+//! Global memory lives in the main workspace. Each project has a memory of
+//! its own under `workspace/projects/<id>/`, which agents in the project's
+//! sessions use instead of the global one.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::OnceLock;
 use tokio::sync::Mutex as TokioMutex;
 
@@ -28,34 +36,56 @@ pub struct KnowledgeStats {
     pub auto_save: bool,
 }
 
-// ──────────────────── Cached Memory Backend ──────────────────
+// ──────────────────── Cached Memory Backends ─────────────────
 
-fn memory_backend() -> &'static TokioMutex<Option<Box<dyn zeroclaw::memory::Memory>>> {
-    static BACKEND: OnceLock<TokioMutex<Option<Box<dyn zeroclaw::memory::Memory>>>> =
-        OnceLock::new();
-    BACKEND.get_or_init(|| TokioMutex::new(None))
+type MemoryBackends = HashMap<String, Box<dyn zeroclaw::memory::Memory>>;
+
+/// Memory backends keyed by project ID ("" = global memory)
+fn memory_backends() -> &'static TokioMutex<MemoryBackends> {
+    static BACKENDS: OnceLock<TokioMutex<MemoryBackends>> = OnceLock::new();
+    BACKENDS.get_or_init(|| TokioMutex::new(HashMap::new()))
 }
 
-async fn ensure_memory_backend() -> Result<(), String> {
-    let mut mem = memory_backend().lock().await;
-    if mem.is_some() {
+async fn ensure_memory_backend(project_id: &str) -> Result<(), String> {
+    // The id becomes a directory name below
+    if !project_id.is_empty() && !super::project_api::is_valid_project_id(project_id) {
+        return Err(format!("invalid project id: {project_id}"));
+    }
+    let mut backends = memory_backends().lock().await;
+    if backends.contains_key(project_id) {
         return Ok(());
     }
 
     let cs = super::agent_api::config_state().read().await;
     let config = cs.config.as_ref().ok_or("not initialized")?;
 
-    let backend = zeroclaw::memory::create_memory(
-        &config.memory,
-        &config.workspace_dir,
-        config.api_key.as_deref(),
-    )
-    .map_err(|e| format!("memory init failed: {e}"))?;
+    let workspace_dir = if project_id.is_empty() {
+        config.workspace_dir.clone()
+    } else {
+        let dir = project_memory_workspace(project_id);
+        std::fs::create_dir_all(&dir).map_err(|e| format!("memory init failed: {e}"))?;
+        dir
+    };
+    let backend =
+        zeroclaw::memory::create_memory(&config.memory, &workspace_dir, config.api_key.as_deref())
+            .map_err(|e| format!("memory init failed: {e}"))?;
 
-    *mem = Some(backend);
+    backends.insert(project_id.to_string(), backend);
     Ok(())
 }
 
+/// Workspace holding a project's memory (the memory itself is in its
+/// `memory/` subdirectory, which project sessions link to). `project_id`
+/// must pass `is_valid_project_id`.
+pub(crate) fn project_memory_workspace(project_id: &str) -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join(".coraldesk")
+        .join("workspace")
+        .join("projects")
+        .join(project_id)
+}
+
 // ──────────────────── API Functions ──────────────────────────
 
 /// Get knowledge base statistics
@@ -74,10 +104,10 @@ pub async fn get_knowledge_stats() -> KnowledgeStats {
         };
     drop(cs);
 
-    let (total, healthy) = match ensure_memory_backend().await {
+    let (total, healthy) = match ensure_memory_backend("").await {
         Ok(()) => {
-            let mem = memory_backend().lock().await;
-            if let Some(backend) = mem.as_ref() {
+            let backends = memory_backends().lock().await;
+            if let Some(backend) = backends.get("") {
                 let count = backend.count().await.unwrap_or(0) as u32;
                 let health = backend.health_check().await;
                 (count, health)
@@ -98,14 +128,20 @@ pub async fn get_knowledge_stats() -> KnowledgeStats {
     }
 }
 
-/// List knowledge entries, optionally filtered by category
-pub async fn list_knowledge_entries(category: Option<String>, limit: u32) -> Vec<KnowledgeEntry> {
-    if ensure_memory_backend().await.is_err() {
+/// List knowledge entries, optionally filtered by category.
+/// With `project_id`, lists that project's memory instead of the global one.
+pub async fn list_knowledge_entries(
+    category: Option<String>,
+    limit: u32,
+    project_id: Option<String>,
+) -> Vec<KnowledgeEntry> {
+    let project_id = project_id.unwrap_or_default();
+    if ensure_memory_backend(&project_id).await.is_err() {
         return vec![];
     }
 
-    let mem = memory_backend().lock().await;
-    let backend = match mem.as_ref() {
+    let backends = memory_backends().lock().await;
+    let backend = match backends.get(&project_id) {
         Some(b) => b,
         None => return vec![],
     };
@@ -119,36 +155,34 @@ pub async fn list_knowledge_entries(category: Option<String>, limit: u32) -> Vec
     entries
         .into_iter()
         .take(limit as usize)
-        .map(entry_to_dto)
+        .map(|e| entry_to_dto(e, &project_id))
         .collect()
 }
 
-/// Search knowledge entries by query (semantic search if embeddings available, otherwise keyword)
-pub async fn search_knowledge(query: String, limit: u32) -> Vec<KnowledgeEntry> {
-    if ensure_memory_backend().await.is_err() {
-        return vec![];
-    }
-
-    let mem = memory_backend().lock().await;
-    let backend = match mem.as_ref() {
-        Some(b) => b,
-        None => return vec![],
-    };
-
-    match backend.recall(&query, limit as usize, None).await {
-        Ok(entries) => entries.into_iter().map(entry_to_dto).collect(),
-        Err(_) => vec![],
-    }
+/// Search knowledge entries by query (semantic search if embeddings available, otherwise keyword).
+/// With `project_id`, searches that project's memory instead of the global one.
+pub async fn search_knowledge(
+    query: String,
+    limit: u32,
+    project_id: Option<String>,
+) -> Vec<KnowledgeEntry> {
+    recall_entries(&project_id.unwrap_or_default(), &query, limit as usize).await
 }
 
-/// Add a new knowledge entry
-pub async fn add_knowledge_entry(key: String, content: String, category: String) -> String {
-    if let Err(e) = ensure_memory_backend().await {
+/// Add a new knowledge entry, to a project's memory when `project_id` is set
+pub async fn add_knowledge_entry(
+    key: String,
+    content: String,
+    category: String,
+    project_id: Option<String>,
+) -> String {
+    let project_id = project_id.unwrap_or_default();
+    if let Err(e) = ensure_memory_backend(&project_id).await {
         return format!("error: {e}");
     }
 
-    let mem = memory_backend().lock().await;
-    let backend = match mem.as_ref() {
+    let backends = memory_backends().lock().await;
+    let backend = match backends.get(&project_id) {
         Some(b) => b,
         None => return "error: no backend".into(),
     };
@@ -161,13 +195,14 @@ pub async fn add_knowledge_entry(key: String, content: String, category: String)
 }
 
 /// Delete a knowledge entry by key
-pub async fn delete_knowledge_entry(key: String) -> String {
-    if let Err(e) = ensure_memory_backend().await {
+pub async fn delete_knowledge_entry(key: String, project_id: Option<String>) -> String {
+    let project_id = project_id.unwrap_or_default();
+    if let Err(e) = ensure_memory_backend(&project_id).await {
         return format!("error: {e}");
     }
 
-    let mem = memory_backend().lock().await;
-    let backend = match mem.as_ref() {
+    let backends = memory_backends().lock().await;
+    let backend = match backends.get(&project_id) {
         Some(b) => b,
         None => return "error: no backend".into(),
     };
@@ -180,23 +215,54 @@ pub async fn delete_knowledge_entry(key: String) -> String {
 }
 
 /// Get a single knowledge entry by key
-pub async fn get_knowledge_entry(key: String) -> Option<KnowledgeEntry> {
-    if ensure_memory_backend().await.is_err() {
+pub async fn get_knowledge_entry(
+    key: String,
+    project_id: Option<String>,
+) -> Option<KnowledgeEntry> {
+    let project_id = project_id.unwrap_or_default();
+    if ensure_memory_backend(&project_id).await.is_err() {
         return None;
     }
 
-    let mem = memory_backend().lock().await;
-    let backend = match mem.as_ref() {
-        Some(b) => b,
-        None => return None,
-    };
+    let backends = memory_backends().lock().await;
+    let backend = backends.get(&project_id)?;
 
     match backend.get(&key).await {
-        Ok(Some(entry)) => Some(entry_to_dto(entry)),
+        Ok(Some(entry)) => Some(entry_to_dto(entry, &project_id)),
         _ => None,
     }
 }
 
+/// Recall for an agent in a project session: the project's entries first,
+/// then — when `fallback_to_global` — global entries up to `limit`.
+pub(crate) async fn recall_for_project(
+    project_id: &str,
+    query: &str,
+    limit: usize,
+    fallback_to_global: bool,
+) -> Vec<KnowledgeEntry> {
+    let mut entries = recall_entries(project_id, query, limit).await;
+    if fallback_to_global && entries.len() < limit {
+        let missing = limit - entries.len();
+        entries.extend(recall_entries("", query, missing).await);
+    }
+    entries
+}
+
+/// Drop a deleted project's memory
+pub(crate) async fn delete_project_knowledge(project_id: &str) {
+    if !super::project_api::is_valid_project_id(project_id) {
+        return;
+    }
+    memory_backends().lock().await.remove(project_id);
+    let dir = project_memory_workspace(project_id);
+    if dir.exists() {
+        if let Err(e) = std::fs::remove_dir_all(&dir) {
+            tracing::warn!("Failed to delete memory of project {project_id}: {e}");
+        }
+    }
+}
+
 // ──────────────────── Helpers ─────────────────────────────────
 
 fn parse_category(s: &str) -> zeroclaw::memory::MemoryCategory {
@@ -217,10 +283,28 @@ fn category_to_string(cat: &zeroclaw::memory::MemoryCategory) -> String {
     }
 }
 
-fn entry_to_dto(entry: zeroclaw::memory::MemoryEntry) -> KnowledgeEntry {
-    // Derive project_id from session_id if the session belongs to a project.
-    // For now, we use an empty string — project scoping is resolved at the
-    // Dart layer by filtering entries whose session_id belong to a project.
+async fn recall_entries(project_id: &str, query: &str, limit: usize) -> Vec<KnowledgeEntry> {
+    if ensure_memory_backend(project_id).await.is_err() {
+        return vec![];
+    }
+
+    let backends = memory_backends().lock().await;
+    let backend = match backends.get(project_id) {
+        Some(b) => b,
+        None => return vec![],
+    };
+
+    match backend.recall(query, limit, None).await {
+        Ok(entries) => entries
+            .into_iter()
+            .map(|e| entry_to_dto(e, project_id))
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+/// `project_id` is the memory the entry was read from ("" = global)
+fn entry_to_dto(entry: zeroclaw::memory::MemoryEntry, project_id: &str) -> KnowledgeEntry {
     KnowledgeEntry {
         id: entry.id,
         key: entry.key,
@@ -228,7 +312,7 @@ fn entry_to_dto(entry: zeroclaw::memory::MemoryEntry) -> KnowledgeEntry {
         category: category_to_string(&entry.category),
         timestamp: entry.timestamp,
         session_id: entry.session_id.unwrap_or_default(),
-        project_id: String::new(), // Resolved by Dart layer via session→project mapping
+        project_id: project_id.to_string(),
         score: entry.score.unwrap_or(0.0),
     }
 }
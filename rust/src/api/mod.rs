@@ -11,6 +11,11 @@ pub mod knowledge_api;
 pub mod llm_debug_api;
 pub mod mcp_api;
 pub mod project_api;
+pub mod project_bundle_api;
+pub mod project_git_api;
+pub mod project_index_api;
+pub mod project_template_api;
+pub mod project_timeline_api;
 pub mod providers_api;
 pub mod proxy_api;
 pub mod routes_api;
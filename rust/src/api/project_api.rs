@@ -4,9 +4,15 @@
 //! providing persistent context, directory binding, and cross-session
 //! knowledge for long-running tasks (e.g. coding projects, daily data
 //! processing, automation workflows).
+//!
+//! Project membership of sessions is owned by this module: a project's
+//! `session_ids` is the only record of which sessions belong to it, and a
+//! session belongs to at most one project. The sessions store asks here
+//! when it needs a session's project.
 
 use flutter_rust_bridge::frb;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::OnceLock;
 use tokio::sync::Mutex as TokioMutex;
@@ -55,13 +61,63 @@ pub struct ProjectDto {
     pub role_ids: Vec<String>,
     /// Default role ID for new sessions (empty = no default)
     pub default_role_id: String,
-    /// Associated session IDs
+    /// Associated session IDs. Ignored by `upsert_project` for existing
+    /// projects; use `add_session_to_project` / `remove_session_from_project`.
     pub session_ids: Vec<String>,
     pub tags: Vec<String>,
+    /// Agents recall global memory when the project's own memory has too
+    /// few matches
+    pub global_memory_fallback: bool,
+    /// Autonomy and tool settings for this project's sessions
+    pub autonomy: ProjectAutonomyDto,
+    /// Model provider profile for this project's sessions (empty = the
+    /// global default provider)
+    pub model_profile_id: String,
+    /// Model for this project's sessions (empty = the profile's default
+    /// model, or the global one)
+    pub model: String,
+    /// None = the global default temperature
+    pub temperature: Option<f64>,
     pub created_at: i64,
     pub updated_at: i64,
 }
 
+/// A project's overrides of the global autonomy settings, applied to the
+/// agents of its sessions and its cron jobs. Empty fields inherit the
+/// global value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectAutonomyDto {
+    /// "read_only", "supervised" or "full"; empty = global level
+    pub level: String,
+    /// Run without approval prompts; None = global setting
+    pub trust_me: Option<bool>,
+    /// Replaces the global allowed commands when non-empty
+    pub allowed_commands: Vec<String>,
+    /// Forbidden in addition to the global forbidden paths
+    pub forbidden_paths: Vec<String>,
+    /// Tools approved without asking, on top of the global list
+    pub auto_approve: Vec<String>,
+    /// Tools always asked about, on top of the global list. Wins over
+    /// `auto_approve`, here and globally.
+    pub always_ask: Vec<String>,
+    /// The only tools the agents get; empty = no restriction
+    pub enabled_tools: Vec<String>,
+}
+
+/// Result of checking project ↔ session membership
+#[derive(Debug, Clone, Default)]
+pub struct ProjectSessionReport {
+    /// Sessions whose legacy session-side project_id was adopted by that project
+    pub migrated_sessions: Vec<String>,
+    /// Memberships of sessions that no longer exist
+    pub missing_sessions: Vec<String>,
+    /// Sessions listed by more than one project (the first project keeps them)
+    pub duplicate_sessions: Vec<String>,
+    /// Whether the problems were fixed
+    pub repaired: bool,
+}
+
 /// Summary for listing projects in sidebar/list
 #[derive(Debug, Clone)]
 pub struct ProjectSummary {
@@ -115,6 +171,16 @@ struct PersistedProject {
     session_ids: Vec<String>,
     #[serde(default)]
     tags: Vec<String>,
+    #[serde(default = "default_true")]
+    global_memory_fallback: bool,
+    #[serde(default)]
+    autonomy: ProjectAutonomyDto,
+    #[serde(default)]
+    model_profile_id: String,
+    #[serde(default)]
+    model: String,
+    #[serde(default)]
+    temperature: Option<f64>,
     created_at: i64,
     updated_at: i64,
 }
@@ -129,6 +195,11 @@ fn default_project_status() -> String {
     "active".into()
 }
 
+#[frb(ignore)]
+fn default_true() -> bool {
+    true
+}
+
 #[frb(ignore)]
 #[derive(Debug, Default, Serialize, Deserialize)]
 struct ProjectStore {
@@ -150,7 +221,7 @@ fn store_file_path() -> PathBuf {
 // ──────────────────── Conversion Helpers ─────────────────────
 
 #[frb(ignore)]
-fn project_type_to_string(pt: &ProjectType) -> String {
+pub(crate) fn project_type_to_string(pt: &ProjectType) -> String {
     match pt {
         ProjectType::General => "general",
         ProjectType::CodeProject => "code_project",
@@ -162,7 +233,7 @@ fn project_type_to_string(pt: &ProjectType) -> String {
 }
 
 #[frb(ignore)]
-fn string_to_project_type(s: &str) -> ProjectType {
+pub(crate) fn string_to_project_type(s: &str) -> ProjectType {
     match s {
         "code_project" => ProjectType::CodeProject,
         "data_processing" => ProjectType::DataProcessing,
@@ -173,7 +244,7 @@ fn string_to_project_type(s: &str) -> ProjectType {
 }
 
 #[frb(ignore)]
-fn status_to_string(s: &ProjectStatus) -> String {
+pub(crate) fn status_to_string(s: &ProjectStatus) -> String {
     match s {
         ProjectStatus::Active => "active",
         ProjectStatus::Paused => "paused",
@@ -184,7 +255,7 @@ fn status_to_string(s: &ProjectStatus) -> String {
 }
 
 #[frb(ignore)]
-fn string_to_status(s: &str) -> ProjectStatus {
+pub(crate) fn string_to_status(s: &str) -> ProjectStatus {
     match s {
         "paused" => ProjectStatus::Paused,
         "archived" => ProjectStatus::Archived,
@@ -194,6 +265,16 @@ fn string_to_status(s: &str) -> ProjectStatus {
 }
 
 #[frb(ignore)]
+/// Project ids name directories (memory, code index), so they must be a
+/// single plain path component
+pub(crate) fn is_valid_project_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= 128
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
 fn persisted_to_dto(p: &PersistedProject) -> ProjectDto {
     // Migrate legacy agent_workspace_id → role_ids if needed
     let mut role_ids = p.role_ids.clone();
@@ -219,6 +300,11 @@ fn persisted_to_dto(p: &PersistedProject) -> ProjectDto {
         default_role_id,
         session_ids: p.session_ids.clone(),
         tags: p.tags.clone(),
+        global_memory_fallback: p.global_memory_fallback,
+        autonomy: p.autonomy.clone(),
+        model_profile_id: p.model_profile_id.clone(),
+        model: p.model.clone(),
+        temperature: p.temperature,
         created_at: p.created_at,
         updated_at: p.updated_at,
     }
@@ -274,9 +360,83 @@ pub async fn init_project_store() -> String {
 
     let count = store.projects.len();
     *project_store().lock().await = store;
+
+    let report = check_project_sessions(true).await;
+    if !report.migrated_sessions.is_empty()
+        || !report.missing_sessions.is_empty()
+        || !report.duplicate_sessions.is_empty()
+    {
+        tracing::info!(
+            "Repaired project sessions: {} migrated, {} missing, {} duplicate",
+            report.migrated_sessions.len(),
+            report.missing_sessions.len(),
+            report.duplicate_sessions.len()
+        );
+    }
     format!("loaded {} projects", count)
 }
 
+/// Check that every project session exists and belongs to only one project,
+/// and adopt memberships still recorded on the session side by older versions.
+/// With `repair`, the problems found are fixed and saved.
+pub async fn check_project_sessions(repair: bool) -> ProjectSessionReport {
+    // None until the sessions store is loaded — then nothing can be checked
+    let Some(sessions) = super::sessions_api::legacy_session_projects().await else {
+        return ProjectSessionReport::default();
+    };
+    let legacy: HashMap<&str, &str> = sessions
+        .iter()
+        .map(|(id, project_id)| (id.as_str(), project_id.as_str()))
+        .collect();
+
+    let mut report = ProjectSessionReport::default();
+    let mut store = project_store().lock().await;
+    let mut seen = HashSet::new();
+    for project in &mut store.projects {
+        project.session_ids.retain(|sid| {
+            if !legacy.contains_key(sid.as_str()) {
+                report.missing_sessions.push(sid.clone());
+                !repair
+            } else if !seen.insert(sid.clone()) {
+                report.duplicate_sessions.push(sid.clone());
+                !repair
+            } else {
+                true
+            }
+        });
+    }
+    for (sid, project_id) in &sessions {
+        if project_id.is_empty() || seen.contains(sid) {
+            continue;
+        }
+        if let Some(project) = store.projects.iter_mut().find(|p| &p.id == project_id) {
+            report.migrated_sessions.push(sid.clone());
+            if repair {
+                project.session_ids.push(sid.clone());
+            }
+        }
+    }
+    drop(store);
+
+    if repair {
+        let changed = !report.missing_sessions.is_empty()
+            || !report.duplicate_sessions.is_empty()
+            || !report.migrated_sessions.is_empty();
+        let result = if changed {
+            persist_store().await
+        } else {
+            "ok".into()
+        };
+        if result == "ok" {
+            super::sessions_api::clear_legacy_session_projects().await;
+            report.repaired = true;
+        } else {
+            tracing::warn!("Failed to save repaired project sessions: {result}");
+        }
+    }
+    report
+}
+
 /// List all projects (summary only)
 pub async fn list_projects() -> Vec<ProjectSummary> {
     let store = project_store().lock().await;
@@ -285,6 +445,11 @@ pub async fn list_projects() -> Vec<ProjectSummary> {
 
 /// Get full details of a single project
 pub async fn get_project(project_id: String) -> Option<ProjectDto> {
+    project_info(&project_id).await
+}
+
+/// A project as stored
+pub(crate) async fn project_info(project_id: &str) -> Option<ProjectDto> {
     let store = project_store().lock().await;
     store
         .projects
@@ -293,22 +458,50 @@ pub async fn get_project(project_id: String) -> Option<ProjectDto> {
         .map(persisted_to_dto)
 }
 
-/// Create or update a project
+/// Create or update a project. Session membership of an existing project is
+/// left as is; a new project takes over the listed sessions from other projects.
 pub async fn upsert_project(project: ProjectDto) -> String {
     let id = if project.id.trim().is_empty() {
         uuid::Uuid::new_v4().to_string()
     } else {
         project.id.trim().to_string()
     };
+    if !is_valid_project_id(&id) {
+        return format!("error: invalid project id: {id}");
+    }
     if project.name.trim().is_empty() {
         return "error: project name must not be empty".into();
     }
+    let level = project.autonomy.level.trim();
+    if !matches!(level, "" | "read_only" | "supervised" | "full") {
+        return format!("error: unknown autonomy level: {level}");
+    }
+    let model_profile_id = project.model_profile_id.trim().to_string();
+    let profile_changed = project_info(&id)
+        .await
+        .is_none_or(|p| p.model_profile_id != model_profile_id);
+    if profile_changed && !model_profile_id.is_empty() && !profile_exists(&model_profile_id).await {
+        return format!("error: model provider profile '{model_profile_id}' not found");
+    }
+    if project
+        .temperature
+        .is_some_and(|t| !(0.0..=2.0).contains(&t))
+    {
+        return "error: temperature must be between 0 and 2".into();
+    }
+    let model = project.model.trim().to_string();
 
     let now = chrono::Utc::now().timestamp();
     let mut store = project_store().lock().await;
 
     let project_type_str = project_type_to_string(&project.project_type);
     let status_str = status_to_string(&project.status);
+    let previous_status = store
+        .projects
+        .iter()
+        .find(|p| p.id == id)
+        .map(|p| p.status.clone());
+    let mut settings_changed_for = Vec::new();
 
     if let Some(existing) = store.projects.iter_mut().find(|p| p.id == id) {
         existing.name = project.name;
@@ -316,16 +509,35 @@ pub async fn upsert_project(project: ProjectDto) -> String {
         existing.icon = project.icon;
         existing.color_tag = project.color_tag;
         existing.project_type = project_type_str;
-        existing.status = status_str;
+        existing.status = status_str.clone();
         existing.project_dir = project.project_dir;
         existing.pinned_context = project.pinned_context;
         existing.role_ids = project.role_ids;
         existing.default_role_id = project.default_role_id;
         existing.agent_workspace_id = String::new(); // cleared after migration
-        existing.session_ids = project.session_ids;
         existing.tags = project.tags;
+        existing.global_memory_fallback = project.global_memory_fallback;
+        if existing.autonomy != project.autonomy
+            || existing.model_profile_id != model_profile_id
+            || existing.model != model
+            || existing.temperature != project.temperature
+        {
+            existing.autonomy = project.autonomy;
+            existing.model_profile_id = model_profile_id;
+            existing.model = model;
+            existing.temperature = project.temperature;
+            settings_changed_for = existing.session_ids.clone();
+        }
         existing.updated_at = now;
     } else {
+        let mut session_ids = Vec::new();
+        for sid in project.session_ids {
+            if !session_ids.contains(&sid) {
+                detach_session(&mut store, &sid);
+                session_ids.push(sid);
+            }
+        }
+        settings_changed_for = session_ids.clone();
         store.projects.insert(
             0,
             PersistedProject {
@@ -335,14 +547,19 @@ pub async fn upsert_project(project: ProjectDto) -> String {
                 icon: project.icon,
                 color_tag: project.color_tag,
                 project_type: project_type_str,
-                status: status_str,
+                status: status_str.clone(),
                 project_dir: project.project_dir,
                 pinned_context: project.pinned_context,
                 agent_workspace_id: String::new(),
                 role_ids: project.role_ids,
                 default_role_id: project.default_role_id,
-                session_ids: project.session_ids,
+                session_ids,
                 tags: project.tags,
+                global_memory_fallback: project.global_memory_fallback,
+                autonomy: project.autonomy,
+                model_profile_id,
+                model,
+                temperature: project.temperature,
                 created_at: now,
                 updated_at: now,
             },
@@ -352,46 +569,109 @@ pub async fn upsert_project(project: ProjectDto) -> String {
     drop(store);
     let result = persist_store().await;
     if result == "ok" {
+        if let Some(previous) = previous_status {
+            sync_archived_cron_jobs(&id, &previous, &status_str);
+        }
+        // Rebuild the sessions' agents with the project's new settings
+        for session_id in settings_changed_for {
+            super::agent_api::invalidate_session_agent(&session_id).await;
+        }
         id
     } else {
         result
     }
 }
 
-/// Delete a project (does NOT delete the sessions; only the project container)
+/// Delete a project (does NOT delete the sessions; only the project container).
+/// Its sessions become free sessions; the project's cron jobs and memory are
+/// deleted with it.
 pub async fn delete_project(project_id: String) -> String {
+    if !is_valid_project_id(&project_id) {
+        return format!("error: invalid project id: {project_id}");
+    }
     let mut store = project_store().lock().await;
+    let before = store.projects.len();
     store.projects.retain(|p| p.id != project_id);
+    if store.projects.len() == before {
+        return "error: project not found".into();
+    }
     drop(store);
-    persist_store().await
+    let result = persist_store().await;
+    if result == "ok" {
+        super::cron_api::delete_project_cron_jobs(&project_id);
+        super::knowledge_api::delete_project_knowledge(&project_id).await;
+    }
+    result
 }
 
-/// Add a session to a project
+/// Add a session to a project, moving it out of any other project
 pub async fn add_session_to_project(project_id: String, session_id: String) -> String {
+    set_session_project(&session_id, &project_id).await
+}
+
+/// Remove a session from a project
+pub async fn remove_session_from_project(project_id: String, session_id: String) -> String {
     let mut store = project_store().lock().await;
     if let Some(project) = store.projects.iter_mut().find(|p| p.id == project_id) {
-        if !project.session_ids.contains(&session_id) {
-            project.session_ids.push(session_id);
-            project.updated_at = chrono::Utc::now().timestamp();
-        }
-    } else {
-        return "error: project not found".into();
+        project.session_ids.retain(|s| s != &session_id);
+        project.updated_at = chrono::Utc::now().timestamp();
     }
     drop(store);
+    super::agent_api::invalidate_session_agent(&session_id).await;
     persist_store().await
 }
 
-/// Remove a session from a project
-pub async fn remove_session_from_project(project_id: String, session_id: String) -> String {
+/// Make a session belong to `project_id`, or to no project when it's empty.
+/// Only writes to disk when the membership actually changes.
+pub(crate) async fn set_session_project(session_id: &str, project_id: &str) -> String {
     let mut store = project_store().lock().await;
+    let current = store
+        .projects
+        .iter()
+        .find(|p| p.session_ids.iter().any(|s| s == session_id))
+        .map(|p| p.id.clone());
+    if current.as_deref().unwrap_or("") == project_id {
+        return "ok".into();
+    }
+    if !project_id.is_empty() && !store.projects.iter().any(|p| p.id == project_id) {
+        return "error: project not found".into();
+    }
+    detach_session(&mut store, session_id);
     if let Some(project) = store.projects.iter_mut().find(|p| p.id == project_id) {
-        project.session_ids.retain(|s| s != &session_id);
+        project.session_ids.push(session_id.to_string());
         project.updated_at = chrono::Utc::now().timestamp();
     }
     drop(store);
+    // The session's agent was built with the old project's settings
+    super::agent_api::invalidate_session_agent(session_id).await;
     persist_store().await
 }
 
+/// Remove deleted sessions from their projects
+pub(crate) async fn remove_sessions_from_projects(session_ids: &[String]) -> String {
+    let mut store = project_store().lock().await;
+    let mut changed = false;
+    for sid in session_ids {
+        changed |= detach_session(&mut store, sid);
+    }
+    drop(store);
+    if changed {
+        persist_store().await
+    } else {
+        "ok".into()
+    }
+}
+
+/// Project of every session that belongs to one
+pub(crate) async fn session_project_map() -> HashMap<String, String> {
+    let store = project_store().lock().await;
+    store
+        .projects
+        .iter()
+        .flat_map(|p| p.session_ids.iter().map(|sid| (sid.clone(), p.id.clone())))
+        .collect()
+}
+
 /// Update project pinned context
 pub async fn update_project_context(project_id: String, pinned_context: String) -> String {
     let mut store = project_store().lock().await;
@@ -405,17 +685,37 @@ pub async fn update_project_context(project_id: String, pinned_context: String)
     persist_store().await
 }
 
-/// Update project status
+/// Update project status. Archiving pauses the project's cron jobs;
+/// restoring it resumes them.
 pub async fn update_project_status(project_id: String, status: ProjectStatus) -> String {
     let mut store = project_store().lock().await;
-    if let Some(project) = store.projects.iter_mut().find(|p| p.id == project_id) {
-        project.status = status_to_string(&status);
-        project.updated_at = chrono::Utc::now().timestamp();
-    } else {
-        return "error: project not found".into();
-    }
+    let new_status = status_to_string(&status);
+    let previous_status =
+        if let Some(project) = store.projects.iter_mut().find(|p| p.id == project_id) {
+            let previous = std::mem::replace(&mut project.status, new_status.clone());
+            project.updated_at = chrono::Utc::now().timestamp();
+            previous
+        } else {
+            return "error: project not found".into();
+        };
     drop(store);
-    persist_store().await
+    let result = persist_store().await;
+    if result == "ok" {
+        sync_archived_cron_jobs(&project_id, &previous_status, &new_status);
+    }
+    result
+}
+
+/// Pause or resume a project's cron jobs when it enters or leaves "archived"
+fn sync_archived_cron_jobs(project_id: &str, previous_status: &str, new_status: &str) {
+    let was_archived = previous_status == "archived";
+    let is_archived = new_status == "archived";
+    if was_archived != is_archived {
+        let result = super::cron_api::set_project_cron_jobs_paused(project_id, is_archived);
+        if result != "ok" {
+            tracing::warn!("Failed to update cron jobs of project {project_id}: {result}");
+        }
+    }
 }
 
 /// Add a role (agent workspace) to a project
@@ -500,8 +800,320 @@ pub async fn get_project_pinned_context(project_id: String) -> String {
         .unwrap_or_default()
 }
 
+// ──────────────────── Agent Prompt ───────────────────────────
+
+/// Rough token budget for the project block in the system prompt
+const PROMPT_TOKEN_BUDGET: usize = 1500;
+
+/// Tool through which agents propose pinned-context updates. Project
+/// sessions list it in `always_ask`, so every call needs the user's approval.
+pub(crate) const CONTEXT_UPDATE_TOOL: &str = "project_context_update";
+
+/// The project block added to the system prompt of the project's sessions:
+/// name, description, tags and as much pinned context as fits the budget.
+pub(crate) async fn project_prompt_context(project_id: &str) -> Option<String> {
+    let project = project_info(project_id).await?;
+    let mut block = format!("# Project: {}\n", project.name);
+    if !project.description.trim().is_empty() {
+        block.push_str(&format!("\n{}\n", project.description.trim()));
+    }
+    if !project.tags.is_empty() {
+        block.push_str(&format!("\nTags: {}\n", project.tags.join(", ")));
+    }
+    if !project.project_dir.is_empty() {
+        block.push_str(&format!("Directory: {}\n", project.project_dir));
+    }
+    block.push_str(&format!(
+        "\n## Pinned Context\n\
+         Notes shared by every session of this project. Propose changes with the \
+         `{CONTEXT_UPDATE_TOOL}` tool; the user approves each one.\n\n"
+    ));
+    let budget = PROMPT_TOKEN_BUDGET.saturating_sub(estimate_tokens(&block));
+    let pinned = project.pinned_context.trim();
+    if pinned.is_empty() {
+        block.push_str("(empty)\n");
+    } else {
+        let kept = truncate_to_tokens(pinned, budget);
+        block.push_str(kept);
+        if kept.len() < pinned.len() {
+            block.push_str("\n…(truncated)");
+        }
+        block.push('\n');
+    }
+    Some(block)
+}
+
+/// Approximate token count: ~4 ASCII characters per token, one token for
+/// any other character (CJK text is roughly one token per character)
+fn estimate_tokens(text: &str) -> usize {
+    text.chars().map(char_cost).sum::<usize>().div_ceil(4)
+}
+
+/// Longest prefix of `text` within `budget` tokens, cut at a line break
+/// when one is close to the limit
+fn truncate_to_tokens(text: &str, budget: usize) -> &str {
+    let limit = budget * 4;
+    let mut cost = 0;
+    for (i, c) in text.char_indices() {
+        cost += char_cost(c);
+        if cost > limit {
+            let head = &text[..i];
+            return match head.rfind('\n') {
+                Some(nl) if nl >= i * 3 / 4 => &head[..nl],
+                _ => head,
+            };
+        }
+    }
+    text
+}
+
+/// Cost in quarter tokens
+fn char_cost(c: char) -> usize {
+    if c.is_ascii() {
+        1
+    } else {
+        4
+    }
+}
+
+/// Agent tool for the sessions of `project_id`. Every update is approved by
+/// the user of `session_id`.
+pub(crate) fn project_context_tool(
+    project_id: &str,
+    session_id: &str,
+) -> Box<dyn zeroclaw::tools::Tool> {
+    Box::new(ProjectContextTool {
+        project_id: project_id.to_string(),
+        session_id: session_id.to_string(),
+    })
+}
+
+struct ProjectContextTool {
+    project_id: String,
+    session_id: String,
+}
+
+#[async_trait::async_trait]
+impl zeroclaw::tools::Tool for ProjectContextTool {
+    fn name(&self) -> &str {
+        CONTEXT_UPDATE_TOOL
+    }
+
+    fn description(&self) -> &str {
+        "Propose an update to the project's pinned context — the notes every session \
+         of this project sees in its instructions. Keep it short: decisions, conventions \
+         and facts future sessions need. The user must approve the change."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "content": {
+                    "type": "string",
+                    "description": "Text to append, or the complete new pinned context with mode \"replace\""
+                },
+                "mode": {
+                    "type": "string",
+                    "enum": ["append", "replace"],
+                    "description": "\"append\" (default) adds to the end; \"replace\" rewrites it"
+                },
+                "reason": {
+                    "type": "string",
+                    "description": "One sentence for the user on why this belongs in the pinned context"
+                }
+            },
+            "required": ["content"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        args: serde_json::Value,
+    ) -> anyhow::Result<zeroclaw::tools::ToolResult> {
+        // Pinned context reaches every session of the project, so the user
+        // approves each update whatever the autonomy settings. Without a
+        // dialog to ask in, the update is refused.
+        let decision = super::agent_api::request_tool_approval(
+            &self.session_id,
+            CONTEXT_UPDATE_TOOL,
+            args.clone(),
+        )
+        .await;
+        let denied = match decision {
+            zeroclaw::approval::ApprovalResponse::Yes
+            | zeroclaw::approval::ApprovalResponse::Always => None,
+            zeroclaw::approval::ApprovalResponse::DenyWithReason(reason) => {
+                Some(format!("The user declined the update: {reason}"))
+            }
+            _ => Some("The user did not approve the update.".to_string()),
+        };
+        if let Some(error) = denied {
+            return Ok(zeroclaw::tools::ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(error),
+            });
+        }
+
+        let content = args
+            .get("content")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .trim();
+        let replace = args.get("mode").and_then(|v| v.as_str()) == Some("replace");
+        let current = get_project_pinned_context(self.project_id.clone()).await;
+        let updated = if replace || current.trim().is_empty() {
+            content.to_string()
+        } else if content.is_empty() {
+            current.clone()
+        } else {
+            format!("{}\n\n{content}", current.trim_end())
+        };
+
+        let result = update_project_context(self.project_id.clone(), updated.clone()).await;
+        if result != "ok" {
+            return Ok(zeroclaw::tools::ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(result),
+            });
+        }
+        let mut output = "Pinned context updated.".to_string();
+        if estimate_tokens(&updated) > PROMPT_TOKEN_BUDGET {
+            output.push_str(&format!(
+                " It is now longer than the ~{PROMPT_TOKEN_BUDGET} token prompt budget, \
+                 so sessions only see its beginning — consider condensing it."
+            ));
+        }
+        Ok(zeroclaw::tools::ToolResult {
+            success: true,
+            output,
+            error: None,
+        })
+    }
+}
+
+// ──────────────────── Session Overrides ────────────────────────
+
+/// Apply a project's provider profile, model and temperature to a
+/// session's config. A profile that no longer exists is skipped.
+pub(crate) fn apply_model_overrides(config: &mut zeroclaw::Config, project: &ProjectDto) {
+    if !project.model_profile_id.is_empty() {
+        if config
+            .model_providers
+            .contains_key(&project.model_profile_id)
+        {
+            // The profile only sets what it has; the global provider's
+            // endpoint and key must not carry over to it
+            config.api_url = None;
+            config.api_key = None;
+        }
+        if let Err(e) = super::providers_api::apply_profile(config, &project.model_profile_id) {
+            tracing::warn!("Project {}: {e}; using the global provider", project.id);
+        }
+    }
+    if !project.model.is_empty() {
+        config.default_model = Some(project.model.clone());
+    }
+    if let Some(temperature) = project.temperature {
+        config.default_temperature = temperature;
+    }
+}
+
+/// Apply a project's autonomy overrides to a session's config. Approval
+/// overrides win over the global lists. The tool filter is applied
+/// separately by `narrow_enabled_tools`.
+pub(crate) fn apply_autonomy_overrides(
+    config: &mut zeroclaw::Config,
+    overrides: &ProjectAutonomyDto,
+) {
+    let autonomy = &mut config.autonomy;
+    let level = overrides.level.trim();
+    if !level.is_empty() {
+        match serde_json::from_value(serde_json::Value::String(level.to_string())) {
+            Ok(level) => autonomy.level = level,
+            Err(_) => tracing::warn!("Ignoring unknown project autonomy level {level}"),
+        }
+    }
+    if let Some(trust_me) = overrides.trust_me {
+        autonomy.trust_me = trust_me;
+    }
+    if !overrides.allowed_commands.is_empty() {
+        autonomy.allowed_commands = overrides.allowed_commands.clone();
+    }
+    for path in &overrides.forbidden_paths {
+        if !autonomy.forbidden_paths.contains(path) {
+            autonomy.forbidden_paths.push(path.clone());
+        }
+    }
+    for tool in &overrides.auto_approve {
+        if overrides.always_ask.contains(tool) {
+            continue;
+        }
+        autonomy.always_ask.retain(|t| t != tool);
+        if !autonomy.auto_approve.contains(tool) {
+            autonomy.auto_approve.push(tool.clone());
+        }
+    }
+    for tool in &overrides.always_ask {
+        autonomy.auto_approve.retain(|t| t != tool);
+        if !autonomy.always_ask.contains(tool) {
+            autonomy.always_ask.push(tool.clone());
+        }
+    }
+}
+
+/// Narrow the role's tool filter to the project's enabled tools rather than
+/// replacing it. An empty filter means all tools, so an empty intersection
+/// can't be expressed: fails when the two lists have nothing in common.
+pub(crate) fn narrow_enabled_tools(
+    config: &mut zeroclaw::Config,
+    overrides: &ProjectAutonomyDto,
+) -> Result<(), String> {
+    if !overrides.enabled_tools.is_empty() {
+        let allowed = &mut config.agent.allowed_tools;
+        if allowed.is_empty() {
+            *allowed = overrides.enabled_tools.clone();
+        } else {
+            let narrowed: Vec<String> = allowed
+                .iter()
+                .filter(|t| overrides.enabled_tools.contains(t))
+                .cloned()
+                .collect();
+            if narrowed.is_empty() {
+                return Err("none of the project's enabled tools is available to the role".into());
+            }
+            *allowed = narrowed;
+        }
+    }
+    Ok(())
+}
+
 // ──────────────────── Helpers ─────────────────────────────────
 
+async fn profile_exists(profile_id: &str) -> bool {
+    let gc = super::agent_api::global_config().read().await;
+    gc.config
+        .as_ref()
+        .is_some_and(|c| c.model_providers.contains_key(profile_id))
+}
+
+/// Remove a session from whichever project lists it; true if one did
+fn detach_session(store: &mut ProjectStore, session_id: &str) -> bool {
+    let now = chrono::Utc::now().timestamp();
+    let mut detached = false;
+    for project in &mut store.projects {
+        let before = project.session_ids.len();
+        project.session_ids.retain(|s| s != session_id);
+        if project.session_ids.len() != before {
+            project.updated_at = now;
+            detached = true;
+        }
+    }
+    detached
+}
+
 async fn persist_store() -> String {
     let store = project_store().lock().await;
     let path = store_file_path();
@@ -516,3 +1128,80 @@ async fn persist_store() -> String {
         Err(e) => format!("error: serialize failed: {e}"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_pinned_context_to_budget() {
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("项目"), 2);
+        assert_eq!(truncate_to_tokens("short", 10), "short");
+
+        let text = "a".repeat(30) + "\n" + &"b".repeat(30);
+        assert_eq!(truncate_to_tokens(&text, 10), "a".repeat(30));
+        assert_eq!(truncate_to_tokens(&"项".repeat(20), 5), "项".repeat(5));
+    }
+
+    #[test]
+    fn project_ids_are_plain_path_components() {
+        assert!(is_valid_project_id("7d1f0c1e-3b6a-4c57-9a0e-2f4b8e1d6c90"));
+        assert!(is_valid_project_id("proj_1"));
+        assert!(!is_valid_project_id(""));
+        assert!(!is_valid_project_id(".."));
+        assert!(!is_valid_project_id("a/b"));
+    }
+
+    #[tokio::test]
+    async fn context_updates_fail_closed_without_an_approver() {
+        let tool = project_context_tool("proj_unapproved", "session-without-dialog");
+        let result = tool
+            .execute(serde_json::json!({ "content": "Deploy on Fridays" }))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(get_project_pinned_context("proj_unapproved".into())
+            .await
+            .is_empty());
+    }
+
+    #[test]
+    fn project_approvals_override_global_lists() {
+        let mut config = zeroclaw::Config::default();
+        config.autonomy.auto_approve = vec!["shell".into(), "file_read".into()];
+        config.autonomy.always_ask = vec!["browser".into()];
+        config.autonomy.forbidden_paths = vec!["/etc".into()];
+        config.agent.allowed_tools = vec!["shell".into(), "browser".into()];
+
+        let overrides = ProjectAutonomyDto {
+            level: "read_only".into(),
+            trust_me: Some(false),
+            forbidden_paths: vec!["/srv/prod".into()],
+            auto_approve: vec!["browser".into(), "file_write".into()],
+            always_ask: vec!["shell".into(), "file_write".into()],
+            enabled_tools: vec!["shell".into(), "file_read".into()],
+            ..Default::default()
+        };
+        apply_autonomy_overrides(&mut config, &overrides);
+        narrow_enabled_tools(&mut config, &overrides).unwrap();
+        let autonomy = &config.autonomy;
+        assert_eq!(autonomy.auto_approve, ["file_read", "browser"]);
+        assert_eq!(autonomy.always_ask, ["shell", "file_write"]);
+        assert_eq!(autonomy.forbidden_paths, ["/etc", "/srv/prod"]);
+        assert!(!autonomy.trust_me);
+        assert_eq!(
+            serde_json::to_value(autonomy.level).unwrap(),
+            serde_json::json!("read_only")
+        );
+        assert_eq!(config.agent.allowed_tools, ["shell"]);
+
+        // Disjoint tool lists must not fall back to the project's list
+        let disjoint = ProjectAutonomyDto {
+            enabled_tools: vec!["file_read".into()],
+            ..Default::default()
+        };
+        assert!(narrow_enabled_tools(&mut config, &disjoint).is_err());
+        assert_eq!(config.agent.allowed_tools, ["shell"]);
+    }
+}
@@ -0,0 +1,428 @@
+//! Project Bundle API — move a project between machines as a single file.
+//!
+//! A bundle is one UTF-8 JSON file (`*.json`, pretty-printed) rather than a
+//! zip archive, so it can be read and diffed by hand. Its top-level fields:
+//!
+//! - `format` (`"coraldesk-project"`) and `version`, checked on import
+//! - `exported_at`: epoch seconds
+//! - `project`: the project's settings, as in `ProjectDto`
+//! - `sessions`: the project's sessions with their messages
+//! - `roles`: the roles (agent workspaces) bound to the project or its
+//!   sessions, with their SOUL.md, AGENTS.md, USER.md and IDENTITY.md
+//! - `cron_jobs`: the project's cron jobs as a TOML cron file (see
+//!   `cron_api::export_cron_jobs`)
+//! - `knowledge`: the project-scoped knowledge entries
+//!
+//! Importing never overwrites anything:
+//! the project, its sessions and new roles get fresh ids, cron jobs get a
+//! numbered suffix when their name is taken, and roles that already exist
+//! are reused as they are. Nothing that lets agents or jobs act on their
+//! own is taken over: cron jobs arrive disabled, and the project's autonomy
+//! settings and directory are left for the user to set up again.
+//!
+//! Not included: the code search index (rebuilt from the project directory),
+//! cron secrets and model provider profiles, which have to be set up again
+//! on the new machine.
+
+use super::project_api::{ProjectAutonomyDto, ProjectDto};
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+// ──────────────────────── DTOs ────────────────────────────
+
+/// Outcome of `import_project`
+#[derive(Debug, Clone)]
+pub struct ProjectImportResult {
+    /// ID of the imported project (empty when the import failed)
+    pub project_id: String,
+    pub errors: Vec<String>,
+    /// What was renamed, reused or left out along the way
+    pub notes: Vec<String>,
+}
+
+// ──────────────────── Bundle File ────────────────────────────
+
+const BUNDLE_FORMAT: &str = "coraldesk-project";
+const BUNDLE_VERSION: u32 = 1;
+
+#[frb(ignore)]
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectBundle {
+    format: String,
+    version: u32,
+    exported_at: i64,
+    project: BundleProject,
+    #[serde(default)]
+    sessions: Vec<serde_json::Value>,
+    #[serde(default)]
+    roles: Vec<serde_json::Value>,
+    /// The project's cron jobs as a TOML cron file
+    #[serde(default)]
+    cron_jobs: String,
+    #[serde(default)]
+    knowledge: Vec<BundleKnowledge>,
+}
+
+#[frb(ignore)]
+#[derive(Debug, Serialize, Deserialize)]
+struct BundleProject {
+    id: String,
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    icon: String,
+    #[serde(default)]
+    color_tag: String,
+    #[serde(default)]
+    project_type: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    project_dir: String,
+    #[serde(default)]
+    pinned_context: String,
+    #[serde(default)]
+    role_ids: Vec<String>,
+    #[serde(default)]
+    default_role_id: String,
+    #[serde(default)]
+    session_ids: Vec<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default = "default_true")]
+    global_memory_fallback: bool,
+    #[serde(default)]
+    autonomy: ProjectAutonomyDto,
+    /// Provider profile id; profiles themselves (and their API keys) stay
+    /// on the machine
+    #[serde(default)]
+    model_profile_id: String,
+    #[serde(default)]
+    model: String,
+    #[serde(default)]
+    temperature: Option<f64>,
+}
+
+#[frb(ignore)]
+fn default_true() -> bool {
+    true
+}
+
+#[frb(ignore)]
+#[derive(Debug, Serialize, Deserialize)]
+struct BundleKnowledge {
+    key: String,
+    content: String,
+    category: String,
+}
+
+// ──────────────────── API Functions ──────────────────────────
+
+/// Write a project with its sessions, roles, cron jobs and knowledge to a
+/// bundle file at `path`. Returns "ok" or "error: ...".
+pub async fn export_project(project_id: String, path: String) -> String {
+    let Some(project) = super::project_api::project_info(&project_id).await else {
+        return "error: project not found".into();
+    };
+
+    let mut role_ids = project.role_ids.clone();
+    for sid in &project.session_ids {
+        if let Some(binding) = super::agent_workspace_api::get_binding_for_session(sid).await {
+            if !role_ids.contains(&binding) {
+                role_ids.push(binding);
+            }
+        }
+    }
+    let cron_jobs = match super::cron_api::export_project_cron_jobs(&project_id) {
+        Ok(content) => content,
+        Err(e) => return format!("error: cron jobs: {e}"),
+    };
+    let knowledge =
+        super::knowledge_api::list_knowledge_entries(None, u32::MAX, Some(project_id.clone()))
+            .await
+            .into_iter()
+            .map(|e| BundleKnowledge {
+                key: e.key,
+                content: e.content,
+                category: e.category,
+            })
+            .collect();
+
+    let bundle = ProjectBundle {
+        format: BUNDLE_FORMAT.into(),
+        version: BUNDLE_VERSION,
+        exported_at: chrono::Utc::now().timestamp(),
+        sessions: super::sessions_api::export_sessions(&project.session_ids).await,
+        roles: super::agent_workspace_api::export_workspaces(&role_ids).await,
+        cron_jobs,
+        knowledge,
+        project: BundleProject {
+            id: project.id,
+            name: project.name,
+            description: project.description,
+            icon: project.icon,
+            color_tag: project.color_tag,
+            project_type: super::project_api::project_type_to_string(&project.project_type),
+            status: super::project_api::status_to_string(&project.status),
+            project_dir: project.project_dir,
+            pinned_context: project.pinned_context,
+            role_ids: project.role_ids,
+            default_role_id: project.default_role_id,
+            session_ids: project.session_ids,
+            tags: project.tags,
+            global_memory_fallback: project.global_memory_fallback,
+            autonomy: project.autonomy,
+            model_profile_id: project.model_profile_id,
+            model: project.model,
+            temperature: project.temperature,
+        },
+    };
+
+    let json = match serde_json::to_string_pretty(&bundle) {
+        Ok(json) => json,
+        Err(e) => return format!("error: serialize failed: {e}"),
+    };
+    if let Some(parent) = Path::new(&path).parent() {
+        let _ = tokio::fs::create_dir_all(parent).await;
+    }
+    match tokio::fs::write(&path, json).await {
+        Ok(()) => "ok".into(),
+        Err(e) => format!("error: write failed: {e}"),
+    }
+}
+
+/// Restore a project from a bundle file written by `export_project`, as a
+/// new project next to the existing ones
+pub async fn import_project(path: String) -> ProjectImportResult {
+    let failed = |errors: Vec<String>, notes: Vec<String>| ProjectImportResult {
+        project_id: String::new(),
+        errors,
+        notes,
+    };
+    let bundle = match tokio::fs::read_to_string(&path).await {
+        Ok(content) => parse_bundle(&content),
+        Err(e) => Err(format!("read failed: {e}")),
+    };
+    let bundle = match bundle {
+        Ok(b) => b,
+        Err(e) => return failed(vec![e], vec![]),
+    };
+    let mut notes = Vec::new();
+
+    // 1. Roles — existing ones are reused, new ones get fresh ids
+    let role_ids = match super::agent_workspace_api::import_workspaces(bundle.roles).await {
+        Ok(ids) => ids,
+        Err(e) => return failed(vec![format!("roles: {e}")], notes),
+    };
+    notes.extend(
+        role_ids
+            .iter()
+            .filter(|(old, new)| old == new)
+            .map(|(id, _)| format!("role {id} already exists here and was kept as is")),
+    );
+
+    // Roles created by this import, removed again if the project can't be
+    let created_roles: Vec<String> = role_ids
+        .iter()
+        .filter(|(old, new)| old != new)
+        .map(|(_, new)| new.clone())
+        .collect();
+
+    // 2. Sessions, always under fresh ids
+    let session_ids = match super::sessions_api::import_sessions(bundle.sessions, &role_ids).await {
+        Ok(ids) => ids,
+        Err(e) => {
+            roll_back_import(&created_roles, &[]).await;
+            return failed(vec![format!("sessions: {e}")], notes);
+        }
+    };
+
+    // 3. The project itself, under a fresh id. Autonomy overrides and the
+    //    project directory widen what agents may do, so they are left for
+    //    the user to set up again.
+    let source = bundle.project;
+    let existing = super::project_api::list_projects().await;
+    let taken: HashSet<String> = existing.into_iter().map(|p| p.name).collect();
+    let name = unique_name(&source.name, &taken);
+    if name != source.name {
+        notes.push(format!("renamed to {name:?}, the name was taken"));
+    }
+    if !source.project_dir.is_empty() {
+        notes.push(format!(
+            "project directory {} was not bound; set it in the project settings",
+            source.project_dir
+        ));
+    }
+    if source.autonomy != ProjectAutonomyDto::default() {
+        notes
+            .push("autonomy and tool settings were not imported; the global settings apply".into());
+    }
+    let profiles = super::providers_api::list_model_provider_profiles().await;
+    let model_profile_id = if source.model_profile_id.is_empty()
+        || profiles.iter().any(|p| p.id == source.model_profile_id)
+    {
+        source.model_profile_id
+    } else {
+        notes.push(format!(
+            "model provider profile {} doesn't exist here; the default provider is used",
+            source.model_profile_id
+        ));
+        String::new()
+    };
+    let archived = source.status == "archived";
+    let map_role = |id: &String| role_ids.get(id).cloned();
+    let project_id = super::project_api::upsert_project(ProjectDto {
+        id: String::new(),
+        name,
+        description: source.description,
+        icon: source.icon,
+        color_tag: source.color_tag,
+        project_type: super::project_api::string_to_project_type(&source.project_type),
+        status: super::project_api::string_to_status(&source.status),
+        project_dir: String::new(),
+        pinned_context: source.pinned_context,
+        role_ids: source.role_ids.iter().filter_map(map_role).collect(),
+        default_role_id: map_role(&source.default_role_id).unwrap_or_default(),
+        session_ids: source
+            .session_ids
+            .iter()
+            .filter_map(|sid| session_ids.get(sid).cloned())
+            .collect(),
+        tags: source.tags,
+        global_memory_fallback: source.global_memory_fallback,
+        autonomy: ProjectAutonomyDto::default(),
+        model_profile_id,
+        model: source.model,
+        temperature: source.temperature,
+        created_at: 0,
+        updated_at: 0,
+    })
+    .await;
+    if project_id.starts_with("error") {
+        let sessions: Vec<String> = session_ids.into_values().collect();
+        roll_back_import(&created_roles, &sessions).await;
+        return failed(vec![project_id], notes);
+    }
+
+    // 4. Cron jobs and knowledge; failures here keep the project
+    let mut errors = Vec::new();
+    if !bundle.cron_jobs.trim().is_empty() {
+        match super::cron_api::import_project_cron_jobs(
+            &bundle.cron_jobs,
+            &project_id,
+            &session_ids,
+        ) {
+            Ok(created) => {
+                if created > 0 {
+                    notes.push(format!(
+                        "{created} cron job(s) were imported disabled; review and enable them"
+                    ));
+                }
+                if archived {
+                    let result = super::cron_api::set_project_cron_jobs_paused(&project_id, true);
+                    if result != "ok" {
+                        errors.push(format!("cron jobs: {result}"));
+                    }
+                }
+            }
+            Err(e) => errors.push(format!("cron jobs: {e}")),
+        }
+    }
+    for entry in bundle.knowledge {
+        let result = super::knowledge_api::add_knowledge_entry(
+            entry.key.clone(),
+            entry.content,
+            entry.category,
+            Some(project_id.clone()),
+        )
+        .await;
+        if result != "ok" {
+            errors.push(format!("knowledge {:?}: {result}", entry.key));
+        }
+    }
+
+    ProjectImportResult {
+        project_id,
+        errors,
+        notes,
+    }
+}
+
+// ──────────────────── Helpers ─────────────────────────────────
+
+/// Remove the roles and sessions a failed import created
+async fn roll_back_import(role_ids: &[String], session_ids: &[String]) {
+    for id in session_ids {
+        let result = super::sessions_api::delete_session(id.clone()).await;
+        if result != "ok" {
+            tracing::warn!("Failed to remove session {id} of a failed import: {result}");
+        }
+    }
+    for id in role_ids {
+        let result = super::agent_workspace_api::delete_agent_workspace(id.clone()).await;
+        if result != "ok" {
+            tracing::warn!("Failed to remove role {id} of a failed import: {result}");
+        }
+    }
+}
+
+fn parse_bundle(content: &str) -> Result<ProjectBundle, String> {
+    let bundle: ProjectBundle =
+        serde_json::from_str(content).map_err(|e| format!("not a project bundle: {e}"))?;
+    if bundle.format != BUNDLE_FORMAT {
+        return Err(format!("not a project bundle: format {:?}", bundle.format));
+    }
+    if bundle.version > BUNDLE_VERSION {
+        return Err(format!(
+            "bundle version {} is newer than this app supports ({BUNDLE_VERSION})",
+            bundle.version
+        ));
+    }
+    if bundle.project.name.trim().is_empty() {
+        return Err("project name is missing".into());
+    }
+    Ok(bundle)
+}
+
+/// `name`, or `name (2)`, `name (3)`... when it's taken
+fn unique_name(name: &str, taken: &HashSet<String>) -> String {
+    let mut candidate = name.to_string();
+    let mut n = 2;
+    while taken.contains(&candidate) {
+        candidate = format!("{name} ({n})");
+        n += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bundles_of_supported_versions() {
+        let bundle = |format: &str, version: u32| {
+            format!(
+                r#"{{"format":"{format}","version":{version},"exported_at":0,
+                    "project":{{"id":"p1","name":"Demo"}}}}"#
+            )
+        };
+        let parsed = parse_bundle(&bundle(BUNDLE_FORMAT, 1)).unwrap();
+        assert_eq!(parsed.project.name, "Demo");
+        assert!(parsed.project.global_memory_fallback);
+        assert!(parsed.sessions.is_empty());
+
+        assert!(parse_bundle(&bundle("something-else", 1)).is_err());
+        assert!(parse_bundle(&bundle(BUNDLE_FORMAT, BUNDLE_VERSION + 1)).is_err());
+    }
+
+    #[test]
+    fn numbers_taken_names() {
+        let taken: HashSet<String> = ["Demo".into(), "Demo (2)".into()].into();
+        assert_eq!(unique_name("Demo", &taken), "Demo (3)");
+        assert_eq!(unique_name("Other", &taken), "Other");
+    }
+}
@@ -0,0 +1,786 @@
+//! Project Git API — git awareness for project directories.
+//!
+//! For projects whose directory is inside a git work tree, this reports the
+//! branch, uncommitted changes and recent commits, and lets the user undo
+//! what an agent turn wrote.
+//!
+//! Before each agent turn of a project session whose agent can write files,
+//! the files that differ from the user's index (untracked files included,
+//! ignored files not) are saved. `git status` finds them from the index's
+//! stat cache, so only those files are read. The snapshots live in a bare
+//! repository of their own next to the project's activity database, so the
+//! user's index, refs and object store are never touched. When the turn
+//! wrote files through the file tools, the earlier version of each of them
+//! (from the saved files or the user's index) is kept as a tree under
+//! `refs/coraldesk/snapshots/`, and `undo_last_agent_changes` puts exactly
+//! those files back. Changes made through the shell tool are not tracked.
+
+use flutter_rust_bridge::frb;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+// ──────────────────────── DTOs ────────────────────────────
+
+/// Git state of a project directory
+#[derive(Debug, Clone)]
+pub struct ProjectGitStatus {
+    /// Current branch ("HEAD" when detached)
+    pub branch: String,
+    /// Uncommitted changes, untracked files included
+    pub dirty: bool,
+    pub changed_files: u32,
+    /// Newest first
+    pub recent_commits: Vec<GitCommitDto>,
+}
+
+/// A commit in a project's history
+#[derive(Debug, Clone)]
+pub struct GitCommitDto {
+    /// Abbreviated hash
+    pub hash: String,
+    pub summary: String,
+    pub author: String,
+    /// Commit time, epoch seconds
+    pub timestamp: i64,
+}
+
+/// Outcome of `undo_last_agent_changes`. File paths are relative to the
+/// project directory.
+#[derive(Debug, Clone)]
+pub struct AgentUndoResult {
+    /// Files put back the way they were before the turn
+    pub restored: Vec<String>,
+    /// Files the turn created, now deleted
+    pub removed: Vec<String>,
+    /// Files left alone, and why; or why nothing could be undone
+    pub errors: Vec<String>,
+}
+
+const RECENT_COMMITS: u32 = 5;
+
+/// Undoable turns kept per session
+const SNAPSHOTS_PER_SESSION: u32 = 20;
+
+/// Working tree state saved before an agent turn
+#[frb(ignore)]
+pub(crate) struct TurnSnapshot {
+    project_id: String,
+    project_dir: PathBuf,
+    repo: SnapshotRepo,
+    /// Files that differed from the user's index, relative to the work tree
+    /// top, as saved (None = deleted from the work tree)
+    dirty: HashMap<String, Option<SavedFile>>,
+}
+
+/// A file's content and mode as saved in the snapshot repository
+#[frb(ignore)]
+#[derive(Debug, Clone)]
+struct SavedFile {
+    blob: String,
+    /// Git file mode, "100755" for executables and "100644" otherwise
+    mode: &'static str,
+}
+
+/// The repository holding a project's snapshots
+#[frb(ignore)]
+struct SnapshotRepo {
+    git_dir: PathBuf,
+    /// Top level of the user's work tree
+    work_tree: PathBuf,
+}
+
+/// A file written by a snapshotted turn
+#[frb(ignore)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SnapshotFile {
+    /// Relative to the project directory
+    path: String,
+    /// Blob hash of what the agent left in the file ("" = no file)
+    after: String,
+}
+
+// ──────────────────── API Functions ──────────────────────────
+
+/// Branch, changes and recent commits of the project's directory, or None
+/// when it isn't in a git work tree (or git isn't installed)
+pub async fn get_project_git_status(project_id: String) -> Option<ProjectGitStatus> {
+    let project = super::project_api::project_info(&project_id).await?;
+    let dir = project.project_dir.trim();
+    if dir.is_empty() {
+        return None;
+    }
+    let dir = PathBuf::from(dir);
+    tokio::task::spawn_blocking(move || read_status(&dir))
+        .await
+        .ok()
+        .flatten()
+}
+
+/// Revert the files written by the session's last undoable agent turn.
+/// Files changed again since then are left alone. Calling it again undoes
+/// the turn before that.
+pub async fn undo_last_agent_changes(session_id: String) -> AgentUndoResult {
+    let failed = |error: String| AgentUndoResult {
+        restored: vec![],
+        removed: vec![],
+        errors: vec![error],
+    };
+    let Some(project_id) = super::project_api::get_session_project(session_id.clone()).await else {
+        return failed("session is not in a project".into());
+    };
+    let Some(project) = super::project_api::project_info(&project_id).await else {
+        return failed("project not found".into());
+    };
+    let conn = match super::project_timeline_api::open_activity_db(&project_id) {
+        Ok(c) => c,
+        Err(e) => return failed(e),
+    };
+    let latest = conn.query_row(
+        "SELECT id, tree, files FROM turn_snapshots WHERE session_id = ?1 \
+         ORDER BY id DESC LIMIT 1",
+        params![session_id],
+        |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        },
+    );
+    let Ok((snapshot_id, tree, files)) = latest else {
+        return failed("no agent changes to undo".into());
+    };
+    let files: Vec<SnapshotFile> = serde_json::from_str(&files).unwrap_or_default();
+
+    let dir = PathBuf::from(&project.project_dir);
+    let repo = match open_snapshot_repo(&snapshot_git_dir(&project_id), &dir) {
+        Ok(repo) => repo,
+        Err(e) => return failed(e),
+    };
+    let result = {
+        let dir = dir.clone();
+        let snapshot = snapshot_ref(&session_id, snapshot_id);
+        tokio::task::spawn_blocking(move || {
+            let result = restore_files(&dir, &repo, &tree, &files);
+            let _ = repo.git(&["update-ref", "-d", &snapshot]);
+            result
+        })
+        .await
+        .unwrap_or_else(|e| failed(e.to_string()))
+    };
+
+    let _ = conn.execute(
+        "DELETE FROM turn_snapshots WHERE id = ?1",
+        params![snapshot_id],
+    );
+
+    let changes: Vec<(String, String)> = result
+        .restored
+        .iter()
+        .chain(&result.removed)
+        .map(|path| ("undo".to_string(), path.clone()))
+        .collect();
+    super::project_timeline_api::record_file_changes(&project_id, &session_id, &changes);
+    result
+}
+
+// ──────────────────── Crate Hooks ────────────────────────────
+
+/// Snapshot the working tree of the session's project before an agent turn
+pub(crate) async fn snapshot_before_turn(session_id: &str) -> Option<TurnSnapshot> {
+    let project_id = super::project_api::get_session_project(session_id.to_string()).await?;
+    let project = super::project_api::project_info(&project_id).await?;
+    if project.project_dir.trim().is_empty() {
+        return None;
+    }
+    let project_dir = PathBuf::from(project.project_dir.trim());
+    let dir = project_dir.clone();
+    let git_dir = snapshot_git_dir(&project_id);
+    let snapshot = tokio::task::spawn_blocking(move || {
+        let repo = open_snapshot_repo(&git_dir, &dir)?;
+        let dirty = repo.save_dirty_files()?;
+        Ok::<_, String>((repo, dirty))
+    })
+    .await
+    .ok()?;
+    match snapshot {
+        Ok((repo, dirty)) => Some(TurnSnapshot {
+            project_id,
+            project_dir,
+            repo,
+            dirty,
+        }),
+        Err(e) => {
+            tracing::debug!("No git snapshot for session {session_id}: {e}");
+            None
+        }
+    }
+}
+
+/// Keep a turn's snapshot for undo, with the files the turn wrote
+pub(crate) async fn keep_turn_snapshot(session_id: &str, snapshot: TurnSnapshot, paths: &[String]) {
+    if paths.is_empty() {
+        // Saved files nobody needs are left for `gc` to prune
+        if !snapshot.dirty.is_empty() {
+            let repo = snapshot.repo;
+            let _ =
+                tokio::task::spawn_blocking(move || repo.git(&["gc", "--auto", "--quiet"])).await;
+        }
+        return;
+    }
+    let session_id = session_id.to_string();
+    let paths = paths.to_vec();
+    let result = tokio::task::spawn_blocking(move || {
+        let dir = &snapshot.project_dir;
+        let repo = &snapshot.repo;
+        let (tree, files) = repo.turn_tree(dir, &snapshot.dirty, paths)?;
+        if files.is_empty() {
+            return Ok(());
+        }
+        let conn = super::project_timeline_api::open_activity_db(&snapshot.project_id)?;
+        conn.execute(
+            "INSERT INTO turn_snapshots (session_id, created_at, tree, files) \
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                session_id,
+                chrono::Utc::now().timestamp(),
+                tree,
+                serde_json::to_string(&files).unwrap_or_default()
+            ],
+        )
+        .map_err(|e| e.to_string())?;
+        let id = conn.last_insert_rowid();
+        repo.git(&["update-ref", &snapshot_ref(&session_id, id), &tree])?;
+
+        // Drop the session's oldest snapshots
+        let stale: Vec<i64> = conn
+            .prepare(
+                "SELECT id FROM turn_snapshots WHERE session_id = ?1 \
+                 ORDER BY id DESC LIMIT -1 OFFSET ?2",
+            )
+            .and_then(|mut stmt| {
+                stmt.query_map(params![session_id, SNAPSHOTS_PER_SESSION], |row| row.get(0))
+                    .map(|rows| rows.filter_map(|r| r.ok()).collect())
+            })
+            .unwrap_or_default();
+        for id in &stale {
+            let _ = conn.execute("DELETE FROM turn_snapshots WHERE id = ?1", params![id]);
+            let _ = repo.git(&["update-ref", "-d", &snapshot_ref(&session_id, *id)]);
+        }
+        let _ = repo.git(&["gc", "--auto", "--quiet"]);
+        Ok::<(), String>(())
+    })
+    .await;
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => tracing::warn!("Failed to keep git snapshot: {e}"),
+        Err(e) => tracing::warn!("Failed to keep git snapshot: {e}"),
+    }
+}
+
+// ──────────────────── Git Helpers ────────────────────────────
+
+fn git_command(dir: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.arg("-C").arg(dir).args(args);
+    cmd
+}
+
+fn run(mut cmd: Command) -> Result<Vec<u8>, String> {
+    let output = cmd
+        .output()
+        .map_err(|e| format!("failed to run git: {e}"))?;
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Like `run`, with `input` written to the command's stdin
+fn run_with_input(mut cmd: Command, input: &[u8]) -> Result<Vec<u8>, String> {
+    use std::io::Write;
+    let mut child = cmd
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run git: {e}"))?;
+    let mut stdin = child.stdin.take().ok_or("failed to open git's stdin")?;
+    // Write from another thread so a full stdout pipe can't block both sides
+    let output = std::thread::scope(|scope| {
+        let writer = scope.spawn(move || stdin.write_all(input));
+        let output = child.wait_with_output();
+        let _ = writer.join();
+        output
+    })
+    .map_err(|e| format!("failed to run git: {e}"))?;
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Trimmed stdout of a git command run in `dir`
+fn git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    run(git_command(dir, args)).map(|out| String::from_utf8_lossy(&out).trim().to_string())
+}
+
+fn snapshot_ref(session_id: &str, snapshot_id: i64) -> String {
+    format!("refs/coraldesk/snapshots/{session_id}/{snapshot_id}")
+}
+
+fn read_status(dir: &Path) -> Option<ProjectGitStatus> {
+    if git(dir, &["rev-parse", "--is-inside-work-tree"]).ok()? != "true" {
+        return None;
+    }
+    let branch = git(dir, &["rev-parse", "--abbrev-ref", "HEAD"])
+        .or_else(|_| git(dir, &["symbolic-ref", "--short", "HEAD"]))
+        .unwrap_or_default();
+    let changed_files = git(dir, &["status", "--porcelain"])
+        .map(|out| out.lines().count() as u32)
+        .unwrap_or(0);
+    let count = format!("-{RECENT_COMMITS}");
+    let recent_commits = git(dir, &["log", &count, "--format=%h%x1f%s%x1f%an%x1f%ct"])
+        .map(|out| out.lines().filter_map(parse_commit_line).collect())
+        .unwrap_or_default();
+    Some(ProjectGitStatus {
+        branch,
+        dirty: changed_files > 0,
+        changed_files,
+        recent_commits,
+    })
+}
+
+fn parse_commit_line(line: &str) -> Option<GitCommitDto> {
+    let mut fields = line.split('\u{1f}');
+    Some(GitCommitDto {
+        hash: fields.next()?.to_string(),
+        summary: fields.next()?.to_string(),
+        author: fields.next()?.to_string(),
+        timestamp: fields.next()?.parse().ok()?,
+    })
+}
+
+fn snapshot_git_dir(project_id: &str) -> PathBuf {
+    super::knowledge_api::project_memory_workspace(project_id).join("snapshots.git")
+}
+
+/// Open (creating it when needed) the snapshot repository at `git_dir` for
+/// the git work tree `dir` is in
+fn open_snapshot_repo(git_dir: &Path, dir: &Path) -> Result<SnapshotRepo, String> {
+    let work_tree = PathBuf::from(git(dir, &["rev-parse", "--show-toplevel"])?);
+    if !git_dir.join("HEAD").exists() {
+        std::fs::create_dir_all(git_dir).map_err(|e| e.to_string())?;
+        git(git_dir, &["init", "--bare", "-q"])?;
+    }
+    Ok(SnapshotRepo {
+        git_dir: git_dir.to_path_buf(),
+        work_tree,
+    })
+}
+
+impl SnapshotRepo {
+    fn command(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::new("git");
+        cmd.arg("--git-dir")
+            .arg(&self.git_dir)
+            .arg("--work-tree")
+            .arg(&self.work_tree)
+            .current_dir(&self.work_tree)
+            .args(args);
+        cmd
+    }
+
+    /// Trimmed stdout of a git command run against the snapshot repository
+    fn git(&self, args: &[&str]) -> Result<String, String> {
+        run(self.command(args)).map(|out| String::from_utf8_lossy(&out).trim().to_string())
+    }
+
+    /// Save the files that differ from the user's index, keyed by their path
+    /// from the work tree top, with None for deleted files
+    fn save_dirty_files(&self) -> Result<HashMap<String, Option<SavedFile>>, String> {
+        let mut status = git_command(
+            &self.work_tree,
+            &[
+                "status",
+                "--porcelain",
+                "-z",
+                "--untracked-files=all",
+                "--no-renames",
+            ],
+        );
+        // Don't refresh the user's index behind their back
+        status.env("GIT_OPTIONAL_LOCKS", "0");
+        let mut dirty = HashMap::new();
+        let mut present = vec![];
+        for entry in run(status)?.split(|b| *b == 0) {
+            // "XY path"
+            let Some(path) = entry.get(3..).filter(|p| !p.is_empty()) else {
+                continue;
+            };
+            let path = String::from_utf8_lossy(path).to_string();
+            if self.work_tree.join(&path).is_file() {
+                present.push(path);
+            } else {
+                dirty.insert(path, None);
+            }
+        }
+        if !present.is_empty() {
+            let paths = present.join("\n") + "\n";
+            let out = run_with_input(
+                self.command(&["hash-object", "-w", "--no-filters", "--stdin-paths"]),
+                paths.as_bytes(),
+            )?;
+            let blobs = String::from_utf8_lossy(&out);
+            dirty.extend(present.into_iter().zip(blobs.lines()).map(|(path, blob)| {
+                let mode = file_mode(&self.work_tree.join(&path));
+                let saved = SavedFile {
+                    blob: blob.to_string(),
+                    mode,
+                };
+                (path, Some(saved))
+            }));
+        }
+        Ok(dirty)
+    }
+
+    /// A file as it was before the turn, copied into the snapshot
+    /// repository; None when it didn't exist. Fails for ignored files, whose
+    /// earlier state isn't known.
+    fn file_before_turn(
+        &self,
+        dirty: &HashMap<String, Option<SavedFile>>,
+        path: &str,
+    ) -> Result<Option<SavedFile>, String> {
+        if let Some(saved) = dirty.get(path) {
+            return Ok(saved.clone());
+        }
+        // "<mode> <blob> <stage>\t<path>"
+        let entry = git(
+            &self.work_tree,
+            &["--literal-pathspecs", "ls-files", "-s", "--", path],
+        )?;
+        if let [mode, blob, ..] = entry.split_whitespace().collect::<Vec<_>>()[..] {
+            let content = run(git_command(&self.work_tree, &["cat-file", "blob", blob]))?;
+            let out = run_with_input(self.command(&["hash-object", "-w", "--stdin"]), &content)?;
+            return Ok(Some(SavedFile {
+                blob: String::from_utf8_lossy(&out).trim().to_string(),
+                mode: if mode == "100755" { "100755" } else { "100644" },
+            }));
+        }
+        if git(&self.work_tree, &["check-ignore", "-q", "--", path]).is_ok() {
+            return Err("ignored by git".into());
+        }
+        Ok(None)
+    }
+
+    /// Tree of the files a turn wrote (relative to `dir`) as they were before
+    /// it, with what the turn left in each. Ignored files are left out, as
+    /// undoing them could delete or clobber a file we know nothing about.
+    fn turn_tree(
+        &self,
+        dir: &Path,
+        dirty: &HashMap<String, Option<SavedFile>>,
+        paths: Vec<String>,
+    ) -> Result<(String, Vec<SnapshotFile>), String> {
+        let prefix = git(dir, &["rev-parse", "--show-prefix"])?;
+        let mut before = Vec::with_capacity(paths.len());
+        let mut files = Vec::with_capacity(paths.len());
+        for path in paths {
+            let top_path = format!("{prefix}{}", path.replace(std::path::MAIN_SEPARATOR, "/"));
+            match self.file_before_turn(dirty, &top_path) {
+                Ok(Some(saved)) => before.push((top_path, saved)),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::debug!("Not keeping {path} for undo: {e}");
+                    continue;
+                }
+            }
+            files.push(SnapshotFile {
+                after: hash_file(dir, &path).unwrap_or_default(),
+                path,
+            });
+        }
+        Ok((self.write_tree(&before)?, files))
+    }
+
+    /// Write a tree object holding `files` (path from the work tree top)
+    fn write_tree(&self, files: &[(String, SavedFile)]) -> Result<String, String> {
+        let index = self.git_dir.join(format!("index-{}", uuid::Uuid::new_v4()));
+        let info: String = files
+            .iter()
+            .map(|(path, saved)| format!("{} {}\t{path}\n", saved.mode, saved.blob))
+            .collect();
+        let mut update = self.command(&["update-index", "--add", "--index-info"]);
+        update.env("GIT_INDEX_FILE", &index);
+        let mut write = self.command(&["write-tree"]);
+        write.env("GIT_INDEX_FILE", &index);
+        let tree = run_with_input(update, info.as_bytes())
+            .and_then(|_| run(write))
+            .map(|out| String::from_utf8_lossy(&out).trim().to_string());
+        let _ = std::fs::remove_file(&index);
+        tree
+    }
+}
+
+/// Git file mode for a file in the work tree, keeping only the executable bit
+/// like git does
+fn file_mode(path: &Path) -> &'static str {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if std::fs::metadata(path).is_ok_and(|m| m.permissions().mode() & 0o111 != 0) {
+            return "100755";
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    "100644"
+}
+
+/// Set or clear a file's executable bits to match a saved git mode
+#[cfg(unix)]
+fn set_file_mode(path: &Path, mode: &str) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = std::fs::metadata(path)?.permissions();
+    let bits = permissions.mode();
+    permissions.set_mode(if mode == "100755" {
+        bits | 0o111
+    } else {
+        bits & !0o111
+    });
+    std::fs::set_permissions(path, permissions)
+}
+
+#[cfg(not(unix))]
+fn set_file_mode(_path: &Path, _mode: &str) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Blob hash of a file's current content; None when it doesn't exist
+fn hash_file(dir: &Path, path: &str) -> Option<String> {
+    if !dir.join(path).is_file() {
+        return None;
+    }
+    git(dir, &["hash-object", "--", path]).ok()
+}
+
+/// Put the snapshot's version of each file back, or delete files the
+/// snapshot doesn't have, skipping files that changed after the turn
+fn restore_files(
+    dir: &Path,
+    repo: &SnapshotRepo,
+    tree: &str,
+    files: &[SnapshotFile],
+) -> AgentUndoResult {
+    let mut result = AgentUndoResult {
+        restored: vec![],
+        removed: vec![],
+        errors: vec![],
+    };
+    // Without its tree, every file would look like the turn created it
+    if repo
+        .git(&["cat-file", "-e", &format!("{tree}^{{tree}}")])
+        .is_err()
+    {
+        result
+            .errors
+            .push("the snapshot is no longer available".into());
+        return result;
+    }
+    // Tree paths are relative to the repository root
+    let prefix = match git(dir, &["rev-parse", "--show-prefix"]) {
+        Ok(prefix) => prefix,
+        Err(e) => {
+            result.errors.push(format!("not a git work tree: {e}"));
+            return result;
+        }
+    };
+    for file in files {
+        // Never follow a path out of the project directory
+        if !Path::new(&file.path)
+            .components()
+            .all(|c| matches!(c, std::path::Component::Normal(_)))
+        {
+            result
+                .errors
+                .push(format!("{}: outside the project, left as is", file.path));
+            continue;
+        }
+        if hash_file(dir, &file.path).unwrap_or_default() != file.after {
+            result.errors.push(format!(
+                "{}: changed since the agent wrote it, left as is",
+                file.path
+            ));
+            continue;
+        }
+        let tree_path = format!(
+            "{prefix}{}",
+            file.path.replace(std::path::MAIN_SEPARATOR, "/")
+        );
+        let target = dir.join(&file.path);
+        // "<mode> blob <blob>\t<path>", empty when the snapshot doesn't have it
+        let entry = match repo.git(&["--literal-pathspecs", "ls-tree", tree, "--", &tree_path]) {
+            Ok(entry) => entry,
+            Err(e) => {
+                result.errors.push(format!("{}: {e}", file.path));
+                continue;
+            }
+        };
+        let Some(mode) = entry.split_whitespace().next() else {
+            match std::fs::remove_file(&target) {
+                Ok(()) => result.removed.push(file.path.clone()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => result.errors.push(format!("{}: {e}", file.path)),
+            }
+            continue;
+        };
+        let object = format!("{tree}:{tree_path}");
+        let restored = run(repo.command(&["cat-file", "blob", &object])).and_then(|content| {
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            std::fs::write(&target, content).map_err(|e| e.to_string())?;
+            set_file_mode(&target, mode).map_err(|e| e.to_string())
+        });
+        match restored {
+            Ok(()) => result.restored.push(file.path.clone()),
+            Err(e) => result.errors.push(format!("{}: {e}", file.path)),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commit_lines() {
+        let commit =
+            parse_commit_line("a1b2c3d\u{1f}Fix parser\u{1f}Dana\u{1f}1700000000").unwrap();
+        assert_eq!(commit.hash, "a1b2c3d");
+        assert_eq!(commit.summary, "Fix parser");
+        assert_eq!(commit.timestamp, 1_700_000_000);
+        assert!(parse_commit_line("a1b2c3d\u{1f}no time").is_none());
+    }
+
+    #[test]
+    fn restores_files_written_after_snapshot() {
+        let repo = std::env::temp_dir().join(format!("coraldesk-git-{}", uuid::Uuid::new_v4()));
+        let dir = repo.join("app");
+        std::fs::create_dir_all(&dir).unwrap();
+        if git(&repo, &["init", "-q"]).is_err() {
+            return; // git not installed
+        }
+        std::fs::write(dir.join("main.rs"), "fn main() {}\n").unwrap();
+        git(&repo, &["add", "app/main.rs"]).unwrap();
+        git(
+            &repo,
+            &[
+                "-c",
+                "user.name=T",
+                "-c",
+                "user.email=t@example.com",
+                "commit",
+                "-qm",
+                "init",
+            ],
+        )
+        .unwrap();
+        std::fs::write(dir.join("notes.md"), "draft\n").unwrap();
+        std::fs::write(dir.join("todo.md"), "- ship\n").unwrap();
+        let objects_before = git(&repo, &["count-objects"]).unwrap();
+        let snapshots = open_snapshot_repo(&repo.with_extension("snapshots"), &dir).unwrap();
+        let dirty = snapshots.save_dirty_files().unwrap();
+        assert_eq!(dirty.len(), 2);
+
+        // The agent edits main.rs (clean) and todo.md (untracked), creates
+        // new.rs and rewrites notes.md, which the user then edits by hand
+        std::fs::write(dir.join("main.rs"), "fn main() { todo!() }\n").unwrap();
+        std::fs::write(dir.join("todo.md"), "- done\n").unwrap();
+        std::fs::write(dir.join("new.rs"), "// new\n").unwrap();
+        std::fs::write(dir.join("notes.md"), "agent notes\n").unwrap();
+        let paths = ["main.rs", "new.rs", "notes.md", "todo.md"].map(String::from);
+        let (tree, files) = snapshots.turn_tree(&dir, &dirty, paths.to_vec()).unwrap();
+        std::fs::write(dir.join("notes.md"), "edited by hand\n").unwrap();
+        // The user's repository is left alone
+        assert_eq!(git(&repo, &["count-objects"]).unwrap(), objects_before);
+
+        let result = restore_files(&dir, &snapshots, &tree, &files);
+        assert_eq!(result.restored, ["main.rs", "todo.md"]);
+        assert_eq!(result.removed, ["new.rs"]);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("main.rs")).unwrap(),
+            "fn main() {}\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.join("todo.md")).unwrap(),
+            "- ship\n"
+        );
+        assert!(!dir.join("new.rs").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("notes.md")).unwrap(),
+            "edited by hand\n"
+        );
+        let _ = std::fs::remove_dir_all(&repo);
+        let _ = std::fs::remove_dir_all(repo.with_extension("snapshots"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn restores_executable_bits() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let repo = std::env::temp_dir().join(format!("coraldesk-git-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&repo).unwrap();
+        if git(&repo, &["init", "-q"]).is_err() {
+            return; // git not installed
+        }
+        let set_mode = |name: &str, mode: u32| {
+            std::fs::set_permissions(repo.join(name), std::fs::Permissions::from_mode(mode))
+                .unwrap()
+        };
+        let is_executable = |name: &str| {
+            std::fs::metadata(repo.join(name))
+                .unwrap()
+                .permissions()
+                .mode()
+                & 0o111
+                != 0
+        };
+        // deploy.sh is tracked, build.sh untracked; both start executable
+        std::fs::write(repo.join("deploy.sh"), "#!/bin/sh\necho deploy\n").unwrap();
+        set_mode("deploy.sh", 0o755);
+        git(&repo, &["add", "deploy.sh"]).unwrap();
+        std::fs::write(repo.join("build.sh"), "#!/bin/sh\necho build\n").unwrap();
+        set_mode("build.sh", 0o755);
+        let snapshots = open_snapshot_repo(&repo.with_extension("snapshots"), &repo).unwrap();
+        let dirty = snapshots.save_dirty_files().unwrap();
+
+        // The agent recreates both files without the executable bit
+        for name in ["deploy.sh", "build.sh"] {
+            std::fs::remove_file(repo.join(name)).unwrap();
+            std::fs::write(repo.join(name), "echo changed\n").unwrap();
+            set_mode(name, 0o644);
+        }
+        let paths = ["build.sh", "deploy.sh"].map(String::from);
+        let (tree, files) = snapshots.turn_tree(&repo, &dirty, paths.to_vec()).unwrap();
+
+        let result = restore_files(&repo, &snapshots, &tree, &files);
+        assert_eq!(result.restored, ["build.sh", "deploy.sh"]);
+        assert!(is_executable("deploy.sh") && is_executable("build.sh"));
+        assert_eq!(
+            std::fs::read_to_string(repo.join("deploy.sh")).unwrap(),
+            "#!/bin/sh\necho deploy\n"
+        );
+        let _ = std::fs::remove_dir_all(&repo);
+        let _ = std::fs::remove_dir_all(repo.with_extension("snapshots"));
+    }
+}
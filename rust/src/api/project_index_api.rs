@@ -0,0 +1,733 @@
+//! Project Index API — searchable index of a code project's source files.
+//!
+//! For `CodeProject` projects with a bound directory, source files are split
+//! into overlapping line chunks and embedded with the configured embedding
+//! model (or embedding route, for `hint:` models). The index lives in
+//! `workspace/projects/<id>/code_index.db` and is refreshed incrementally:
+//! only files whose mtime or size changed are re-chunked. Without an
+//! embedding provider, search falls back to keyword matching.
+//!
+//! Agents in the project's sessions get a `project_search` tool backed by
+//! the same index.
+
+use super::project_api::ProjectType;
+use rusqlite::{params, Connection};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+// ──────────────────────── DTOs ────────────────────────────
+
+/// A matching chunk of a project file
+#[derive(Debug, Clone)]
+pub struct CodeSearchHit {
+    /// Path relative to the project directory
+    pub path: String,
+    /// 1-based, inclusive
+    pub start_line: u32,
+    pub end_line: u32,
+    pub snippet: String,
+    /// Cosine similarity for semantic matches, term match ratio for chunks
+    /// without an embedding; semantic matches are listed first
+    pub score: f64,
+}
+
+/// State of a project's code index
+#[derive(Debug, Clone)]
+pub struct ProjectCodeIndexStatus {
+    pub indexing: bool,
+    pub files: u32,
+    pub chunks: u32,
+    /// Chunks with an embedding; semantic search is used when > 0
+    pub embedded_chunks: u32,
+    /// UTC epoch seconds of the last completed indexing run
+    pub last_indexed_at: Option<i64>,
+    /// Error of the last run (empty = none)
+    pub last_error: String,
+}
+
+// ──────────────────── Constants ──────────────────────────────
+
+const CHUNK_LINES: usize = 60;
+const CHUNK_OVERLAP: usize = 10;
+const MAX_FILE_BYTES: u64 = 256 * 1024;
+const MAX_FILES: usize = 20_000;
+const EMBED_BATCH: usize = 32;
+const SEARCH_LIMIT: usize = 10;
+
+/// Extensions of files worth indexing
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "rs", "dart", "py", "js", "jsx", "ts", "tsx", "go", "java", "kt", "kts", "swift", "c", "h",
+    "cc", "cpp", "hpp", "cs", "rb", "php", "scala", "sh", "bash", "zsh", "sql", "md", "toml",
+    "yaml", "yml", "json", "html", "css", "scss", "vue", "svelte", "lua", "zig", "ex", "exs",
+    "erl", "hs", "ml", "proto", "gradle", "xml",
+];
+
+// ──────────────────── DB Helpers ──────────────────────────
+
+fn index_db_path(project_id: &str) -> PathBuf {
+    super::knowledge_api::project_memory_workspace(project_id).join("code_index.db")
+}
+
+fn open_index_db(project_id: &str) -> Result<Connection, String> {
+    let path = index_db_path(project_id);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let conn = Connection::open(&path).map_err(|e| e.to_string())?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS files (
+            path  TEXT PRIMARY KEY,
+            mtime INTEGER NOT NULL,
+            size  INTEGER NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS chunks (
+            id         INTEGER PRIMARY KEY AUTOINCREMENT,
+            path       TEXT NOT NULL,
+            start_line INTEGER NOT NULL,
+            end_line   INTEGER NOT NULL,
+            content    TEXT NOT NULL,
+            embedding  BLOB
+        );
+        CREATE INDEX IF NOT EXISTS idx_chunks_path ON chunks(path);
+        CREATE TABLE IF NOT EXISTS meta (
+            key   TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );",
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(conn)
+}
+
+fn get_meta(conn: &Connection, key: &str) -> Option<String> {
+    conn.query_row("SELECT value FROM meta WHERE key = ?1", params![key], |r| {
+        r.get(0)
+    })
+    .ok()
+}
+
+fn set_meta(conn: &Connection, key: &str, value: &str) {
+    let _ = conn.execute(
+        "INSERT INTO meta (key, value) VALUES (?1, ?2) \
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        params![key, value],
+    );
+}
+
+/// Projects currently being indexed
+fn indexing() -> &'static Mutex<HashSet<String>> {
+    static INDEXING: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    INDEXING.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Marks a project as being indexed until dropped, even when the run panics
+struct IndexingGuard {
+    project_id: String,
+}
+
+impl Drop for IndexingGuard {
+    fn drop(&mut self) {
+        indexing()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.project_id);
+    }
+}
+
+// ──────────────────── API Functions ──────────────────────────
+
+/// Start (re)indexing a code project in the background
+pub async fn index_project_code(project_id: String) -> String {
+    match indexable_project_dir(&project_id).await {
+        Ok(_) => {
+            spawn_project_indexer(&project_id);
+            "ok".into()
+        }
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Get the state of a project's code index
+pub fn get_project_code_index_status(project_id: String) -> ProjectCodeIndexStatus {
+    let indexing = indexing()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .contains(&project_id);
+    let mut status = ProjectCodeIndexStatus {
+        indexing,
+        files: 0,
+        chunks: 0,
+        embedded_chunks: 0,
+        last_indexed_at: None,
+        last_error: String::new(),
+    };
+    if !index_db_path(&project_id).exists() {
+        return status;
+    }
+    let Ok(conn) = open_index_db(&project_id) else {
+        return status;
+    };
+    let count = |sql: &str| -> u32 { conn.query_row(sql, [], |r| r.get(0)).unwrap_or(0) };
+    status.files = count("SELECT COUNT(*) FROM files");
+    status.chunks = count("SELECT COUNT(*) FROM chunks");
+    status.embedded_chunks = count("SELECT COUNT(*) FROM chunks WHERE embedding IS NOT NULL");
+    status.last_indexed_at = get_meta(&conn, "last_indexed_at").and_then(|v| v.parse().ok());
+    status.last_error = get_meta(&conn, "last_error").unwrap_or_default();
+    status
+}
+
+/// Search a code project's files for `query`
+pub async fn search_project_code(project_id: String, query: String) -> Vec<CodeSearchHit> {
+    match search_index(&project_id, &query, SEARCH_LIMIT).await {
+        Ok(hits) => hits,
+        Err(e) => {
+            tracing::warn!("Code search in project {project_id} failed: {e}");
+            vec![]
+        }
+    }
+}
+
+// ──────────────────── Indexer ────────────────────────────────
+
+/// The directory of a project that can be indexed
+async fn indexable_project_dir(project_id: &str) -> Result<PathBuf, String> {
+    let project = super::project_api::project_info(project_id)
+        .await
+        .ok_or("project not found")?;
+    if !matches!(project.project_type, ProjectType::CodeProject) {
+        return Err("only code projects are indexed".into());
+    }
+    let dir = PathBuf::from(project.project_dir.trim());
+    if project.project_dir.trim().is_empty() || !dir.is_dir() {
+        return Err("project has no directory".into());
+    }
+    Ok(dir)
+}
+
+/// Refresh a project's index in the background unless a run is in progress
+pub(crate) fn spawn_project_indexer(project_id: &str) {
+    if !indexing()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(project_id.to_string())
+    {
+        return;
+    }
+    let guard = IndexingGuard {
+        project_id: project_id.to_string(),
+    };
+    tokio::spawn(async move {
+        let project_id = &guard.project_id;
+        let result = index_project(project_id).await;
+        if let Ok(conn) = open_index_db(project_id) {
+            match &result {
+                Ok(_) => {
+                    set_meta(
+                        &conn,
+                        "last_indexed_at",
+                        &chrono::Utc::now().timestamp().to_string(),
+                    );
+                    set_meta(&conn, "last_error", "");
+                }
+                Err(e) => set_meta(&conn, "last_error", e),
+            }
+        }
+        match result {
+            Ok(changed) => {
+                tracing::info!("Indexed project {project_id}: {changed} file(s) updated")
+            }
+            Err(e) => tracing::warn!("Indexing project {project_id} failed: {e}"),
+        }
+    });
+}
+
+/// Bring the index up to date; returns the number of files re-indexed
+async fn index_project(project_id: &str) -> Result<usize, String> {
+    let dir = indexable_project_dir(project_id).await?;
+    let embedder = embedding_provider().await;
+    let files = {
+        let dir = dir.clone();
+        tokio::task::spawn_blocking(move || list_source_files(&dir))
+            .await
+            .map_err(|e| e.to_string())?
+    };
+
+    let mut conn = open_index_db(project_id)?;
+
+    // A different embedding model makes all stored vectors useless
+    let model_key = embedder
+        .as_ref()
+        .map(|(_, key)| key.clone())
+        .unwrap_or_default();
+    if get_meta(&conn, "embedding_model").unwrap_or_default() != model_key {
+        conn.execute_batch("DELETE FROM chunks; DELETE FROM files;")
+            .map_err(|e| e.to_string())?;
+        set_meta(&conn, "embedding_model", &model_key);
+    }
+
+    let indexed: HashMap<String, (i64, i64)> = conn
+        .prepare("SELECT path, mtime, size FROM files")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |r| Ok((r.get(0)?, (r.get(1)?, r.get(2)?))))
+                .map(|rows| rows.filter_map(|r| r.ok()).collect())
+        })
+        .map_err(|e| e.to_string())?;
+
+    let current: HashSet<&str> = files.iter().map(|f| f.path.as_str()).collect();
+    for path in indexed.keys().filter(|p| !current.contains(p.as_str())) {
+        let _ = conn.execute("DELETE FROM chunks WHERE path = ?1", params![path]);
+        let _ = conn.execute("DELETE FROM files WHERE path = ?1", params![path]);
+    }
+
+    let mut changed = 0;
+    for file in &files {
+        if indexed.get(&file.path) == Some(&(file.mtime, file.size)) {
+            continue;
+        }
+        let Ok(content) = tokio::fs::read_to_string(dir.join(&file.path)).await else {
+            continue; // not UTF-8 text
+        };
+        let chunks = chunk_lines(&content);
+        let embeddings = match &embedder {
+            Some((provider, _)) => embed_chunks(provider.as_ref(), &file.path, &chunks).await,
+            None => Ok(vec![]),
+        };
+
+        // One transaction per file: a crash never leaves it half indexed
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        tx.execute("DELETE FROM chunks WHERE path = ?1", params![file.path])
+            .map_err(|e| e.to_string())?;
+        for (i, chunk) in chunks.iter().enumerate() {
+            let embedding = embeddings
+                .as_ref()
+                .ok()
+                .and_then(|e| e.get(i))
+                .map(|v| embedding_to_blob(v));
+            tx.execute(
+                "INSERT INTO chunks (path, start_line, end_line, content, embedding) \
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    file.path,
+                    chunk.start_line,
+                    chunk.end_line,
+                    chunk.text,
+                    embedding
+                ],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        // Leave the file unrecorded when embedding failed so the next run retries it
+        if let Err(e) = embeddings {
+            tracing::warn!("Embedding {} failed: {e}", file.path);
+            tx.commit().map_err(|e| e.to_string())?;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO files (path, mtime, size) VALUES (?1, ?2, ?3) \
+             ON CONFLICT(path) DO UPDATE SET mtime = excluded.mtime, size = excluded.size",
+            params![file.path, file.mtime, file.size],
+        )
+        .map_err(|e| e.to_string())?;
+        tx.commit().map_err(|e| e.to_string())?;
+        changed += 1;
+    }
+    Ok(changed)
+}
+
+struct SourceFile {
+    /// Relative to the project directory, with '/' separators
+    path: String,
+    mtime: i64,
+    size: i64,
+}
+
+/// Source files of a project, honoring .gitignore, .ignore and hidden files
+fn list_source_files(dir: &Path) -> Vec<SourceFile> {
+    let walker = ignore::WalkBuilder::new(dir).require_git(false).build();
+    let mut files = Vec::new();
+    for entry in walker.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let is_source = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()));
+        if !is_source || !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if meta.len() > MAX_FILE_BYTES {
+            continue;
+        }
+        let Ok(relative) = path.strip_prefix(dir) else {
+            continue;
+        };
+        let mtime = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or(0);
+        files.push(SourceFile {
+            path: relative.to_string_lossy().replace('\\', "/"),
+            mtime,
+            size: meta.len() as i64,
+        });
+        if files.len() >= MAX_FILES {
+            tracing::warn!("Stopped indexing {} at {MAX_FILES} files", dir.display());
+            break;
+        }
+    }
+    files
+}
+
+struct Chunk {
+    start_line: u32,
+    end_line: u32,
+    text: String,
+}
+
+/// Split a file into windows of `CHUNK_LINES` lines overlapping by `CHUNK_OVERLAP`
+fn chunk_lines(content: &str) -> Vec<Chunk> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < lines.len() {
+        let end = (start + CHUNK_LINES).min(lines.len());
+        let text = lines[start..end].join("\n");
+        if !text.trim().is_empty() {
+            chunks.push(Chunk {
+                start_line: start as u32 + 1,
+                end_line: end as u32,
+                text,
+            });
+        }
+        if end == lines.len() {
+            break;
+        }
+        start = end - CHUNK_OVERLAP;
+    }
+    chunks
+}
+
+async fn embed_chunks(
+    provider: &dyn zeroclaw::memory::embeddings::EmbeddingProvider,
+    path: &str,
+    chunks: &[Chunk],
+) -> Result<Vec<Vec<f32>>, String> {
+    let mut embeddings = Vec::with_capacity(chunks.len());
+    for batch in chunks.chunks(EMBED_BATCH) {
+        // The path gives the model context the chunk itself may lack
+        let texts: Vec<String> = batch
+            .iter()
+            .map(|c| format!("{path}\n{}", c.text))
+            .collect();
+        let refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+        embeddings.extend(provider.embed(&refs).await.map_err(|e| e.to_string())?);
+    }
+    Ok(embeddings)
+}
+
+/// The embedding provider from the memory settings, resolving `hint:` models
+/// through the embedding routes. Returns it with a key identifying the model.
+async fn embedding_provider() -> Option<(
+    Box<dyn zeroclaw::memory::embeddings::EmbeddingProvider>,
+    String,
+)> {
+    let cs = super::agent_api::config_state().read().await;
+    let config = cs.config.as_ref()?;
+    let memory = &config.memory;
+    let fallback_key = memory
+        .embedding_api_key
+        .clone()
+        .or_else(|| config.api_key.clone());
+
+    let (provider, model, dimensions, api_key) = match memory.embedding_model.strip_prefix("hint:")
+    {
+        Some(hint) => {
+            let route = config.embedding_routes.iter().find(|r| r.hint == hint)?;
+            (
+                route.provider.clone(),
+                route.model.clone(),
+                route.dimensions.unwrap_or(memory.embedding_dimensions),
+                route.api_key.clone().or(fallback_key),
+            )
+        }
+        None => (
+            memory.embedding_provider.clone(),
+            memory.embedding_model.clone(),
+            memory.embedding_dimensions,
+            fallback_key,
+        ),
+    };
+    if provider.is_empty() || provider == "none" {
+        return None;
+    }
+    let embedder = zeroclaw::memory::embeddings::create_embedding_provider(
+        &provider,
+        api_key.as_deref(),
+        &model,
+        dimensions,
+    );
+    Some((embedder, format!("{provider}:{model}:{dimensions}")))
+}
+
+// ──────────────────── Search ─────────────────────────────────
+
+async fn search_index(
+    project_id: &str,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<CodeSearchHit>, String> {
+    if query.trim().is_empty() || !index_db_path(project_id).exists() {
+        return Ok(vec![]);
+    }
+    let query_embedding = match embedding_provider().await {
+        Some((provider, _)) => provider.embed_one(query).await.ok(),
+        None => None,
+    };
+
+    let conn = open_index_db(project_id)?;
+    let mut stmt = conn
+        .prepare("SELECT path, start_line, end_line, content, embedding FROM chunks")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |r| {
+            Ok((
+                CodeSearchHit {
+                    path: r.get(0)?,
+                    start_line: r.get(1)?,
+                    end_line: r.get(2)?,
+                    snippet: r.get(3)?,
+                    score: 0.0,
+                },
+                r.get::<_, Option<Vec<u8>>>(4)?,
+            ))
+        })
+        .map_err(|e| e.to_string())?;
+
+    let terms = query_terms(query);
+    let mut semantic = Vec::new();
+    let mut keyword = Vec::new();
+    for (mut hit, blob) in rows.filter_map(|r| r.ok()) {
+        match (&query_embedding, blob) {
+            (Some(q), Some(blob)) => {
+                hit.score = cosine_similarity(q, &blob_to_embedding(&blob));
+                if hit.score > 0.0 {
+                    semantic.push(hit);
+                }
+            }
+            _ => {
+                hit.score = keyword_score(&terms, &hit.snippet);
+                if hit.score > 0.0 {
+                    keyword.push(hit);
+                }
+            }
+        }
+    }
+    Ok(merge_hits(semantic, keyword, limit))
+}
+
+/// Cosine similarities and keyword ratios aren't comparable, so keyword
+/// matches of chunks without an embedding only follow the semantic ones
+fn merge_hits(
+    mut semantic: Vec<CodeSearchHit>,
+    mut keyword: Vec<CodeSearchHit>,
+    limit: usize,
+) -> Vec<CodeSearchHit> {
+    semantic.sort_by(|a, b| b.score.total_cmp(&a.score));
+    keyword.sort_by(|a, b| b.score.total_cmp(&a.score));
+    semantic.extend(keyword);
+    semantic.truncate(limit);
+    semantic
+}
+
+fn query_terms(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|t| t.len() > 1)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Share of query terms found in the text, with a small bonus per repeat
+fn keyword_score(terms: &[String], text: &str) -> f64 {
+    if terms.is_empty() {
+        return 0.0;
+    }
+    let text = text.to_lowercase();
+    let mut matched = 0;
+    let mut occurrences = 0;
+    for term in terms {
+        let n = text.matches(term.as_str()).count();
+        if n > 0 {
+            matched += 1;
+            occurrences += n;
+        }
+    }
+    if matched == 0 {
+        return 0.0;
+    }
+    let extra = (occurrences - matched).min(10) as f64 * 0.01;
+    matched as f64 / terms.len() as f64 + extra
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f64, 0.0f64, 0.0f64);
+    for (x, y) in a.iter().zip(b) {
+        dot += *x as f64 * *y as f64;
+        norm_a += *x as f64 * *x as f64;
+        norm_b += *y as f64 * *y as f64;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+fn embedding_to_blob(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn blob_to_embedding(blob: &[u8]) -> Vec<f32> {
+    blob.chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+// ──────────────────── Agent Tool ─────────────────────────────
+
+/// `project_search` for agents in a code project's sessions, if the project
+/// can be indexed. Also brings the index up to date in the background.
+pub(crate) async fn project_search_tool(
+    project_id: &str,
+) -> Option<Box<dyn zeroclaw::tools::Tool>> {
+    indexable_project_dir(project_id).await.ok()?;
+    spawn_project_indexer(project_id);
+    Some(Box::new(ProjectSearchTool {
+        project_id: project_id.to_string(),
+    }))
+}
+
+struct ProjectSearchTool {
+    project_id: String,
+}
+
+#[async_trait::async_trait]
+impl zeroclaw::tools::Tool for ProjectSearchTool {
+    fn name(&self) -> &str {
+        "project_search"
+    }
+
+    fn description(&self) -> &str {
+        "Search the current project's source code by meaning or keywords. \
+         Returns matching file excerpts with line numbers; use file_read for more context."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "What to look for, e.g. \"where are retries configured\""
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of results (default 10)"
+                }
+            },
+            "required": ["query"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        args: serde_json::Value,
+    ) -> anyhow::Result<zeroclaw::tools::ToolResult> {
+        let query = args.get("query").and_then(|v| v.as_str()).unwrap_or("");
+        let limit = args
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .map(|n| n.clamp(1, 50) as usize)
+            .unwrap_or(SEARCH_LIMIT);
+        let result = match search_index(&self.project_id, query, limit).await {
+            Ok(hits) if hits.is_empty() => zeroclaw::tools::ToolResult {
+                success: true,
+                output: "No matches (the index may still be building).".into(),
+                error: None,
+            },
+            Ok(hits) => zeroclaw::tools::ToolResult {
+                success: true,
+                output: hits
+                    .iter()
+                    .map(|h| {
+                        format!(
+                            "{}:{}-{} (score {:.2})\n{}",
+                            h.path, h.start_line, h.end_line, h.score, h.snippet
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n---\n\n"),
+                error: None,
+            },
+            Err(e) => zeroclaw::tools::ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(e),
+            },
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_with_overlap() {
+        let content = (1..=130)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let chunks = chunk_lines(&content);
+        let ranges: Vec<(u32, u32)> = chunks.iter().map(|c| (c.start_line, c.end_line)).collect();
+        assert_eq!(ranges, [(1, 60), (51, 110), (101, 130)]);
+        assert!(chunks[1].text.starts_with("line 51\n"));
+        assert!(chunk_lines("").is_empty());
+    }
+
+    #[test]
+    fn scores_keyword_matches() {
+        let terms = query_terms("retry Backoff, x");
+        assert_eq!(terms, ["retry", "backoff"]);
+        assert_eq!(keyword_score(&terms, "nothing here"), 0.0);
+        let one = keyword_score(&terms, "fn retry() {}");
+        let both = keyword_score(&terms, "fn retry_backoff() {}");
+        assert!(both > one && one > 0.0);
+
+        let hit = |path: &str, score| CodeSearchHit {
+            path: path.into(),
+            start_line: 1,
+            end_line: 1,
+            snippet: String::new(),
+            score,
+        };
+        let merged = merge_hits(vec![hit("a", 0.3), hit("b", 0.6)], vec![hit("c", 1.0)], 10);
+        let paths: Vec<&str> = merged.iter().map(|h| h.path.as_str()).collect();
+        assert_eq!(paths, ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn round_trips_embeddings() {
+        let v = vec![0.5f32, -1.25, 3.0];
+        assert_eq!(blob_to_embedding(&embedding_to_blob(&v)), v);
+        assert!((cosine_similarity(&v, &v) - 1.0).abs() < 1e-9);
+        assert_eq!(cosine_similarity(&v, &[1.0]), 0.0);
+    }
+}
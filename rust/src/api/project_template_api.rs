@@ -0,0 +1,420 @@
+//! Project Template API — scaffold new projects from templates.
+//!
+//! A template describes everything a new project starts with: pinned
+//! context, bound roles (agent workspaces), starter cron jobs, knowledge
+//! entries and files to create in the project directory. Built-in templates
+//! ship with the app; users can add their own as TOML or YAML files in
+//! `~/.coraldesk/project_templates/` (a user template with the same id
+//! replaces the built-in one).
+//!
+//! Text fields may use `{{project_name}}`, `{{project_dir}}` and `{{date}}`.
+
+use super::project_api::{ProjectDto, ProjectStatus};
+use flutter_rust_bridge::frb;
+use serde::Deserialize;
+use std::path::{Component, Path, PathBuf};
+
+// ──────────────────────── DTOs ────────────────────────────
+
+/// A project template for the "new project" picker
+#[derive(Debug, Clone)]
+pub struct ProjectTemplateDto {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub icon: String,
+    pub color_tag: String,
+    /// "general", "code_project", "data_processing", "writing" or "automation"
+    pub project_type: String,
+    /// Shipped with the app (false = loaded from the user's templates dir)
+    pub is_builtin: bool,
+    pub role_ids: Vec<String>,
+    pub cron_job_count: u32,
+    pub knowledge_count: u32,
+    pub file_count: u32,
+}
+
+// ──────────────────── Template Files ─────────────────────────
+
+const BUILTIN_TEMPLATES: [(&str, &str); 4] = [
+    (
+        "code_project",
+        include_str!("project_templates/code_project.toml"),
+    ),
+    (
+        "data_processing",
+        include_str!("project_templates/data_processing.toml"),
+    ),
+    ("writing", include_str!("project_templates/writing.toml")),
+    (
+        "automation",
+        include_str!("project_templates/automation.toml"),
+    ),
+];
+
+#[frb(ignore)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct ProjectTemplate {
+    /// Defaults to the file name without extension
+    id: String,
+    name: String,
+    description: String,
+    icon: String,
+    color_tag: String,
+    project_type: String,
+    pinned_context: String,
+    tags: Vec<String>,
+    /// Agent workspace IDs bound as project roles
+    roles: Vec<String>,
+    /// Defaults to the first role
+    default_role: String,
+    cron_jobs: Vec<TemplateCronJob>,
+    knowledge: Vec<TemplateKnowledge>,
+    files: Vec<TemplateFile>,
+}
+
+#[frb(ignore)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+struct TemplateCronJob {
+    name: String,
+    /// "shell" or "agent"
+    job_type: String,
+    schedule_type: String,
+    expression: String,
+    command: String,
+    prompt: String,
+    session_target: String,
+    timezone: Option<String>,
+}
+
+impl Default for TemplateCronJob {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            job_type: "shell".into(),
+            schedule_type: "cron".into(),
+            expression: String::new(),
+            command: String::new(),
+            prompt: String::new(),
+            session_target: "isolated".into(),
+            timezone: None,
+        }
+    }
+}
+
+#[frb(ignore)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+struct TemplateKnowledge {
+    key: String,
+    content: String,
+    category: String,
+}
+
+impl Default for TemplateKnowledge {
+    fn default() -> Self {
+        Self {
+            key: String::new(),
+            content: String::new(),
+            category: "core".into(),
+        }
+    }
+}
+
+#[frb(ignore)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct TemplateFile {
+    /// Relative to the project directory
+    path: String,
+    content: String,
+}
+
+fn user_templates_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join(".coraldesk")
+        .join("project_templates")
+}
+
+// ──────────────────── API Functions ──────────────────────────
+
+/// List built-in and user-defined project templates
+pub fn list_project_templates() -> Vec<ProjectTemplateDto> {
+    load_templates()
+        .into_iter()
+        .map(|(template, is_builtin)| ProjectTemplateDto {
+            id: template.id,
+            name: template.name,
+            description: template.description,
+            icon: template.icon,
+            color_tag: template.color_tag,
+            project_type: template.project_type,
+            is_builtin,
+            role_ids: template.roles,
+            cron_job_count: template.cron_jobs.len() as u32,
+            knowledge_count: template.knowledge.len() as u32,
+            file_count: template.files.len() as u32,
+        })
+        .collect()
+}
+
+/// Directory where user-defined templates are read from (created if missing)
+pub fn get_project_templates_dir() -> String {
+    let dir = user_templates_dir();
+    let _ = std::fs::create_dir_all(&dir);
+    dir.to_string_lossy().to_string()
+}
+
+/// Create a project from a template: sets its pinned context and roles,
+/// creates the starter cron jobs and knowledge entries, and with `scaffold`
+/// writes the template's files into `project_dir` (existing files are kept).
+/// Returns the new project ID, or "error: ...".
+pub async fn create_project_from_template(
+    template_id: String,
+    name: String,
+    project_dir: String,
+    scaffold: bool,
+) -> String {
+    let Some(template) = load_templates()
+        .into_iter()
+        .map(|(t, _)| t)
+        .find(|t| t.id == template_id)
+    else {
+        return format!("error: template not found: {template_id}");
+    };
+    let name = if name.trim().is_empty() {
+        template.name.clone()
+    } else {
+        name.trim().to_string()
+    };
+    let project_dir = project_dir.trim().to_string();
+    let render = |text: &str| render_template_text(text, &name, &project_dir);
+
+    if scaffold && !template.files.is_empty() {
+        if project_dir.is_empty() {
+            return "error: a project directory is required to scaffold files".into();
+        }
+        if let Err(e) = scaffold_files(Path::new(&project_dir), &template.files, &render) {
+            return format!("error: {e}");
+        }
+    }
+
+    let mut role_ids = Vec::new();
+    for role_id in &template.roles {
+        if super::agent_workspace_api::get_agent_workspace(role_id.clone())
+            .await
+            .is_some()
+        {
+            role_ids.push(role_id.clone());
+        } else {
+            tracing::warn!("Template {template_id}: skipping unknown role {role_id}");
+        }
+    }
+    let default_role_id = if role_ids.contains(&template.default_role) {
+        template.default_role.clone()
+    } else {
+        role_ids.first().cloned().unwrap_or_default()
+    };
+
+    let project_id = super::project_api::upsert_project(ProjectDto {
+        id: String::new(),
+        name: name.clone(),
+        description: template.description.clone(),
+        icon: template.icon.clone(),
+        color_tag: template.color_tag.clone(),
+        project_type: super::project_api::string_to_project_type(&template.project_type),
+        status: ProjectStatus::Active,
+        project_dir: project_dir.clone(),
+        pinned_context: render(&template.pinned_context),
+        role_ids,
+        default_role_id,
+        session_ids: vec![],
+        tags: template.tags.clone(),
+        global_memory_fallback: true,
+        autonomy: Default::default(),
+        model_profile_id: String::new(),
+        model: String::new(),
+        temperature: None,
+        created_at: 0,
+        updated_at: 0,
+    })
+    .await;
+    if project_id.starts_with("error") {
+        return project_id;
+    }
+
+    for job in &template.cron_jobs {
+        let result = if job.job_type == "agent" {
+            super::cron_api::add_agent_cron_job(
+                Some(render(&job.name)),
+                job.schedule_type.clone(),
+                render(&job.expression),
+                render(&job.prompt),
+                job.session_target.clone(),
+                None,
+                false,
+                None,
+                job.timezone.clone(),
+                Some(project_id.clone()),
+            )
+        } else {
+            super::cron_api::add_shell_cron_job(
+                Some(render(&job.name)),
+                job.schedule_type.clone(),
+                render(&job.expression),
+                render(&job.command),
+                job.timezone.clone(),
+                Some(project_id.clone()),
+            )
+        };
+        if result.starts_with("error") {
+            tracing::warn!("Template {template_id}: cron job {:?}: {result}", job.name);
+        }
+    }
+
+    for entry in &template.knowledge {
+        let result = super::knowledge_api::add_knowledge_entry(
+            entry.key.clone(),
+            render(&entry.content),
+            entry.category.clone(),
+            Some(project_id.clone()),
+        )
+        .await;
+        if result != "ok" {
+            tracing::warn!(
+                "Template {template_id}: knowledge {:?}: {result}",
+                entry.key
+            );
+        }
+    }
+
+    project_id
+}
+
+// ──────────────────── Helpers ─────────────────────────────────
+
+/// Built-in templates followed by the user's, each flagged whether built in.
+/// A user template replaces the built-in one with the same id.
+fn load_templates() -> Vec<(ProjectTemplate, bool)> {
+    let mut templates: Vec<(ProjectTemplate, bool)> = BUILTIN_TEMPLATES
+        .iter()
+        .filter_map(|(id, content)| match parse_template(id, content, "toml") {
+            Ok(t) => Some((t, true)),
+            Err(e) => {
+                tracing::error!("Built-in project template {id} is invalid: {e}");
+                None
+            }
+        })
+        .collect();
+
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(user_templates_dir())
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+        .unwrap_or_default();
+    paths.sort();
+    for path in paths {
+        let format = match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => "toml",
+            Some("yaml" | "yml") => "yaml",
+            _ => continue,
+        };
+        let id = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| parse_template(&id, &content, format));
+        match parsed {
+            Ok(template) => {
+                templates.retain(|(t, _)| t.id != template.id);
+                templates.push((template, false));
+            }
+            Err(e) => tracing::warn!("Skipping project template {}: {e}", path.display()),
+        }
+    }
+    templates
+}
+
+fn parse_template(id: &str, content: &str, format: &str) -> Result<ProjectTemplate, String> {
+    let mut template: ProjectTemplate = match format {
+        "yaml" => serde_yaml_ng::from_str(content).map_err(|e| e.to_string())?,
+        _ => toml::from_str(content).map_err(|e| e.to_string())?,
+    };
+    if template.id.trim().is_empty() {
+        template.id = id.to_string();
+    }
+    if template.name.trim().is_empty() {
+        return Err("name is required".into());
+    }
+    if template.project_type.is_empty() {
+        template.project_type = "general".into();
+    }
+    for file in &template.files {
+        relative_file_path(&file.path)?;
+    }
+    Ok(template)
+}
+
+/// A template file path, which must stay inside the project directory
+fn relative_file_path(path: &str) -> Result<&Path, String> {
+    let p = Path::new(path);
+    if path.trim().is_empty() || !p.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err(format!("invalid file path: {path}"));
+    }
+    Ok(p)
+}
+
+fn scaffold_files(
+    project_dir: &Path,
+    files: &[TemplateFile],
+    render: &dyn Fn(&str) -> String,
+) -> Result<(), String> {
+    for file in files {
+        let target = project_dir.join(relative_file_path(&file.path)?);
+        if target.exists() {
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("create {}: {e}", parent.display()))?;
+        }
+        std::fs::write(&target, render(&file.content))
+            .map_err(|e| format!("write {}: {e}", target.display()))?;
+    }
+    Ok(())
+}
+
+fn render_template_text(text: &str, project_name: &str, project_dir: &str) -> String {
+    text.replace("{{project_name}}", project_name)
+        .replace("{{project_dir}}", project_dir)
+        .replace(
+            "{{date}}",
+            &chrono::Local::now().format("%Y-%m-%d").to_string(),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_builtin_templates() {
+        for (id, content) in BUILTIN_TEMPLATES {
+            let template = parse_template(id, content, "toml").unwrap();
+            assert_eq!(template.id, id);
+            assert!(!template.roles.is_empty(), "{id} has no roles");
+            assert!(template.roles.iter().all(|r| r.starts_with("preset_")));
+        }
+    }
+
+    #[test]
+    fn rejects_file_paths_outside_project() {
+        assert!(relative_file_path("docs/notes.md").is_ok());
+        for path in ["", "../escape.md", "/etc/passwd", "docs/../../x"] {
+            assert!(relative_file_path(path).is_err(), "{path}");
+        }
+    }
+}
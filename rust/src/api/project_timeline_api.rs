@@ -0,0 +1,480 @@
+//! Project Timeline API — what happened in a project, newest first.
+//!
+//! The feed merges session activity, runs of the project's cron jobs,
+//! knowledge added to the project's memory and files in the project
+//! directory changed by agent tools. File changes are recorded here as the
+//! agents make them; everything else is read from the stores that already
+//! keep it.
+
+use rusqlite::{params, Connection};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+// ──────────────────────── DTOs ────────────────────────────
+
+/// One entry of a project's activity feed
+#[derive(Debug, Clone)]
+pub struct ProjectTimelineEvent {
+    /// Epoch seconds
+    pub timestamp: i64,
+    /// "session_created", "session_updated", "cron_run", "knowledge_added"
+    /// or "file_changed"
+    pub kind: String,
+    /// Session title, job name, knowledge key or file path
+    pub title: String,
+    pub detail: String,
+    /// The session (sessions and file changes) or cron job the event is about
+    pub ref_id: String,
+    /// Run status for cron runs ("ok", "error", ...); empty otherwise
+    pub status: String,
+}
+
+/// A page of the timeline
+#[derive(Debug, Clone)]
+pub struct ProjectTimelinePage {
+    pub events: Vec<ProjectTimelineEvent>,
+    /// Pass as `cursor` to get the next (older) page; None at the end
+    pub next_cursor: Option<i64>,
+}
+
+/// Agent tools that write files, with the argument holding the path
+const FILE_TOOLS: [(&str, &str); 2] = [("file_write", "path"), ("file_edit", "path")];
+
+/// Whether the agent has any of the tools whose writes are tracked
+pub(crate) fn can_write_files(agent: &zeroclaw::agent::Agent) -> bool {
+    agent
+        .tool_specs()
+        .iter()
+        .any(|spec| FILE_TOOLS.iter().any(|(name, _)| *name == spec.name))
+}
+
+fn activity_db_path(project_id: &str) -> PathBuf {
+    super::knowledge_api::project_memory_workspace(project_id).join("activity.db")
+}
+
+/// The project's activity database: file changes for the timeline and the
+/// working tree snapshots `project_git_api` keeps for undo
+pub(crate) fn open_activity_db(project_id: &str) -> Result<Connection, String> {
+    let path = activity_db_path(project_id);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let conn = Connection::open(&path).map_err(|e| e.to_string())?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS file_changes (
+            id         INTEGER PRIMARY KEY AUTOINCREMENT,
+            changed_at INTEGER NOT NULL,
+            session_id TEXT NOT NULL,
+            tool       TEXT NOT NULL,
+            path       TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_file_changes_at ON file_changes(changed_at);
+        CREATE TABLE IF NOT EXISTS turn_snapshots (
+            id         INTEGER PRIMARY KEY AUTOINCREMENT,
+            session_id TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            tree       TEXT NOT NULL,
+            files      TEXT NOT NULL
+        );",
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(conn)
+}
+
+// ──────────────────── API Functions ──────────────────────────
+
+/// Activity of a project older than `cursor` (epoch seconds; None = from
+/// now), newest first. A page holds about `limit` events; events sharing a
+/// timestamp are never split across pages.
+pub async fn get_project_timeline(
+    project_id: String,
+    cursor: Option<i64>,
+    limit: u32,
+) -> ProjectTimelinePage {
+    let limit = limit.clamp(1, 500);
+    let before = cursor.unwrap_or(i64::MAX);
+    let mut events = session_events(&project_id, before).await;
+    events.extend(knowledge_events(&project_id, before).await);
+    // One more than a page from the capped sources tells whether there's
+    // more. They also return the whole second of their oldest event, which
+    // `paginate` keeps together.
+    events.extend(cron_events(&project_id, cursor, limit + 1));
+    events.extend(file_change_events(&project_id, before, limit + 1));
+    events.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
+    paginate(events, limit as usize)
+}
+
+/// Record the files in the project directory that an agent turn's tool
+/// calls wrote, and return their paths. `messages` are the turn's new
+/// history entries.
+pub(crate) async fn record_agent_file_changes(
+    project_id: &str,
+    session_id: &str,
+    messages: &[zeroclaw::providers::ConversationMessage],
+) -> Vec<String> {
+    let Some(project) = super::project_api::project_info(project_id).await else {
+        return vec![];
+    };
+    if project.project_dir.is_empty() {
+        return vec![];
+    }
+    let changes = file_changes(messages, Path::new(&project.project_dir));
+    record_file_changes(project_id, session_id, &changes);
+    let mut paths: Vec<String> = Vec::new();
+    for (_, path) in changes {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// Add file changes to the timeline, each as (tool, path relative to the
+/// project directory)
+pub(crate) fn record_file_changes(
+    project_id: &str,
+    session_id: &str,
+    changes: &[(String, String)],
+) {
+    if changes.is_empty() {
+        return;
+    }
+    let result = open_activity_db(project_id).and_then(|conn| {
+        let now = chrono::Utc::now().timestamp();
+        for (tool, path) in changes {
+            conn.execute(
+                "INSERT INTO file_changes (changed_at, session_id, tool, path) \
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![now, session_id, tool, path],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    });
+    if let Err(e) = result {
+        tracing::warn!("Failed to record file changes of project {project_id}: {e}");
+    }
+}
+
+// ──────────────────── Sources ─────────────────────────────────
+
+async fn session_events(project_id: &str, before: i64) -> Vec<ProjectTimelineEvent> {
+    let mut events = Vec::new();
+    for session in super::sessions_api::list_sessions().await {
+        if session.project_id != project_id {
+            continue;
+        }
+        if session.created_at < before {
+            events.push(ProjectTimelineEvent {
+                timestamp: session.created_at,
+                kind: "session_created".into(),
+                title: session.title.clone(),
+                detail: String::new(),
+                ref_id: session.id.clone(),
+                status: String::new(),
+            });
+        }
+        if session.updated_at > session.created_at && session.updated_at < before {
+            events.push(ProjectTimelineEvent {
+                timestamp: session.updated_at,
+                kind: "session_updated".into(),
+                title: session.title,
+                detail: format!("{} messages", session.message_count),
+                ref_id: session.id,
+                status: String::new(),
+            });
+        }
+    }
+    events
+}
+
+/// Entries saved to the project's memory. Conversation memory (the agent's
+/// automatic transcript saves) is left out; it would drown everything else.
+async fn knowledge_events(project_id: &str, before: i64) -> Vec<ProjectTimelineEvent> {
+    super::knowledge_api::list_knowledge_entries(None, u32::MAX, Some(project_id.to_string()))
+        .await
+        .into_iter()
+        .filter(|e| e.category != "conversation")
+        .filter_map(|e| {
+            let timestamp = chrono::DateTime::parse_from_rfc3339(&e.timestamp)
+                .ok()?
+                .timestamp();
+            (timestamp < before).then(|| ProjectTimelineEvent {
+                timestamp,
+                kind: "knowledge_added".into(),
+                title: e.key,
+                detail: e.content.chars().take(200).collect(),
+                ref_id: String::new(),
+                status: String::new(),
+            })
+        })
+        .collect()
+}
+
+fn cron_events(project_id: &str, before: Option<i64>, limit: u32) -> Vec<ProjectTimelineEvent> {
+    let runs = super::cron_api::project_cron_runs(project_id, before, limit);
+    if runs.is_empty() {
+        return vec![];
+    }
+    let jobs = super::cron_api::list_cron_jobs();
+    runs.into_iter()
+        .map(|run| {
+            let name = jobs
+                .iter()
+                .find(|j| j.id == run.job_id)
+                .map(|j| j.name.clone())
+                .filter(|n| !n.is_empty())
+                .unwrap_or_else(|| run.job_id.clone());
+            ProjectTimelineEvent {
+                timestamp: run.started_at,
+                kind: "cron_run".into(),
+                title: name,
+                detail: run
+                    .output
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .chars()
+                    .take(200)
+                    .collect(),
+                ref_id: run.job_id,
+                status: run.status,
+            }
+        })
+        .collect()
+}
+
+fn file_change_events(project_id: &str, before: i64, limit: u32) -> Vec<ProjectTimelineEvent> {
+    if !activity_db_path(project_id).exists() {
+        return vec![];
+    }
+    let conn = match open_activity_db(project_id) {
+        Ok(c) => c,
+        Err(e) => {
+            tracing::warn!("Failed to open activity of project {project_id}: {e}");
+            return vec![];
+        }
+    };
+    query_file_changes(&conn, before, limit)
+}
+
+/// The latest `limit` file changes before `before`, plus the rest of the
+/// oldest one's second
+fn query_file_changes(conn: &Connection, before: i64, limit: u32) -> Vec<ProjectTimelineEvent> {
+    conn.prepare(
+        "SELECT changed_at, session_id, tool, path FROM file_changes \
+         WHERE changed_at < ?1 AND changed_at >= IFNULL(( \
+             SELECT changed_at FROM file_changes WHERE changed_at < ?1 \
+             ORDER BY changed_at DESC, id DESC LIMIT 1 OFFSET ?2 \
+         ), changed_at) \
+         ORDER BY changed_at DESC, id DESC",
+    )
+    .and_then(|mut stmt| {
+        stmt.query_map(params![before, limit.max(1) - 1], |row| {
+            Ok(ProjectTimelineEvent {
+                timestamp: row.get(0)?,
+                kind: "file_changed".into(),
+                ref_id: row.get(1)?,
+                detail: row.get(2)?,
+                title: row.get(3)?,
+                status: String::new(),
+            })
+        })
+        .map(|rows| rows.filter_map(|r| r.ok()).collect())
+    })
+    .unwrap_or_default()
+}
+
+// ──────────────────── Helpers ─────────────────────────────────
+
+/// First `limit` events (sorted newest first), plus any that share the last
+/// one's timestamp, so the next page can start strictly before it
+fn paginate(mut events: Vec<ProjectTimelineEvent>, limit: usize) -> ProjectTimelinePage {
+    if events.len() <= limit {
+        return ProjectTimelinePage {
+            events,
+            next_cursor: None,
+        };
+    }
+    let boundary = events[limit - 1].timestamp;
+    let end = events
+        .iter()
+        .position(|e| e.timestamp < boundary)
+        .unwrap_or(events.len());
+    let more = end < events.len();
+    events.truncate(end);
+    ProjectTimelinePage {
+        events,
+        next_cursor: more.then_some(boundary),
+    }
+}
+
+/// Paths (relative to `project_dir`) written by successful file tool calls,
+/// each with the tool that wrote it
+fn file_changes(
+    messages: &[zeroclaw::providers::ConversationMessage],
+    project_dir: &Path,
+) -> Vec<(String, String)> {
+    use zeroclaw::providers::ConversationMessage;
+
+    let Some(project_dir) = normalize(project_dir) else {
+        return vec![];
+    };
+    let failed: HashSet<&str> = messages
+        .iter()
+        .filter_map(|m| match m {
+            ConversationMessage::ToolResults(results) => Some(results),
+            _ => None,
+        })
+        .flatten()
+        .filter(|r| r.content.trim_start().starts_with("Error"))
+        .map(|r| r.tool_call_id.as_str())
+        .collect();
+
+    let mut changes: Vec<(String, String)> = Vec::new();
+    for message in messages {
+        let ConversationMessage::AssistantToolCalls { tool_calls, .. } = message else {
+            continue;
+        };
+        for call in tool_calls {
+            let Some((tool, arg)) = FILE_TOOLS.iter().find(|(name, _)| *name == call.name) else {
+                continue;
+            };
+            if failed.contains(call.id.as_str()) {
+                continue;
+            }
+            let Some(path) = serde_json::from_str::<serde_json::Value>(&call.arguments)
+                .ok()
+                .and_then(|args| args.get(*arg)?.as_str().map(PathBuf::from))
+            else {
+                continue;
+            };
+            // Relative paths resolve against the session workspace, not the project
+            let Some(path) = normalize(&path) else {
+                continue;
+            };
+            let Ok(relative) = path.strip_prefix(&project_dir) else {
+                continue;
+            };
+            let change = (tool.to_string(), relative.to_string_lossy().to_string());
+            if !changes.contains(&change) {
+                changes.push(change);
+            }
+        }
+    }
+    changes
+}
+
+/// Resolve `.` and `..` in an absolute path without touching the file
+/// system; None for relative paths and ones climbing above the root
+fn normalize(path: &Path) -> Option<PathBuf> {
+    use std::path::Component;
+
+    if !path.is_absolute() {
+        return None;
+    }
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    Some(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zeroclaw::providers::{ConversationMessage, ToolCall, ToolResultMessage};
+
+    fn event(timestamp: i64) -> ProjectTimelineEvent {
+        ProjectTimelineEvent {
+            timestamp,
+            kind: "session_created".into(),
+            title: String::new(),
+            detail: String::new(),
+            ref_id: String::new(),
+            status: String::new(),
+        }
+    }
+
+    #[test]
+    fn keeps_equal_timestamps_on_one_page() {
+        let page = paginate(vec![event(5), event(4), event(4), event(3)], 2);
+        assert_eq!(page.events.len(), 3);
+        assert_eq!(page.next_cursor, Some(4));
+
+        let page = paginate(vec![event(5), event(4)], 2);
+        assert_eq!(page.events.len(), 2);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn returns_whole_second_of_oldest_file_change() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE file_changes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT, changed_at INTEGER NOT NULL,
+                session_id TEXT NOT NULL, tool TEXT NOT NULL, path TEXT NOT NULL
+            );",
+        )
+        .unwrap();
+        for ts in [1, 2, 2, 2, 3] {
+            conn.execute(
+                "INSERT INTO file_changes (changed_at, session_id, tool, path) \
+                 VALUES (?1, 's', 'file_write', 'a.rs')",
+                params![ts],
+            )
+            .unwrap();
+        }
+        let stamps = |before, limit| -> Vec<i64> {
+            query_file_changes(&conn, before, limit)
+                .iter()
+                .map(|e| e.timestamp)
+                .collect()
+        };
+        assert_eq!(stamps(i64::MAX, 2), [3, 2, 2, 2]);
+        assert_eq!(stamps(2, 2), [1]);
+        assert_eq!(stamps(i64::MAX, 10).len(), 5);
+    }
+
+    #[test]
+    fn records_successful_writes_inside_project() {
+        let call = |id: &str, name: &str, path: &str| ToolCall {
+            id: id.into(),
+            name: name.into(),
+            arguments: serde_json::json!({ "path": path }).to_string(),
+        };
+        let messages = vec![
+            ConversationMessage::AssistantToolCalls {
+                text: None,
+                tool_calls: vec![
+                    call("1", "file_write", "/work/app/src/main.rs"),
+                    call("2", "file_write", "/tmp/elsewhere.txt"),
+                    call("3", "file_read", "/work/app/README.md"),
+                    call("4", "file_edit", "/work/app/Cargo.toml"),
+                    call("5", "file_write", "/work/app/src/main.rs"),
+                    call("6", "file_write", "/work/app/../../etc/passwd"),
+                    call("7", "file_write", "/work/app/src/../lib.rs"),
+                ],
+            },
+            ConversationMessage::ToolResults(vec![ToolResultMessage {
+                tool_call_id: "4".into(),
+                content: "Error: old_string not found".into(),
+            }]),
+        ];
+        assert_eq!(
+            file_changes(&messages, Path::new("/work/app")),
+            vec![
+                ("file_write".to_string(), "src/main.rs".to_string()),
+                ("file_write".to_string(), "lib.rs".to_string()),
+            ]
+        );
+    }
+}
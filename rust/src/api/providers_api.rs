@@ -274,23 +274,41 @@ pub async fn set_default_profile(id: String) -> String {
         return "error: profile id must not be empty".into();
     }
 
-    let (provider_name, base_url, model, api_key) = {
-        let gc = super::agent_api::global_config().read().await;
-        let config = match &gc.config {
+    // Update BOTH global_config and config_state (critical for agent creation)
+    {
+        let mut gc = super::agent_api::global_config().write().await;
+        let mut cs = super::agent_api::config_state().write().await;
+
+        let config = match gc.config.as_mut() {
             Some(c) => c,
             None => return "error: runtime not initialized".into(),
         };
-        let profile = match config.model_providers.get(&id) {
-            Some(p) => p,
-            None => return format!("error: profile '{}' not found", id),
-        };
-        (
-            profile.name.clone(),
-            profile.base_url.clone(),
-            profile.default_model.clone().unwrap_or_default(),
-            profile.api_key.clone(),
-        )
-    };
+        if let Err(e) = apply_profile(config, &id) {
+            return format!("error: {e}");
+        }
+
+        // Sync to legacy config_state
+        cs.config = Some(config.clone());
+
+        // Store the profile ID for UI persistence
+        gc.default_profile_id = Some(id.clone());
+    }
+
+    super::agent_api::invalidate_all_agents().await;
+    super::agent_api::save_config_to_disk().await
+}
+
+/// Point a config's provider, api_url, api_key and default_model at a
+/// profile. Also used for projects that pin their own profile.
+pub(crate) fn apply_profile(config: &mut zeroclaw::Config, id: &str) -> Result<(), String> {
+    let profile = config
+        .model_providers
+        .get(id)
+        .ok_or_else(|| format!("profile '{}' not found", id))?;
+    let provider_name = profile.name.clone();
+    let base_url = profile.base_url.clone();
+    let model = profile.default_model.clone().unwrap_or_default();
+    let api_key = profile.api_key.clone();
 
     // Known valid zeroclaw provider types
     const KNOWN_PROVIDERS: &[&str] = &[
@@ -334,51 +352,32 @@ pub async fn set_default_profile(id: String) -> String {
         } else if let Some(name) = &provider_name {
             name.trim().to_string()
         } else {
-            id.clone()
+            id.to_string()
         }
     } else if let Some(name) = &provider_name {
         name.trim().to_string()
     } else {
-        id.clone()
+        id.to_string()
     };
 
-    // Update BOTH global_config and config_state (critical for agent creation)
-    {
-        let mut gc = super::agent_api::global_config().write().await;
-        let mut cs = super::agent_api::config_state().write().await;
-
-        // Store the profile ID for UI persistence (do this first to avoid borrow issues)
-        gc.default_profile_id = Some(id.clone());
-
-        let config = match gc.config.as_mut() {
-            Some(c) => c,
-            None => return "error: runtime not initialized".into(),
-        };
-
-        config.default_provider = Some(effective_provider);
+    config.default_provider = Some(effective_provider);
 
-        if !model.is_empty() {
-            config.default_model = Some(model);
-        }
+    if !model.is_empty() {
+        config.default_model = Some(model);
+    }
 
-        if let Some(key) = &api_key {
-            if !key.trim().is_empty() {
-                config.api_key = Some(key.trim().to_string());
-            }
+    if let Some(key) = &api_key {
+        if !key.trim().is_empty() {
+            config.api_key = Some(key.trim().to_string());
         }
+    }
 
-        if let Some(url) = &base_url {
-            if !url.trim().is_empty() {
-                config.api_url = Some(url.trim().to_string());
-            }
+    if let Some(url) = &base_url {
+        if !url.trim().is_empty() {
+            config.api_url = Some(url.trim().to_string());
         }
-
-        // Sync to legacy config_state
-        cs.config = Some(config.clone());
     }
-
-    super::agent_api::invalidate_all_agents().await;
-    super::agent_api::save_config_to_disk().await
+    Ok(())
 }
 
 /// Get the current default profile ID.
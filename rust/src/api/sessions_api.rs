@@ -1,4 +1,5 @@
 use flutter_rust_bridge::frb;
+use std::collections::HashMap;
 use std::sync::OnceLock;
 use tokio::sync::Mutex as TokioMutex;
 
@@ -73,8 +74,10 @@ struct PersistedSession {
     messages: Vec<PersistedMessage>,
     #[serde(default)]
     attached_files: Vec<String>,
-    /// Project this session belongs to (empty = free/independent)
-    #[serde(default)]
+    /// Legacy copy of the session's project, written by older versions.
+    /// Membership now lives in the project store; this is only read until
+    /// `project_api::check_project_sessions` adopts it.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     project_id: String,
     /// Whether this session is ephemeral
     #[serde(default)]
@@ -107,6 +110,10 @@ struct PersistedMessage {
 #[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
 struct SessionStore {
     sessions: Vec<PersistedSession>,
+    /// Set once loaded from disk, so membership checks don't mistake an
+    /// empty store for deleted sessions
+    #[serde(skip)]
+    loaded: bool,
 }
 
 fn session_store() -> &'static TokioMutex<SessionStore> {
@@ -126,7 +133,7 @@ fn sessions_file_path() -> std::path::PathBuf {
 /// Initialize session store — load from disk
 pub async fn init_session_store() -> String {
     let path = sessions_file_path();
-    let store = if path.exists() {
+    let mut store = if path.exists() {
         match tokio::fs::read_to_string(&path).await {
             Ok(content) => serde_json::from_str::<SessionStore>(&content).unwrap_or_default(),
             Err(_) => SessionStore::default(),
@@ -135,6 +142,7 @@ pub async fn init_session_store() -> String {
         SessionStore::default()
     };
 
+    store.loaded = true;
     let count = store.sessions.len();
 
     // Restore agent workspace bindings from persisted session metadata
@@ -153,6 +161,7 @@ pub async fn init_session_store() -> String {
 
 /// List all sessions (without full messages)
 pub async fn list_sessions() -> Vec<SessionSummary> {
+    let projects = super::project_api::session_project_map().await;
     let store = session_store().lock().await;
     store
         .sessions
@@ -178,7 +187,10 @@ pub async fn list_sessions() -> Vec<SessionSummary> {
                 message_count: s.messages.len() as u32,
                 last_message_preview: preview,
                 attached_files: s.attached_files.clone(),
-                project_id: s.project_id.clone(),
+                project_id: projects
+                    .get(&s.id)
+                    .cloned()
+                    .unwrap_or_else(|| s.project_id.clone()),
                 ephemeral: s.ephemeral,
                 agent_binding: s.agent_binding.clone(),
             }
@@ -218,12 +230,13 @@ pub async fn get_session_detail(session_id: String) -> Option<SessionDetail> {
         })
 }
 
-/// Save/update a session with its messages
+/// Save/update a session with its messages.
+/// Project membership is left alone; it only changes through
+/// `add_session_to_project` / `remove_session_from_project`.
 pub async fn save_session(
     session_id: String,
     title: String,
     messages: Vec<SessionMessage>,
-    project_id: String,
     ephemeral: bool,
     agent_binding: String,
 ) -> String {
@@ -249,20 +262,19 @@ pub async fn save_session(
         session.title = title;
         session.updated_at = now;
         session.messages = persisted_msgs;
-        session.project_id = project_id;
         session.ephemeral = ephemeral;
         session.agent_binding = agent_binding;
     } else {
         store.sessions.insert(
             0,
             PersistedSession {
-                id: session_id,
+                id: session_id.clone(),
                 title,
                 created_at: now,
                 updated_at: now,
                 messages: persisted_msgs,
                 attached_files: vec![],
-                project_id,
+                project_id: String::new(),
                 ephemeral,
                 agent_binding,
             },
@@ -274,12 +286,19 @@ pub async fn save_session(
     persist_to_disk().await
 }
 
-/// Delete a session
+/// Delete a session and remove it from its project
 pub async fn delete_session(session_id: String) -> String {
     let mut store = session_store().lock().await;
     store.sessions.retain(|s| s.id != session_id);
     drop(store);
-    persist_to_disk().await
+    let result = persist_to_disk().await;
+    if result == "ok" {
+        let cleanup = super::project_api::remove_sessions_from_projects(&[session_id]).await;
+        if cleanup != "ok" {
+            tracing::warn!("Failed to remove deleted session from its project: {cleanup}");
+        }
+    }
+    result
 }
 
 /// Rename a session
@@ -307,11 +326,6 @@ pub async fn update_session_metadata(
 ) -> String {
     let mut store = session_store().lock().await;
     if let Some(session) = store.sessions.iter_mut().find(|s| s.id == session_id) {
-        if project_id == "__CLEAR__" {
-            session.project_id = String::new();
-        } else if !project_id.is_empty() {
-            session.project_id = project_id;
-        }
         match ephemeral {
             0 => session.ephemeral = false,
             1 => session.ephemeral = true,
@@ -327,7 +341,15 @@ pub async fn update_session_metadata(
         return "error: session not found".into();
     }
     drop(store);
-    persist_to_disk().await
+    let result = persist_to_disk().await;
+    if result != "ok" {
+        return result;
+    }
+    match project_id.as_str() {
+        "" => result,
+        "__CLEAR__" => super::project_api::set_session_project(&session_id, "").await,
+        project_id => super::project_api::set_session_project(&session_id, project_id).await,
+    }
 }
 
 /// Get session statistics
@@ -344,12 +366,19 @@ pub async fn get_session_stats() -> SessionStats {
     }
 }
 
-/// Clear all sessions
+/// Clear all sessions, emptying their projects
 pub async fn clear_all_sessions() -> String {
     let mut store = session_store().lock().await;
-    store.sessions.clear();
+    let session_ids: Vec<String> = store.sessions.drain(..).map(|s| s.id).collect();
     drop(store);
-    persist_to_disk().await
+    let result = persist_to_disk().await;
+    if result == "ok" {
+        let cleanup = super::project_api::remove_sessions_from_projects(&session_ids).await;
+        if cleanup != "ok" {
+            tracing::warn!("Failed to remove cleared sessions from projects: {cleanup}");
+        }
+    }
+    result
 }
 
 // ──────────────────── Session File Attachments ───────────────
@@ -437,6 +466,114 @@ pub async fn clear_session_files(session_id: String) -> String {
     persist_to_disk().await
 }
 
+/// Append messages to a persisted session without touching the rest of it.
+/// Used by background producers (cron jobs) that don't own the UI's message list.
+pub(crate) async fn append_session_messages(
+    session_id: &str,
+    messages: Vec<SessionMessage>,
+) -> String {
+    let mut store = session_store().lock().await;
+    let session = match store.sessions.iter_mut().find(|s| s.id == session_id) {
+        Some(s) => s,
+        None => return format!("error: session not found: {session_id}"),
+    };
+
+    session
+        .messages
+        .extend(messages.into_iter().map(|m| PersistedMessage {
+            id: m.id,
+            role: m.role,
+            content: m.content,
+            timestamp: m.timestamp,
+            tool_calls_json: m.tool_calls_json,
+            parts_json: m.parts_json,
+            agent_role: m.agent_role,
+            agent_color: m.agent_color,
+            agent_icon: m.agent_icon,
+        }));
+    session.updated_at = chrono::Utc::now().timestamp();
+
+    drop(store);
+    persist_to_disk().await
+}
+
+/// Every persisted session with the project recorded on it by older
+/// versions (usually empty). None until the store is loaded from disk.
+pub(crate) async fn legacy_session_projects() -> Option<Vec<(String, String)>> {
+    let store = session_store().lock().await;
+    store.loaded.then(|| {
+        store
+            .sessions
+            .iter()
+            .map(|s| (s.id.clone(), s.project_id.clone()))
+            .collect()
+    })
+}
+
+/// Drop the legacy session-side project ids once the project store owns them
+pub(crate) async fn clear_legacy_session_projects() {
+    let mut store = session_store().lock().await;
+    let mut changed = false;
+    for session in &mut store.sessions {
+        if !session.project_id.is_empty() {
+            session.project_id.clear();
+            changed = true;
+        }
+    }
+    drop(store);
+    if changed {
+        let result = persist_to_disk().await;
+        if result != "ok" {
+            tracing::warn!("Failed to clear legacy session projects: {result}");
+        }
+    }
+}
+
+/// Persisted form of the given sessions, for project bundles
+pub(crate) async fn export_sessions(session_ids: &[String]) -> Vec<serde_json::Value> {
+    let store = session_store().lock().await;
+    store
+        .sessions
+        .iter()
+        .filter(|s| session_ids.contains(&s.id))
+        .filter_map(|s| serde_json::to_value(s).ok())
+        .collect()
+}
+
+/// Add sessions from a project bundle. Every session gets a fresh id, and
+/// its role binding is mapped through `role_ids` (bundle id → local id);
+/// bindings to roles that weren't imported are dropped. Returns the id each
+/// session was stored under, keyed by its id in the bundle.
+pub(crate) async fn import_sessions(
+    sessions: Vec<serde_json::Value>,
+    role_ids: &HashMap<String, String>,
+) -> Result<HashMap<String, String>, String> {
+    let mut ids = HashMap::new();
+    let mut store = session_store().lock().await;
+    let mut agent_bindings = super::agent_workspace_api::session_bindings_mut().await;
+    for value in sessions {
+        let mut session: PersistedSession =
+            serde_json::from_value(value).map_err(|e| format!("invalid session: {e}"))?;
+        let original_id = std::mem::replace(&mut session.id, uuid::Uuid::new_v4().to_string());
+        session.agent_binding = role_ids
+            .get(&session.agent_binding)
+            .cloned()
+            .unwrap_or_default();
+        if !session.agent_binding.is_empty() {
+            agent_bindings.insert(session.id.clone(), session.agent_binding.clone());
+        }
+        session.project_id.clear();
+        ids.insert(original_id, session.id.clone());
+        store.sessions.insert(0, session);
+    }
+    drop(agent_bindings);
+    drop(store);
+    match persist_to_disk().await.as_str() {
+        "ok" => Ok(ids),
+        err => Err(err.to_string()),
+    }
+}
+
 // ──────────────────── Helpers ─────────────────────────────────
 
 async fn persist_to_disk() -> String {
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -845868303;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -7856974;
 
 // Section: executor
 
@@ -75,6 +75,8 @@ fn wire__crate__api__cron_api__add_agent_cron_job_impl(
             let api_model = <Option<String>>::sse_decode(&mut deserializer);
             let api_delete_after_run = <bool>::sse_decode(&mut deserializer);
             let api_target_session_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_timezone = <Option<String>>::sse_decode(&mut deserializer);
+            let api_project_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
@@ -87,6 +89,8 @@ fn wire__crate__api__cron_api__add_agent_cron_job_impl(
                         api_model,
                         api_delete_after_run,
                         api_target_session_id,
+                        api_timezone,
+                        api_project_id,
                     ))?;
                     Ok(output_ok)
                 })())
@@ -157,6 +161,7 @@ fn wire__crate__api__knowledge_api__add_knowledge_entry_impl(
             let api_key = <String>::sse_decode(&mut deserializer);
             let api_content = <String>::sse_decode(&mut deserializer);
             let api_category = <String>::sse_decode(&mut deserializer);
+            let api_project_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
@@ -166,6 +171,7 @@ fn wire__crate__api__knowledge_api__add_knowledge_entry_impl(
                                 api_key,
                                 api_content,
                                 api_category,
+                                api_project_id,
                             )
                             .await,
                         )?;
@@ -370,6 +376,8 @@ fn wire__crate__api__cron_api__add_shell_cron_job_impl(
             let api_schedule_type = <String>::sse_decode(&mut deserializer);
             let api_expression = <String>::sse_decode(&mut deserializer);
             let api_command = <String>::sse_decode(&mut deserializer);
+            let api_timezone = <Option<String>>::sse_decode(&mut deserializer);
+            let api_project_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
@@ -378,6 +386,8 @@ fn wire__crate__api__cron_api__add_shell_cron_job_impl(
                         api_schedule_type,
                         api_expression,
                         api_command,
+                        api_timezone,
+                        api_project_id,
                     ))?;
                     Ok(output_ok)
                 })())
@@ -542,6 +552,44 @@ fn wire__crate__api__agent_api__cancel_generation_impl(
         },
     )
 }
+fn wire__crate__api__project_api__check_project_sessions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "check_project_sessions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_repair = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::project_api::check_project_sessions(api_repair).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__sessions_api__clear_all_sessions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -943,6 +991,53 @@ fn wire__crate__api__agent_api__copy_file_to_impl(
         },
     )
 }
+fn wire__crate__api__project_template_api__create_project_from_template_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_project_from_template",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_template_id = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_project_dir = <String>::sse_decode(&mut deserializer);
+            let api_scaffold = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::project_template_api::create_project_from_template(
+                                api_template_id,
+                                api_name,
+                                api_project_dir,
+                                api_scaffold,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__agent_api__create_session_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -972,6 +1067,105 @@ fn wire__crate__api__agent_api__create_session_impl(
         },
     )
 }
+fn wire__crate__api__cron_api__cron_active_window_dto_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cron_active_window_dto_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::CronActiveWindowDto::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__cron_env_var_dto_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cron_env_var_dto_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::CronEnvVarDto::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__cron_shell_sandbox_dto_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cron_shell_sandbox_dto_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::CronShellSandboxDto::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__agents_api__delegate_agent_count_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1066,12 +1260,17 @@ fn wire__crate__api__knowledge_api__delete_knowledge_entry_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_key = <String>::sse_decode(&mut deserializer);
+            let api_project_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::knowledge_api::delete_knowledge_entry(api_key).await,
+                            crate::api::knowledge_api::delete_knowledge_entry(
+                                api_key,
+                                api_project_id,
+                            )
+                            .await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -1157,6 +1356,86 @@ fn wire__crate__api__sessions_api__delete_session_impl(
         },
     )
 }
+fn wire__crate__api__cron_api__export_cron_jobs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_cron_jobs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_format = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::export_cron_jobs(
+                        api_project_id,
+                        api_format,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__project_bundle_api__export_project_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_project",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_id = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::project_bundle_api::export_project(
+                                api_project_id,
+                                api_path,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__workspace_api__feature_toggles_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1489,7 +1768,7 @@ fn wire__crate__api__workspace_api__get_cost_config_impl(
         },
     )
 }
-fn wire__crate__api__cron_api__get_cron_config_impl(
+fn wire__crate__api__cron_api__get_cron_active_window_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1497,7 +1776,7 @@ fn wire__crate__api__cron_api__get_cron_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_cron_config",
+            debug_name: "get_cron_active_window",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1514,22 +1793,23 @@ fn wire__crate__api__cron_api__get_cron_config_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::get_cron_config())?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::get_cron_active_window())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__agent_api__get_current_config_impl(
+fn wire__crate__api__cron_api__get_cron_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_current_config",
+            debug_name: "get_cron_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1544,17 +1824,195 @@ fn wire__crate__api__agent_api__get_current_config_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::agent_api::get_current_config().await)?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::get_cron_config())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__get_cron_job_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_cron_job_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_window_hours = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::get_cron_job_stats(
+                        api_job_id,
+                        api_window_hours,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__get_cron_run_output_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_cron_run_output",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i64>::sse_decode(&mut deserializer);
+            let api_offset = <u64>::sse_decode(&mut deserializer);
+            let api_len = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::get_cron_run_output(api_run_id, api_offset, api_len),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__get_cron_scheduler_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_cron_scheduler_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::cron_api::get_cron_scheduler_status().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__get_cron_webhook_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_cron_webhook_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::get_cron_webhook_info(api_job_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__agent_api__get_current_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_current_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::agent_api::get_current_config().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
     )
 }
 fn wire__crate__api__providers_api__get_default_profile_id_impl(
@@ -1729,12 +2187,14 @@ fn wire__crate__api__knowledge_api__get_knowledge_entry_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_key = <String>::sse_decode(&mut deserializer);
+            let api_project_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::knowledge_api::get_knowledge_entry(api_key).await,
+                            crate::api::knowledge_api::get_knowledge_entry(api_key, api_project_id)
+                                .await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -1995,7 +2455,44 @@ fn wire__crate__api__project_api__get_project_impl(
         },
     )
 }
-fn wire__crate__api__project_api__get_project_pinned_context_impl(
+fn wire__crate__api__project_index_api__get_project_code_index_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_project_code_index_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::project_index_api::get_project_code_index_status(
+                            api_project_id,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__project_git_api__get_project_git_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2003,7 +2500,7 @@ fn wire__crate__api__project_api__get_project_pinned_context_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_project_pinned_context",
+            debug_name: "get_project_git_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2023,7 +2520,7 @@ fn wire__crate__api__project_api__get_project_pinned_context_impl(
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::project_api::get_project_pinned_context(api_project_id)
+                            crate::api::project_git_api::get_project_git_status(api_project_id)
                                 .await,
                         )?;
                         Ok(output_ok)
@@ -2034,7 +2531,7 @@ fn wire__crate__api__project_api__get_project_pinned_context_impl(
         },
     )
 }
-fn wire__crate__api__proxy_api__get_proxy_config_impl(
+fn wire__crate__api__project_api__get_project_pinned_context_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2042,7 +2539,7 @@ fn wire__crate__api__proxy_api__get_proxy_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_proxy_config",
+            debug_name: "get_project_pinned_context",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2056,12 +2553,15 @@ fn wire__crate__api__proxy_api__get_proxy_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::proxy_api::get_proxy_config().await)?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::project_api::get_project_pinned_context(api_project_id)
+                                .await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2070,16 +2570,17 @@ fn wire__crate__api__proxy_api__get_proxy_config_impl(
         },
     )
 }
-fn wire__crate__api__proxy_api__get_runtime_proxy_status_impl(
+fn wire__crate__api__project_template_api__get_project_templates_dir_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_runtime_proxy_status",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_project_templates_dir",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2092,15 +2593,18 @@ fn wire__crate__api__proxy_api__get_runtime_proxy_status_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::proxy_api::get_runtime_proxy_status())?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::project_template_api::get_project_templates_dir(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__agent_api__get_runtime_status_impl(
+fn wire__crate__api__project_timeline_api__get_project_timeline_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2108,7 +2612,7 @@ fn wire__crate__api__agent_api__get_runtime_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_runtime_status",
+            debug_name: "get_project_timeline",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2122,12 +2626,21 @@ fn wire__crate__api__agent_api__get_runtime_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_id = <String>::sse_decode(&mut deserializer);
+            let api_cursor = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::agent_api::get_runtime_status().await)?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::project_timeline_api::get_project_timeline(
+                                api_project_id,
+                                api_cursor,
+                                api_limit,
+                            )
+                            .await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2136,7 +2649,109 @@ fn wire__crate__api__agent_api__get_runtime_status_impl(
         },
     )
 }
-fn wire__crate__api__agents_api__get_session_active_roles_impl(
+fn wire__crate__api__proxy_api__get_proxy_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_proxy_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::proxy_api::get_proxy_config().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__proxy_api__get_runtime_proxy_status_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_runtime_proxy_status",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::proxy_api::get_runtime_proxy_status())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__agent_api__get_runtime_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_runtime_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::agent_api::get_runtime_status().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__agents_api__get_session_active_roles_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2542,6 +3157,121 @@ fn wire__crate__api__simple__greet_impl(
         },
     )
 }
+fn wire__crate__api__cron_api__import_cron_jobs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_cron_jobs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_format = <String>::sse_decode(&mut deserializer);
+            let api_dry_run = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::import_cron_jobs(
+                        api_content,
+                        api_format,
+                        api_dry_run,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__project_bundle_api__import_project_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_project",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::project_bundle_api::import_project(api_path).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__project_index_api__index_project_code_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "index_project_code",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::project_index_api::index_project_code(api_project_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__agent_workspace_api__init_agent_workspace_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -2939,7 +3669,7 @@ fn wire__crate__api__workspace_api__list_channels_impl(
         },
     )
 }
-fn wire__crate__api__cron_api__list_cron_jobs_impl(
+fn wire__crate__api__cron_api__list_cron_chain_runs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2947,7 +3677,7 @@ fn wire__crate__api__cron_api__list_cron_jobs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_cron_jobs",
+            debug_name: "list_cron_chain_runs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2961,17 +3691,20 @@ fn wire__crate__api__cron_api__list_cron_jobs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_chain_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::list_cron_jobs())?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::list_cron_chain_runs(api_chain_id),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__cron_api__list_cron_runs_impl(
+fn wire__crate__api__cron_api__list_cron_jobs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2979,7 +3712,7 @@ fn wire__crate__api__cron_api__list_cron_runs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_cron_runs",
+            debug_name: "list_cron_jobs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2993,29 +3726,25 @@ fn wire__crate__api__cron_api__list_cron_runs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_job_id = <String>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::list_cron_runs(
-                        api_job_id, api_limit,
-                    ))?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::list_cron_jobs())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__agents_api__list_delegate_agents_impl(
+fn wire__crate__api__cron_api__list_cron_jobs_for_project_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_delegate_agents",
+            debug_name: "list_cron_jobs_for_project",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3029,30 +3758,28 @@ fn wire__crate__api__agents_api__list_delegate_agents_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::agents_api::list_delegate_agents().await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::list_cron_jobs_for_project(api_project_id),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__routes_api__list_embedding_routes_impl(
+fn wire__crate__api__cron_api__list_cron_runs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_embedding_routes",
+            debug_name: "list_cron_runs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3066,16 +3793,121 @@ fn wire__crate__api__routes_api__list_embedding_routes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::routes_api::list_embedding_routes().await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::list_cron_runs(
+                        api_job_id, api_limit,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__list_cron_secrets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_cron_secrets",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::list_cron_secrets())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__agents_api__list_delegate_agents_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_delegate_agents",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::agents_api::list_delegate_agents().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__routes_api__list_embedding_routes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_embedding_routes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::routes_api::list_embedding_routes().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
                 )
             }
         },
@@ -3105,6 +3937,7 @@ fn wire__crate__api__knowledge_api__list_knowledge_entries_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_category = <Option<String>>::sse_decode(&mut deserializer);
             let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_project_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
@@ -3113,6 +3946,7 @@ fn wire__crate__api__knowledge_api__list_knowledge_entries_impl(
                             crate::api::knowledge_api::list_knowledge_entries(
                                 api_category,
                                 api_limit,
+                                api_project_id,
                             )
                             .await,
                         )?;
@@ -3233,6 +4067,40 @@ fn wire__crate__api__routes_api__list_model_routes_impl(
         },
     )
 }
+fn wire__crate__api__project_template_api__list_project_templates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_project_templates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::project_template_api::list_project_templates(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__project_api__list_projects_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -3634,7 +4502,7 @@ fn wire__crate__api__cron_api__pause_cron_job_impl(
         },
     )
 }
-fn wire__crate__api__proxy_api__proxy_config_dto_default_impl(
+fn wire__crate__api__cron_api__preview_cron_schedule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3642,7 +4510,7 @@ fn wire__crate__api__proxy_api__proxy_config_dto_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "proxy_config_dto_default",
+            debug_name: "preview_cron_schedule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3656,26 +4524,33 @@ fn wire__crate__api__proxy_api__proxy_config_dto_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_expression = <String>::sse_decode(&mut deserializer);
+            let api_timezone = <Option<String>>::sse_decode(&mut deserializer);
+            let api_count = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::proxy_api::ProxyConfigDto::default())?;
+                        Result::<_, ()>::Ok(crate::api::cron_api::preview_cron_schedule(
+                            api_expression,
+                            api_timezone,
+                            api_count,
+                        ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__agent_api__reload_config_from_disk_impl(
+fn wire__crate__api__project_api__project_autonomy_dto_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "reload_config_from_disk",
+            debug_name: "project_autonomy_dto_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3690,29 +4565,26 @@ fn wire__crate__api__agent_api__reload_config_from_disk_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::agent_api::reload_config_from_disk().await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::project_api::ProjectAutonomyDto::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__workspace_api__remove_allowed_command_impl(
+fn wire__crate__api__project_api__project_session_report_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_allowed_command",
+            debug_name: "project_session_report_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3726,23 +4598,19 @@ fn wire__crate__api__workspace_api__remove_allowed_command_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_command = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_api::remove_allowed_command(api_command).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::project_api::ProjectSessionReport::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__cron_api__remove_cron_job_impl(
+fn wire__crate__api__proxy_api__proxy_config_dto_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3750,7 +4618,7 @@ fn wire__crate__api__cron_api__remove_cron_job_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_cron_job",
+            debug_name: "proxy_config_dto_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3764,27 +4632,26 @@ fn wire__crate__api__cron_api__remove_cron_job_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_job_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::cron_api::remove_cron_job(api_job_id))?;
+                        Result::<_, ()>::Ok(crate::api::proxy_api::ProxyConfigDto::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__agents_api__remove_delegate_agent_impl(
+fn wire__crate__api__cron_api__regenerate_cron_webhook_secret_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_delegate_agent",
+            debug_name: "regenerate_cron_webhook_secret",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3798,23 +4665,20 @@ fn wire__crate__api__agents_api__remove_delegate_agent_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::agents_api::remove_delegate_agent(api_name).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::regenerate_cron_webhook_secret(api_job_id),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__routes_api__remove_embedding_route_impl(
+fn wire__crate__api__agent_api__reload_config_from_disk_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3822,7 +4686,7 @@ fn wire__crate__api__routes_api__remove_embedding_route_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_embedding_route",
+            debug_name: "reload_config_from_disk",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3836,13 +4700,12 @@ fn wire__crate__api__routes_api__remove_embedding_route_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_hint = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::routes_api::remove_embedding_route(api_hint).await,
+                            crate::api::agent_api::reload_config_from_disk().await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -3852,7 +4715,7 @@ fn wire__crate__api__routes_api__remove_embedding_route_impl(
         },
     )
 }
-fn wire__crate__api__mcp_api__remove_mcp_server_impl(
+fn wire__crate__api__workspace_api__remove_allowed_command_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3860,7 +4723,7 @@ fn wire__crate__api__mcp_api__remove_mcp_server_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_mcp_server",
+            debug_name: "remove_allowed_command",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3874,13 +4737,13 @@ fn wire__crate__api__mcp_api__remove_mcp_server_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_command = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::mcp_api::remove_mcp_server(api_name).await,
+                            crate::api::workspace_api::remove_allowed_command(api_command).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -3890,15 +4753,15 @@ fn wire__crate__api__mcp_api__remove_mcp_server_impl(
         },
     )
 }
-fn wire__crate__api__providers_api__remove_model_provider_profile_impl(
+fn wire__crate__api__cron_api__remove_cron_job_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_model_provider_profile",
+            debug_name: "remove_cron_job",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3912,23 +4775,205 @@ fn wire__crate__api__providers_api__remove_model_provider_profile_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::providers_api::remove_model_provider_profile(api_id).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::remove_cron_job(api_job_id))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__routes_api__remove_model_route_impl(
+fn wire__crate__api__cron_api__remove_cron_secret_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_cron_secret",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::remove_cron_secret(api_name))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__agents_api__remove_delegate_agent_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_delegate_agent",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::agents_api::remove_delegate_agent(api_name).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__routes_api__remove_embedding_route_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_embedding_route",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hint = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::routes_api::remove_embedding_route(api_hint).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_api__remove_mcp_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_mcp_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::mcp_api::remove_mcp_server(api_name).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__providers_api__remove_model_provider_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_model_provider_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::providers_api::remove_model_provider_profile(api_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__routes_api__remove_model_route_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4309,6 +5354,7 @@ fn wire__crate__api__agent_api__respond_to_tool_approval_by_id_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_request_id = <String>::sse_decode(&mut deserializer);
             let api_decision = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
@@ -4317,6 +5363,7 @@ fn wire__crate__api__agent_api__respond_to_tool_approval_by_id_impl(
                             crate::api::agent_api::respond_to_tool_approval_by_id(
                                 api_request_id,
                                 api_decision,
+                                api_reason,
                             )
                             .await,
                         )?;
@@ -4617,7 +5664,6 @@ fn wire__crate__api__sessions_api__save_session_impl(
             let api_title = <String>::sse_decode(&mut deserializer);
             let api_messages =
                 <Vec<crate::api::sessions_api::SessionMessage>>::sse_decode(&mut deserializer);
-            let api_project_id = <String>::sse_decode(&mut deserializer);
             let api_ephemeral = <bool>::sse_decode(&mut deserializer);
             let api_agent_binding = <String>::sse_decode(&mut deserializer);
             deserializer.end();
@@ -4629,7 +5675,6 @@ fn wire__crate__api__sessions_api__save_session_impl(
                                 api_session_id,
                                 api_title,
                                 api_messages,
-                                api_project_id,
                                 api_ephemeral,
                                 api_agent_binding,
                             )
@@ -4694,7 +5739,632 @@ fn wire__crate__api__knowledge_api__search_knowledge_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_knowledge",
+            debug_name: "search_knowledge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_project_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::knowledge_api::search_knowledge(
+                                api_query,
+                                api_limit,
+                                api_project_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__project_index_api__search_project_code_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_project_code",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_id = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::project_index_api::search_project_code(
+                                api_project_id,
+                                api_query,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__agents_api__seed_preset_roles_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "seed_preset_roles",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::agents_api::seed_preset_roles().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__agent_workspace_api__seed_preset_workspaces_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "seed_preset_workspaces",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::agent_workspace_api::seed_preset_workspaces().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__agent_api__send_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::agent_api::send_message(api_session_id, api_message).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__agent_api__send_message_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::agent_api::AgentEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::agent_api::send_message_stream(
+                                api_session_id,
+                                api_message,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_active_window_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_active_window",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_window =
+                <crate::api::cron_api::CronActiveWindowDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::set_cron_active_window(api_window),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_auto_disable_after_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_auto_disable_after",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_failures = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::set_cron_auto_disable_after(api_failures),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_job_active_window_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_job_active_window",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_window =
+                <Option<crate::api::cron_api::CronActiveWindowDto>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::set_cron_job_active_window(api_job_id, api_window),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_job_chain_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_job_chain",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_on_success = <String>::sse_decode(&mut deserializer);
+            let api_on_failure = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::set_cron_job_chain(
+                        api_job_id,
+                        api_on_success,
+                        api_on_failure,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_job_delivery_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_job_delivery",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_targets =
+                <Vec<crate::api::cron_api::CronDeliveryDto>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::set_cron_job_delivery(api_job_id, api_targets),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_job_retry_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_job_retry_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_timeout_secs = <u32>::sse_decode(&mut deserializer);
+            let api_max_retries = <u32>::sse_decode(&mut deserializer);
+            let api_retry_backoff_secs = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::set_cron_job_retry_policy(
+                            api_job_id,
+                            api_timeout_secs,
+                            api_max_retries,
+                            api_retry_backoff_secs,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_job_run_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_job_run_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_misfire_policy = <String>::sse_decode(&mut deserializer);
+            let api_no_overlap = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::set_cron_job_run_policy(
+                            api_job_id,
+                            api_misfire_policy,
+                            api_no_overlap,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_job_sandbox_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_job_sandbox",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_sandbox =
+                <crate::api::cron_api::CronShellSandboxDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::set_cron_job_sandbox(api_job_id, api_sandbox),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_job_timezone_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_job_timezone",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_timezone = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::set_cron_job_timezone(api_job_id, api_timezone),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_job_watch_debounce_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_job_watch_debounce",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <String>::sse_decode(&mut deserializer);
+            let api_debounce_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::set_cron_job_watch_debounce(
+                            api_job_id,
+                            api_debounce_ms,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__cron_api__set_cron_max_concurrency_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cron_max_concurrency",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4708,32 +6378,28 @@ fn wire__crate__api__knowledge_api__search_knowledge_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_max_concurrency = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::knowledge_api::search_knowledge(api_query, api_limit).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::set_cron_max_concurrency(api_max_concurrency),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__agents_api__seed_preset_roles_impl(
+fn wire__crate__api__cron_api__set_cron_max_run_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "seed_preset_roles",
+            debug_name: "set_cron_max_run_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4747,21 +6413,20 @@ fn wire__crate__api__agents_api__seed_preset_roles_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_run_history = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::agents_api::seed_preset_roles().await)?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::cron_api::set_cron_max_run_history(api_max_run_history),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__agent_workspace_api__seed_preset_workspaces_impl(
+fn wire__crate__api__cron_api__set_cron_scheduler_enabled_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4769,7 +6434,7 @@ fn wire__crate__api__agent_workspace_api__seed_preset_workspaces_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "seed_preset_workspaces",
+            debug_name: "set_cron_scheduler_enabled",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4783,12 +6448,13 @@ fn wire__crate__api__agent_workspace_api__seed_preset_workspaces_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::agent_workspace_api::seed_preset_workspaces().await,
+                            crate::api::cron_api::set_cron_scheduler_enabled(api_enabled).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -4798,15 +6464,15 @@ fn wire__crate__api__agent_workspace_api__seed_preset_workspaces_impl(
         },
     )
 }
-fn wire__crate__api__agent_api__send_message_impl(
+fn wire__crate__api__cron_api__set_cron_secret_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_message",
+            debug_name: "set_cron_secret",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4820,32 +6486,29 @@ fn wire__crate__api__agent_api__send_message_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_session_id = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_value = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::agent_api::send_message(api_session_id, api_message).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cron_api::set_cron_secret(
+                        api_name, api_value,
+                    ))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__agent_api__send_message_stream_impl(
+fn wire__crate__api__cron_api__set_cron_webhook_port_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_message_stream",
+            debug_name: "set_cron_webhook_port",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4859,28 +6522,14 @@ fn wire__crate__api__agent_api__send_message_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_session_id = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::agent_api::AgentEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::agent_api::send_message_stream(
-                                api_session_id,
-                                api_message,
-                                api_sink,
-                            )
-                            .await;
-                        })?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cron_api::set_cron_webhook_port(api_port))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
@@ -5235,6 +6884,42 @@ fn wire__crate__api__channel_runtime_api__stop_channel_listeners_impl(
         },
     )
 }
+fn wire__crate__api__cron_api__stop_cron_scheduler_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_cron_scheduler",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::cron_api::stop_cron_scheduler().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__cron_notification_api__subscribe_cron_notifications_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -5357,6 +7042,44 @@ fn wire__crate__api__agent_api__switch_session_impl(
         },
     )
 }
+fn wire__crate__api__cron_api__test_cron_delivery_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_cron_delivery",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target = <crate::api::cron_api::CronDeliveryDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::cron_api::test_cron_delivery(api_target).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__workspace_api__toggle_channel_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -5559,6 +7282,45 @@ fn wire__crate__api__agent_workspace_api__unbind_session_agent_impl(
         },
     )
 }
+fn wire__crate__api__project_git_api__undo_last_agent_changes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "undo_last_agent_changes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::project_git_api::undo_last_agent_changes(api_session_id)
+                                .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__workspace_api__update_agent_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -6561,6 +8323,20 @@ impl SseDecode for crate::api::agent_api::AgentEvent {
     }
 }
 
+impl SseDecode for crate::api::project_git_api::AgentUndoResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_restored = <Vec<String>>::sse_decode(deserializer);
+        let mut var_removed = <Vec<String>>::sse_decode(deserializer);
+        let mut var_errors = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::project_git_api::AgentUndoResult {
+            restored: var_restored,
+            removed: var_removed,
+            errors: var_errors,
+        };
+    }
+}
+
 impl SseDecode for crate::api::agent_workspace_api::AgentWorkspaceDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6728,6 +8504,24 @@ impl SseDecode for crate::api::agent_api::ChatSessionInfo {
     }
 }
 
+impl SseDecode for crate::api::project_index_api::CodeSearchHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_startLine = <u32>::sse_decode(deserializer);
+        let mut var_endLine = <u32>::sse_decode(deserializer);
+        let mut var_snippet = <String>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        return crate::api::project_index_api::CodeSearchHit {
+            path: var_path,
+            start_line: var_startLine,
+            end_line: var_endLine,
+            snippet: var_snippet,
+            score: var_score,
+        };
+    }
+}
+
 impl SseDecode for crate::api::copilot_api::CopilotAuthStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6788,17 +8582,37 @@ impl SseDecode for crate::api::workspace_api::CostConfigDto {
     }
 }
 
+impl SseDecode for crate::api::cron_api::CronActiveWindowDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_quietStart = <String>::sse_decode(deserializer);
+        let mut var_quietEnd = <String>::sse_decode(deserializer);
+        let mut var_activeDays = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_holidaysIcs = <String>::sse_decode(deserializer);
+        return crate::api::cron_api::CronActiveWindowDto {
+            quiet_start: var_quietStart,
+            quiet_end: var_quietEnd,
+            active_days: var_activeDays,
+            holidays_ics: var_holidaysIcs,
+        };
+    }
+}
+
 impl SseDecode for crate::api::cron_api::CronConfigDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_enabled = <bool>::sse_decode(deserializer);
         let mut var_maxRunHistory = <u32>::sse_decode(deserializer);
+        let mut var_maxConcurrency = <u32>::sse_decode(deserializer);
+        let mut var_autoDisableAfter = <u32>::sse_decode(deserializer);
         let mut var_totalJobs = <u32>::sse_decode(deserializer);
         let mut var_activeJobs = <u32>::sse_decode(deserializer);
         let mut var_pausedJobs = <u32>::sse_decode(deserializer);
         return crate::api::cron_api::CronConfigDto {
             enabled: var_enabled,
             max_run_history: var_maxRunHistory,
+            max_concurrency: var_maxConcurrency,
+            auto_disable_after: var_autoDisableAfter,
             total_jobs: var_totalJobs,
             active_jobs: var_activeJobs,
             paused_jobs: var_pausedJobs,
@@ -6806,6 +8620,83 @@ impl SseDecode for crate::api::cron_api::CronConfigDto {
     }
 }
 
+impl SseDecode for crate::api::cron_api::CronDeliveryDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_channel = <String>::sse_decode(deserializer);
+        let mut var_to = <String>::sse_decode(deserializer);
+        let mut var_onSuccess = <bool>::sse_decode(deserializer);
+        let mut var_onFailure = <bool>::sse_decode(deserializer);
+        let mut var_template = <String>::sse_decode(deserializer);
+        return crate::api::cron_api::CronDeliveryDto {
+            channel: var_channel,
+            to: var_to,
+            on_success: var_onSuccess,
+            on_failure: var_onFailure,
+            template: var_template,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronEnvVarDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        let mut var_secret = <bool>::sse_decode(deserializer);
+        return crate::api::cron_api::CronEnvVarDto {
+            name: var_name,
+            value: var_value,
+            secret: var_secret,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronExecutingJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_jobId = <String>::sse_decode(deserializer);
+        let mut var_jobName = <String>::sse_decode(deserializer);
+        let mut var_startedAt = <i64>::sse_decode(deserializer);
+        let mut var_runs = <u32>::sse_decode(deserializer);
+        return crate::api::cron_api::CronExecutingJob {
+            job_id: var_jobId,
+            job_name: var_jobName,
+            started_at: var_startedAt,
+            runs: var_runs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronImportChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_action = <String>::sse_decode(deserializer);
+        let mut var_changedFields = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::cron_api::CronImportChange {
+            name: var_name,
+            action: var_action,
+            changed_fields: var_changedFields,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronImportResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_errors = <Vec<String>>::sse_decode(deserializer);
+        let mut var_applied = <bool>::sse_decode(deserializer);
+        let mut var_changes =
+            <Vec<crate::api::cron_api::CronImportChange>>::sse_decode(deserializer);
+        return crate::api::cron_api::CronImportResult {
+            errors: var_errors,
+            applied: var_applied,
+            changes: var_changes,
+        };
+    }
+}
+
 impl SseDecode for crate::api::cron_api::CronJobDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6814,6 +8705,7 @@ impl SseDecode for crate::api::cron_api::CronJobDto {
         let mut var_expression = <String>::sse_decode(deserializer);
         let mut var_scheduleType = <String>::sse_decode(deserializer);
         let mut var_scheduleDisplay = <String>::sse_decode(deserializer);
+        let mut var_timezone = <String>::sse_decode(deserializer);
         let mut var_command = <String>::sse_decode(deserializer);
         let mut var_prompt = <String>::sse_decode(deserializer);
         let mut var_jobType = <String>::sse_decode(deserializer);
@@ -6828,12 +8720,25 @@ impl SseDecode for crate::api::cron_api::CronJobDto {
         let mut var_lastRun = <Option<i64>>::sse_decode(deserializer);
         let mut var_lastStatus = <String>::sse_decode(deserializer);
         let mut var_lastOutput = <String>::sse_decode(deserializer);
+        let mut var_misfirePolicy = <String>::sse_decode(deserializer);
+        let mut var_noOverlap = <bool>::sse_decode(deserializer);
+        let mut var_timeoutSecs = <u32>::sse_decode(deserializer);
+        let mut var_maxRetries = <u32>::sse_decode(deserializer);
+        let mut var_retryBackoffSecs = <u32>::sse_decode(deserializer);
+        let mut var_onSuccess = <String>::sse_decode(deserializer);
+        let mut var_onFailure = <String>::sse_decode(deserializer);
+        let mut var_delivery =
+            <Vec<crate::api::cron_api::CronDeliveryDto>>::sse_decode(deserializer);
+        let mut var_activeWindow =
+            <Option<crate::api::cron_api::CronActiveWindowDto>>::sse_decode(deserializer);
+        let mut var_sandbox = <crate::api::cron_api::CronShellSandboxDto>::sse_decode(deserializer);
         return crate::api::cron_api::CronJobDto {
             id: var_id,
             name: var_name,
             expression: var_expression,
             schedule_type: var_scheduleType,
             schedule_display: var_scheduleDisplay,
+            timezone: var_timezone,
             command: var_command,
             prompt: var_prompt,
             job_type: var_jobType,
@@ -6848,6 +8753,42 @@ impl SseDecode for crate::api::cron_api::CronJobDto {
             last_run: var_lastRun,
             last_status: var_lastStatus,
             last_output: var_lastOutput,
+            misfire_policy: var_misfirePolicy,
+            no_overlap: var_noOverlap,
+            timeout_secs: var_timeoutSecs,
+            max_retries: var_maxRetries,
+            retry_backoff_secs: var_retryBackoffSecs,
+            on_success: var_onSuccess,
+            on_failure: var_onFailure,
+            delivery: var_delivery,
+            active_window: var_activeWindow,
+            sandbox: var_sandbox,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronJobStatsDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_jobId = <String>::sse_decode(deserializer);
+        let mut var_totalRuns = <u32>::sse_decode(deserializer);
+        let mut var_succeeded = <u32>::sse_decode(deserializer);
+        let mut var_failed = <u32>::sse_decode(deserializer);
+        let mut var_successRate = <f64>::sse_decode(deserializer);
+        let mut var_p50DurationMs = <i64>::sse_decode(deserializer);
+        let mut var_p95DurationMs = <i64>::sse_decode(deserializer);
+        let mut var_consecutiveFailures = <u32>::sse_decode(deserializer);
+        let mut var_lastSuccess = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::cron_api::CronJobStatsDto {
+            job_id: var_jobId,
+            total_runs: var_totalRuns,
+            succeeded: var_succeeded,
+            failed: var_failed,
+            success_rate: var_successRate,
+            p50_duration_ms: var_p50DurationMs,
+            p95_duration_ms: var_p95DurationMs,
+            consecutive_failures: var_consecutiveFailures,
+            last_success: var_lastSuccess,
         };
     }
 }
@@ -6865,6 +8806,7 @@ impl SseDecode for crate::api::cron_notification_api::CronNotification {
         let mut var_prompt = <String>::sse_decode(deserializer);
         let mut var_durationMs = <i64>::sse_decode(deserializer);
         let mut var_finishedAt = <i64>::sse_decode(deserializer);
+        let mut var_autoDisabled = <bool>::sse_decode(deserializer);
         return crate::api::cron_notification_api::CronNotification {
             job_id: var_jobId,
             job_name: var_jobName,
@@ -6876,6 +8818,7 @@ impl SseDecode for crate::api::cron_notification_api::CronNotification {
             prompt: var_prompt,
             duration_ms: var_durationMs,
             finished_at: var_finishedAt,
+            auto_disabled: var_autoDisabled,
         };
     }
 }
@@ -6890,6 +8833,13 @@ impl SseDecode for crate::api::cron_api::CronRunDto {
         let mut var_status = <String>::sse_decode(deserializer);
         let mut var_output = <String>::sse_decode(deserializer);
         let mut var_durationMs = <i64>::sse_decode(deserializer);
+        let mut var_attempt = <u32>::sse_decode(deserializer);
+        let mut var_exitCode = <Option<i32>>::sse_decode(deserializer);
+        let mut var_stdoutLen = <u64>::sse_decode(deserializer);
+        let mut var_stderrLen = <u64>::sse_decode(deserializer);
+        let mut var_stdoutTruncated = <bool>::sse_decode(deserializer);
+        let mut var_stderrTruncated = <bool>::sse_decode(deserializer);
+        let mut var_chainId = <String>::sse_decode(deserializer);
         return crate::api::cron_api::CronRunDto {
             id: var_id,
             job_id: var_jobId,
@@ -6898,6 +8848,96 @@ impl SseDecode for crate::api::cron_api::CronRunDto {
             status: var_status,
             output: var_output,
             duration_ms: var_durationMs,
+            attempt: var_attempt,
+            exit_code: var_exitCode,
+            stdout_len: var_stdoutLen,
+            stderr_len: var_stderrLen,
+            stdout_truncated: var_stdoutTruncated,
+            stderr_truncated: var_stderrTruncated,
+            chain_id: var_chainId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronRunOutput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i64>::sse_decode(deserializer);
+        let mut var_stdout = <String>::sse_decode(deserializer);
+        let mut var_stderr = <String>::sse_decode(deserializer);
+        let mut var_stdoutLen = <u64>::sse_decode(deserializer);
+        let mut var_stderrLen = <u64>::sse_decode(deserializer);
+        let mut var_stdoutTruncated = <bool>::sse_decode(deserializer);
+        let mut var_stderrTruncated = <bool>::sse_decode(deserializer);
+        let mut var_exitCode = <Option<i32>>::sse_decode(deserializer);
+        return crate::api::cron_api::CronRunOutput {
+            run_id: var_runId,
+            stdout: var_stdout,
+            stderr: var_stderr,
+            stdout_len: var_stdoutLen,
+            stderr_len: var_stderrLen,
+            stdout_truncated: var_stdoutTruncated,
+            stderr_truncated: var_stderrTruncated,
+            exit_code: var_exitCode,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronSchedulePreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_error = <String>::sse_decode(deserializer);
+        let mut var_fireTimes = <Vec<i64>>::sse_decode(deserializer);
+        return crate::api::cron_api::CronSchedulePreview {
+            error: var_error,
+            fire_times: var_fireTimes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronSchedulerStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_running = <bool>::sse_decode(deserializer);
+        let mut var_lastTick = <Option<i64>>::sse_decode(deserializer);
+        let mut var_executing =
+            <Vec<crate::api::cron_api::CronExecutingJob>>::sse_decode(deserializer);
+        return crate::api::cron_api::CronSchedulerStatus {
+            enabled: var_enabled,
+            running: var_running,
+            last_tick: var_lastTick,
+            executing: var_executing,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronShellSandboxDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_workingDir = <String>::sse_decode(deserializer);
+        let mut var_inheritEnv = <bool>::sse_decode(deserializer);
+        let mut var_env = <Vec<crate::api::cron_api::CronEnvVarDto>>::sse_decode(deserializer);
+        let mut var_cpuLimitSecs = <u64>::sse_decode(deserializer);
+        let mut var_memoryLimitMb = <u64>::sse_decode(deserializer);
+        return crate::api::cron_api::CronShellSandboxDto {
+            working_dir: var_workingDir,
+            inherit_env: var_inheritEnv,
+            env: var_env,
+            cpu_limit_secs: var_cpuLimitSecs,
+            memory_limit_mb: var_memoryLimitMb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::cron_api::CronWebhookInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_secret = <String>::sse_decode(deserializer);
+        return crate::api::cron_api::CronWebhookInfo {
+            url: var_url,
+            secret: var_secret,
         };
     }
 }
@@ -7017,6 +9057,22 @@ impl SseDecode for crate::api::workspace_api::FeatureToggles {
     }
 }
 
+impl SseDecode for crate::api::project_git_api::GitCommitDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hash = <String>::sse_decode(deserializer);
+        let mut var_summary = <String>::sse_decode(deserializer);
+        let mut var_author = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        return crate::api::project_git_api::GitCommitDto {
+            hash: var_hash,
+            summary: var_summary,
+            author: var_author,
+            timestamp: var_timestamp,
+        };
+    }
+}
+
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7148,6 +9204,76 @@ impl SseDecode for Vec<crate::api::workspace_api::ChannelSummary> {
     }
 }
 
+impl SseDecode for Vec<crate::api::project_index_api::CodeSearchHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::project_index_api::CodeSearchHit>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cron_api::CronDeliveryDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cron_api::CronDeliveryDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cron_api::CronEnvVarDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cron_api::CronEnvVarDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cron_api::CronExecutingJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cron_api::CronExecutingJob>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::cron_api::CronImportChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::cron_api::CronImportChange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::cron_api::CronJobDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7200,6 +9326,20 @@ impl SseDecode for Vec<crate::api::routes_api::EmbeddingRouteDto> {
     }
 }
 
+impl SseDecode for Vec<crate::api::project_git_api::GitCommitDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::project_git_api::GitCommitDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::mcp_api::KeyValueDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7308,6 +9448,18 @@ impl SseDecode for Vec<crate::api::routes_api::ModelRouteDto> {
     }
 }
 
+impl SseDecode for Vec<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7334,6 +9486,34 @@ impl SseDecode for Vec<crate::api::project_api::ProjectSummary> {
     }
 }
 
+impl SseDecode for Vec<crate::api::project_template_api::ProjectTemplateDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::project_template_api::ProjectTemplateDto>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::project_timeline_api::ProjectTimelineEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::project_timeline_api::ProjectTimelineEvent>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::config_api::ProviderInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7661,6 +9841,58 @@ impl SseDecode for Option<bool> {
     }
 }
 
+impl SseDecode for Option<crate::api::cron_api::CronActiveWindowDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cron_api::CronActiveWindowDto>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cron_api::CronJobStatsDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cron_api::CronJobStatsDto>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cron_api::CronRunOutput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cron_api::CronRunOutput>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::cron_api::CronWebhookInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::cron_api::CronWebhookInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::agents_api::DelegateAgentDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7685,6 +9917,17 @@ impl SseDecode for Option<f64> {
     }
 }
 
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7735,6 +9978,19 @@ impl SseDecode for Option<crate::api::project_api::ProjectDto> {
     }
 }
 
+impl SseDecode for Option<crate::api::project_git_api::ProjectGitStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::project_git_api::ProjectGitStatus>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::sessions_api::SessionDetail> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7770,6 +10026,48 @@ impl SseDecode for Option<u64> {
     }
 }
 
+impl SseDecode for crate::api::project_api::ProjectAutonomyDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_level = <String>::sse_decode(deserializer);
+        let mut var_trustMe = <Option<bool>>::sse_decode(deserializer);
+        let mut var_allowedCommands = <Vec<String>>::sse_decode(deserializer);
+        let mut var_forbiddenPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_autoApprove = <Vec<String>>::sse_decode(deserializer);
+        let mut var_alwaysAsk = <Vec<String>>::sse_decode(deserializer);
+        let mut var_enabledTools = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::project_api::ProjectAutonomyDto {
+            level: var_level,
+            trust_me: var_trustMe,
+            allowed_commands: var_allowedCommands,
+            forbidden_paths: var_forbiddenPaths,
+            auto_approve: var_autoApprove,
+            always_ask: var_alwaysAsk,
+            enabled_tools: var_enabledTools,
+        };
+    }
+}
+
+impl SseDecode for crate::api::project_index_api::ProjectCodeIndexStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_indexing = <bool>::sse_decode(deserializer);
+        let mut var_files = <u32>::sse_decode(deserializer);
+        let mut var_chunks = <u32>::sse_decode(deserializer);
+        let mut var_embeddedChunks = <u32>::sse_decode(deserializer);
+        let mut var_lastIndexedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_lastError = <String>::sse_decode(deserializer);
+        return crate::api::project_index_api::ProjectCodeIndexStatus {
+            indexing: var_indexing,
+            files: var_files,
+            chunks: var_chunks,
+            embedded_chunks: var_embeddedChunks,
+            last_indexed_at: var_lastIndexedAt,
+            last_error: var_lastError,
+        };
+    }
+}
+
 impl SseDecode for crate::api::project_api::ProjectDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7786,6 +10084,12 @@ impl SseDecode for crate::api::project_api::ProjectDto {
         let mut var_defaultRoleId = <String>::sse_decode(deserializer);
         let mut var_sessionIds = <Vec<String>>::sse_decode(deserializer);
         let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_globalMemoryFallback = <bool>::sse_decode(deserializer);
+        let mut var_autonomy =
+            <crate::api::project_api::ProjectAutonomyDto>::sse_decode(deserializer);
+        let mut var_modelProfileId = <String>::sse_decode(deserializer);
+        let mut var_model = <String>::sse_decode(deserializer);
+        let mut var_temperature = <Option<f64>>::sse_decode(deserializer);
         let mut var_createdAt = <i64>::sse_decode(deserializer);
         let mut var_updatedAt = <i64>::sse_decode(deserializer);
         return crate::api::project_api::ProjectDto {
@@ -7802,12 +10106,64 @@ impl SseDecode for crate::api::project_api::ProjectDto {
             default_role_id: var_defaultRoleId,
             session_ids: var_sessionIds,
             tags: var_tags,
+            global_memory_fallback: var_globalMemoryFallback,
+            autonomy: var_autonomy,
+            model_profile_id: var_modelProfileId,
+            model: var_model,
+            temperature: var_temperature,
             created_at: var_createdAt,
             updated_at: var_updatedAt,
         };
     }
 }
 
+impl SseDecode for crate::api::project_git_api::ProjectGitStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_branch = <String>::sse_decode(deserializer);
+        let mut var_dirty = <bool>::sse_decode(deserializer);
+        let mut var_changedFiles = <u32>::sse_decode(deserializer);
+        let mut var_recentCommits =
+            <Vec<crate::api::project_git_api::GitCommitDto>>::sse_decode(deserializer);
+        return crate::api::project_git_api::ProjectGitStatus {
+            branch: var_branch,
+            dirty: var_dirty,
+            changed_files: var_changedFiles,
+            recent_commits: var_recentCommits,
+        };
+    }
+}
+
+impl SseDecode for crate::api::project_bundle_api::ProjectImportResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_projectId = <String>::sse_decode(deserializer);
+        let mut var_errors = <Vec<String>>::sse_decode(deserializer);
+        let mut var_notes = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::project_bundle_api::ProjectImportResult {
+            project_id: var_projectId,
+            errors: var_errors,
+            notes: var_notes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::project_api::ProjectSessionReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_migratedSessions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_missingSessions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_duplicateSessions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_repaired = <bool>::sse_decode(deserializer);
+        return crate::api::project_api::ProjectSessionReport {
+            migrated_sessions: var_migratedSessions,
+            missing_sessions: var_missingSessions,
+            duplicate_sessions: var_duplicateSessions,
+            repaired: var_repaired,
+        };
+    }
+}
+
 impl SseDecode for crate::api::project_api::ProjectStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7858,6 +10214,69 @@ impl SseDecode for crate::api::project_api::ProjectSummary {
     }
 }
 
+impl SseDecode for crate::api::project_template_api::ProjectTemplateDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_icon = <String>::sse_decode(deserializer);
+        let mut var_colorTag = <String>::sse_decode(deserializer);
+        let mut var_projectType = <String>::sse_decode(deserializer);
+        let mut var_isBuiltin = <bool>::sse_decode(deserializer);
+        let mut var_roleIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_cronJobCount = <u32>::sse_decode(deserializer);
+        let mut var_knowledgeCount = <u32>::sse_decode(deserializer);
+        let mut var_fileCount = <u32>::sse_decode(deserializer);
+        return crate::api::project_template_api::ProjectTemplateDto {
+            id: var_id,
+            name: var_name,
+            description: var_description,
+            icon: var_icon,
+            color_tag: var_colorTag,
+            project_type: var_projectType,
+            is_builtin: var_isBuiltin,
+            role_ids: var_roleIds,
+            cron_job_count: var_cronJobCount,
+            knowledge_count: var_knowledgeCount,
+            file_count: var_fileCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::project_timeline_api::ProjectTimelineEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_detail = <String>::sse_decode(deserializer);
+        let mut var_refId = <String>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        return crate::api::project_timeline_api::ProjectTimelineEvent {
+            timestamp: var_timestamp,
+            kind: var_kind,
+            title: var_title,
+            detail: var_detail,
+            ref_id: var_refId,
+            status: var_status,
+        };
+    }
+}
+
+impl SseDecode for crate::api::project_timeline_api::ProjectTimelinePage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_events =
+            <Vec<crate::api::project_timeline_api::ProjectTimelineEvent>>::sse_decode(deserializer);
+        let mut var_nextCursor = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::project_timeline_api::ProjectTimelinePage {
+            events: var_events,
+            next_cursor: var_nextCursor,
+        };
+    }
+}
+
 impl SseDecode for crate::api::project_api::ProjectType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -8171,6 +10590,13 @@ impl SseDecode for crate::api::agent_api::ToolSpecDto {
     }
 }
 
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -8269,620 +10695,862 @@ fn pde_ffi_dispatcher_primary_impl(
         12 => {
             wire__crate__api__agent_api__cancel_generation_impl(port, ptr, rust_vec_len, data_len)
         }
-        13 => wire__crate__api__sessions_api__clear_all_sessions_impl(
+        13 => wire__crate__api__project_api__check_project_sessions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__sessions_api__clear_all_sessions_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        14 => wire__crate__api__llm_debug_api__clear_llm_debug_entries_impl(
+        15 => wire__crate__api__llm_debug_api__clear_llm_debug_entries_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        15 => wire__crate__api__agent_api__clear_session_impl(port, ptr, rust_vec_len, data_len),
-        16 => {
+        16 => wire__crate__api__agent_api__clear_session_impl(port, ptr, rust_vec_len, data_len),
+        17 => {
             wire__crate__api__agent_api__clear_session_agent_impl(port, ptr, rust_vec_len, data_len)
         }
-        17 => wire__crate__api__sessions_api__clear_session_files_impl(
+        18 => wire__crate__api__sessions_api__clear_session_files_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__copilot_api__copilot_check_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__copilot_api__copilot_logout_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__copilot_api__copilot_poll_authorization_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        19 => wire__crate__api__copilot_api__copilot_check_status_impl(
+        23 => wire__crate__api__copilot_api__copilot_start_device_flow_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        20 => wire__crate__api__copilot_api__copilot_logout_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__copilot_api__copilot_poll_authorization_impl(
+        24 => wire__crate__api__agent_api__copy_file_to_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__project_template_api__create_project_from_template_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        22 => wire__crate__api__copilot_api__copilot_start_device_flow_impl(
+        27 => wire__crate__api__cron_api__cron_active_window_dto_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        23 => wire__crate__api__agent_api__copy_file_to_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__agent_workspace_api__delete_agent_workspace_impl(
+        28 => wire__crate__api__cron_api__cron_env_var_dto_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        27 => wire__crate__api__knowledge_api__delete_knowledge_entry_impl(
+        29 => wire__crate__api__cron_api__cron_shell_sandbox_dto_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        28 => wire__crate__api__project_api__delete_project_impl(port, ptr, rust_vec_len, data_len),
-        29 => {
+        31 => wire__crate__api__agent_workspace_api__delete_agent_workspace_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__knowledge_api__delete_knowledge_entry_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__project_api__delete_project_impl(port, ptr, rust_vec_len, data_len),
+        34 => {
             wire__crate__api__sessions_api__delete_session_impl(port, ptr, rust_vec_len, data_len)
         }
-        30 => wire__crate__api__workspace_api__feature_toggles_default_impl(
+        35 => wire__crate__api__cron_api__export_cron_jobs_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__project_bundle_api__export_project_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__workspace_api__feature_toggles_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        31 => wire__crate__api__browser_bootstrap__find_agent_browser_impl(
+        38 => wire__crate__api__browser_bootstrap__find_agent_browser_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        32 => wire__crate__api__channel_runtime_api__get_active_channel_types_impl(
+        39 => wire__crate__api__channel_runtime_api__get_active_channel_types_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        33 => wire__crate__api__workspace_api__get_agent_config_impl(
+        40 => wire__crate__api__workspace_api__get_agent_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        34 => wire__crate__api__agent_workspace_api__get_agent_workspace_impl(
+        41 => wire__crate__api__agent_workspace_api__get_agent_workspace_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        35 => wire__crate__api__agent_workspace_api__get_agent_workspace_dir_impl(
+        42 => wire__crate__api__agent_workspace_api__get_agent_workspace_dir_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        36 => wire__crate__api__workspace_api__get_autonomy_config_impl(
+        43 => wire__crate__api__workspace_api__get_autonomy_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        37 => wire__crate__api__workspace_api__get_channel_config_impl(
+        44 => wire__crate__api__workspace_api__get_channel_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        38 => {
+        45 => {
             wire__crate__api__workspace_api__get_cost_config_impl(port, ptr, rust_vec_len, data_len)
         }
-        39 => wire__crate__api__cron_api__get_cron_config_impl(port, ptr, rust_vec_len, data_len),
-        40 => {
+        46 => wire__crate__api__cron_api__get_cron_active_window_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        47 => wire__crate__api__cron_api__get_cron_config_impl(port, ptr, rust_vec_len, data_len),
+        48 => {
+            wire__crate__api__cron_api__get_cron_job_stats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        49 => {
+            wire__crate__api__cron_api__get_cron_run_output_impl(port, ptr, rust_vec_len, data_len)
+        }
+        50 => wire__crate__api__cron_api__get_cron_scheduler_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        51 => wire__crate__api__cron_api__get_cron_webhook_info_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        52 => {
             wire__crate__api__agent_api__get_current_config_impl(port, ptr, rust_vec_len, data_len)
         }
-        41 => wire__crate__api__providers_api__get_default_profile_id_impl(
+        53 => wire__crate__api__providers_api__get_default_profile_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        42 => {
+        54 => {
             wire__crate__api__agents_api__get_delegate_agent_impl(port, ptr, rust_vec_len, data_len)
         }
-        43 => wire__crate__api__routes_api__get_embedding_config_impl(
+        55 => wire__crate__api__routes_api__get_embedding_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__workspace_api__get_feature_toggles_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__knowledge_api__get_knowledge_entry_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => wire__crate__api__knowledge_api__get_knowledge_stats_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        59 => wire__crate__api__llm_debug_api__get_llm_debug_entries_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        44 => wire__crate__api__workspace_api__get_feature_toggles_impl(
+        60 => wire__crate__api__llm_debug_api__get_llm_debug_entry_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        45 => wire__crate__api__knowledge_api__get_knowledge_entry_impl(
+        62 => wire__crate__api__mcp_api__get_mcp_config_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__workspace_api__get_memory_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        46 => wire__crate__api__knowledge_api__get_knowledge_stats_impl(
+        64 => wire__crate__api__project_api__get_project_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__project_index_api__get_project_code_index_status_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        47 => wire__crate__api__llm_debug_api__get_llm_debug_entries_impl(
+        66 => wire__crate__api__project_git_api__get_project_git_status_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => wire__crate__api__llm_debug_api__get_llm_debug_entry_impl(
+        67 => wire__crate__api__project_api__get_project_pinned_context_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        50 => wire__crate__api__mcp_api__get_mcp_config_impl(port, ptr, rust_vec_len, data_len),
-        51 => wire__crate__api__workspace_api__get_memory_config_impl(
+        68 => wire__crate__api__project_template_api__get_project_templates_dir_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        52 => wire__crate__api__project_api__get_project_impl(port, ptr, rust_vec_len, data_len),
-        53 => wire__crate__api__project_api__get_project_pinned_context_impl(
+        69 => wire__crate__api__project_timeline_api__get_project_timeline_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        54 => wire__crate__api__proxy_api__get_proxy_config_impl(port, ptr, rust_vec_len, data_len),
-        56 => {
+        70 => wire__crate__api__proxy_api__get_proxy_config_impl(port, ptr, rust_vec_len, data_len),
+        72 => {
             wire__crate__api__agent_api__get_runtime_status_impl(port, ptr, rust_vec_len, data_len)
         }
-        57 => wire__crate__api__agents_api__get_session_active_roles_impl(
+        73 => wire__crate__api__agents_api__get_session_active_roles_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        58 => wire__crate__api__agent_workspace_api__get_session_agent_binding_impl(
+        74 => wire__crate__api__agent_workspace_api__get_session_agent_binding_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        59 => wire__crate__api__sessions_api__get_session_detail_impl(
+        75 => wire__crate__api__sessions_api__get_session_detail_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        60 => wire__crate__api__sessions_api__get_session_files_impl(
+        76 => wire__crate__api__sessions_api__get_session_files_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        61 => wire__crate__api__project_api__get_session_project_impl(
+        77 => wire__crate__api__project_api__get_session_project_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        62 => wire__crate__api__sessions_api__get_session_stats_impl(
+        78 => wire__crate__api__sessions_api__get_session_stats_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        63 => wire__crate__api__agent_api__get_session_workspace_dir_impl(
+        79 => wire__crate__api__agent_api__get_session_workspace_dir_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        64 => {
+        80 => {
             wire__crate__api__skills_api__get_skills_config_impl(port, ptr, rust_vec_len, data_len)
         }
-        65 => {
+        81 => {
             wire__crate__api__workspace_api__get_tool_config_impl(port, ptr, rust_vec_len, data_len)
         }
-        66 => wire__crate__api__workspace_api__get_workspace_config_impl(
+        82 => wire__crate__api__workspace_api__get_workspace_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        84 => wire__crate__api__cron_api__import_cron_jobs_impl(port, ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__project_bundle_api__import_project_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        86 => wire__crate__api__project_index_api__index_project_code_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        68 => wire__crate__api__agent_workspace_api__init_agent_workspace_store_impl(
+        87 => wire__crate__api__agent_workspace_api__init_agent_workspace_store_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        69 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        70 => wire__crate__api__project_api__init_project_store_impl(
+        88 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__project_api__init_project_store_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        71 => wire__crate__api__agent_api__init_runtime_impl(port, ptr, rust_vec_len, data_len),
-        72 => wire__crate__api__sessions_api__init_session_store_impl(
+        90 => wire__crate__api__agent_api__init_runtime_impl(port, ptr, rust_vec_len, data_len),
+        91 => wire__crate__api__sessions_api__init_session_store_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        73 => wire__crate__api__skills_api__install_skill_impl(port, ptr, rust_vec_len, data_len),
-        74 => wire__crate__api__channel_runtime_api__is_channel_listeners_running_impl(
+        92 => wire__crate__api__skills_api__install_skill_impl(port, ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__channel_runtime_api__is_channel_listeners_running_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        76 => wire__crate__api__agents_api__is_session_multi_agent_impl(
+        95 => wire__crate__api__agents_api__is_session_multi_agent_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        77 => wire__crate__api__agent_workspace_api__list_agent_workspaces_impl(
+        96 => wire__crate__api__agent_workspace_api__list_agent_workspaces_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        78 => {
+        97 => {
             wire__crate__api__workspace_api__list_channels_impl(port, ptr, rust_vec_len, data_len)
         }
-        79 => wire__crate__api__cron_api__list_cron_jobs_impl(port, ptr, rust_vec_len, data_len),
-        80 => wire__crate__api__cron_api__list_cron_runs_impl(port, ptr, rust_vec_len, data_len),
-        81 => wire__crate__api__agents_api__list_delegate_agents_impl(
+        98 => {
+            wire__crate__api__cron_api__list_cron_chain_runs_impl(port, ptr, rust_vec_len, data_len)
+        }
+        99 => wire__crate__api__cron_api__list_cron_jobs_impl(port, ptr, rust_vec_len, data_len),
+        100 => wire__crate__api__cron_api__list_cron_jobs_for_project_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        101 => wire__crate__api__cron_api__list_cron_runs_impl(port, ptr, rust_vec_len, data_len),
+        102 => {
+            wire__crate__api__cron_api__list_cron_secrets_impl(port, ptr, rust_vec_len, data_len)
+        }
+        103 => wire__crate__api__agents_api__list_delegate_agents_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        82 => wire__crate__api__routes_api__list_embedding_routes_impl(
+        104 => wire__crate__api__routes_api__list_embedding_routes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        83 => wire__crate__api__knowledge_api__list_knowledge_entries_impl(
+        105 => wire__crate__api__knowledge_api__list_knowledge_entries_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        84 => wire__crate__api__mcp_api__list_mcp_servers_impl(port, ptr, rust_vec_len, data_len),
-        85 => wire__crate__api__providers_api__list_model_provider_profiles_impl(
+        106 => wire__crate__api__mcp_api__list_mcp_servers_impl(port, ptr, rust_vec_len, data_len),
+        107 => wire__crate__api__providers_api__list_model_provider_profiles_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        86 => {
+        108 => {
             wire__crate__api__routes_api__list_model_routes_impl(port, ptr, rust_vec_len, data_len)
         }
-        87 => wire__crate__api__project_api__list_projects_impl(port, ptr, rust_vec_len, data_len),
-        90 => wire__crate__api__agent_api__list_session_workspace_files_impl(
+        109 => wire__crate__api__project_template_api__list_project_templates_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__crate__api__project_api__list_projects_impl(port, ptr, rust_vec_len, data_len),
+        113 => wire__crate__api__agent_api__list_session_workspace_files_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        114 => {
+            wire__crate__api__sessions_api__list_sessions_impl(port, ptr, rust_vec_len, data_len)
+        }
+        115 => wire__crate__api__skills_api__list_skills_impl(port, ptr, rust_vec_len, data_len),
+        117 => wire__crate__api__workspace_api__list_tools_with_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        118 => wire__crate__api__config_api__load_config_impl(port, ptr, rust_vec_len, data_len),
+        120 => wire__crate__api__agent_api__open_in_system_impl(port, ptr, rust_vec_len, data_len),
+        121 => wire__crate__api__cron_api__pause_cron_job_impl(port, ptr, rust_vec_len, data_len),
+        122 => wire__crate__api__cron_api__preview_cron_schedule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        123 => wire__crate__api__project_api__project_autonomy_dto_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        124 => wire__crate__api__project_api__project_session_report_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        91 => wire__crate__api__sessions_api__list_sessions_impl(port, ptr, rust_vec_len, data_len),
-        92 => wire__crate__api__skills_api__list_skills_impl(port, ptr, rust_vec_len, data_len),
-        94 => wire__crate__api__workspace_api__list_tools_with_status_impl(
+        125 => wire__crate__api__proxy_api__proxy_config_dto_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        95 => wire__crate__api__config_api__load_config_impl(port, ptr, rust_vec_len, data_len),
-        97 => wire__crate__api__agent_api__open_in_system_impl(port, ptr, rust_vec_len, data_len),
-        98 => wire__crate__api__cron_api__pause_cron_job_impl(port, ptr, rust_vec_len, data_len),
-        99 => wire__crate__api__proxy_api__proxy_config_dto_default_impl(
+        126 => wire__crate__api__cron_api__regenerate_cron_webhook_secret_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        100 => wire__crate__api__agent_api__reload_config_from_disk_impl(
+        127 => wire__crate__api__agent_api__reload_config_from_disk_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        101 => wire__crate__api__workspace_api__remove_allowed_command_impl(
+        128 => wire__crate__api__workspace_api__remove_allowed_command_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        102 => wire__crate__api__cron_api__remove_cron_job_impl(port, ptr, rust_vec_len, data_len),
-        103 => wire__crate__api__agents_api__remove_delegate_agent_impl(
+        129 => wire__crate__api__cron_api__remove_cron_job_impl(port, ptr, rust_vec_len, data_len),
+        130 => {
+            wire__crate__api__cron_api__remove_cron_secret_impl(port, ptr, rust_vec_len, data_len)
+        }
+        131 => wire__crate__api__agents_api__remove_delegate_agent_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        104 => wire__crate__api__routes_api__remove_embedding_route_impl(
+        132 => wire__crate__api__routes_api__remove_embedding_route_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        105 => wire__crate__api__mcp_api__remove_mcp_server_impl(port, ptr, rust_vec_len, data_len),
-        106 => wire__crate__api__providers_api__remove_model_provider_profile_impl(
+        133 => wire__crate__api__mcp_api__remove_mcp_server_impl(port, ptr, rust_vec_len, data_len),
+        134 => wire__crate__api__providers_api__remove_model_provider_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        107 => {
+        135 => {
             wire__crate__api__routes_api__remove_model_route_impl(port, ptr, rust_vec_len, data_len)
         }
-        108 => wire__crate__api__project_api__remove_role_from_project_impl(
+        136 => wire__crate__api__project_api__remove_role_from_project_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        109 => wire__crate__api__agent_api__remove_session_agent_impl(
+        137 => wire__crate__api__agent_api__remove_session_agent_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        110 => wire__crate__api__sessions_api__remove_session_file_impl(
+        138 => wire__crate__api__sessions_api__remove_session_file_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        111 => wire__crate__api__project_api__remove_session_from_project_impl(
+        139 => wire__crate__api__project_api__remove_session_from_project_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        112 => wire__crate__api__skills_api__remove_skill_impl(port, ptr, rust_vec_len, data_len),
-        113 => {
+        140 => wire__crate__api__skills_api__remove_skill_impl(port, ptr, rust_vec_len, data_len),
+        141 => {
             wire__crate__api__sessions_api__rename_session_impl(port, ptr, rust_vec_len, data_len)
         }
-        114 => {
+        142 => {
             wire__crate__api__proxy_api__reset_proxy_config_impl(port, ptr, rust_vec_len, data_len)
         }
-        115 => wire__crate__api__agent_api__respond_to_tool_approval_impl(
+        143 => wire__crate__api__agent_api__respond_to_tool_approval_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        144 => wire__crate__api__agent_api__respond_to_tool_approval_by_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        145 => wire__crate__api__channel_runtime_api__restart_channel_listeners_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        146 => wire__crate__api__cron_api__resume_cron_job_impl(port, ptr, rust_vec_len, data_len),
+        147 => wire__crate__api__cron_api__run_cron_job_now_impl(port, ptr, rust_vec_len, data_len),
+        148 => wire__crate__api__workspace_api__save_channel_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        149 => wire__crate__api__config_api__save_config_impl(port, ptr, rust_vec_len, data_len),
+        150 => {
+            wire__crate__api__agent_api__save_config_to_disk_impl(port, ptr, rust_vec_len, data_len)
+        }
+        151 => {
+            wire__crate__api__proxy_api__save_proxy_to_disk_impl(port, ptr, rust_vec_len, data_len)
+        }
+        152 => wire__crate__api__sessions_api__save_session_impl(port, ptr, rust_vec_len, data_len),
+        153 => wire__crate__api__workspace_api__save_tool_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        154 => wire__crate__api__knowledge_api__search_knowledge_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        155 => wire__crate__api__project_index_api__search_project_code_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        156 => {
+            wire__crate__api__agents_api__seed_preset_roles_impl(port, ptr, rust_vec_len, data_len)
+        }
+        157 => wire__crate__api__agent_workspace_api__seed_preset_workspaces_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        158 => wire__crate__api__agent_api__send_message_impl(port, ptr, rust_vec_len, data_len),
+        159 => {
+            wire__crate__api__agent_api__send_message_stream_impl(port, ptr, rust_vec_len, data_len)
+        }
+        160 => wire__crate__api__cron_api__set_cron_active_window_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        161 => wire__crate__api__cron_api__set_cron_auto_disable_after_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        162 => wire__crate__api__cron_api__set_cron_job_active_window_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        163 => {
+            wire__crate__api__cron_api__set_cron_job_chain_impl(port, ptr, rust_vec_len, data_len)
+        }
+        164 => wire__crate__api__cron_api__set_cron_job_delivery_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        165 => wire__crate__api__cron_api__set_cron_job_retry_policy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        166 => wire__crate__api__cron_api__set_cron_job_run_policy_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        116 => wire__crate__api__agent_api__respond_to_tool_approval_by_id_impl(
+        167 => {
+            wire__crate__api__cron_api__set_cron_job_sandbox_impl(port, ptr, rust_vec_len, data_len)
+        }
+        168 => wire__crate__api__cron_api__set_cron_job_timezone_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        117 => wire__crate__api__channel_runtime_api__restart_channel_listeners_impl(
+        169 => wire__crate__api__cron_api__set_cron_job_watch_debounce_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        118 => wire__crate__api__cron_api__resume_cron_job_impl(port, ptr, rust_vec_len, data_len),
-        119 => wire__crate__api__cron_api__run_cron_job_now_impl(port, ptr, rust_vec_len, data_len),
-        120 => wire__crate__api__workspace_api__save_channel_config_impl(
+        170 => wire__crate__api__cron_api__set_cron_max_concurrency_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        121 => wire__crate__api__config_api__save_config_impl(port, ptr, rust_vec_len, data_len),
-        122 => {
-            wire__crate__api__agent_api__save_config_to_disk_impl(port, ptr, rust_vec_len, data_len)
-        }
-        123 => {
-            wire__crate__api__proxy_api__save_proxy_to_disk_impl(port, ptr, rust_vec_len, data_len)
-        }
-        124 => wire__crate__api__sessions_api__save_session_impl(port, ptr, rust_vec_len, data_len),
-        125 => wire__crate__api__workspace_api__save_tool_config_impl(
+        171 => wire__crate__api__cron_api__set_cron_max_run_history_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        126 => wire__crate__api__knowledge_api__search_knowledge_impl(
+        172 => wire__crate__api__cron_api__set_cron_scheduler_enabled_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        127 => {
-            wire__crate__api__agents_api__seed_preset_roles_impl(port, ptr, rust_vec_len, data_len)
-        }
-        128 => wire__crate__api__agent_workspace_api__seed_preset_workspaces_impl(
+        173 => wire__crate__api__cron_api__set_cron_secret_impl(port, ptr, rust_vec_len, data_len),
+        174 => wire__crate__api__cron_api__set_cron_webhook_port_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        129 => wire__crate__api__agent_api__send_message_impl(port, ptr, rust_vec_len, data_len),
-        130 => {
-            wire__crate__api__agent_api__send_message_stream_impl(port, ptr, rust_vec_len, data_len)
-        }
-        131 => wire__crate__api__providers_api__set_default_profile_impl(
+        175 => wire__crate__api__providers_api__set_default_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        133 => wire__crate__api__mcp_api__set_mcp_enabled_impl(port, ptr, rust_vec_len, data_len),
-        134 => wire__crate__api__project_api__set_project_default_role_impl(
+        177 => wire__crate__api__mcp_api__set_mcp_enabled_impl(port, ptr, rust_vec_len, data_len),
+        178 => wire__crate__api__project_api__set_project_default_role_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        135 => wire__crate__api__agents_api__set_session_multi_agent_mode_impl(
+        179 => wire__crate__api__agents_api__set_session_multi_agent_mode_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        136 => wire__crate__api__workspace_api__set_tool_approval_impl(
+        180 => wire__crate__api__workspace_api__set_tool_approval_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        137 => wire__crate__api__channel_runtime_api__start_channel_listeners_impl(
+        181 => wire__crate__api__channel_runtime_api__start_channel_listeners_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        138 => {
+        182 => {
             wire__crate__api__cron_api__start_cron_scheduler_impl(port, ptr, rust_vec_len, data_len)
         }
-        139 => wire__crate__api__channel_runtime_api__stop_channel_listeners_impl(
+        183 => wire__crate__api__channel_runtime_api__stop_channel_listeners_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        140 => wire__crate__api__cron_notification_api__subscribe_cron_notifications_impl(
+        184 => {
+            wire__crate__api__cron_api__stop_cron_scheduler_impl(port, ptr, rust_vec_len, data_len)
+        }
+        185 => wire__crate__api__cron_notification_api__subscribe_cron_notifications_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        141 => wire__crate__api__providers_api__switch_active_model_impl(
+        186 => wire__crate__api__providers_api__switch_active_model_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        142 => wire__crate__api__agent_api__switch_session_impl(port, ptr, rust_vec_len, data_len),
-        143 => {
+        187 => wire__crate__api__agent_api__switch_session_impl(port, ptr, rust_vec_len, data_len),
+        188 => {
+            wire__crate__api__cron_api__test_cron_delivery_impl(port, ptr, rust_vec_len, data_len)
+        }
+        189 => {
             wire__crate__api__workspace_api__toggle_channel_impl(port, ptr, rust_vec_len, data_len)
         }
-        144 => wire__crate__api__mcp_api__toggle_mcp_server_impl(port, ptr, rust_vec_len, data_len),
-        145 => {
+        190 => wire__crate__api__mcp_api__toggle_mcp_server_impl(port, ptr, rust_vec_len, data_len),
+        191 => {
             wire__crate__api__skills_api__toggle_open_skills_impl(port, ptr, rust_vec_len, data_len)
         }
-        146 => wire__crate__api__agent_api__truncate_session_agent_history_impl(
+        192 => wire__crate__api__agent_api__truncate_session_agent_history_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        147 => wire__crate__api__agent_workspace_api__unbind_session_agent_impl(
+        193 => wire__crate__api__agent_workspace_api__unbind_session_agent_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        148 => wire__crate__api__workspace_api__update_agent_config_impl(
+        194 => wire__crate__api__project_git_api__undo_last_agent_changes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        149 => wire__crate__api__workspace_api__update_allowed_commands_impl(
+        195 => wire__crate__api__workspace_api__update_agent_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        150 => wire__crate__api__workspace_api__update_autonomy_level_impl(
+        196 => wire__crate__api__workspace_api__update_allowed_commands_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        151 => wire__crate__api__agent_api__update_config_impl(port, ptr, rust_vec_len, data_len),
-        152 => wire__crate__api__cron_api__update_cron_job_impl(port, ptr, rust_vec_len, data_len),
-        153 => wire__crate__api__routes_api__update_embedding_config_impl(
+        197 => wire__crate__api__workspace_api__update_autonomy_level_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        154 => wire__crate__api__workspace_api__update_feature_toggle_impl(
+        198 => wire__crate__api__agent_api__update_config_impl(port, ptr, rust_vec_len, data_len),
+        199 => wire__crate__api__cron_api__update_cron_job_impl(port, ptr, rust_vec_len, data_len),
+        200 => wire__crate__api__routes_api__update_embedding_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        155 => wire__crate__api__mcp_api__update_mcp_server_impl(port, ptr, rust_vec_len, data_len),
-        156 => wire__crate__api__project_api__update_project_context_impl(
+        201 => wire__crate__api__workspace_api__update_feature_toggle_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        157 => wire__crate__api__project_api__update_project_status_impl(
+        202 => wire__crate__api__mcp_api__update_mcp_server_impl(port, ptr, rust_vec_len, data_len),
+        203 => wire__crate__api__project_api__update_project_context_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        158 => wire__crate__api__skills_api__update_prompt_injection_mode_impl(
+        204 => wire__crate__api__project_api__update_project_status_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        159 => {
+        205 => wire__crate__api__skills_api__update_prompt_injection_mode_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        206 => {
             wire__crate__api__proxy_api__update_proxy_config_impl(port, ptr, rust_vec_len, data_len)
         }
-        160 => wire__crate__api__sessions_api__update_session_metadata_impl(
+        207 => wire__crate__api__sessions_api__update_session_metadata_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        161 => {
+        208 => {
             wire__crate__api__workspace_api__update_trust_me_impl(port, ptr, rust_vec_len, data_len)
         }
-        162 => wire__crate__api__agent_workspace_api__upsert_agent_workspace_impl(
+        209 => wire__crate__api__agent_workspace_api__upsert_agent_workspace_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        163 => wire__crate__api__agents_api__upsert_delegate_agent_impl(
+        210 => wire__crate__api__agents_api__upsert_delegate_agent_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        164 => wire__crate__api__routes_api__upsert_embedding_route_impl(
+        211 => wire__crate__api__routes_api__upsert_embedding_route_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        165 => wire__crate__api__providers_api__upsert_model_provider_profile_impl(
+        212 => wire__crate__api__providers_api__upsert_model_provider_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        166 => {
+        213 => {
             wire__crate__api__routes_api__upsert_model_route_impl(port, ptr, rust_vec_len, data_len)
         }
-        167 => {
+        214 => {
             wire__crate__api__project_api__upsert_project_impl(port, ptr, rust_vec_len, data_len)
         }
         _ => unreachable!(),
@@ -8897,37 +11565,37 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        18 => wire__crate__api__copilot_api__copilot_available_models_impl(
+        19 => wire__crate__api__copilot_api__copilot_available_models_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        24 => wire__crate__api__agent_api__create_session_impl(ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__agents_api__delegate_agent_count_impl(ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__llm_debug_api__get_llm_debug_log_path_impl(
+        26 => wire__crate__api__agent_api__create_session_impl(ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__agents_api__delegate_agent_count_impl(ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__llm_debug_api__get_llm_debug_log_path_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        55 => {
+        71 => {
             wire__crate__api__proxy_api__get_runtime_proxy_status_impl(ptr, rust_vec_len, data_len)
         }
-        67 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        75 => {
+        83 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        94 => {
             wire__crate__api__llm_debug_api__is_llm_debug_enabled_impl(ptr, rust_vec_len, data_len)
         }
-        88 => wire__crate__api__config_api__list_providers_impl(ptr, rust_vec_len, data_len),
-        89 => wire__crate__api__proxy_api__list_proxy_services_impl(ptr, rust_vec_len, data_len),
-        93 => wire__crate__api__agent_api__list_tools_impl(ptr, rust_vec_len, data_len),
-        96 => wire__crate__api__providers_api__model_provider_profile_count_impl(
+        111 => wire__crate__api__config_api__list_providers_impl(ptr, rust_vec_len, data_len),
+        112 => wire__crate__api__proxy_api__list_proxy_services_impl(ptr, rust_vec_len, data_len),
+        116 => wire__crate__api__agent_api__list_tools_impl(ptr, rust_vec_len, data_len),
+        119 => wire__crate__api__providers_api__model_provider_profile_count_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        132 => {
+        176 => {
             wire__crate__api__llm_debug_api__set_llm_debug_enabled_impl(ptr, rust_vec_len, data_len)
         }
-        168 => wire__crate__api__proxy_api__validate_proxy_url_impl(ptr, rust_vec_len, data_len),
+        215 => wire__crate__api__proxy_api__validate_proxy_url_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -9055,6 +11723,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::agent_api::AgentEvent>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_git_api::AgentUndoResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.restored.into_into_dart().into_dart(),
+            self.removed.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_git_api::AgentUndoResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_git_api::AgentUndoResult>
+    for crate::api::project_git_api::AgentUndoResult
+{
+    fn into_into_dart(self) -> crate::api::project_git_api::AgentUndoResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::agent_workspace_api::AgentWorkspaceDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9227,6 +11917,30 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::agent_api::ChatSessionInfo>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_index_api::CodeSearchHit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.start_line.into_into_dart().into_dart(),
+            self.end_line.into_into_dart().into_dart(),
+            self.snippet.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_index_api::CodeSearchHit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_index_api::CodeSearchHit>
+    for crate::api::project_index_api::CodeSearchHit
+{
+    fn into_into_dart(self) -> crate::api::project_index_api::CodeSearchHit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::copilot_api::CopilotAuthStatus {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9317,11 +12031,36 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::workspace_api::CostConfigDto>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronActiveWindowDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.quiet_start.into_into_dart().into_dart(),
+            self.quiet_end.into_into_dart().into_dart(),
+            self.active_days.into_into_dart().into_dart(),
+            self.holidays_ics.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronActiveWindowDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronActiveWindowDto>
+    for crate::api::cron_api::CronActiveWindowDto
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronActiveWindowDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronConfigDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.enabled.into_into_dart().into_dart(),
             self.max_run_history.into_into_dart().into_dart(),
+            self.max_concurrency.into_into_dart().into_dart(),
+            self.auto_disable_after.into_into_dart().into_dart(),
             self.total_jobs.into_into_dart().into_dart(),
             self.active_jobs.into_into_dart().into_dart(),
             self.paused_jobs.into_into_dart().into_dart(),
@@ -9341,6 +12080,119 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronConfigDto>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronDeliveryDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.channel.into_into_dart().into_dart(),
+            self.to.into_into_dart().into_dart(),
+            self.on_success.into_into_dart().into_dart(),
+            self.on_failure.into_into_dart().into_dart(),
+            self.template.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronDeliveryDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronDeliveryDto>
+    for crate::api::cron_api::CronDeliveryDto
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronDeliveryDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronEnvVarDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.secret.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronEnvVarDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronEnvVarDto>
+    for crate::api::cron_api::CronEnvVarDto
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronEnvVarDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronExecutingJob {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.job_id.into_into_dart().into_dart(),
+            self.job_name.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.runs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronExecutingJob
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronExecutingJob>
+    for crate::api::cron_api::CronExecutingJob
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronExecutingJob {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronImportChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+            self.changed_fields.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronImportChange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronImportChange>
+    for crate::api::cron_api::CronImportChange
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronImportChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronImportResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.errors.into_into_dart().into_dart(),
+            self.applied.into_into_dart().into_dart(),
+            self.changes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronImportResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronImportResult>
+    for crate::api::cron_api::CronImportResult
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronImportResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronJobDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9349,6 +12201,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronJobDto {
             self.expression.into_into_dart().into_dart(),
             self.schedule_type.into_into_dart().into_dart(),
             self.schedule_display.into_into_dart().into_dart(),
+            self.timezone.into_into_dart().into_dart(),
             self.command.into_into_dart().into_dart(),
             self.prompt.into_into_dart().into_dart(),
             self.job_type.into_into_dart().into_dart(),
@@ -9356,80 +12209,242 @@ impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronJobDto {
             self.target_session_id.into_into_dart().into_dart(),
             self.model.into_into_dart().into_dart(),
             self.enabled.into_into_dart().into_dart(),
-            self.delete_after_run.into_into_dart().into_dart(),
-            self.project_id.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.next_run.into_into_dart().into_dart(),
-            self.last_run.into_into_dart().into_dart(),
-            self.last_status.into_into_dart().into_dart(),
-            self.last_output.into_into_dart().into_dart(),
+            self.delete_after_run.into_into_dart().into_dart(),
+            self.project_id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.next_run.into_into_dart().into_dart(),
+            self.last_run.into_into_dart().into_dart(),
+            self.last_status.into_into_dart().into_dart(),
+            self.last_output.into_into_dart().into_dart(),
+            self.misfire_policy.into_into_dart().into_dart(),
+            self.no_overlap.into_into_dart().into_dart(),
+            self.timeout_secs.into_into_dart().into_dart(),
+            self.max_retries.into_into_dart().into_dart(),
+            self.retry_backoff_secs.into_into_dart().into_dart(),
+            self.on_success.into_into_dart().into_dart(),
+            self.on_failure.into_into_dart().into_dart(),
+            self.delivery.into_into_dart().into_dart(),
+            self.active_window.into_into_dart().into_dart(),
+            self.sandbox.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronJobDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronJobDto>
+    for crate::api::cron_api::CronJobDto
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronJobDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronJobStatsDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.job_id.into_into_dart().into_dart(),
+            self.total_runs.into_into_dart().into_dart(),
+            self.succeeded.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+            self.success_rate.into_into_dart().into_dart(),
+            self.p50_duration_ms.into_into_dart().into_dart(),
+            self.p95_duration_ms.into_into_dart().into_dart(),
+            self.consecutive_failures.into_into_dart().into_dart(),
+            self.last_success.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronJobStatsDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronJobStatsDto>
+    for crate::api::cron_api::CronJobStatsDto
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronJobStatsDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_notification_api::CronNotification {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.job_id.into_into_dart().into_dart(),
+            self.job_name.into_into_dart().into_dart(),
+            self.job_type.into_into_dart().into_dart(),
+            self.session_target.into_into_dart().into_dart(),
+            self.target_session_id.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.output.into_into_dart().into_dart(),
+            self.prompt.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.finished_at.into_into_dart().into_dart(),
+            self.auto_disabled.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_notification_api::CronNotification
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_notification_api::CronNotification>
+    for crate::api::cron_notification_api::CronNotification
+{
+    fn into_into_dart(self) -> crate::api::cron_notification_api::CronNotification {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronRunDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.job_id.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.finished_at.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.output.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.attempt.into_into_dart().into_dart(),
+            self.exit_code.into_into_dart().into_dart(),
+            self.stdout_len.into_into_dart().into_dart(),
+            self.stderr_len.into_into_dart().into_dart(),
+            self.stdout_truncated.into_into_dart().into_dart(),
+            self.stderr_truncated.into_into_dart().into_dart(),
+            self.chain_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronRunDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronRunDto>
+    for crate::api::cron_api::CronRunDto
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronRunDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronRunOutput {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.stdout.into_into_dart().into_dart(),
+            self.stderr.into_into_dart().into_dart(),
+            self.stdout_len.into_into_dart().into_dart(),
+            self.stderr_len.into_into_dart().into_dart(),
+            self.stdout_truncated.into_into_dart().into_dart(),
+            self.stderr_truncated.into_into_dart().into_dart(),
+            self.exit_code.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronRunOutput
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronRunOutput>
+    for crate::api::cron_api::CronRunOutput
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronRunOutput {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronSchedulePreview {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.error.into_into_dart().into_dart(),
+            self.fire_times.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::cron_api::CronSchedulePreview
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronSchedulePreview>
+    for crate::api::cron_api::CronSchedulePreview
+{
+    fn into_into_dart(self) -> crate::api::cron_api::CronSchedulePreview {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronSchedulerStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.running.into_into_dart().into_dart(),
+            self.last_tick.into_into_dart().into_dart(),
+            self.executing.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::cron_api::CronJobDto
+    for crate::api::cron_api::CronSchedulerStatus
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronJobDto>
-    for crate::api::cron_api::CronJobDto
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronSchedulerStatus>
+    for crate::api::cron_api::CronSchedulerStatus
 {
-    fn into_into_dart(self) -> crate::api::cron_api::CronJobDto {
+    fn into_into_dart(self) -> crate::api::cron_api::CronSchedulerStatus {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::cron_notification_api::CronNotification {
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronShellSandboxDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.job_id.into_into_dart().into_dart(),
-            self.job_name.into_into_dart().into_dart(),
-            self.job_type.into_into_dart().into_dart(),
-            self.session_target.into_into_dart().into_dart(),
-            self.target_session_id.into_into_dart().into_dart(),
-            self.status.into_into_dart().into_dart(),
-            self.output.into_into_dart().into_dart(),
-            self.prompt.into_into_dart().into_dart(),
-            self.duration_ms.into_into_dart().into_dart(),
-            self.finished_at.into_into_dart().into_dart(),
+            self.working_dir.into_into_dart().into_dart(),
+            self.inherit_env.into_into_dart().into_dart(),
+            self.env.into_into_dart().into_dart(),
+            self.cpu_limit_secs.into_into_dart().into_dart(),
+            self.memory_limit_mb.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::cron_notification_api::CronNotification
+    for crate::api::cron_api::CronShellSandboxDto
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_notification_api::CronNotification>
-    for crate::api::cron_notification_api::CronNotification
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronShellSandboxDto>
+    for crate::api::cron_api::CronShellSandboxDto
 {
-    fn into_into_dart(self) -> crate::api::cron_notification_api::CronNotification {
+    fn into_into_dart(self) -> crate::api::cron_api::CronShellSandboxDto {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronRunDto {
+impl flutter_rust_bridge::IntoDart for crate::api::cron_api::CronWebhookInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.job_id.into_into_dart().into_dart(),
-            self.started_at.into_into_dart().into_dart(),
-            self.finished_at.into_into_dart().into_dart(),
-            self.status.into_into_dart().into_dart(),
-            self.output.into_into_dart().into_dart(),
-            self.duration_ms.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.secret.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::cron_api::CronRunDto
+    for crate::api::cron_api::CronWebhookInfo
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronRunDto>
-    for crate::api::cron_api::CronRunDto
+impl flutter_rust_bridge::IntoIntoDart<crate::api::cron_api::CronWebhookInfo>
+    for crate::api::cron_api::CronWebhookInfo
 {
-    fn into_into_dart(self) -> crate::api::cron_api::CronRunDto {
+    fn into_into_dart(self) -> crate::api::cron_api::CronWebhookInfo {
         self
     }
 }
@@ -9548,6 +12563,29 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::workspace_api::FeatureToggles
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_git_api::GitCommitDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hash.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+            self.author.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_git_api::GitCommitDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_git_api::GitCommitDto>
+    for crate::api::project_git_api::GitCommitDto
+{
+    fn into_into_dart(self) -> crate::api::project_git_api::GitCommitDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::mcp_api::KeyValueDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9826,6 +12864,57 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::routes_api::ModelRouteDto>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_api::ProjectAutonomyDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.level.into_into_dart().into_dart(),
+            self.trust_me.into_into_dart().into_dart(),
+            self.allowed_commands.into_into_dart().into_dart(),
+            self.forbidden_paths.into_into_dart().into_dart(),
+            self.auto_approve.into_into_dart().into_dart(),
+            self.always_ask.into_into_dart().into_dart(),
+            self.enabled_tools.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_api::ProjectAutonomyDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_api::ProjectAutonomyDto>
+    for crate::api::project_api::ProjectAutonomyDto
+{
+    fn into_into_dart(self) -> crate::api::project_api::ProjectAutonomyDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_index_api::ProjectCodeIndexStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.indexing.into_into_dart().into_dart(),
+            self.files.into_into_dart().into_dart(),
+            self.chunks.into_into_dart().into_dart(),
+            self.embedded_chunks.into_into_dart().into_dart(),
+            self.last_indexed_at.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_index_api::ProjectCodeIndexStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_index_api::ProjectCodeIndexStatus>
+    for crate::api::project_index_api::ProjectCodeIndexStatus
+{
+    fn into_into_dart(self) -> crate::api::project_index_api::ProjectCodeIndexStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::project_api::ProjectDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9842,6 +12931,11 @@ impl flutter_rust_bridge::IntoDart for crate::api::project_api::ProjectDto {
             self.default_role_id.into_into_dart().into_dart(),
             self.session_ids.into_into_dart().into_dart(),
             self.tags.into_into_dart().into_dart(),
+            self.global_memory_fallback.into_into_dart().into_dart(),
+            self.autonomy.into_into_dart().into_dart(),
+            self.model_profile_id.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.temperature.into_into_dart().into_dart(),
             self.created_at.into_into_dart().into_dart(),
             self.updated_at.into_into_dart().into_dart(),
         ]
@@ -9860,6 +12954,74 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::project_api::ProjectDto>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_git_api::ProjectGitStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.branch.into_into_dart().into_dart(),
+            self.dirty.into_into_dart().into_dart(),
+            self.changed_files.into_into_dart().into_dart(),
+            self.recent_commits.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_git_api::ProjectGitStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_git_api::ProjectGitStatus>
+    for crate::api::project_git_api::ProjectGitStatus
+{
+    fn into_into_dart(self) -> crate::api::project_git_api::ProjectGitStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_bundle_api::ProjectImportResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.project_id.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+            self.notes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_bundle_api::ProjectImportResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_bundle_api::ProjectImportResult>
+    for crate::api::project_bundle_api::ProjectImportResult
+{
+    fn into_into_dart(self) -> crate::api::project_bundle_api::ProjectImportResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_api::ProjectSessionReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.migrated_sessions.into_into_dart().into_dart(),
+            self.missing_sessions.into_into_dart().into_dart(),
+            self.duplicate_sessions.into_into_dart().into_dart(),
+            self.repaired.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_api::ProjectSessionReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_api::ProjectSessionReport>
+    for crate::api::project_api::ProjectSessionReport
+{
+    fn into_into_dart(self) -> crate::api::project_api::ProjectSessionReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::project_api::ProjectStatus {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -9916,6 +13078,82 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::project_api::ProjectSummary>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_template_api::ProjectTemplateDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.icon.into_into_dart().into_dart(),
+            self.color_tag.into_into_dart().into_dart(),
+            self.project_type.into_into_dart().into_dart(),
+            self.is_builtin.into_into_dart().into_dart(),
+            self.role_ids.into_into_dart().into_dart(),
+            self.cron_job_count.into_into_dart().into_dart(),
+            self.knowledge_count.into_into_dart().into_dart(),
+            self.file_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_template_api::ProjectTemplateDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_template_api::ProjectTemplateDto>
+    for crate::api::project_template_api::ProjectTemplateDto
+{
+    fn into_into_dart(self) -> crate::api::project_template_api::ProjectTemplateDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_timeline_api::ProjectTimelineEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.timestamp.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+            self.ref_id.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_timeline_api::ProjectTimelineEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_timeline_api::ProjectTimelineEvent>
+    for crate::api::project_timeline_api::ProjectTimelineEvent
+{
+    fn into_into_dart(self) -> crate::api::project_timeline_api::ProjectTimelineEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project_timeline_api::ProjectTimelinePage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.events.into_into_dart().into_dart(),
+            self.next_cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project_timeline_api::ProjectTimelinePage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project_timeline_api::ProjectTimelinePage>
+    for crate::api::project_timeline_api::ProjectTimelinePage
+{
+    fn into_into_dart(self) -> crate::api::project_timeline_api::ProjectTimelinePage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::project_api::ProjectType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -10484,6 +13722,15 @@ impl SseEncode for crate::api::agent_api::AgentEvent {
     }
 }
 
+impl SseEncode for crate::api::project_git_api::AgentUndoResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.restored, serializer);
+        <Vec<String>>::sse_encode(self.removed, serializer);
+        <Vec<String>>::sse_encode(self.errors, serializer);
+    }
+}
+
 impl SseEncode for crate::api::agent_workspace_api::AgentWorkspaceDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10583,6 +13830,17 @@ impl SseEncode for crate::api::agent_api::ChatSessionInfo {
     }
 }
 
+impl SseEncode for crate::api::project_index_api::CodeSearchHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <u32>::sse_encode(self.start_line, serializer);
+        <u32>::sse_encode(self.end_line, serializer);
+        <String>::sse_encode(self.snippet, serializer);
+        <f64>::sse_encode(self.score, serializer);
+    }
+}
+
 impl SseEncode for crate::api::copilot_api::CopilotAuthStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10595,40 +13853,100 @@ impl SseEncode for crate::api::copilot_api::CopilotAuthStatus {
 impl SseEncode for crate::api::copilot_api::CopilotDeviceFlowInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.user_code, serializer);
-        <String>::sse_encode(self.verification_uri, serializer);
-        <String>::sse_encode(self.device_code, serializer);
-        <u64>::sse_encode(self.interval, serializer);
-        <u64>::sse_encode(self.expires_in, serializer);
+        <String>::sse_encode(self.user_code, serializer);
+        <String>::sse_encode(self.verification_uri, serializer);
+        <String>::sse_encode(self.device_code, serializer);
+        <u64>::sse_encode(self.interval, serializer);
+        <u64>::sse_encode(self.expires_in, serializer);
+    }
+}
+
+impl SseEncode for crate::api::copilot_api::CopilotPollResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.status, serializer);
+        <Option<String>>::sse_encode(self.username, serializer);
+    }
+}
+
+impl SseEncode for crate::api::workspace_api::CostConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <f64>::sse_encode(self.daily_limit_usd, serializer);
+        <f64>::sse_encode(self.monthly_limit_usd, serializer);
+        <u8>::sse_encode(self.warn_at_percent, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronActiveWindowDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.quiet_start, serializer);
+        <String>::sse_encode(self.quiet_end, serializer);
+        <Vec<u8>>::sse_encode(self.active_days, serializer);
+        <String>::sse_encode(self.holidays_ics, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <u32>::sse_encode(self.max_run_history, serializer);
+        <u32>::sse_encode(self.max_concurrency, serializer);
+        <u32>::sse_encode(self.auto_disable_after, serializer);
+        <u32>::sse_encode(self.total_jobs, serializer);
+        <u32>::sse_encode(self.active_jobs, serializer);
+        <u32>::sse_encode(self.paused_jobs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronDeliveryDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.channel, serializer);
+        <String>::sse_encode(self.to, serializer);
+        <bool>::sse_encode(self.on_success, serializer);
+        <bool>::sse_encode(self.on_failure, serializer);
+        <String>::sse_encode(self.template, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronEnvVarDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.value, serializer);
+        <bool>::sse_encode(self.secret, serializer);
     }
 }
 
-impl SseEncode for crate::api::copilot_api::CopilotPollResult {
+impl SseEncode for crate::api::cron_api::CronExecutingJob {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.status, serializer);
-        <Option<String>>::sse_encode(self.username, serializer);
+        <String>::sse_encode(self.job_id, serializer);
+        <String>::sse_encode(self.job_name, serializer);
+        <i64>::sse_encode(self.started_at, serializer);
+        <u32>::sse_encode(self.runs, serializer);
     }
 }
 
-impl SseEncode for crate::api::workspace_api::CostConfigDto {
+impl SseEncode for crate::api::cron_api::CronImportChange {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.enabled, serializer);
-        <f64>::sse_encode(self.daily_limit_usd, serializer);
-        <f64>::sse_encode(self.monthly_limit_usd, serializer);
-        <u8>::sse_encode(self.warn_at_percent, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.action, serializer);
+        <Vec<String>>::sse_encode(self.changed_fields, serializer);
     }
 }
 
-impl SseEncode for crate::api::cron_api::CronConfigDto {
+impl SseEncode for crate::api::cron_api::CronImportResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.enabled, serializer);
-        <u32>::sse_encode(self.max_run_history, serializer);
-        <u32>::sse_encode(self.total_jobs, serializer);
-        <u32>::sse_encode(self.active_jobs, serializer);
-        <u32>::sse_encode(self.paused_jobs, serializer);
+        <Vec<String>>::sse_encode(self.errors, serializer);
+        <bool>::sse_encode(self.applied, serializer);
+        <Vec<crate::api::cron_api::CronImportChange>>::sse_encode(self.changes, serializer);
     }
 }
 
@@ -10640,6 +13958,7 @@ impl SseEncode for crate::api::cron_api::CronJobDto {
         <String>::sse_encode(self.expression, serializer);
         <String>::sse_encode(self.schedule_type, serializer);
         <String>::sse_encode(self.schedule_display, serializer);
+        <String>::sse_encode(self.timezone, serializer);
         <String>::sse_encode(self.command, serializer);
         <String>::sse_encode(self.prompt, serializer);
         <String>::sse_encode(self.job_type, serializer);
@@ -10654,6 +13973,34 @@ impl SseEncode for crate::api::cron_api::CronJobDto {
         <Option<i64>>::sse_encode(self.last_run, serializer);
         <String>::sse_encode(self.last_status, serializer);
         <String>::sse_encode(self.last_output, serializer);
+        <String>::sse_encode(self.misfire_policy, serializer);
+        <bool>::sse_encode(self.no_overlap, serializer);
+        <u32>::sse_encode(self.timeout_secs, serializer);
+        <u32>::sse_encode(self.max_retries, serializer);
+        <u32>::sse_encode(self.retry_backoff_secs, serializer);
+        <String>::sse_encode(self.on_success, serializer);
+        <String>::sse_encode(self.on_failure, serializer);
+        <Vec<crate::api::cron_api::CronDeliveryDto>>::sse_encode(self.delivery, serializer);
+        <Option<crate::api::cron_api::CronActiveWindowDto>>::sse_encode(
+            self.active_window,
+            serializer,
+        );
+        <crate::api::cron_api::CronShellSandboxDto>::sse_encode(self.sandbox, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronJobStatsDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.job_id, serializer);
+        <u32>::sse_encode(self.total_runs, serializer);
+        <u32>::sse_encode(self.succeeded, serializer);
+        <u32>::sse_encode(self.failed, serializer);
+        <f64>::sse_encode(self.success_rate, serializer);
+        <i64>::sse_encode(self.p50_duration_ms, serializer);
+        <i64>::sse_encode(self.p95_duration_ms, serializer);
+        <u32>::sse_encode(self.consecutive_failures, serializer);
+        <Option<i64>>::sse_encode(self.last_success, serializer);
     }
 }
 
@@ -10670,6 +14017,7 @@ impl SseEncode for crate::api::cron_notification_api::CronNotification {
         <String>::sse_encode(self.prompt, serializer);
         <i64>::sse_encode(self.duration_ms, serializer);
         <i64>::sse_encode(self.finished_at, serializer);
+        <bool>::sse_encode(self.auto_disabled, serializer);
     }
 }
 
@@ -10683,6 +14031,64 @@ impl SseEncode for crate::api::cron_api::CronRunDto {
         <String>::sse_encode(self.status, serializer);
         <String>::sse_encode(self.output, serializer);
         <i64>::sse_encode(self.duration_ms, serializer);
+        <u32>::sse_encode(self.attempt, serializer);
+        <Option<i32>>::sse_encode(self.exit_code, serializer);
+        <u64>::sse_encode(self.stdout_len, serializer);
+        <u64>::sse_encode(self.stderr_len, serializer);
+        <bool>::sse_encode(self.stdout_truncated, serializer);
+        <bool>::sse_encode(self.stderr_truncated, serializer);
+        <String>::sse_encode(self.chain_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronRunOutput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.run_id, serializer);
+        <String>::sse_encode(self.stdout, serializer);
+        <String>::sse_encode(self.stderr, serializer);
+        <u64>::sse_encode(self.stdout_len, serializer);
+        <u64>::sse_encode(self.stderr_len, serializer);
+        <bool>::sse_encode(self.stdout_truncated, serializer);
+        <bool>::sse_encode(self.stderr_truncated, serializer);
+        <Option<i32>>::sse_encode(self.exit_code, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronSchedulePreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.error, serializer);
+        <Vec<i64>>::sse_encode(self.fire_times, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronSchedulerStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <bool>::sse_encode(self.running, serializer);
+        <Option<i64>>::sse_encode(self.last_tick, serializer);
+        <Vec<crate::api::cron_api::CronExecutingJob>>::sse_encode(self.executing, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronShellSandboxDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.working_dir, serializer);
+        <bool>::sse_encode(self.inherit_env, serializer);
+        <Vec<crate::api::cron_api::CronEnvVarDto>>::sse_encode(self.env, serializer);
+        <u64>::sse_encode(self.cpu_limit_secs, serializer);
+        <u64>::sse_encode(self.memory_limit_mb, serializer);
+    }
+}
+
+impl SseEncode for crate::api::cron_api::CronWebhookInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.secret, serializer);
     }
 }
 
@@ -10755,6 +14161,16 @@ impl SseEncode for crate::api::workspace_api::FeatureToggles {
     }
 }
 
+impl SseEncode for crate::api::project_git_api::GitCommitDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.hash, serializer);
+        <String>::sse_encode(self.summary, serializer);
+        <String>::sse_encode(self.author, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10850,6 +14266,56 @@ impl SseEncode for Vec<crate::api::workspace_api::ChannelSummary> {
     }
 }
 
+impl SseEncode for Vec<crate::api::project_index_api::CodeSearchHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::project_index_api::CodeSearchHit>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::cron_api::CronDeliveryDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cron_api::CronDeliveryDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::cron_api::CronEnvVarDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cron_api::CronEnvVarDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::cron_api::CronExecutingJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cron_api::CronExecutingJob>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::cron_api::CronImportChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::cron_api::CronImportChange>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::cron_api::CronJobDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10890,6 +14356,16 @@ impl SseEncode for Vec<crate::api::routes_api::EmbeddingRouteDto> {
     }
 }
 
+impl SseEncode for Vec<crate::api::project_git_api::GitCommitDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::project_git_api::GitCommitDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::mcp_api::KeyValueDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10970,6 +14446,16 @@ impl SseEncode for Vec<crate::api::routes_api::ModelRouteDto> {
     }
 }
 
+impl SseEncode for Vec<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i64>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10990,6 +14476,26 @@ impl SseEncode for Vec<crate::api::project_api::ProjectSummary> {
     }
 }
 
+impl SseEncode for Vec<crate::api::project_template_api::ProjectTemplateDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::project_template_api::ProjectTemplateDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::project_timeline_api::ProjectTimelineEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::project_timeline_api::ProjectTimelineEvent>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::config_api::ProviderInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -11215,6 +14721,46 @@ impl SseEncode for Option<bool> {
     }
 }
 
+impl SseEncode for Option<crate::api::cron_api::CronActiveWindowDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cron_api::CronActiveWindowDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::cron_api::CronJobStatsDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cron_api::CronJobStatsDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::cron_api::CronRunOutput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cron_api::CronRunOutput>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::cron_api::CronWebhookInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::cron_api::CronWebhookInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::agents_api::DelegateAgentDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -11235,6 +14781,16 @@ impl SseEncode for Option<f64> {
     }
 }
 
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -11275,6 +14831,16 @@ impl SseEncode for Option<crate::api::project_api::ProjectDto> {
     }
 }
 
+impl SseEncode for Option<crate::api::project_git_api::ProjectGitStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::project_git_api::ProjectGitStatus>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::sessions_api::SessionDetail> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -11305,6 +14871,31 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for crate::api::project_api::ProjectAutonomyDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.level, serializer);
+        <Option<bool>>::sse_encode(self.trust_me, serializer);
+        <Vec<String>>::sse_encode(self.allowed_commands, serializer);
+        <Vec<String>>::sse_encode(self.forbidden_paths, serializer);
+        <Vec<String>>::sse_encode(self.auto_approve, serializer);
+        <Vec<String>>::sse_encode(self.always_ask, serializer);
+        <Vec<String>>::sse_encode(self.enabled_tools, serializer);
+    }
+}
+
+impl SseEncode for crate::api::project_index_api::ProjectCodeIndexStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.indexing, serializer);
+        <u32>::sse_encode(self.files, serializer);
+        <u32>::sse_encode(self.chunks, serializer);
+        <u32>::sse_encode(self.embedded_chunks, serializer);
+        <Option<i64>>::sse_encode(self.last_indexed_at, serializer);
+        <String>::sse_encode(self.last_error, serializer);
+    }
+}
+
 impl SseEncode for crate::api::project_api::ProjectDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -11321,11 +14912,48 @@ impl SseEncode for crate::api::project_api::ProjectDto {
         <String>::sse_encode(self.default_role_id, serializer);
         <Vec<String>>::sse_encode(self.session_ids, serializer);
         <Vec<String>>::sse_encode(self.tags, serializer);
+        <bool>::sse_encode(self.global_memory_fallback, serializer);
+        <crate::api::project_api::ProjectAutonomyDto>::sse_encode(self.autonomy, serializer);
+        <String>::sse_encode(self.model_profile_id, serializer);
+        <String>::sse_encode(self.model, serializer);
+        <Option<f64>>::sse_encode(self.temperature, serializer);
         <i64>::sse_encode(self.created_at, serializer);
         <i64>::sse_encode(self.updated_at, serializer);
     }
 }
 
+impl SseEncode for crate::api::project_git_api::ProjectGitStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.branch, serializer);
+        <bool>::sse_encode(self.dirty, serializer);
+        <u32>::sse_encode(self.changed_files, serializer);
+        <Vec<crate::api::project_git_api::GitCommitDto>>::sse_encode(
+            self.recent_commits,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::project_bundle_api::ProjectImportResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.project_id, serializer);
+        <Vec<String>>::sse_encode(self.errors, serializer);
+        <Vec<String>>::sse_encode(self.notes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::project_api::ProjectSessionReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.migrated_sessions, serializer);
+        <Vec<String>>::sse_encode(self.missing_sessions, serializer);
+        <Vec<String>>::sse_encode(self.duplicate_sessions, serializer);
+        <bool>::sse_encode(self.repaired, serializer);
+    }
+}
+
 impl SseEncode for crate::api::project_api::ProjectStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -11364,6 +14992,46 @@ impl SseEncode for crate::api::project_api::ProjectSummary {
     }
 }
 
+impl SseEncode for crate::api::project_template_api::ProjectTemplateDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <String>::sse_encode(self.icon, serializer);
+        <String>::sse_encode(self.color_tag, serializer);
+        <String>::sse_encode(self.project_type, serializer);
+        <bool>::sse_encode(self.is_builtin, serializer);
+        <Vec<String>>::sse_encode(self.role_ids, serializer);
+        <u32>::sse_encode(self.cron_job_count, serializer);
+        <u32>::sse_encode(self.knowledge_count, serializer);
+        <u32>::sse_encode(self.file_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::project_timeline_api::ProjectTimelineEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.timestamp, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.detail, serializer);
+        <String>::sse_encode(self.ref_id, serializer);
+        <String>::sse_encode(self.status, serializer);
+    }
+}
+
+impl SseEncode for crate::api::project_timeline_api::ProjectTimelinePage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::project_timeline_api::ProjectTimelineEvent>>::sse_encode(
+            self.events,
+            serializer,
+        );
+        <Option<i64>>::sse_encode(self.next_cursor, serializer);
+    }
+}
+
 impl SseEncode for crate::api::project_api::ProjectType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -11584,6 +15252,13 @@ impl SseEncode for crate::api::agent_api::ToolSpecDto {
     }
 }
 
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {